@@ -62,10 +62,138 @@ Interaction interactionFromYaml({required String yaml}) =>
 String objectiveToYaml({required Objective objective}) =>
     RustLib.instance.api.crateApiObjectiveToYaml(objective: objective);
 
+/// Record that an objective or its progress changed just now.
+///
+/// Call this on an edit before saving; `objective_to_yaml` keeps `updated`
+/// as it is so saving alone does not reset pulse reminders.
+Objective touchObjective({required Objective objective}) =>
+    RustLib.instance.api.crateApiTouchObjective(objective: objective);
+
 /// Parse objective from YAML string.
 Objective objectiveFromYaml({required String yaml}) =>
     RustLib.instance.api.crateApiObjectiveFromYaml(yaml: yaml);
 
+/// Normalise an email address for comparison and storage.
+String normalizeEmail({required String email}) =>
+    RustLib.instance.api.crateApiNormalizeEmail(email: email);
+
+/// Verify a member's pincode in the team at `root`.
+///
+/// Failed attempts are throttled per member. While throttled the pincode
+/// is not checked and `retry_after_secs` says how long to wait.
+Future<PincodeCheck> verifyMemberPincode(
+        {required String root,
+        required String email,
+        required String pincode}) =>
+    RustLib.instance.api.crateApiVerifyMemberPincode(
+        root: root, email: email, pincode: pincode);
+
+/// Seconds a member has to wait before their next pincode attempt (0 if none).
+Future<BigInt> pincodeRetryAfterSecs(
+        {required String root, required String email}) =>
+    RustLib.instance.api
+        .crateApiPincodeRetryAfterSecs(root: root, email: email);
+
+/// Whether a shell hook may run on this machine for the team at `root`.
+Future<bool> shellHookApproved(
+        {required String root, required ShellHook hook}) =>
+    RustLib.instance.api.crateApiShellHookApproved(root: root, hook: hook);
+
+/// Let a shell hook run on this machine for the team at `root`.
+///
+/// The approval is for this exact command; a changed one has to be
+/// approved again.
+Future<void> approveShellHook(
+        {required String root, required ShellHook hook}) =>
+    RustLib.instance.api.crateApiApproveShellHook(root: root, hook: hook);
+
+/// Load the manifesto of the team at `root`, if it has one.
+///
+/// Legacy plain-text manifestos come back as a preamble without principles.
+Future<Manifesto?> loadTeamManifesto({required String root}) =>
+    RustLib.instance.api.crateApiLoadTeamManifesto(root: root);
+
+/// Count, per manifesto principle, the kudos that named it, in manifesto order.
+Future<List<PrincipleRecognition>> principleReport({required String root}) =>
+    RustLib.instance.api.crateApiPrincipleReport(root: root);
+
+/// Sent and received counts for everyone in the team at `root`.
+Future<List<MemberInteractionStats>> memberInteractionStats(
+        {required String root}) =>
+    RustLib.instance.api.crateApiMemberInteractionStats(root: root);
+
+/// Who appreciates whom in the team at `root`, most frequent first.
+Future<List<AppreciationEdge>> appreciationGraph({required String root}) =>
+    RustLib.instance.api.crateApiAppreciationGraph(root: root);
+
+/// Share (0.0 to 1.0) of appreciating pairs where it goes both ways, if any.
+Future<double?> appreciationReciprocity({required String root}) =>
+    RustLib.instance.api.crateApiAppreciationReciprocity(root: root);
+
+/// Team members nobody has appreciated in the last `weeks` weeks.
+Future<List<String>> unrecognisedMembers(
+        {required String root, required int weeks}) =>
+    RustLib.instance.api.crateApiUnrecognisedMembers(root: root, weeks: weeks);
+
+/// Interactions per week for the last `weeks` weeks, oldest first.
+///
+/// Weeks start on Monday in `timezone` (an IANA name), or UTC if not given.
+Future<List<WeekActivity>> weeklyActivity(
+        {required String root, required int weeks, String? timezone}) =>
+    RustLib.instance.api
+        .crateApiWeeklyActivity(root: root, weeks: weeks, timezone: timezone);
+
+/// Export interactions and objectives from the team at `root`.
+///
+/// `format` is csv, json or md. `kinds` is a comma-separated filter such as
+/// "kudos,feedback,okr" and `since` a YYYY-MM-DD date. Private items are
+/// only included when `email` and `pincode` authenticate their owner.
+Future<String> exportTeamData(
+        {required String root,
+        required String format,
+        String? kinds,
+        String? since,
+        String? email,
+        String? pincode}) =>
+    RustLib.instance.api.crateApiExportTeamData(
+        root: root,
+        format: format,
+        kinds: kinds,
+        since: since,
+        email: email,
+        pincode: pincode);
+
+/// Pulse prompts due now for the member with `email` in the team at `root`.
+Future<List<DuePulsePrompt>> duePulsePrompts(
+        {required String root, required String email}) =>
+    RustLib.instance.api.crateApiDuePulsePrompts(root: root, email: email);
+
+/// Announce an interaction the app has just saved to the team at `root`.
+///
+/// Runs the team's webhooks and shell hooks if the interaction is shared, and
+/// queues the event for [`take_team_events`]. Returns the hooks that failed;
+/// the interaction stays saved either way.
+Future<List<String>> announceInteraction(
+        {required String root, required Interaction interaction}) =>
+    RustLib.instance.api
+        .crateApiAnnounceInteraction(root: root, interaction: interaction);
+
+/// Take the events announced since the last call, oldest first.
+///
+/// The app polls this to show changes as they happen.
+Future<List<TeamEvent>> takeTeamEvents() =>
+    RustLib.instance.api.crateApiTakeTeamEvents();
+
+/// Appreciations from one member to another.
+@freezed
+sealed class AppreciationEdge with _$AppreciationEdge {
+  const factory AppreciationEdge({
+    required String from,
+    required String to,
+    required int count,
+  }) = _AppreciationEdge;
+}
+
 /// Configuration for backups.
 @freezed
 sealed class BackupConfig with _$BackupConfig {
@@ -97,6 +225,31 @@ sealed class Credentials with _$Credentials {
       .crateApiCredentialsVerify(that: this, pincode: pincode);
 }
 
+/// A webhook posting to any URL with a configurable payload.
+@freezed
+sealed class CustomWebhook with _$CustomWebhook {
+  const factory CustomWebhook({
+    String? name,
+    required String url,
+    String? template,
+    String? contentType,
+    required List<WebhookHeader> headers,
+    String? secret,
+    String? signatureHeader,
+    required List<String> events,
+  }) = _CustomWebhook;
+}
+
+/// A pulse prompt that is due for a member.
+@freezed
+sealed class DuePulsePrompt with _$DuePulsePrompt {
+  const factory DuePulsePrompt({
+    required String prompt,
+    required String message,
+    String? objectiveId,
+  }) = _DuePulsePrompt;
+}
+
 /// A logged interaction between people.
 @freezed
 sealed class Interaction with _$Interaction {
@@ -109,6 +262,8 @@ sealed class Interaction with _$Interaction {
     required String note,
     required String timestamp,
     required bool shared,
+    required List<String> principles,
+    String? importedFrom,
   }) = _Interaction;
 
   /// Create an appreciation interaction.
@@ -189,6 +344,15 @@ sealed class LintingConfig with _$LintingConfig {
   }) = _LintingConfig;
 }
 
+/// The team manifesto: an optional preamble and ordered principles.
+@freezed
+sealed class Manifesto with _$Manifesto {
+  const factory Manifesto({
+    String? preamble,
+    required List<Principle> principles,
+  }) = _Manifesto;
+}
+
 /// A team member's profile.
 @freezed
 sealed class Member with _$Member {
@@ -198,6 +362,7 @@ sealed class Member with _$Member {
     String? name,
     String? bio,
     String? timezone,
+    required List<String> aliases,
   }) = _Member;
 
   /// Create a new member with the given email.
@@ -230,6 +395,29 @@ sealed class MemberCredentials with _$MemberCredentials {
       .crateApiMemberCredentialsVerify(that: this, pincode: pincode);
 }
 
+/// Interactions a member sent and received.
+@freezed
+sealed class MemberInteractionStats with _$MemberInteractionStats {
+  const factory MemberInteractionStats({
+    required String email,
+    required int kudosGiven,
+    required int kudosReceived,
+    required int feedbackGiven,
+    required int feedbackReceived,
+    required int totalSent,
+    required int totalReceived,
+  }) = _MemberInteractionStats;
+}
+
+/// One member's overrides of the team's pulse cadences.
+@freezed
+sealed class MemberPulse with _$MemberPulse {
+  const factory MemberPulse({
+    required String email,
+    required PulseCadences cadences,
+  }) = _MemberPulse;
+}
+
 /// An objective with key results.
 @freezed
 sealed class Objective with _$Objective {
@@ -242,6 +430,8 @@ sealed class Objective with _$Objective {
     required OkrVisibility visibility,
     String? owner,
     String? quarter,
+    required List<String> principles,
+    String? updated,
   }) = _Objective;
 
   /// Create a new objective.
@@ -268,6 +458,36 @@ enum OkrVisibility {
       RustLib.instance.api.crateApiOkrVisibilityDefault();
 }
 
+/// Outcome of a pincode check against a team directory.
+@freezed
+sealed class PincodeCheck with _$PincodeCheck {
+  const factory PincodeCheck({
+    required bool verified,
+    required String email,
+    required BigInt retryAfterSecs,
+  }) = _PincodeCheck;
+}
+
+/// A principle in the team manifesto.
+@freezed
+sealed class Principle with _$Principle {
+  const factory Principle({
+    required String id,
+    required String title,
+    String? description,
+  }) = _Principle;
+}
+
+/// How often kudos have recognised a manifesto principle.
+@freezed
+sealed class PrincipleRecognition with _$PrincipleRecognition {
+  const factory PrincipleRecognition({
+    required String id,
+    required String title,
+    required int count,
+  }) = _PrincipleRecognition;
+}
+
 /// Configuration for publishing markdown files.
 @freezed
 sealed class PublishConfig with _$PublishConfig {
@@ -275,19 +495,54 @@ sealed class PublishConfig with _$PublishConfig {
     String? manifesto,
     String? vision,
     String? okrs,
+    String? interactions,
+    String? timezone,
   }) = _PublishConfig;
 }
 
+/// Cadences per pulse prompt; unset prompts fall back to the next level.
+///
+/// Each is weekly, biweekly, monthly, quarterly or off.
+@freezed
+sealed class PulseCadences with _$PulseCadences {
+  const factory PulseCadences({
+    String? checkin,
+    String? okrUpdate,
+    String? retro,
+  }) = _PulseCadences;
+}
+
+/// Configuration for pulse prompts.
+@freezed
+sealed class PulseConfig with _$PulseConfig {
+  const factory PulseConfig({
+    required PulseCadences team,
+    required List<MemberPulse> members,
+  }) = _PulseConfig;
+}
+
+/// A command run when something changes in the team.
+///
+/// It only runs on machines where it was approved, see [`approve_shell_hook`].
+@freezed
+sealed class ShellHook with _$ShellHook {
+  const factory ShellHook({
+    required List<String> on,
+    required String run,
+  }) = _ShellHook;
+}
+
 /// A team with its manifesto, vision, and members.
 @freezed
 sealed class Team with _$Team {
   const Team._();
   const factory Team({
     required String name,
-    String? manifesto,
+    Manifesto? manifesto,
     String? vision,
     required List<String> leaders,
     required List<String> members,
+    required List<String> archived,
   }) = _Team;
 
   /// Create a new team with the given name.
@@ -312,6 +567,8 @@ sealed class TeamConfig with _$TeamConfig {
     WebhookConfig? webhooks,
     LintingConfig? linting,
     BackupConfig? backup,
+    PulseConfig? pulse,
+    required List<ShellHook> hooks,
   }) = _TeamConfig;
 
   /// Create an empty configuration.
@@ -322,6 +579,26 @@ sealed class TeamConfig with _$TeamConfig {
       RustLib.instance.api.crateApiTeamConfigWithDefaults();
 }
 
+/// Something that changed in the team.
+@freezed
+sealed class TeamEvent with _$TeamEvent {
+  const factory TeamEvent({
+    required String id,
+    required String name,
+    required String text,
+    required List<TeamEventField> fields,
+  }) = _TeamEvent;
+}
+
+/// A field of a team event.
+@freezed
+sealed class TeamEventField with _$TeamEventField {
+  const factory TeamEventField({
+    required String key,
+    required String value,
+  }) = _TeamEventField;
+}
+
 /// Configuration for webhook notifications.
 @freezed
 sealed class WebhookConfig with _$WebhookConfig {
@@ -329,5 +606,24 @@ sealed class WebhookConfig with _$WebhookConfig {
     String? discord,
     String? slack,
     String? signal,
+    required List<CustomWebhook> custom,
   }) = _WebhookConfig;
 }
+
+/// An extra HTTP header sent by a custom webhook.
+@freezed
+sealed class WebhookHeader with _$WebhookHeader {
+  const factory WebhookHeader({
+    required String name,
+    required String value,
+  }) = _WebhookHeader;
+}
+
+/// Number of interactions in one week.
+@freezed
+sealed class WeekActivity with _$WeekActivity {
+  const factory WeekActivity({
+    required String weekStart,
+    required int count,
+  }) = _WeekActivity;
+}
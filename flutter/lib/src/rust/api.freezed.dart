@@ -14,6 +14,171 @@ T _$identity<T>(T value) => value;
 final _privateConstructorUsedError = UnsupportedError(
     'It seems like you constructed your class using `MyClass._()`. This constructor is only meant to be used by freezed and you are not supposed to need it nor use it.\nPlease check the documentation here for more information: https://github.com/rrousselGit/freezed#adding-getters-and-methods-to-our-models');
 
+/// @nodoc
+mixin _$AppreciationEdge {
+  String get from => throw _privateConstructorUsedError;
+  String get to => throw _privateConstructorUsedError;
+  int get count => throw _privateConstructorUsedError;
+
+  /// Create a copy of AppreciationEdge
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  $AppreciationEdgeCopyWith<AppreciationEdge> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $AppreciationEdgeCopyWith<$Res> {
+  factory $AppreciationEdgeCopyWith(
+          AppreciationEdge value, $Res Function(AppreciationEdge) then) =
+      _$AppreciationEdgeCopyWithImpl<$Res, AppreciationEdge>;
+  @useResult
+  $Res call({String from, String to, int count});
+}
+
+/// @nodoc
+class _$AppreciationEdgeCopyWithImpl<$Res, $Val extends AppreciationEdge>
+    implements $AppreciationEdgeCopyWith<$Res> {
+  _$AppreciationEdgeCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of AppreciationEdge
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? from = null,
+    Object? to = null,
+    Object? count = null,
+  }) {
+    return _then(_value.copyWith(
+      from: null == from
+          ? _value.from
+          : from // ignore: cast_nullable_to_non_nullable
+              as String,
+      to: null == to
+          ? _value.to
+          : to // ignore: cast_nullable_to_non_nullable
+              as String,
+      count: null == count
+          ? _value.count
+          : count // ignore: cast_nullable_to_non_nullable
+              as int,
+    ) as $Val);
+  }
+}
+
+/// @nodoc
+abstract class _$$AppreciationEdgeImplCopyWith<$Res>
+    implements $AppreciationEdgeCopyWith<$Res> {
+  factory _$$AppreciationEdgeImplCopyWith(_$AppreciationEdgeImpl value,
+          $Res Function(_$AppreciationEdgeImpl) then) =
+      __$$AppreciationEdgeImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call({String from, String to, int count});
+}
+
+/// @nodoc
+class __$$AppreciationEdgeImplCopyWithImpl<$Res>
+    extends _$AppreciationEdgeCopyWithImpl<$Res, _$AppreciationEdgeImpl>
+    implements _$$AppreciationEdgeImplCopyWith<$Res> {
+  __$$AppreciationEdgeImplCopyWithImpl(_$AppreciationEdgeImpl _value,
+      $Res Function(_$AppreciationEdgeImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of AppreciationEdge
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? from = null,
+    Object? to = null,
+    Object? count = null,
+  }) {
+    return _then(_$AppreciationEdgeImpl(
+      from: null == from
+          ? _value.from
+          : from // ignore: cast_nullable_to_non_nullable
+              as String,
+      to: null == to
+          ? _value.to
+          : to // ignore: cast_nullable_to_non_nullable
+              as String,
+      count: null == count
+          ? _value.count
+          : count // ignore: cast_nullable_to_non_nullable
+              as int,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$AppreciationEdgeImpl implements _AppreciationEdge {
+  const _$AppreciationEdgeImpl(
+      {required this.from, required this.to, required this.count});
+
+  @override
+  final String from;
+  @override
+  final String to;
+  @override
+  final int count;
+
+  @override
+  String toString() {
+    return 'AppreciationEdge(from: $from, to: $to, count: $count)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$AppreciationEdgeImpl &&
+            (identical(other.from, from) || other.from == from) &&
+            (identical(other.to, to) || other.to == to) &&
+            (identical(other.count, count) || other.count == count));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, from, to, count);
+
+  /// Create a copy of AppreciationEdge
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$AppreciationEdgeImplCopyWith<_$AppreciationEdgeImpl> get copyWith =>
+      __$$AppreciationEdgeImplCopyWithImpl<_$AppreciationEdgeImpl>(
+          this, _$identity);
+}
+
+abstract class _AppreciationEdge implements AppreciationEdge {
+  const factory _AppreciationEdge(
+      {required final String from,
+      required final String to,
+      required final int count}) = _$AppreciationEdgeImpl;
+
+  @override
+  String get from;
+  @override
+  String get to;
+  @override
+  int get count;
+
+  /// Create a copy of AppreciationEdge
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$AppreciationEdgeImplCopyWith<_$AppreciationEdgeImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
 /// @nodoc
 mixin _$BackupConfig {
   String? get protectedBranch => throw _privateConstructorUsedError;
@@ -294,373 +459,400 @@ abstract class _Credentials extends Credentials {
 }
 
 /// @nodoc
-mixin _$Interaction {
-  String get id => throw _privateConstructorUsedError;
-  InteractionKind get kind => throw _privateConstructorUsedError;
-  String get from => throw _privateConstructorUsedError;
-  List<String> get withMembers => throw _privateConstructorUsedError;
-  String get note => throw _privateConstructorUsedError;
-  String get timestamp => throw _privateConstructorUsedError;
-  bool get shared => throw _privateConstructorUsedError;
-
-  /// Create a copy of Interaction
+mixin _$CustomWebhook {
+  String? get name => throw _privateConstructorUsedError;
+  String get url => throw _privateConstructorUsedError;
+  String? get template => throw _privateConstructorUsedError;
+  String? get contentType => throw _privateConstructorUsedError;
+  List<WebhookHeader> get headers => throw _privateConstructorUsedError;
+  String? get secret => throw _privateConstructorUsedError;
+  String? get signatureHeader => throw _privateConstructorUsedError;
+  List<String> get events => throw _privateConstructorUsedError;
+
+  /// Create a copy of CustomWebhook
   /// with the given fields replaced by the non-null parameter values.
   @JsonKey(includeFromJson: false, includeToJson: false)
-  $InteractionCopyWith<Interaction> get copyWith =>
+  $CustomWebhookCopyWith<CustomWebhook> get copyWith =>
       throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-abstract class $InteractionCopyWith<$Res> {
-  factory $InteractionCopyWith(
-          Interaction value, $Res Function(Interaction) then) =
-      _$InteractionCopyWithImpl<$Res, Interaction>;
+abstract class $CustomWebhookCopyWith<$Res> {
+  factory $CustomWebhookCopyWith(
+          CustomWebhook value, $Res Function(CustomWebhook) then) =
+      _$CustomWebhookCopyWithImpl<$Res, CustomWebhook>;
   @useResult
   $Res call(
-      {String id,
-      InteractionKind kind,
-      String from,
-      List<String> withMembers,
-      String note,
-      String timestamp,
-      bool shared});
+      {String? name,
+      String url,
+      String? template,
+      String? contentType,
+      List<WebhookHeader> headers,
+      String? secret,
+      String? signatureHeader,
+      List<String> events});
 }
 
 /// @nodoc
-class _$InteractionCopyWithImpl<$Res, $Val extends Interaction>
-    implements $InteractionCopyWith<$Res> {
-  _$InteractionCopyWithImpl(this._value, this._then);
+class _$CustomWebhookCopyWithImpl<$Res, $Val extends CustomWebhook>
+    implements $CustomWebhookCopyWith<$Res> {
+  _$CustomWebhookCopyWithImpl(this._value, this._then);
 
   // ignore: unused_field
   final $Val _value;
   // ignore: unused_field
   final $Res Function($Val) _then;
 
-  /// Create a copy of Interaction
+  /// Create a copy of CustomWebhook
   /// with the given fields replaced by the non-null parameter values.
   @pragma('vm:prefer-inline')
   @override
   $Res call({
-    Object? id = null,
-    Object? kind = null,
-    Object? from = null,
-    Object? withMembers = null,
-    Object? note = null,
-    Object? timestamp = null,
-    Object? shared = null,
+    Object? name = freezed,
+    Object? url = null,
+    Object? template = freezed,
+    Object? contentType = freezed,
+    Object? headers = null,
+    Object? secret = freezed,
+    Object? signatureHeader = freezed,
+    Object? events = null,
   }) {
     return _then(_value.copyWith(
-      id: null == id
-          ? _value.id
-          : id // ignore: cast_nullable_to_non_nullable
-              as String,
-      kind: null == kind
-          ? _value.kind
-          : kind // ignore: cast_nullable_to_non_nullable
-              as InteractionKind,
-      from: null == from
-          ? _value.from
-          : from // ignore: cast_nullable_to_non_nullable
+      name: freezed == name
+          ? _value.name
+          : name // ignore: cast_nullable_to_non_nullable
+              as String?,
+      url: null == url
+          ? _value.url
+          : url // ignore: cast_nullable_to_non_nullable
               as String,
-      withMembers: null == withMembers
-          ? _value.withMembers
-          : withMembers // ignore: cast_nullable_to_non_nullable
+      template: freezed == template
+          ? _value.template
+          : template // ignore: cast_nullable_to_non_nullable
+              as String?,
+      contentType: freezed == contentType
+          ? _value.contentType
+          : contentType // ignore: cast_nullable_to_non_nullable
+              as String?,
+      headers: null == headers
+          ? _value.headers
+          : headers // ignore: cast_nullable_to_non_nullable
+              as List<WebhookHeader>,
+      secret: freezed == secret
+          ? _value.secret
+          : secret // ignore: cast_nullable_to_non_nullable
+              as String?,
+      signatureHeader: freezed == signatureHeader
+          ? _value.signatureHeader
+          : signatureHeader // ignore: cast_nullable_to_non_nullable
+              as String?,
+      events: null == events
+          ? _value.events
+          : events // ignore: cast_nullable_to_non_nullable
               as List<String>,
-      note: null == note
-          ? _value.note
-          : note // ignore: cast_nullable_to_non_nullable
-              as String,
-      timestamp: null == timestamp
-          ? _value.timestamp
-          : timestamp // ignore: cast_nullable_to_non_nullable
-              as String,
-      shared: null == shared
-          ? _value.shared
-          : shared // ignore: cast_nullable_to_non_nullable
-              as bool,
     ) as $Val);
   }
 }
 
 /// @nodoc
-abstract class _$$InteractionImplCopyWith<$Res>
-    implements $InteractionCopyWith<$Res> {
-  factory _$$InteractionImplCopyWith(
-          _$InteractionImpl value, $Res Function(_$InteractionImpl) then) =
-      __$$InteractionImplCopyWithImpl<$Res>;
+abstract class _$$CustomWebhookImplCopyWith<$Res>
+    implements $CustomWebhookCopyWith<$Res> {
+  factory _$$CustomWebhookImplCopyWith(
+          _$CustomWebhookImpl value, $Res Function(_$CustomWebhookImpl) then) =
+      __$$CustomWebhookImplCopyWithImpl<$Res>;
   @override
   @useResult
   $Res call(
-      {String id,
-      InteractionKind kind,
-      String from,
-      List<String> withMembers,
-      String note,
-      String timestamp,
-      bool shared});
+      {String? name,
+      String url,
+      String? template,
+      String? contentType,
+      List<WebhookHeader> headers,
+      String? secret,
+      String? signatureHeader,
+      List<String> events});
 }
 
 /// @nodoc
-class __$$InteractionImplCopyWithImpl<$Res>
-    extends _$InteractionCopyWithImpl<$Res, _$InteractionImpl>
-    implements _$$InteractionImplCopyWith<$Res> {
-  __$$InteractionImplCopyWithImpl(
-      _$InteractionImpl _value, $Res Function(_$InteractionImpl) _then)
+class __$$CustomWebhookImplCopyWithImpl<$Res>
+    extends _$CustomWebhookCopyWithImpl<$Res, _$CustomWebhookImpl>
+    implements _$$CustomWebhookImplCopyWith<$Res> {
+  __$$CustomWebhookImplCopyWithImpl(
+      _$CustomWebhookImpl _value, $Res Function(_$CustomWebhookImpl) _then)
       : super(_value, _then);
 
-  /// Create a copy of Interaction
+  /// Create a copy of CustomWebhook
   /// with the given fields replaced by the non-null parameter values.
   @pragma('vm:prefer-inline')
   @override
   $Res call({
-    Object? id = null,
-    Object? kind = null,
-    Object? from = null,
-    Object? withMembers = null,
-    Object? note = null,
-    Object? timestamp = null,
-    Object? shared = null,
+    Object? name = freezed,
+    Object? url = null,
+    Object? template = freezed,
+    Object? contentType = freezed,
+    Object? headers = null,
+    Object? secret = freezed,
+    Object? signatureHeader = freezed,
+    Object? events = null,
   }) {
-    return _then(_$InteractionImpl(
-      id: null == id
-          ? _value.id
-          : id // ignore: cast_nullable_to_non_nullable
-              as String,
-      kind: null == kind
-          ? _value.kind
-          : kind // ignore: cast_nullable_to_non_nullable
-              as InteractionKind,
-      from: null == from
-          ? _value.from
-          : from // ignore: cast_nullable_to_non_nullable
+    return _then(_$CustomWebhookImpl(
+      name: freezed == name
+          ? _value.name
+          : name // ignore: cast_nullable_to_non_nullable
+              as String?,
+      url: null == url
+          ? _value.url
+          : url // ignore: cast_nullable_to_non_nullable
               as String,
-      withMembers: null == withMembers
-          ? _value._withMembers
-          : withMembers // ignore: cast_nullable_to_non_nullable
+      template: freezed == template
+          ? _value.template
+          : template // ignore: cast_nullable_to_non_nullable
+              as String?,
+      contentType: freezed == contentType
+          ? _value.contentType
+          : contentType // ignore: cast_nullable_to_non_nullable
+              as String?,
+      headers: null == headers
+          ? _value._headers
+          : headers // ignore: cast_nullable_to_non_nullable
+              as List<WebhookHeader>,
+      secret: freezed == secret
+          ? _value.secret
+          : secret // ignore: cast_nullable_to_non_nullable
+              as String?,
+      signatureHeader: freezed == signatureHeader
+          ? _value.signatureHeader
+          : signatureHeader // ignore: cast_nullable_to_non_nullable
+              as String?,
+      events: null == events
+          ? _value._events
+          : events // ignore: cast_nullable_to_non_nullable
               as List<String>,
-      note: null == note
-          ? _value.note
-          : note // ignore: cast_nullable_to_non_nullable
-              as String,
-      timestamp: null == timestamp
-          ? _value.timestamp
-          : timestamp // ignore: cast_nullable_to_non_nullable
-              as String,
-      shared: null == shared
-          ? _value.shared
-          : shared // ignore: cast_nullable_to_non_nullable
-              as bool,
     ));
   }
 }
 
 /// @nodoc
 
-class _$InteractionImpl extends _Interaction {
-  const _$InteractionImpl(
-      {required this.id,
-      required this.kind,
-      required this.from,
-      required final List<String> withMembers,
-      required this.note,
-      required this.timestamp,
-      required this.shared})
-      : _withMembers = withMembers,
-        super._();
+class _$CustomWebhookImpl implements _CustomWebhook {
+  const _$CustomWebhookImpl(
+      {this.name,
+      required this.url,
+      this.template,
+      this.contentType,
+      required final List<WebhookHeader> headers,
+      this.secret,
+      this.signatureHeader,
+      required final List<String> events})
+      : _headers = headers,
+        _events = events;
 
   @override
-  final String id;
+  final String? name;
   @override
-  final InteractionKind kind;
+  final String url;
   @override
-  final String from;
-  final List<String> _withMembers;
+  final String? template;
   @override
-  List<String> get withMembers {
-    if (_withMembers is EqualUnmodifiableListView) return _withMembers;
+  final String? contentType;
+  final List<WebhookHeader> _headers;
+  @override
+  List<WebhookHeader> get headers {
+    if (_headers is EqualUnmodifiableListView) return _headers;
     // ignore: implicit_dynamic_type
-    return EqualUnmodifiableListView(_withMembers);
+    return EqualUnmodifiableListView(_headers);
   }
 
   @override
-  final String note;
+  final String? secret;
   @override
-  final String timestamp;
+  final String? signatureHeader;
+  final List<String> _events;
   @override
-  final bool shared;
+  List<String> get events {
+    if (_events is EqualUnmodifiableListView) return _events;
+    // ignore: implicit_dynamic_type
+    return EqualUnmodifiableListView(_events);
+  }
 
   @override
   String toString() {
-    return 'Interaction(id: $id, kind: $kind, from: $from, withMembers: $withMembers, note: $note, timestamp: $timestamp, shared: $shared)';
+    return 'CustomWebhook(name: $name, url: $url, template: $template, contentType: $contentType, headers: $headers, secret: $secret, signatureHeader: $signatureHeader, events: $events)';
   }
 
   @override
   bool operator ==(Object other) {
     return identical(this, other) ||
         (other.runtimeType == runtimeType &&
-            other is _$InteractionImpl &&
-            (identical(other.id, id) || other.id == id) &&
-            (identical(other.kind, kind) || other.kind == kind) &&
-            (identical(other.from, from) || other.from == from) &&
-            const DeepCollectionEquality()
-                .equals(other._withMembers, _withMembers) &&
-            (identical(other.note, note) || other.note == note) &&
-            (identical(other.timestamp, timestamp) ||
-                other.timestamp == timestamp) &&
-            (identical(other.shared, shared) || other.shared == shared));
+            other is _$CustomWebhookImpl &&
+            (identical(other.name, name) || other.name == name) &&
+            (identical(other.url, url) || other.url == url) &&
+            (identical(other.template, template) ||
+                other.template == template) &&
+            (identical(other.contentType, contentType) ||
+                other.contentType == contentType) &&
+            const DeepCollectionEquality().equals(other._headers, _headers) &&
+            (identical(other.secret, secret) || other.secret == secret) &&
+            (identical(other.signatureHeader, signatureHeader) ||
+                other.signatureHeader == signatureHeader) &&
+            const DeepCollectionEquality().equals(other._events, _events));
   }
 
   @override
   int get hashCode => Object.hash(
       runtimeType,
-      id,
-      kind,
-      from,
-      const DeepCollectionEquality().hash(_withMembers),
-      note,
-      timestamp,
-      shared);
-
-  /// Create a copy of Interaction
+      name,
+      url,
+      template,
+      contentType,
+      const DeepCollectionEquality().hash(_headers),
+      secret,
+      signatureHeader,
+      const DeepCollectionEquality().hash(_events));
+
+  /// Create a copy of CustomWebhook
   /// with the given fields replaced by the non-null parameter values.
   @JsonKey(includeFromJson: false, includeToJson: false)
   @override
   @pragma('vm:prefer-inline')
-  _$$InteractionImplCopyWith<_$InteractionImpl> get copyWith =>
-      __$$InteractionImplCopyWithImpl<_$InteractionImpl>(this, _$identity);
+  _$$CustomWebhookImplCopyWith<_$CustomWebhookImpl> get copyWith =>
+      __$$CustomWebhookImplCopyWithImpl<_$CustomWebhookImpl>(this, _$identity);
 }
 
-abstract class _Interaction extends Interaction {
-  const factory _Interaction(
-      {required final String id,
-      required final InteractionKind kind,
-      required final String from,
-      required final List<String> withMembers,
-      required final String note,
-      required final String timestamp,
-      required final bool shared}) = _$InteractionImpl;
-  const _Interaction._() : super._();
+abstract class _CustomWebhook implements CustomWebhook {
+  const factory _CustomWebhook(
+      {final String? name,
+      required final String url,
+      final String? template,
+      final String? contentType,
+      required final List<WebhookHeader> headers,
+      final String? secret,
+      final String? signatureHeader,
+      required final List<String> events}) = _$CustomWebhookImpl;
 
   @override
-  String get id;
+  String? get name;
   @override
-  InteractionKind get kind;
+  String get url;
   @override
-  String get from;
+  String? get template;
   @override
-  List<String> get withMembers;
+  String? get contentType;
   @override
-  String get note;
+  List<WebhookHeader> get headers;
   @override
-  String get timestamp;
+  String? get secret;
   @override
-  bool get shared;
+  String? get signatureHeader;
+  @override
+  List<String> get events;
 
-  /// Create a copy of Interaction
+  /// Create a copy of CustomWebhook
   /// with the given fields replaced by the non-null parameter values.
   @override
   @JsonKey(includeFromJson: false, includeToJson: false)
-  _$$InteractionImplCopyWith<_$InteractionImpl> get copyWith =>
+  _$$CustomWebhookImplCopyWith<_$CustomWebhookImpl> get copyWith =>
       throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-mixin _$KeyResult {
-  String get description => throw _privateConstructorUsedError;
-  double get progress => throw _privateConstructorUsedError;
-  String? get notes => throw _privateConstructorUsedError;
+mixin _$DuePulsePrompt {
+  String get prompt => throw _privateConstructorUsedError;
+  String get message => throw _privateConstructorUsedError;
+  String? get objectiveId => throw _privateConstructorUsedError;
 
-  /// Create a copy of KeyResult
+  /// Create a copy of DuePulsePrompt
   /// with the given fields replaced by the non-null parameter values.
   @JsonKey(includeFromJson: false, includeToJson: false)
-  $KeyResultCopyWith<KeyResult> get copyWith =>
+  $DuePulsePromptCopyWith<DuePulsePrompt> get copyWith =>
       throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-abstract class $KeyResultCopyWith<$Res> {
-  factory $KeyResultCopyWith(KeyResult value, $Res Function(KeyResult) then) =
-      _$KeyResultCopyWithImpl<$Res, KeyResult>;
+abstract class $DuePulsePromptCopyWith<$Res> {
+  factory $DuePulsePromptCopyWith(
+          DuePulsePrompt value, $Res Function(DuePulsePrompt) then) =
+      _$DuePulsePromptCopyWithImpl<$Res, DuePulsePrompt>;
   @useResult
-  $Res call({String description, double progress, String? notes});
+  $Res call({String prompt, String message, String? objectiveId});
 }
 
 /// @nodoc
-class _$KeyResultCopyWithImpl<$Res, $Val extends KeyResult>
-    implements $KeyResultCopyWith<$Res> {
-  _$KeyResultCopyWithImpl(this._value, this._then);
+class _$DuePulsePromptCopyWithImpl<$Res, $Val extends DuePulsePrompt>
+    implements $DuePulsePromptCopyWith<$Res> {
+  _$DuePulsePromptCopyWithImpl(this._value, this._then);
 
   // ignore: unused_field
   final $Val _value;
   // ignore: unused_field
   final $Res Function($Val) _then;
 
-  /// Create a copy of KeyResult
+  /// Create a copy of DuePulsePrompt
   /// with the given fields replaced by the non-null parameter values.
   @pragma('vm:prefer-inline')
   @override
   $Res call({
-    Object? description = null,
-    Object? progress = null,
-    Object? notes = freezed,
+    Object? prompt = null,
+    Object? message = null,
+    Object? objectiveId = freezed,
   }) {
     return _then(_value.copyWith(
-      description: null == description
-          ? _value.description
-          : description // ignore: cast_nullable_to_non_nullable
+      prompt: null == prompt
+          ? _value.prompt
+          : prompt // ignore: cast_nullable_to_non_nullable
               as String,
-      progress: null == progress
-          ? _value.progress
-          : progress // ignore: cast_nullable_to_non_nullable
-              as double,
-      notes: freezed == notes
-          ? _value.notes
-          : notes // ignore: cast_nullable_to_non_nullable
+      message: null == message
+          ? _value.message
+          : message // ignore: cast_nullable_to_non_nullable
+              as String,
+      objectiveId: freezed == objectiveId
+          ? _value.objectiveId
+          : objectiveId // ignore: cast_nullable_to_non_nullable
               as String?,
     ) as $Val);
   }
 }
 
 /// @nodoc
-abstract class _$$KeyResultImplCopyWith<$Res>
-    implements $KeyResultCopyWith<$Res> {
-  factory _$$KeyResultImplCopyWith(
-          _$KeyResultImpl value, $Res Function(_$KeyResultImpl) then) =
-      __$$KeyResultImplCopyWithImpl<$Res>;
+abstract class _$$DuePulsePromptImplCopyWith<$Res>
+    implements $DuePulsePromptCopyWith<$Res> {
+  factory _$$DuePulsePromptImplCopyWith(_$DuePulsePromptImpl value,
+          $Res Function(_$DuePulsePromptImpl) then) =
+      __$$DuePulsePromptImplCopyWithImpl<$Res>;
   @override
   @useResult
-  $Res call({String description, double progress, String? notes});
+  $Res call({String prompt, String message, String? objectiveId});
 }
 
 /// @nodoc
-class __$$KeyResultImplCopyWithImpl<$Res>
-    extends _$KeyResultCopyWithImpl<$Res, _$KeyResultImpl>
-    implements _$$KeyResultImplCopyWith<$Res> {
-  __$$KeyResultImplCopyWithImpl(
-      _$KeyResultImpl _value, $Res Function(_$KeyResultImpl) _then)
+class __$$DuePulsePromptImplCopyWithImpl<$Res>
+    extends _$DuePulsePromptCopyWithImpl<$Res, _$DuePulsePromptImpl>
+    implements _$$DuePulsePromptImplCopyWith<$Res> {
+  __$$DuePulsePromptImplCopyWithImpl(
+      _$DuePulsePromptImpl _value, $Res Function(_$DuePulsePromptImpl) _then)
       : super(_value, _then);
 
-  /// Create a copy of KeyResult
+  /// Create a copy of DuePulsePrompt
   /// with the given fields replaced by the non-null parameter values.
   @pragma('vm:prefer-inline')
   @override
   $Res call({
-    Object? description = null,
-    Object? progress = null,
-    Object? notes = freezed,
+    Object? prompt = null,
+    Object? message = null,
+    Object? objectiveId = freezed,
   }) {
-    return _then(_$KeyResultImpl(
-      description: null == description
-          ? _value.description
-          : description // ignore: cast_nullable_to_non_nullable
+    return _then(_$DuePulsePromptImpl(
+      prompt: null == prompt
+          ? _value.prompt
+          : prompt // ignore: cast_nullable_to_non_nullable
               as String,
-      progress: null == progress
-          ? _value.progress
-          : progress // ignore: cast_nullable_to_non_nullable
-              as double,
-      notes: freezed == notes
-          ? _value.notes
-          : notes // ignore: cast_nullable_to_non_nullable
+      message: null == message
+          ? _value.message
+          : message // ignore: cast_nullable_to_non_nullable
+              as String,
+      objectiveId: freezed == objectiveId
+          ? _value.objectiveId
+          : objectiveId // ignore: cast_nullable_to_non_nullable
               as String?,
     ));
   }
@@ -668,322 +860,486 @@ class __$$KeyResultImplCopyWithImpl<$Res>
 
 /// @nodoc
 
-class _$KeyResultImpl extends _KeyResult {
-  const _$KeyResultImpl(
-      {required this.description, required this.progress, this.notes})
-      : super._();
+class _$DuePulsePromptImpl implements _DuePulsePrompt {
+  const _$DuePulsePromptImpl(
+      {required this.prompt, required this.message, this.objectiveId});
 
   @override
-  final String description;
+  final String prompt;
   @override
-  final double progress;
+  final String message;
   @override
-  final String? notes;
+  final String? objectiveId;
 
   @override
   String toString() {
-    return 'KeyResult(description: $description, progress: $progress, notes: $notes)';
+    return 'DuePulsePrompt(prompt: $prompt, message: $message, objectiveId: $objectiveId)';
   }
 
   @override
   bool operator ==(Object other) {
     return identical(this, other) ||
         (other.runtimeType == runtimeType &&
-            other is _$KeyResultImpl &&
-            (identical(other.description, description) ||
-                other.description == description) &&
-            (identical(other.progress, progress) ||
-                other.progress == progress) &&
-            (identical(other.notes, notes) || other.notes == notes));
+            other is _$DuePulsePromptImpl &&
+            (identical(other.prompt, prompt) || other.prompt == prompt) &&
+            (identical(other.message, message) || other.message == message) &&
+            (identical(other.objectiveId, objectiveId) ||
+                other.objectiveId == objectiveId));
   }
 
   @override
-  int get hashCode => Object.hash(runtimeType, description, progress, notes);
+  int get hashCode => Object.hash(runtimeType, prompt, message, objectiveId);
 
-  /// Create a copy of KeyResult
+  /// Create a copy of DuePulsePrompt
   /// with the given fields replaced by the non-null parameter values.
   @JsonKey(includeFromJson: false, includeToJson: false)
   @override
   @pragma('vm:prefer-inline')
-  _$$KeyResultImplCopyWith<_$KeyResultImpl> get copyWith =>
-      __$$KeyResultImplCopyWithImpl<_$KeyResultImpl>(this, _$identity);
+  _$$DuePulsePromptImplCopyWith<_$DuePulsePromptImpl> get copyWith =>
+      __$$DuePulsePromptImplCopyWithImpl<_$DuePulsePromptImpl>(
+          this, _$identity);
 }
 
-abstract class _KeyResult extends KeyResult {
-  const factory _KeyResult(
-      {required final String description,
-      required final double progress,
-      final String? notes}) = _$KeyResultImpl;
-  const _KeyResult._() : super._();
+abstract class _DuePulsePrompt implements DuePulsePrompt {
+  const factory _DuePulsePrompt(
+      {required final String prompt,
+      required final String message,
+      final String? objectiveId}) = _$DuePulsePromptImpl;
 
   @override
-  String get description;
+  String get prompt;
   @override
-  double get progress;
+  String get message;
   @override
-  String? get notes;
+  String? get objectiveId;
 
-  /// Create a copy of KeyResult
+  /// Create a copy of DuePulsePrompt
   /// with the given fields replaced by the non-null parameter values.
   @override
   @JsonKey(includeFromJson: false, includeToJson: false)
-  _$$KeyResultImplCopyWith<_$KeyResultImpl> get copyWith =>
+  _$$DuePulsePromptImplCopyWith<_$DuePulsePromptImpl> get copyWith =>
       throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-mixin _$LintingConfig {
-  bool get enabled => throw _privateConstructorUsedError;
-  String? get targetBranch => throw _privateConstructorUsedError;
+mixin _$Interaction {
+  String get id => throw _privateConstructorUsedError;
+  InteractionKind get kind => throw _privateConstructorUsedError;
+  String get from => throw _privateConstructorUsedError;
+  List<String> get withMembers => throw _privateConstructorUsedError;
+  String get note => throw _privateConstructorUsedError;
+  String get timestamp => throw _privateConstructorUsedError;
+  bool get shared => throw _privateConstructorUsedError;
+  List<String> get principles => throw _privateConstructorUsedError;
+  String? get importedFrom => throw _privateConstructorUsedError;
 
-  /// Create a copy of LintingConfig
+  /// Create a copy of Interaction
   /// with the given fields replaced by the non-null parameter values.
   @JsonKey(includeFromJson: false, includeToJson: false)
-  $LintingConfigCopyWith<LintingConfig> get copyWith =>
+  $InteractionCopyWith<Interaction> get copyWith =>
       throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-abstract class $LintingConfigCopyWith<$Res> {
-  factory $LintingConfigCopyWith(
-          LintingConfig value, $Res Function(LintingConfig) then) =
-      _$LintingConfigCopyWithImpl<$Res, LintingConfig>;
+abstract class $InteractionCopyWith<$Res> {
+  factory $InteractionCopyWith(
+          Interaction value, $Res Function(Interaction) then) =
+      _$InteractionCopyWithImpl<$Res, Interaction>;
   @useResult
-  $Res call({bool enabled, String? targetBranch});
+  $Res call(
+      {String id,
+      InteractionKind kind,
+      String from,
+      List<String> withMembers,
+      String note,
+      String timestamp,
+      bool shared,
+      List<String> principles,
+      String? importedFrom});
 }
 
 /// @nodoc
-class _$LintingConfigCopyWithImpl<$Res, $Val extends LintingConfig>
-    implements $LintingConfigCopyWith<$Res> {
-  _$LintingConfigCopyWithImpl(this._value, this._then);
+class _$InteractionCopyWithImpl<$Res, $Val extends Interaction>
+    implements $InteractionCopyWith<$Res> {
+  _$InteractionCopyWithImpl(this._value, this._then);
 
   // ignore: unused_field
   final $Val _value;
   // ignore: unused_field
   final $Res Function($Val) _then;
 
-  /// Create a copy of LintingConfig
+  /// Create a copy of Interaction
   /// with the given fields replaced by the non-null parameter values.
   @pragma('vm:prefer-inline')
   @override
   $Res call({
-    Object? enabled = null,
-    Object? targetBranch = freezed,
+    Object? id = null,
+    Object? kind = null,
+    Object? from = null,
+    Object? withMembers = null,
+    Object? note = null,
+    Object? timestamp = null,
+    Object? shared = null,
+    Object? principles = null,
+    Object? importedFrom = freezed,
   }) {
     return _then(_value.copyWith(
-      enabled: null == enabled
-          ? _value.enabled
-          : enabled // ignore: cast_nullable_to_non_nullable
+      id: null == id
+          ? _value.id
+          : id // ignore: cast_nullable_to_non_nullable
+              as String,
+      kind: null == kind
+          ? _value.kind
+          : kind // ignore: cast_nullable_to_non_nullable
+              as InteractionKind,
+      from: null == from
+          ? _value.from
+          : from // ignore: cast_nullable_to_non_nullable
+              as String,
+      withMembers: null == withMembers
+          ? _value.withMembers
+          : withMembers // ignore: cast_nullable_to_non_nullable
+              as List<String>,
+      note: null == note
+          ? _value.note
+          : note // ignore: cast_nullable_to_non_nullable
+              as String,
+      timestamp: null == timestamp
+          ? _value.timestamp
+          : timestamp // ignore: cast_nullable_to_non_nullable
+              as String,
+      shared: null == shared
+          ? _value.shared
+          : shared // ignore: cast_nullable_to_non_nullable
               as bool,
-      targetBranch: freezed == targetBranch
-          ? _value.targetBranch
-          : targetBranch // ignore: cast_nullable_to_non_nullable
+      principles: null == principles
+          ? _value.principles
+          : principles // ignore: cast_nullable_to_non_nullable
+              as List<String>,
+      importedFrom: freezed == importedFrom
+          ? _value.importedFrom
+          : importedFrom // ignore: cast_nullable_to_non_nullable
               as String?,
     ) as $Val);
   }
 }
 
 /// @nodoc
-abstract class _$$LintingConfigImplCopyWith<$Res>
-    implements $LintingConfigCopyWith<$Res> {
-  factory _$$LintingConfigImplCopyWith(
-          _$LintingConfigImpl value, $Res Function(_$LintingConfigImpl) then) =
-      __$$LintingConfigImplCopyWithImpl<$Res>;
+abstract class _$$InteractionImplCopyWith<$Res>
+    implements $InteractionCopyWith<$Res> {
+  factory _$$InteractionImplCopyWith(
+          _$InteractionImpl value, $Res Function(_$InteractionImpl) then) =
+      __$$InteractionImplCopyWithImpl<$Res>;
   @override
   @useResult
-  $Res call({bool enabled, String? targetBranch});
+  $Res call(
+      {String id,
+      InteractionKind kind,
+      String from,
+      List<String> withMembers,
+      String note,
+      String timestamp,
+      bool shared,
+      List<String> principles,
+      String? importedFrom});
 }
 
 /// @nodoc
-class __$$LintingConfigImplCopyWithImpl<$Res>
-    extends _$LintingConfigCopyWithImpl<$Res, _$LintingConfigImpl>
-    implements _$$LintingConfigImplCopyWith<$Res> {
-  __$$LintingConfigImplCopyWithImpl(
-      _$LintingConfigImpl _value, $Res Function(_$LintingConfigImpl) _then)
+class __$$InteractionImplCopyWithImpl<$Res>
+    extends _$InteractionCopyWithImpl<$Res, _$InteractionImpl>
+    implements _$$InteractionImplCopyWith<$Res> {
+  __$$InteractionImplCopyWithImpl(
+      _$InteractionImpl _value, $Res Function(_$InteractionImpl) _then)
       : super(_value, _then);
 
-  /// Create a copy of LintingConfig
+  /// Create a copy of Interaction
   /// with the given fields replaced by the non-null parameter values.
   @pragma('vm:prefer-inline')
   @override
   $Res call({
-    Object? enabled = null,
-    Object? targetBranch = freezed,
+    Object? id = null,
+    Object? kind = null,
+    Object? from = null,
+    Object? withMembers = null,
+    Object? note = null,
+    Object? timestamp = null,
+    Object? shared = null,
+    Object? principles = null,
+    Object? importedFrom = freezed,
   }) {
-    return _then(_$LintingConfigImpl(
-      enabled: null == enabled
-          ? _value.enabled
-          : enabled // ignore: cast_nullable_to_non_nullable
-              as bool,
-      targetBranch: freezed == targetBranch
-          ? _value.targetBranch
-          : targetBranch // ignore: cast_nullable_to_non_nullable
-              as String?,
-    ));
-  }
-}
-
-/// @nodoc
-
-class _$LintingConfigImpl implements _LintingConfig {
-  const _$LintingConfigImpl({required this.enabled, this.targetBranch});
+    return _then(_$InteractionImpl(
+      id: null == id
+          ? _value.id
+          : id // ignore: cast_nullable_to_non_nullable
+              as String,
+      kind: null == kind
+          ? _value.kind
+          : kind // ignore: cast_nullable_to_non_nullable
+              as InteractionKind,
+      from: null == from
+          ? _value.from
+          : from // ignore: cast_nullable_to_non_nullable
+              as String,
+      withMembers: null == withMembers
+          ? _value._withMembers
+          : withMembers // ignore: cast_nullable_to_non_nullable
+              as List<String>,
+      note: null == note
+          ? _value.note
+          : note // ignore: cast_nullable_to_non_nullable
+              as String,
+      timestamp: null == timestamp
+          ? _value.timestamp
+          : timestamp // ignore: cast_nullable_to_non_nullable
+              as String,
+      shared: null == shared
+          ? _value.shared
+          : shared // ignore: cast_nullable_to_non_nullable
+              as bool,
+      principles: null == principles
+          ? _value._principles
+          : principles // ignore: cast_nullable_to_non_nullable
+              as List<String>,
+      importedFrom: freezed == importedFrom
+          ? _value.importedFrom
+          : importedFrom // ignore: cast_nullable_to_non_nullable
+              as String?,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$InteractionImpl extends _Interaction {
+  const _$InteractionImpl(
+      {required this.id,
+      required this.kind,
+      required this.from,
+      required final List<String> withMembers,
+      required this.note,
+      required this.timestamp,
+      required this.shared,
+      required final List<String> principles,
+      this.importedFrom})
+      : _withMembers = withMembers,
+        _principles = principles,
+        super._();
 
   @override
-  final bool enabled;
+  final String id;
   @override
-  final String? targetBranch;
+  final InteractionKind kind;
+  @override
+  final String from;
+  final List<String> _withMembers;
+  @override
+  List<String> get withMembers {
+    if (_withMembers is EqualUnmodifiableListView) return _withMembers;
+    // ignore: implicit_dynamic_type
+    return EqualUnmodifiableListView(_withMembers);
+  }
+
+  @override
+  final String note;
+  @override
+  final String timestamp;
+  @override
+  final bool shared;
+  final List<String> _principles;
+  @override
+  List<String> get principles {
+    if (_principles is EqualUnmodifiableListView) return _principles;
+    // ignore: implicit_dynamic_type
+    return EqualUnmodifiableListView(_principles);
+  }
+
+  @override
+  final String? importedFrom;
 
   @override
   String toString() {
-    return 'LintingConfig(enabled: $enabled, targetBranch: $targetBranch)';
+    return 'Interaction(id: $id, kind: $kind, from: $from, withMembers: $withMembers, note: $note, timestamp: $timestamp, shared: $shared, principles: $principles, importedFrom: $importedFrom)';
   }
 
   @override
   bool operator ==(Object other) {
     return identical(this, other) ||
         (other.runtimeType == runtimeType &&
-            other is _$LintingConfigImpl &&
-            (identical(other.enabled, enabled) || other.enabled == enabled) &&
-            (identical(other.targetBranch, targetBranch) ||
-                other.targetBranch == targetBranch));
+            other is _$InteractionImpl &&
+            (identical(other.id, id) || other.id == id) &&
+            (identical(other.kind, kind) || other.kind == kind) &&
+            (identical(other.from, from) || other.from == from) &&
+            const DeepCollectionEquality()
+                .equals(other._withMembers, _withMembers) &&
+            (identical(other.note, note) || other.note == note) &&
+            (identical(other.timestamp, timestamp) ||
+                other.timestamp == timestamp) &&
+            (identical(other.shared, shared) || other.shared == shared) &&
+            const DeepCollectionEquality()
+                .equals(other._principles, _principles) &&
+            (identical(other.importedFrom, importedFrom) ||
+                other.importedFrom == importedFrom));
   }
 
   @override
-  int get hashCode => Object.hash(runtimeType, enabled, targetBranch);
+  int get hashCode => Object.hash(
+      runtimeType,
+      id,
+      kind,
+      from,
+      const DeepCollectionEquality().hash(_withMembers),
+      note,
+      timestamp,
+      shared,
+      const DeepCollectionEquality().hash(_principles),
+      importedFrom);
 
-  /// Create a copy of LintingConfig
+  /// Create a copy of Interaction
   /// with the given fields replaced by the non-null parameter values.
   @JsonKey(includeFromJson: false, includeToJson: false)
   @override
   @pragma('vm:prefer-inline')
-  _$$LintingConfigImplCopyWith<_$LintingConfigImpl> get copyWith =>
-      __$$LintingConfigImplCopyWithImpl<_$LintingConfigImpl>(this, _$identity);
+  _$$InteractionImplCopyWith<_$InteractionImpl> get copyWith =>
+      __$$InteractionImplCopyWithImpl<_$InteractionImpl>(this, _$identity);
 }
 
-abstract class _LintingConfig implements LintingConfig {
-  const factory _LintingConfig(
-      {required final bool enabled,
-      final String? targetBranch}) = _$LintingConfigImpl;
+abstract class _Interaction extends Interaction {
+  const factory _Interaction(
+      {required final String id,
+      required final InteractionKind kind,
+      required final String from,
+      required final List<String> withMembers,
+      required final String note,
+      required final String timestamp,
+      required final bool shared,
+      required final List<String> principles,
+      final String? importedFrom}) = _$InteractionImpl;
+  const _Interaction._() : super._();
 
   @override
-  bool get enabled;
+  String get id;
   @override
-  String? get targetBranch;
+  InteractionKind get kind;
+  @override
+  String get from;
+  @override
+  List<String> get withMembers;
+  @override
+  String get note;
+  @override
+  String get timestamp;
+  @override
+  bool get shared;
+  @override
+  List<String> get principles;
+  @override
+  String? get importedFrom;
 
-  /// Create a copy of LintingConfig
+  /// Create a copy of Interaction
   /// with the given fields replaced by the non-null parameter values.
   @override
   @JsonKey(includeFromJson: false, includeToJson: false)
-  _$$LintingConfigImplCopyWith<_$LintingConfigImpl> get copyWith =>
+  _$$InteractionImplCopyWith<_$InteractionImpl> get copyWith =>
       throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-mixin _$Member {
-  String get email => throw _privateConstructorUsedError;
-  String? get name => throw _privateConstructorUsedError;
-  String? get bio => throw _privateConstructorUsedError;
-  String? get timezone => throw _privateConstructorUsedError;
+mixin _$KeyResult {
+  String get description => throw _privateConstructorUsedError;
+  double get progress => throw _privateConstructorUsedError;
+  String? get notes => throw _privateConstructorUsedError;
 
-  /// Create a copy of Member
+  /// Create a copy of KeyResult
   /// with the given fields replaced by the non-null parameter values.
   @JsonKey(includeFromJson: false, includeToJson: false)
-  $MemberCopyWith<Member> get copyWith => throw _privateConstructorUsedError;
+  $KeyResultCopyWith<KeyResult> get copyWith =>
+      throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-abstract class $MemberCopyWith<$Res> {
-  factory $MemberCopyWith(Member value, $Res Function(Member) then) =
-      _$MemberCopyWithImpl<$Res, Member>;
+abstract class $KeyResultCopyWith<$Res> {
+  factory $KeyResultCopyWith(KeyResult value, $Res Function(KeyResult) then) =
+      _$KeyResultCopyWithImpl<$Res, KeyResult>;
   @useResult
-  $Res call({String email, String? name, String? bio, String? timezone});
+  $Res call({String description, double progress, String? notes});
 }
 
 /// @nodoc
-class _$MemberCopyWithImpl<$Res, $Val extends Member>
-    implements $MemberCopyWith<$Res> {
-  _$MemberCopyWithImpl(this._value, this._then);
+class _$KeyResultCopyWithImpl<$Res, $Val extends KeyResult>
+    implements $KeyResultCopyWith<$Res> {
+  _$KeyResultCopyWithImpl(this._value, this._then);
 
   // ignore: unused_field
   final $Val _value;
   // ignore: unused_field
   final $Res Function($Val) _then;
 
-  /// Create a copy of Member
+  /// Create a copy of KeyResult
   /// with the given fields replaced by the non-null parameter values.
   @pragma('vm:prefer-inline')
   @override
   $Res call({
-    Object? email = null,
-    Object? name = freezed,
-    Object? bio = freezed,
-    Object? timezone = freezed,
+    Object? description = null,
+    Object? progress = null,
+    Object? notes = freezed,
   }) {
     return _then(_value.copyWith(
-      email: null == email
-          ? _value.email
-          : email // ignore: cast_nullable_to_non_nullable
+      description: null == description
+          ? _value.description
+          : description // ignore: cast_nullable_to_non_nullable
               as String,
-      name: freezed == name
-          ? _value.name
-          : name // ignore: cast_nullable_to_non_nullable
-              as String?,
-      bio: freezed == bio
-          ? _value.bio
-          : bio // ignore: cast_nullable_to_non_nullable
-              as String?,
-      timezone: freezed == timezone
-          ? _value.timezone
-          : timezone // ignore: cast_nullable_to_non_nullable
+      progress: null == progress
+          ? _value.progress
+          : progress // ignore: cast_nullable_to_non_nullable
+              as double,
+      notes: freezed == notes
+          ? _value.notes
+          : notes // ignore: cast_nullable_to_non_nullable
               as String?,
     ) as $Val);
   }
 }
 
 /// @nodoc
-abstract class _$$MemberImplCopyWith<$Res> implements $MemberCopyWith<$Res> {
-  factory _$$MemberImplCopyWith(
-          _$MemberImpl value, $Res Function(_$MemberImpl) then) =
-      __$$MemberImplCopyWithImpl<$Res>;
+abstract class _$$KeyResultImplCopyWith<$Res>
+    implements $KeyResultCopyWith<$Res> {
+  factory _$$KeyResultImplCopyWith(
+          _$KeyResultImpl value, $Res Function(_$KeyResultImpl) then) =
+      __$$KeyResultImplCopyWithImpl<$Res>;
   @override
   @useResult
-  $Res call({String email, String? name, String? bio, String? timezone});
+  $Res call({String description, double progress, String? notes});
 }
 
 /// @nodoc
-class __$$MemberImplCopyWithImpl<$Res>
-    extends _$MemberCopyWithImpl<$Res, _$MemberImpl>
-    implements _$$MemberImplCopyWith<$Res> {
-  __$$MemberImplCopyWithImpl(
-      _$MemberImpl _value, $Res Function(_$MemberImpl) _then)
+class __$$KeyResultImplCopyWithImpl<$Res>
+    extends _$KeyResultCopyWithImpl<$Res, _$KeyResultImpl>
+    implements _$$KeyResultImplCopyWith<$Res> {
+  __$$KeyResultImplCopyWithImpl(
+      _$KeyResultImpl _value, $Res Function(_$KeyResultImpl) _then)
       : super(_value, _then);
 
-  /// Create a copy of Member
+  /// Create a copy of KeyResult
   /// with the given fields replaced by the non-null parameter values.
   @pragma('vm:prefer-inline')
   @override
   $Res call({
-    Object? email = null,
-    Object? name = freezed,
-    Object? bio = freezed,
-    Object? timezone = freezed,
+    Object? description = null,
+    Object? progress = null,
+    Object? notes = freezed,
   }) {
-    return _then(_$MemberImpl(
-      email: null == email
-          ? _value.email
-          : email // ignore: cast_nullable_to_non_nullable
+    return _then(_$KeyResultImpl(
+      description: null == description
+          ? _value.description
+          : description // ignore: cast_nullable_to_non_nullable
               as String,
-      name: freezed == name
-          ? _value.name
-          : name // ignore: cast_nullable_to_non_nullable
-              as String?,
-      bio: freezed == bio
-          ? _value.bio
-          : bio // ignore: cast_nullable_to_non_nullable
-              as String?,
-      timezone: freezed == timezone
-          ? _value.timezone
-          : timezone // ignore: cast_nullable_to_non_nullable
+      progress: null == progress
+          ? _value.progress
+          : progress // ignore: cast_nullable_to_non_nullable
+              as double,
+      notes: freezed == notes
+          ? _value.notes
+          : notes // ignore: cast_nullable_to_non_nullable
               as String?,
     ));
   }
@@ -991,1164 +1347,3642 @@ class __$$MemberImplCopyWithImpl<$Res>
 
 /// @nodoc
 
-class _$MemberImpl extends _Member {
-  const _$MemberImpl({required this.email, this.name, this.bio, this.timezone})
+class _$KeyResultImpl extends _KeyResult {
+  const _$KeyResultImpl(
+      {required this.description, required this.progress, this.notes})
       : super._();
 
   @override
-  final String email;
-  @override
-  final String? name;
+  final String description;
   @override
-  final String? bio;
+  final double progress;
   @override
-  final String? timezone;
+  final String? notes;
 
   @override
   String toString() {
-    return 'Member(email: $email, name: $name, bio: $bio, timezone: $timezone)';
+    return 'KeyResult(description: $description, progress: $progress, notes: $notes)';
   }
 
   @override
   bool operator ==(Object other) {
     return identical(this, other) ||
         (other.runtimeType == runtimeType &&
-            other is _$MemberImpl &&
-            (identical(other.email, email) || other.email == email) &&
-            (identical(other.name, name) || other.name == name) &&
-            (identical(other.bio, bio) || other.bio == bio) &&
-            (identical(other.timezone, timezone) ||
-                other.timezone == timezone));
+            other is _$KeyResultImpl &&
+            (identical(other.description, description) ||
+                other.description == description) &&
+            (identical(other.progress, progress) ||
+                other.progress == progress) &&
+            (identical(other.notes, notes) || other.notes == notes));
   }
 
   @override
-  int get hashCode => Object.hash(runtimeType, email, name, bio, timezone);
+  int get hashCode => Object.hash(runtimeType, description, progress, notes);
 
-  /// Create a copy of Member
+  /// Create a copy of KeyResult
   /// with the given fields replaced by the non-null parameter values.
   @JsonKey(includeFromJson: false, includeToJson: false)
   @override
   @pragma('vm:prefer-inline')
-  _$$MemberImplCopyWith<_$MemberImpl> get copyWith =>
-      __$$MemberImplCopyWithImpl<_$MemberImpl>(this, _$identity);
+  _$$KeyResultImplCopyWith<_$KeyResultImpl> get copyWith =>
+      __$$KeyResultImplCopyWithImpl<_$KeyResultImpl>(this, _$identity);
 }
 
-abstract class _Member extends Member {
-  const factory _Member(
-      {required final String email,
-      final String? name,
-      final String? bio,
-      final String? timezone}) = _$MemberImpl;
-  const _Member._() : super._();
+abstract class _KeyResult extends KeyResult {
+  const factory _KeyResult(
+      {required final String description,
+      required final double progress,
+      final String? notes}) = _$KeyResultImpl;
+  const _KeyResult._() : super._();
 
   @override
-  String get email;
-  @override
-  String? get name;
+  String get description;
   @override
-  String? get bio;
+  double get progress;
   @override
-  String? get timezone;
+  String? get notes;
 
-  /// Create a copy of Member
+  /// Create a copy of KeyResult
   /// with the given fields replaced by the non-null parameter values.
   @override
   @JsonKey(includeFromJson: false, includeToJson: false)
-  _$$MemberImplCopyWith<_$MemberImpl> get copyWith =>
+  _$$KeyResultImplCopyWith<_$KeyResultImpl> get copyWith =>
       throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-mixin _$MemberCredentials {
-  String get email => throw _privateConstructorUsedError;
-  Credentials get credentials => throw _privateConstructorUsedError;
+mixin _$LintingConfig {
+  bool get enabled => throw _privateConstructorUsedError;
+  String? get targetBranch => throw _privateConstructorUsedError;
 
-  /// Create a copy of MemberCredentials
+  /// Create a copy of LintingConfig
   /// with the given fields replaced by the non-null parameter values.
   @JsonKey(includeFromJson: false, includeToJson: false)
-  $MemberCredentialsCopyWith<MemberCredentials> get copyWith =>
+  $LintingConfigCopyWith<LintingConfig> get copyWith =>
       throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-abstract class $MemberCredentialsCopyWith<$Res> {
-  factory $MemberCredentialsCopyWith(
-          MemberCredentials value, $Res Function(MemberCredentials) then) =
-      _$MemberCredentialsCopyWithImpl<$Res, MemberCredentials>;
+abstract class $LintingConfigCopyWith<$Res> {
+  factory $LintingConfigCopyWith(
+          LintingConfig value, $Res Function(LintingConfig) then) =
+      _$LintingConfigCopyWithImpl<$Res, LintingConfig>;
   @useResult
-  $Res call({String email, Credentials credentials});
-
-  $CredentialsCopyWith<$Res> get credentials;
+  $Res call({bool enabled, String? targetBranch});
 }
 
 /// @nodoc
-class _$MemberCredentialsCopyWithImpl<$Res, $Val extends MemberCredentials>
-    implements $MemberCredentialsCopyWith<$Res> {
-  _$MemberCredentialsCopyWithImpl(this._value, this._then);
+class _$LintingConfigCopyWithImpl<$Res, $Val extends LintingConfig>
+    implements $LintingConfigCopyWith<$Res> {
+  _$LintingConfigCopyWithImpl(this._value, this._then);
 
   // ignore: unused_field
   final $Val _value;
   // ignore: unused_field
   final $Res Function($Val) _then;
 
-  /// Create a copy of MemberCredentials
+  /// Create a copy of LintingConfig
   /// with the given fields replaced by the non-null parameter values.
   @pragma('vm:prefer-inline')
   @override
   $Res call({
-    Object? email = null,
-    Object? credentials = null,
+    Object? enabled = null,
+    Object? targetBranch = freezed,
   }) {
     return _then(_value.copyWith(
-      email: null == email
-          ? _value.email
-          : email // ignore: cast_nullable_to_non_nullable
-              as String,
-      credentials: null == credentials
-          ? _value.credentials
-          : credentials // ignore: cast_nullable_to_non_nullable
-              as Credentials,
+      enabled: null == enabled
+          ? _value.enabled
+          : enabled // ignore: cast_nullable_to_non_nullable
+              as bool,
+      targetBranch: freezed == targetBranch
+          ? _value.targetBranch
+          : targetBranch // ignore: cast_nullable_to_non_nullable
+              as String?,
     ) as $Val);
   }
-
-  /// Create a copy of MemberCredentials
-  /// with the given fields replaced by the non-null parameter values.
-  @override
-  @pragma('vm:prefer-inline')
-  $CredentialsCopyWith<$Res> get credentials {
-    return $CredentialsCopyWith<$Res>(_value.credentials, (value) {
-      return _then(_value.copyWith(credentials: value) as $Val);
-    });
-  }
 }
 
 /// @nodoc
-abstract class _$$MemberCredentialsImplCopyWith<$Res>
-    implements $MemberCredentialsCopyWith<$Res> {
-  factory _$$MemberCredentialsImplCopyWith(_$MemberCredentialsImpl value,
-          $Res Function(_$MemberCredentialsImpl) then) =
-      __$$MemberCredentialsImplCopyWithImpl<$Res>;
+abstract class _$$LintingConfigImplCopyWith<$Res>
+    implements $LintingConfigCopyWith<$Res> {
+  factory _$$LintingConfigImplCopyWith(
+          _$LintingConfigImpl value, $Res Function(_$LintingConfigImpl) then) =
+      __$$LintingConfigImplCopyWithImpl<$Res>;
   @override
   @useResult
-  $Res call({String email, Credentials credentials});
-
-  @override
-  $CredentialsCopyWith<$Res> get credentials;
+  $Res call({bool enabled, String? targetBranch});
 }
 
 /// @nodoc
-class __$$MemberCredentialsImplCopyWithImpl<$Res>
-    extends _$MemberCredentialsCopyWithImpl<$Res, _$MemberCredentialsImpl>
-    implements _$$MemberCredentialsImplCopyWith<$Res> {
-  __$$MemberCredentialsImplCopyWithImpl(_$MemberCredentialsImpl _value,
-      $Res Function(_$MemberCredentialsImpl) _then)
+class __$$LintingConfigImplCopyWithImpl<$Res>
+    extends _$LintingConfigCopyWithImpl<$Res, _$LintingConfigImpl>
+    implements _$$LintingConfigImplCopyWith<$Res> {
+  __$$LintingConfigImplCopyWithImpl(
+      _$LintingConfigImpl _value, $Res Function(_$LintingConfigImpl) _then)
       : super(_value, _then);
 
-  /// Create a copy of MemberCredentials
+  /// Create a copy of LintingConfig
   /// with the given fields replaced by the non-null parameter values.
   @pragma('vm:prefer-inline')
   @override
   $Res call({
-    Object? email = null,
-    Object? credentials = null,
+    Object? enabled = null,
+    Object? targetBranch = freezed,
   }) {
-    return _then(_$MemberCredentialsImpl(
-      email: null == email
-          ? _value.email
-          : email // ignore: cast_nullable_to_non_nullable
-              as String,
-      credentials: null == credentials
-          ? _value.credentials
-          : credentials // ignore: cast_nullable_to_non_nullable
-              as Credentials,
+    return _then(_$LintingConfigImpl(
+      enabled: null == enabled
+          ? _value.enabled
+          : enabled // ignore: cast_nullable_to_non_nullable
+              as bool,
+      targetBranch: freezed == targetBranch
+          ? _value.targetBranch
+          : targetBranch // ignore: cast_nullable_to_non_nullable
+              as String?,
     ));
   }
 }
 
 /// @nodoc
 
-class _$MemberCredentialsImpl extends _MemberCredentials {
-  const _$MemberCredentialsImpl(
-      {required this.email, required this.credentials})
-      : super._();
+class _$LintingConfigImpl implements _LintingConfig {
+  const _$LintingConfigImpl({required this.enabled, this.targetBranch});
 
   @override
-  final String email;
+  final bool enabled;
   @override
-  final Credentials credentials;
+  final String? targetBranch;
 
   @override
   String toString() {
-    return 'MemberCredentials(email: $email, credentials: $credentials)';
+    return 'LintingConfig(enabled: $enabled, targetBranch: $targetBranch)';
   }
 
   @override
   bool operator ==(Object other) {
     return identical(this, other) ||
         (other.runtimeType == runtimeType &&
-            other is _$MemberCredentialsImpl &&
-            (identical(other.email, email) || other.email == email) &&
-            (identical(other.credentials, credentials) ||
-                other.credentials == credentials));
+            other is _$LintingConfigImpl &&
+            (identical(other.enabled, enabled) || other.enabled == enabled) &&
+            (identical(other.targetBranch, targetBranch) ||
+                other.targetBranch == targetBranch));
   }
 
   @override
-  int get hashCode => Object.hash(runtimeType, email, credentials);
+  int get hashCode => Object.hash(runtimeType, enabled, targetBranch);
 
-  /// Create a copy of MemberCredentials
+  /// Create a copy of LintingConfig
   /// with the given fields replaced by the non-null parameter values.
   @JsonKey(includeFromJson: false, includeToJson: false)
   @override
   @pragma('vm:prefer-inline')
-  _$$MemberCredentialsImplCopyWith<_$MemberCredentialsImpl> get copyWith =>
-      __$$MemberCredentialsImplCopyWithImpl<_$MemberCredentialsImpl>(
-          this, _$identity);
+  _$$LintingConfigImplCopyWith<_$LintingConfigImpl> get copyWith =>
+      __$$LintingConfigImplCopyWithImpl<_$LintingConfigImpl>(this, _$identity);
 }
 
-abstract class _MemberCredentials extends MemberCredentials {
-  const factory _MemberCredentials(
-      {required final String email,
-      required final Credentials credentials}) = _$MemberCredentialsImpl;
-  const _MemberCredentials._() : super._();
+abstract class _LintingConfig implements LintingConfig {
+  const factory _LintingConfig(
+      {required final bool enabled,
+      final String? targetBranch}) = _$LintingConfigImpl;
 
   @override
-  String get email;
+  bool get enabled;
   @override
-  Credentials get credentials;
+  String? get targetBranch;
 
-  /// Create a copy of MemberCredentials
+  /// Create a copy of LintingConfig
   /// with the given fields replaced by the non-null parameter values.
   @override
   @JsonKey(includeFromJson: false, includeToJson: false)
-  _$$MemberCredentialsImplCopyWith<_$MemberCredentialsImpl> get copyWith =>
+  _$$LintingConfigImplCopyWith<_$LintingConfigImpl> get copyWith =>
       throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-mixin _$Objective {
-  String get id => throw _privateConstructorUsedError;
-  String get title => throw _privateConstructorUsedError;
-  String? get description => throw _privateConstructorUsedError;
-  List<KeyResult> get keyResults => throw _privateConstructorUsedError;
-  OkrVisibility get visibility => throw _privateConstructorUsedError;
-  String? get owner => throw _privateConstructorUsedError;
-  String? get quarter => throw _privateConstructorUsedError;
+mixin _$Manifesto {
+  String? get preamble => throw _privateConstructorUsedError;
+  List<Principle> get principles => throw _privateConstructorUsedError;
 
-  /// Create a copy of Objective
+  /// Create a copy of Manifesto
   /// with the given fields replaced by the non-null parameter values.
   @JsonKey(includeFromJson: false, includeToJson: false)
-  $ObjectiveCopyWith<Objective> get copyWith =>
+  $ManifestoCopyWith<Manifesto> get copyWith =>
       throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-abstract class $ObjectiveCopyWith<$Res> {
-  factory $ObjectiveCopyWith(Objective value, $Res Function(Objective) then) =
-      _$ObjectiveCopyWithImpl<$Res, Objective>;
+abstract class $ManifestoCopyWith<$Res> {
+  factory $ManifestoCopyWith(Manifesto value, $Res Function(Manifesto) then) =
+      _$ManifestoCopyWithImpl<$Res, Manifesto>;
   @useResult
-  $Res call(
-      {String id,
-      String title,
-      String? description,
-      List<KeyResult> keyResults,
-      OkrVisibility visibility,
-      String? owner,
-      String? quarter});
+  $Res call({String? preamble, List<Principle> principles});
 }
 
 /// @nodoc
-class _$ObjectiveCopyWithImpl<$Res, $Val extends Objective>
-    implements $ObjectiveCopyWith<$Res> {
-  _$ObjectiveCopyWithImpl(this._value, this._then);
+class _$ManifestoCopyWithImpl<$Res, $Val extends Manifesto>
+    implements $ManifestoCopyWith<$Res> {
+  _$ManifestoCopyWithImpl(this._value, this._then);
 
   // ignore: unused_field
   final $Val _value;
   // ignore: unused_field
   final $Res Function($Val) _then;
 
-  /// Create a copy of Objective
+  /// Create a copy of Manifesto
   /// with the given fields replaced by the non-null parameter values.
   @pragma('vm:prefer-inline')
   @override
   $Res call({
-    Object? id = null,
-    Object? title = null,
-    Object? description = freezed,
-    Object? keyResults = null,
-    Object? visibility = null,
-    Object? owner = freezed,
-    Object? quarter = freezed,
+    Object? preamble = freezed,
+    Object? principles = null,
   }) {
     return _then(_value.copyWith(
-      id: null == id
-          ? _value.id
-          : id // ignore: cast_nullable_to_non_nullable
-              as String,
-      title: null == title
-          ? _value.title
-          : title // ignore: cast_nullable_to_non_nullable
-              as String,
-      description: freezed == description
-          ? _value.description
-          : description // ignore: cast_nullable_to_non_nullable
-              as String?,
-      keyResults: null == keyResults
-          ? _value.keyResults
-          : keyResults // ignore: cast_nullable_to_non_nullable
-              as List<KeyResult>,
-      visibility: null == visibility
-          ? _value.visibility
-          : visibility // ignore: cast_nullable_to_non_nullable
-              as OkrVisibility,
-      owner: freezed == owner
-          ? _value.owner
-          : owner // ignore: cast_nullable_to_non_nullable
-              as String?,
-      quarter: freezed == quarter
-          ? _value.quarter
-          : quarter // ignore: cast_nullable_to_non_nullable
+      preamble: freezed == preamble
+          ? _value.preamble
+          : preamble // ignore: cast_nullable_to_non_nullable
               as String?,
+      principles: null == principles
+          ? _value.principles
+          : principles // ignore: cast_nullable_to_non_nullable
+              as List<Principle>,
     ) as $Val);
   }
 }
 
 /// @nodoc
-abstract class _$$ObjectiveImplCopyWith<$Res>
-    implements $ObjectiveCopyWith<$Res> {
-  factory _$$ObjectiveImplCopyWith(
-          _$ObjectiveImpl value, $Res Function(_$ObjectiveImpl) then) =
-      __$$ObjectiveImplCopyWithImpl<$Res>;
+abstract class _$$ManifestoImplCopyWith<$Res>
+    implements $ManifestoCopyWith<$Res> {
+  factory _$$ManifestoImplCopyWith(
+          _$ManifestoImpl value, $Res Function(_$ManifestoImpl) then) =
+      __$$ManifestoImplCopyWithImpl<$Res>;
   @override
   @useResult
-  $Res call(
-      {String id,
-      String title,
-      String? description,
-      List<KeyResult> keyResults,
-      OkrVisibility visibility,
-      String? owner,
-      String? quarter});
+  $Res call({String? preamble, List<Principle> principles});
 }
 
 /// @nodoc
-class __$$ObjectiveImplCopyWithImpl<$Res>
-    extends _$ObjectiveCopyWithImpl<$Res, _$ObjectiveImpl>
-    implements _$$ObjectiveImplCopyWith<$Res> {
-  __$$ObjectiveImplCopyWithImpl(
-      _$ObjectiveImpl _value, $Res Function(_$ObjectiveImpl) _then)
+class __$$ManifestoImplCopyWithImpl<$Res>
+    extends _$ManifestoCopyWithImpl<$Res, _$ManifestoImpl>
+    implements _$$ManifestoImplCopyWith<$Res> {
+  __$$ManifestoImplCopyWithImpl(
+      _$ManifestoImpl _value, $Res Function(_$ManifestoImpl) _then)
       : super(_value, _then);
 
-  /// Create a copy of Objective
+  /// Create a copy of Manifesto
   /// with the given fields replaced by the non-null parameter values.
   @pragma('vm:prefer-inline')
   @override
   $Res call({
-    Object? id = null,
-    Object? title = null,
-    Object? description = freezed,
-    Object? keyResults = null,
-    Object? visibility = null,
-    Object? owner = freezed,
-    Object? quarter = freezed,
+    Object? preamble = freezed,
+    Object? principles = null,
   }) {
-    return _then(_$ObjectiveImpl(
-      id: null == id
-          ? _value.id
-          : id // ignore: cast_nullable_to_non_nullable
-              as String,
-      title: null == title
-          ? _value.title
-          : title // ignore: cast_nullable_to_non_nullable
-              as String,
-      description: freezed == description
-          ? _value.description
-          : description // ignore: cast_nullable_to_non_nullable
-              as String?,
-      keyResults: null == keyResults
-          ? _value._keyResults
-          : keyResults // ignore: cast_nullable_to_non_nullable
-              as List<KeyResult>,
-      visibility: null == visibility
-          ? _value.visibility
-          : visibility // ignore: cast_nullable_to_non_nullable
-              as OkrVisibility,
-      owner: freezed == owner
-          ? _value.owner
-          : owner // ignore: cast_nullable_to_non_nullable
-              as String?,
-      quarter: freezed == quarter
-          ? _value.quarter
-          : quarter // ignore: cast_nullable_to_non_nullable
+    return _then(_$ManifestoImpl(
+      preamble: freezed == preamble
+          ? _value.preamble
+          : preamble // ignore: cast_nullable_to_non_nullable
               as String?,
+      principles: null == principles
+          ? _value._principles
+          : principles // ignore: cast_nullable_to_non_nullable
+              as List<Principle>,
     ));
   }
 }
 
 /// @nodoc
 
-class _$ObjectiveImpl extends _Objective {
-  const _$ObjectiveImpl(
-      {required this.id,
-      required this.title,
-      this.description,
-      required final List<KeyResult> keyResults,
-      required this.visibility,
-      this.owner,
-      this.quarter})
-      : _keyResults = keyResults,
-        super._();
+class _$ManifestoImpl implements _Manifesto {
+  const _$ManifestoImpl(
+      {this.preamble, required final List<Principle> principles})
+      : _principles = principles;
 
   @override
-  final String id;
-  @override
-  final String title;
-  @override
-  final String? description;
-  final List<KeyResult> _keyResults;
+  final String? preamble;
+  final List<Principle> _principles;
   @override
-  List<KeyResult> get keyResults {
-    if (_keyResults is EqualUnmodifiableListView) return _keyResults;
+  List<Principle> get principles {
+    if (_principles is EqualUnmodifiableListView) return _principles;
     // ignore: implicit_dynamic_type
-    return EqualUnmodifiableListView(_keyResults);
+    return EqualUnmodifiableListView(_principles);
   }
 
-  @override
-  final OkrVisibility visibility;
-  @override
-  final String? owner;
-  @override
-  final String? quarter;
-
   @override
   String toString() {
-    return 'Objective(id: $id, title: $title, description: $description, keyResults: $keyResults, visibility: $visibility, owner: $owner, quarter: $quarter)';
+    return 'Manifesto(preamble: $preamble, principles: $principles)';
   }
 
   @override
   bool operator ==(Object other) {
     return identical(this, other) ||
         (other.runtimeType == runtimeType &&
-            other is _$ObjectiveImpl &&
-            (identical(other.id, id) || other.id == id) &&
-            (identical(other.title, title) || other.title == title) &&
-            (identical(other.description, description) ||
-                other.description == description) &&
+            other is _$ManifestoImpl &&
+            (identical(other.preamble, preamble) ||
+                other.preamble == preamble) &&
             const DeepCollectionEquality()
-                .equals(other._keyResults, _keyResults) &&
-            (identical(other.visibility, visibility) ||
-                other.visibility == visibility) &&
-            (identical(other.owner, owner) || other.owner == owner) &&
-            (identical(other.quarter, quarter) || other.quarter == quarter));
+                .equals(other._principles, _principles));
   }
 
   @override
   int get hashCode => Object.hash(
-      runtimeType,
-      id,
-      title,
-      description,
-      const DeepCollectionEquality().hash(_keyResults),
-      visibility,
-      owner,
-      quarter);
+      runtimeType, preamble, const DeepCollectionEquality().hash(_principles));
 
-  /// Create a copy of Objective
+  /// Create a copy of Manifesto
   /// with the given fields replaced by the non-null parameter values.
   @JsonKey(includeFromJson: false, includeToJson: false)
   @override
   @pragma('vm:prefer-inline')
-  _$$ObjectiveImplCopyWith<_$ObjectiveImpl> get copyWith =>
-      __$$ObjectiveImplCopyWithImpl<_$ObjectiveImpl>(this, _$identity);
+  _$$ManifestoImplCopyWith<_$ManifestoImpl> get copyWith =>
+      __$$ManifestoImplCopyWithImpl<_$ManifestoImpl>(this, _$identity);
 }
 
-abstract class _Objective extends Objective {
-  const factory _Objective(
-      {required final String id,
-      required final String title,
-      final String? description,
-      required final List<KeyResult> keyResults,
-      required final OkrVisibility visibility,
-      final String? owner,
-      final String? quarter}) = _$ObjectiveImpl;
-  const _Objective._() : super._();
+abstract class _Manifesto implements Manifesto {
+  const factory _Manifesto(
+      {final String? preamble,
+      required final List<Principle> principles}) = _$ManifestoImpl;
 
   @override
-  String get id;
-  @override
-  String get title;
-  @override
-  String? get description;
-  @override
-  List<KeyResult> get keyResults;
-  @override
-  OkrVisibility get visibility;
-  @override
-  String? get owner;
+  String? get preamble;
   @override
-  String? get quarter;
+  List<Principle> get principles;
 
-  /// Create a copy of Objective
+  /// Create a copy of Manifesto
   /// with the given fields replaced by the non-null parameter values.
   @override
   @JsonKey(includeFromJson: false, includeToJson: false)
-  _$$ObjectiveImplCopyWith<_$ObjectiveImpl> get copyWith =>
+  _$$ManifestoImplCopyWith<_$ManifestoImpl> get copyWith =>
       throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-mixin _$PublishConfig {
-  String? get manifesto => throw _privateConstructorUsedError;
-  String? get vision => throw _privateConstructorUsedError;
-  String? get okrs => throw _privateConstructorUsedError;
+mixin _$Member {
+  String get email => throw _privateConstructorUsedError;
+  String? get name => throw _privateConstructorUsedError;
+  String? get bio => throw _privateConstructorUsedError;
+  String? get timezone => throw _privateConstructorUsedError;
+  List<String> get aliases => throw _privateConstructorUsedError;
 
-  /// Create a copy of PublishConfig
+  /// Create a copy of Member
   /// with the given fields replaced by the non-null parameter values.
   @JsonKey(includeFromJson: false, includeToJson: false)
-  $PublishConfigCopyWith<PublishConfig> get copyWith =>
-      throw _privateConstructorUsedError;
+  $MemberCopyWith<Member> get copyWith => throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-abstract class $PublishConfigCopyWith<$Res> {
-  factory $PublishConfigCopyWith(
-          PublishConfig value, $Res Function(PublishConfig) then) =
-      _$PublishConfigCopyWithImpl<$Res, PublishConfig>;
+abstract class $MemberCopyWith<$Res> {
+  factory $MemberCopyWith(Member value, $Res Function(Member) then) =
+      _$MemberCopyWithImpl<$Res, Member>;
   @useResult
-  $Res call({String? manifesto, String? vision, String? okrs});
+  $Res call(
+      {String email,
+      String? name,
+      String? bio,
+      String? timezone,
+      List<String> aliases});
 }
 
 /// @nodoc
-class _$PublishConfigCopyWithImpl<$Res, $Val extends PublishConfig>
-    implements $PublishConfigCopyWith<$Res> {
-  _$PublishConfigCopyWithImpl(this._value, this._then);
+class _$MemberCopyWithImpl<$Res, $Val extends Member>
+    implements $MemberCopyWith<$Res> {
+  _$MemberCopyWithImpl(this._value, this._then);
 
   // ignore: unused_field
   final $Val _value;
   // ignore: unused_field
   final $Res Function($Val) _then;
 
-  /// Create a copy of PublishConfig
+  /// Create a copy of Member
   /// with the given fields replaced by the non-null parameter values.
   @pragma('vm:prefer-inline')
   @override
   $Res call({
-    Object? manifesto = freezed,
-    Object? vision = freezed,
-    Object? okrs = freezed,
+    Object? email = null,
+    Object? name = freezed,
+    Object? bio = freezed,
+    Object? timezone = freezed,
+    Object? aliases = null,
   }) {
     return _then(_value.copyWith(
-      manifesto: freezed == manifesto
-          ? _value.manifesto
-          : manifesto // ignore: cast_nullable_to_non_nullable
+      email: null == email
+          ? _value.email
+          : email // ignore: cast_nullable_to_non_nullable
+              as String,
+      name: freezed == name
+          ? _value.name
+          : name // ignore: cast_nullable_to_non_nullable
               as String?,
-      vision: freezed == vision
-          ? _value.vision
-          : vision // ignore: cast_nullable_to_non_nullable
+      bio: freezed == bio
+          ? _value.bio
+          : bio // ignore: cast_nullable_to_non_nullable
               as String?,
-      okrs: freezed == okrs
-          ? _value.okrs
-          : okrs // ignore: cast_nullable_to_non_nullable
+      timezone: freezed == timezone
+          ? _value.timezone
+          : timezone // ignore: cast_nullable_to_non_nullable
               as String?,
+      aliases: null == aliases
+          ? _value.aliases
+          : aliases // ignore: cast_nullable_to_non_nullable
+              as List<String>,
     ) as $Val);
   }
 }
 
 /// @nodoc
-abstract class _$$PublishConfigImplCopyWith<$Res>
-    implements $PublishConfigCopyWith<$Res> {
-  factory _$$PublishConfigImplCopyWith(
-          _$PublishConfigImpl value, $Res Function(_$PublishConfigImpl) then) =
-      __$$PublishConfigImplCopyWithImpl<$Res>;
+abstract class _$$MemberImplCopyWith<$Res> implements $MemberCopyWith<$Res> {
+  factory _$$MemberImplCopyWith(
+          _$MemberImpl value, $Res Function(_$MemberImpl) then) =
+      __$$MemberImplCopyWithImpl<$Res>;
   @override
   @useResult
-  $Res call({String? manifesto, String? vision, String? okrs});
+  $Res call(
+      {String email,
+      String? name,
+      String? bio,
+      String? timezone,
+      List<String> aliases});
 }
 
 /// @nodoc
-class __$$PublishConfigImplCopyWithImpl<$Res>
-    extends _$PublishConfigCopyWithImpl<$Res, _$PublishConfigImpl>
-    implements _$$PublishConfigImplCopyWith<$Res> {
-  __$$PublishConfigImplCopyWithImpl(
-      _$PublishConfigImpl _value, $Res Function(_$PublishConfigImpl) _then)
+class __$$MemberImplCopyWithImpl<$Res>
+    extends _$MemberCopyWithImpl<$Res, _$MemberImpl>
+    implements _$$MemberImplCopyWith<$Res> {
+  __$$MemberImplCopyWithImpl(
+      _$MemberImpl _value, $Res Function(_$MemberImpl) _then)
       : super(_value, _then);
 
-  /// Create a copy of PublishConfig
+  /// Create a copy of Member
   /// with the given fields replaced by the non-null parameter values.
   @pragma('vm:prefer-inline')
   @override
   $Res call({
-    Object? manifesto = freezed,
-    Object? vision = freezed,
-    Object? okrs = freezed,
+    Object? email = null,
+    Object? name = freezed,
+    Object? bio = freezed,
+    Object? timezone = freezed,
+    Object? aliases = null,
   }) {
-    return _then(_$PublishConfigImpl(
-      manifesto: freezed == manifesto
-          ? _value.manifesto
-          : manifesto // ignore: cast_nullable_to_non_nullable
+    return _then(_$MemberImpl(
+      email: null == email
+          ? _value.email
+          : email // ignore: cast_nullable_to_non_nullable
+              as String,
+      name: freezed == name
+          ? _value.name
+          : name // ignore: cast_nullable_to_non_nullable
               as String?,
-      vision: freezed == vision
-          ? _value.vision
-          : vision // ignore: cast_nullable_to_non_nullable
+      bio: freezed == bio
+          ? _value.bio
+          : bio // ignore: cast_nullable_to_non_nullable
               as String?,
-      okrs: freezed == okrs
-          ? _value.okrs
-          : okrs // ignore: cast_nullable_to_non_nullable
+      timezone: freezed == timezone
+          ? _value.timezone
+          : timezone // ignore: cast_nullable_to_non_nullable
               as String?,
+      aliases: null == aliases
+          ? _value._aliases
+          : aliases // ignore: cast_nullable_to_non_nullable
+              as List<String>,
     ));
   }
 }
 
 /// @nodoc
 
-class _$PublishConfigImpl implements _PublishConfig {
-  const _$PublishConfigImpl({this.manifesto, this.vision, this.okrs});
+class _$MemberImpl extends _Member {
+  const _$MemberImpl(
+      {required this.email,
+      this.name,
+      this.bio,
+      this.timezone,
+      required final List<String> aliases})
+      : _aliases = aliases,
+        super._();
 
   @override
-  final String? manifesto;
+  final String email;
   @override
-  final String? vision;
+  final String? name;
   @override
-  final String? okrs;
-
+  final String? bio;
   @override
-  String toString() {
-    return 'PublishConfig(manifesto: $manifesto, vision: $vision, okrs: $okrs)';
-  }
+  final String? timezone;
+  final List<String> _aliases;
+  @override
+  List<String> get aliases {
+    if (_aliases is EqualUnmodifiableListView) return _aliases;
+    // ignore: implicit_dynamic_type
+    return EqualUnmodifiableListView(_aliases);
+  }
+
+  @override
+  String toString() {
+    return 'Member(email: $email, name: $name, bio: $bio, timezone: $timezone, aliases: $aliases)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$MemberImpl &&
+            (identical(other.email, email) || other.email == email) &&
+            (identical(other.name, name) || other.name == name) &&
+            (identical(other.bio, bio) || other.bio == bio) &&
+            (identical(other.timezone, timezone) ||
+                other.timezone == timezone) &&
+            const DeepCollectionEquality().equals(other._aliases, _aliases));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, email, name, bio, timezone,
+      const DeepCollectionEquality().hash(_aliases));
+
+  /// Create a copy of Member
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$MemberImplCopyWith<_$MemberImpl> get copyWith =>
+      __$$MemberImplCopyWithImpl<_$MemberImpl>(this, _$identity);
+}
+
+abstract class _Member extends Member {
+  const factory _Member(
+      {required final String email,
+      final String? name,
+      final String? bio,
+      final String? timezone,
+      required final List<String> aliases}) = _$MemberImpl;
+  const _Member._() : super._();
+
+  @override
+  String get email;
+  @override
+  String? get name;
+  @override
+  String? get bio;
+  @override
+  String? get timezone;
+  @override
+  List<String> get aliases;
+
+  /// Create a copy of Member
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$MemberImplCopyWith<_$MemberImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+mixin _$MemberCredentials {
+  String get email => throw _privateConstructorUsedError;
+  Credentials get credentials => throw _privateConstructorUsedError;
+
+  /// Create a copy of MemberCredentials
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  $MemberCredentialsCopyWith<MemberCredentials> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $MemberCredentialsCopyWith<$Res> {
+  factory $MemberCredentialsCopyWith(
+          MemberCredentials value, $Res Function(MemberCredentials) then) =
+      _$MemberCredentialsCopyWithImpl<$Res, MemberCredentials>;
+  @useResult
+  $Res call({String email, Credentials credentials});
+
+  $CredentialsCopyWith<$Res> get credentials;
+}
+
+/// @nodoc
+class _$MemberCredentialsCopyWithImpl<$Res, $Val extends MemberCredentials>
+    implements $MemberCredentialsCopyWith<$Res> {
+  _$MemberCredentialsCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of MemberCredentials
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? email = null,
+    Object? credentials = null,
+  }) {
+    return _then(_value.copyWith(
+      email: null == email
+          ? _value.email
+          : email // ignore: cast_nullable_to_non_nullable
+              as String,
+      credentials: null == credentials
+          ? _value.credentials
+          : credentials // ignore: cast_nullable_to_non_nullable
+              as Credentials,
+    ) as $Val);
+  }
+
+  /// Create a copy of MemberCredentials
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @pragma('vm:prefer-inline')
+  $CredentialsCopyWith<$Res> get credentials {
+    return $CredentialsCopyWith<$Res>(_value.credentials, (value) {
+      return _then(_value.copyWith(credentials: value) as $Val);
+    });
+  }
+}
+
+/// @nodoc
+abstract class _$$MemberCredentialsImplCopyWith<$Res>
+    implements $MemberCredentialsCopyWith<$Res> {
+  factory _$$MemberCredentialsImplCopyWith(_$MemberCredentialsImpl value,
+          $Res Function(_$MemberCredentialsImpl) then) =
+      __$$MemberCredentialsImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call({String email, Credentials credentials});
+
+  @override
+  $CredentialsCopyWith<$Res> get credentials;
+}
+
+/// @nodoc
+class __$$MemberCredentialsImplCopyWithImpl<$Res>
+    extends _$MemberCredentialsCopyWithImpl<$Res, _$MemberCredentialsImpl>
+    implements _$$MemberCredentialsImplCopyWith<$Res> {
+  __$$MemberCredentialsImplCopyWithImpl(_$MemberCredentialsImpl _value,
+      $Res Function(_$MemberCredentialsImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of MemberCredentials
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? email = null,
+    Object? credentials = null,
+  }) {
+    return _then(_$MemberCredentialsImpl(
+      email: null == email
+          ? _value.email
+          : email // ignore: cast_nullable_to_non_nullable
+              as String,
+      credentials: null == credentials
+          ? _value.credentials
+          : credentials // ignore: cast_nullable_to_non_nullable
+              as Credentials,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$MemberCredentialsImpl extends _MemberCredentials {
+  const _$MemberCredentialsImpl(
+      {required this.email, required this.credentials})
+      : super._();
+
+  @override
+  final String email;
+  @override
+  final Credentials credentials;
+
+  @override
+  String toString() {
+    return 'MemberCredentials(email: $email, credentials: $credentials)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$MemberCredentialsImpl &&
+            (identical(other.email, email) || other.email == email) &&
+            (identical(other.credentials, credentials) ||
+                other.credentials == credentials));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, email, credentials);
+
+  /// Create a copy of MemberCredentials
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$MemberCredentialsImplCopyWith<_$MemberCredentialsImpl> get copyWith =>
+      __$$MemberCredentialsImplCopyWithImpl<_$MemberCredentialsImpl>(
+          this, _$identity);
+}
+
+abstract class _MemberCredentials extends MemberCredentials {
+  const factory _MemberCredentials(
+      {required final String email,
+      required final Credentials credentials}) = _$MemberCredentialsImpl;
+  const _MemberCredentials._() : super._();
+
+  @override
+  String get email;
+  @override
+  Credentials get credentials;
+
+  /// Create a copy of MemberCredentials
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$MemberCredentialsImplCopyWith<_$MemberCredentialsImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+mixin _$MemberInteractionStats {
+  String get email => throw _privateConstructorUsedError;
+  int get kudosGiven => throw _privateConstructorUsedError;
+  int get kudosReceived => throw _privateConstructorUsedError;
+  int get feedbackGiven => throw _privateConstructorUsedError;
+  int get feedbackReceived => throw _privateConstructorUsedError;
+  int get totalSent => throw _privateConstructorUsedError;
+  int get totalReceived => throw _privateConstructorUsedError;
+
+  /// Create a copy of MemberInteractionStats
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  $MemberInteractionStatsCopyWith<MemberInteractionStats> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $MemberInteractionStatsCopyWith<$Res> {
+  factory $MemberInteractionStatsCopyWith(MemberInteractionStats value,
+          $Res Function(MemberInteractionStats) then) =
+      _$MemberInteractionStatsCopyWithImpl<$Res, MemberInteractionStats>;
+  @useResult
+  $Res call(
+      {String email,
+      int kudosGiven,
+      int kudosReceived,
+      int feedbackGiven,
+      int feedbackReceived,
+      int totalSent,
+      int totalReceived});
+}
+
+/// @nodoc
+class _$MemberInteractionStatsCopyWithImpl<$Res, $Val extends MemberInteractionStats>
+    implements $MemberInteractionStatsCopyWith<$Res> {
+  _$MemberInteractionStatsCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of MemberInteractionStats
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? email = null,
+    Object? kudosGiven = null,
+    Object? kudosReceived = null,
+    Object? feedbackGiven = null,
+    Object? feedbackReceived = null,
+    Object? totalSent = null,
+    Object? totalReceived = null,
+  }) {
+    return _then(_value.copyWith(
+      email: null == email
+          ? _value.email
+          : email // ignore: cast_nullable_to_non_nullable
+              as String,
+      kudosGiven: null == kudosGiven
+          ? _value.kudosGiven
+          : kudosGiven // ignore: cast_nullable_to_non_nullable
+              as int,
+      kudosReceived: null == kudosReceived
+          ? _value.kudosReceived
+          : kudosReceived // ignore: cast_nullable_to_non_nullable
+              as int,
+      feedbackGiven: null == feedbackGiven
+          ? _value.feedbackGiven
+          : feedbackGiven // ignore: cast_nullable_to_non_nullable
+              as int,
+      feedbackReceived: null == feedbackReceived
+          ? _value.feedbackReceived
+          : feedbackReceived // ignore: cast_nullable_to_non_nullable
+              as int,
+      totalSent: null == totalSent
+          ? _value.totalSent
+          : totalSent // ignore: cast_nullable_to_non_nullable
+              as int,
+      totalReceived: null == totalReceived
+          ? _value.totalReceived
+          : totalReceived // ignore: cast_nullable_to_non_nullable
+              as int,
+    ) as $Val);
+  }
+}
+
+/// @nodoc
+abstract class _$$MemberInteractionStatsImplCopyWith<$Res>
+    implements $MemberInteractionStatsCopyWith<$Res> {
+  factory _$$MemberInteractionStatsImplCopyWith(
+          _$MemberInteractionStatsImpl value,
+          $Res Function(_$MemberInteractionStatsImpl) then) =
+      __$$MemberInteractionStatsImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call(
+      {String email,
+      int kudosGiven,
+      int kudosReceived,
+      int feedbackGiven,
+      int feedbackReceived,
+      int totalSent,
+      int totalReceived});
+}
+
+/// @nodoc
+class __$$MemberInteractionStatsImplCopyWithImpl<$Res>
+    extends _$MemberInteractionStatsCopyWithImpl<$Res, _$MemberInteractionStatsImpl>
+    implements _$$MemberInteractionStatsImplCopyWith<$Res> {
+  __$$MemberInteractionStatsImplCopyWithImpl(
+      _$MemberInteractionStatsImpl _value,
+      $Res Function(_$MemberInteractionStatsImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of MemberInteractionStats
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? email = null,
+    Object? kudosGiven = null,
+    Object? kudosReceived = null,
+    Object? feedbackGiven = null,
+    Object? feedbackReceived = null,
+    Object? totalSent = null,
+    Object? totalReceived = null,
+  }) {
+    return _then(_$MemberInteractionStatsImpl(
+      email: null == email
+          ? _value.email
+          : email // ignore: cast_nullable_to_non_nullable
+              as String,
+      kudosGiven: null == kudosGiven
+          ? _value.kudosGiven
+          : kudosGiven // ignore: cast_nullable_to_non_nullable
+              as int,
+      kudosReceived: null == kudosReceived
+          ? _value.kudosReceived
+          : kudosReceived // ignore: cast_nullable_to_non_nullable
+              as int,
+      feedbackGiven: null == feedbackGiven
+          ? _value.feedbackGiven
+          : feedbackGiven // ignore: cast_nullable_to_non_nullable
+              as int,
+      feedbackReceived: null == feedbackReceived
+          ? _value.feedbackReceived
+          : feedbackReceived // ignore: cast_nullable_to_non_nullable
+              as int,
+      totalSent: null == totalSent
+          ? _value.totalSent
+          : totalSent // ignore: cast_nullable_to_non_nullable
+              as int,
+      totalReceived: null == totalReceived
+          ? _value.totalReceived
+          : totalReceived // ignore: cast_nullable_to_non_nullable
+              as int,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$MemberInteractionStatsImpl implements _MemberInteractionStats {
+  const _$MemberInteractionStatsImpl(
+      {required this.email,
+      required this.kudosGiven,
+      required this.kudosReceived,
+      required this.feedbackGiven,
+      required this.feedbackReceived,
+      required this.totalSent,
+      required this.totalReceived});
+
+  @override
+  final String email;
+  @override
+  final int kudosGiven;
+  @override
+  final int kudosReceived;
+  @override
+  final int feedbackGiven;
+  @override
+  final int feedbackReceived;
+  @override
+  final int totalSent;
+  @override
+  final int totalReceived;
+
+  @override
+  String toString() {
+    return 'MemberInteractionStats(email: $email, kudosGiven: $kudosGiven, kudosReceived: $kudosReceived, feedbackGiven: $feedbackGiven, feedbackReceived: $feedbackReceived, totalSent: $totalSent, totalReceived: $totalReceived)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$MemberInteractionStatsImpl &&
+            (identical(other.email, email) || other.email == email) &&
+            (identical(other.kudosGiven, kudosGiven) ||
+                other.kudosGiven == kudosGiven) &&
+            (identical(other.kudosReceived, kudosReceived) ||
+                other.kudosReceived == kudosReceived) &&
+            (identical(other.feedbackGiven, feedbackGiven) ||
+                other.feedbackGiven == feedbackGiven) &&
+            (identical(other.feedbackReceived, feedbackReceived) ||
+                other.feedbackReceived == feedbackReceived) &&
+            (identical(other.totalSent, totalSent) ||
+                other.totalSent == totalSent) &&
+            (identical(other.totalReceived, totalReceived) ||
+                other.totalReceived == totalReceived));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, email, kudosGiven, kudosReceived,
+      feedbackGiven, feedbackReceived, totalSent, totalReceived);
+
+  /// Create a copy of MemberInteractionStats
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$MemberInteractionStatsImplCopyWith<_$MemberInteractionStatsImpl> get copyWith =>
+      __$$MemberInteractionStatsImplCopyWithImpl<_$MemberInteractionStatsImpl>(
+          this,
+          _$identity);
+}
+
+abstract class _MemberInteractionStats implements MemberInteractionStats {
+  const factory _MemberInteractionStats(
+      {required final String email,
+      required final int kudosGiven,
+      required final int kudosReceived,
+      required final int feedbackGiven,
+      required final int feedbackReceived,
+      required final int totalSent,
+      required final int totalReceived}) = _$MemberInteractionStatsImpl;
+
+  @override
+  String get email;
+  @override
+  int get kudosGiven;
+  @override
+  int get kudosReceived;
+  @override
+  int get feedbackGiven;
+  @override
+  int get feedbackReceived;
+  @override
+  int get totalSent;
+  @override
+  int get totalReceived;
+
+  /// Create a copy of MemberInteractionStats
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$MemberInteractionStatsImplCopyWith<_$MemberInteractionStatsImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+mixin _$MemberPulse {
+  String get email => throw _privateConstructorUsedError;
+  PulseCadences get cadences => throw _privateConstructorUsedError;
+
+  /// Create a copy of MemberPulse
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  $MemberPulseCopyWith<MemberPulse> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $MemberPulseCopyWith<$Res> {
+  factory $MemberPulseCopyWith(
+          MemberPulse value, $Res Function(MemberPulse) then) =
+      _$MemberPulseCopyWithImpl<$Res, MemberPulse>;
+  @useResult
+  $Res call({String email, PulseCadences cadences});
+
+  $PulseCadencesCopyWith<$Res> get cadences;
+}
+
+/// @nodoc
+class _$MemberPulseCopyWithImpl<$Res, $Val extends MemberPulse>
+    implements $MemberPulseCopyWith<$Res> {
+  _$MemberPulseCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of MemberPulse
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? email = null,
+    Object? cadences = null,
+  }) {
+    return _then(_value.copyWith(
+      email: null == email
+          ? _value.email
+          : email // ignore: cast_nullable_to_non_nullable
+              as String,
+      cadences: null == cadences
+          ? _value.cadences
+          : cadences // ignore: cast_nullable_to_non_nullable
+              as PulseCadences,
+    ) as $Val);
+  }
+
+  /// Create a copy of MemberPulse
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @pragma('vm:prefer-inline')
+  $PulseCadencesCopyWith<$Res> get cadences {
+    return $PulseCadencesCopyWith<$Res>(_value.cadences, (value) {
+      return _then(_value.copyWith(cadences: value) as $Val);
+    });
+  }
+}
+
+/// @nodoc
+abstract class _$$MemberPulseImplCopyWith<$Res>
+    implements $MemberPulseCopyWith<$Res> {
+  factory _$$MemberPulseImplCopyWith(
+          _$MemberPulseImpl value, $Res Function(_$MemberPulseImpl) then) =
+      __$$MemberPulseImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call({String email, PulseCadences cadences});
+
+  @override
+  $PulseCadencesCopyWith<$Res> get cadences;
+}
+
+/// @nodoc
+class __$$MemberPulseImplCopyWithImpl<$Res>
+    extends _$MemberPulseCopyWithImpl<$Res, _$MemberPulseImpl>
+    implements _$$MemberPulseImplCopyWith<$Res> {
+  __$$MemberPulseImplCopyWithImpl(
+      _$MemberPulseImpl _value, $Res Function(_$MemberPulseImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of MemberPulse
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? email = null,
+    Object? cadences = null,
+  }) {
+    return _then(_$MemberPulseImpl(
+      email: null == email
+          ? _value.email
+          : email // ignore: cast_nullable_to_non_nullable
+              as String,
+      cadences: null == cadences
+          ? _value.cadences
+          : cadences // ignore: cast_nullable_to_non_nullable
+              as PulseCadences,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$MemberPulseImpl implements _MemberPulse {
+  const _$MemberPulseImpl({required this.email, required this.cadences});
+
+  @override
+  final String email;
+  @override
+  final PulseCadences cadences;
+
+  @override
+  String toString() {
+    return 'MemberPulse(email: $email, cadences: $cadences)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$MemberPulseImpl &&
+            (identical(other.email, email) || other.email == email) &&
+            (identical(other.cadences, cadences) ||
+                other.cadences == cadences));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, email, cadences);
+
+  /// Create a copy of MemberPulse
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$MemberPulseImplCopyWith<_$MemberPulseImpl> get copyWith =>
+      __$$MemberPulseImplCopyWithImpl<_$MemberPulseImpl>(this, _$identity);
+}
+
+abstract class _MemberPulse implements MemberPulse {
+  const factory _MemberPulse(
+      {required final String email,
+      required final PulseCadences cadences}) = _$MemberPulseImpl;
+
+  @override
+  String get email;
+  @override
+  PulseCadences get cadences;
+
+  /// Create a copy of MemberPulse
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$MemberPulseImplCopyWith<_$MemberPulseImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+mixin _$Objective {
+  String get id => throw _privateConstructorUsedError;
+  String get title => throw _privateConstructorUsedError;
+  String? get description => throw _privateConstructorUsedError;
+  List<KeyResult> get keyResults => throw _privateConstructorUsedError;
+  OkrVisibility get visibility => throw _privateConstructorUsedError;
+  String? get owner => throw _privateConstructorUsedError;
+  String? get quarter => throw _privateConstructorUsedError;
+  List<String> get principles => throw _privateConstructorUsedError;
+  String? get updated => throw _privateConstructorUsedError;
+
+  /// Create a copy of Objective
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  $ObjectiveCopyWith<Objective> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $ObjectiveCopyWith<$Res> {
+  factory $ObjectiveCopyWith(Objective value, $Res Function(Objective) then) =
+      _$ObjectiveCopyWithImpl<$Res, Objective>;
+  @useResult
+  $Res call(
+      {String id,
+      String title,
+      String? description,
+      List<KeyResult> keyResults,
+      OkrVisibility visibility,
+      String? owner,
+      String? quarter,
+      List<String> principles,
+      String? updated});
+}
+
+/// @nodoc
+class _$ObjectiveCopyWithImpl<$Res, $Val extends Objective>
+    implements $ObjectiveCopyWith<$Res> {
+  _$ObjectiveCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of Objective
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? id = null,
+    Object? title = null,
+    Object? description = freezed,
+    Object? keyResults = null,
+    Object? visibility = null,
+    Object? owner = freezed,
+    Object? quarter = freezed,
+    Object? principles = null,
+    Object? updated = freezed,
+  }) {
+    return _then(_value.copyWith(
+      id: null == id
+          ? _value.id
+          : id // ignore: cast_nullable_to_non_nullable
+              as String,
+      title: null == title
+          ? _value.title
+          : title // ignore: cast_nullable_to_non_nullable
+              as String,
+      description: freezed == description
+          ? _value.description
+          : description // ignore: cast_nullable_to_non_nullable
+              as String?,
+      keyResults: null == keyResults
+          ? _value.keyResults
+          : keyResults // ignore: cast_nullable_to_non_nullable
+              as List<KeyResult>,
+      visibility: null == visibility
+          ? _value.visibility
+          : visibility // ignore: cast_nullable_to_non_nullable
+              as OkrVisibility,
+      owner: freezed == owner
+          ? _value.owner
+          : owner // ignore: cast_nullable_to_non_nullable
+              as String?,
+      quarter: freezed == quarter
+          ? _value.quarter
+          : quarter // ignore: cast_nullable_to_non_nullable
+              as String?,
+      principles: null == principles
+          ? _value.principles
+          : principles // ignore: cast_nullable_to_non_nullable
+              as List<String>,
+      updated: freezed == updated
+          ? _value.updated
+          : updated // ignore: cast_nullable_to_non_nullable
+              as String?,
+    ) as $Val);
+  }
+}
+
+/// @nodoc
+abstract class _$$ObjectiveImplCopyWith<$Res>
+    implements $ObjectiveCopyWith<$Res> {
+  factory _$$ObjectiveImplCopyWith(
+          _$ObjectiveImpl value, $Res Function(_$ObjectiveImpl) then) =
+      __$$ObjectiveImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call(
+      {String id,
+      String title,
+      String? description,
+      List<KeyResult> keyResults,
+      OkrVisibility visibility,
+      String? owner,
+      String? quarter,
+      List<String> principles,
+      String? updated});
+}
+
+/// @nodoc
+class __$$ObjectiveImplCopyWithImpl<$Res>
+    extends _$ObjectiveCopyWithImpl<$Res, _$ObjectiveImpl>
+    implements _$$ObjectiveImplCopyWith<$Res> {
+  __$$ObjectiveImplCopyWithImpl(
+      _$ObjectiveImpl _value, $Res Function(_$ObjectiveImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of Objective
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? id = null,
+    Object? title = null,
+    Object? description = freezed,
+    Object? keyResults = null,
+    Object? visibility = null,
+    Object? owner = freezed,
+    Object? quarter = freezed,
+    Object? principles = null,
+    Object? updated = freezed,
+  }) {
+    return _then(_$ObjectiveImpl(
+      id: null == id
+          ? _value.id
+          : id // ignore: cast_nullable_to_non_nullable
+              as String,
+      title: null == title
+          ? _value.title
+          : title // ignore: cast_nullable_to_non_nullable
+              as String,
+      description: freezed == description
+          ? _value.description
+          : description // ignore: cast_nullable_to_non_nullable
+              as String?,
+      keyResults: null == keyResults
+          ? _value._keyResults
+          : keyResults // ignore: cast_nullable_to_non_nullable
+              as List<KeyResult>,
+      visibility: null == visibility
+          ? _value.visibility
+          : visibility // ignore: cast_nullable_to_non_nullable
+              as OkrVisibility,
+      owner: freezed == owner
+          ? _value.owner
+          : owner // ignore: cast_nullable_to_non_nullable
+              as String?,
+      quarter: freezed == quarter
+          ? _value.quarter
+          : quarter // ignore: cast_nullable_to_non_nullable
+              as String?,
+      principles: null == principles
+          ? _value._principles
+          : principles // ignore: cast_nullable_to_non_nullable
+              as List<String>,
+      updated: freezed == updated
+          ? _value.updated
+          : updated // ignore: cast_nullable_to_non_nullable
+              as String?,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$ObjectiveImpl extends _Objective {
+  const _$ObjectiveImpl(
+      {required this.id,
+      required this.title,
+      this.description,
+      required final List<KeyResult> keyResults,
+      required this.visibility,
+      this.owner,
+      this.quarter,
+      required final List<String> principles,
+      this.updated})
+      : _keyResults = keyResults,
+        _principles = principles,
+        super._();
+
+  @override
+  final String id;
+  @override
+  final String title;
+  @override
+  final String? description;
+  final List<KeyResult> _keyResults;
+  @override
+  List<KeyResult> get keyResults {
+    if (_keyResults is EqualUnmodifiableListView) return _keyResults;
+    // ignore: implicit_dynamic_type
+    return EqualUnmodifiableListView(_keyResults);
+  }
+
+  @override
+  final OkrVisibility visibility;
+  @override
+  final String? owner;
+  @override
+  final String? quarter;
+  final List<String> _principles;
+  @override
+  List<String> get principles {
+    if (_principles is EqualUnmodifiableListView) return _principles;
+    // ignore: implicit_dynamic_type
+    return EqualUnmodifiableListView(_principles);
+  }
+
+  @override
+  final String? updated;
+
+  @override
+  String toString() {
+    return 'Objective(id: $id, title: $title, description: $description, keyResults: $keyResults, visibility: $visibility, owner: $owner, quarter: $quarter, principles: $principles, updated: $updated)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$ObjectiveImpl &&
+            (identical(other.id, id) || other.id == id) &&
+            (identical(other.title, title) || other.title == title) &&
+            (identical(other.description, description) ||
+                other.description == description) &&
+            const DeepCollectionEquality()
+                .equals(other._keyResults, _keyResults) &&
+            (identical(other.visibility, visibility) ||
+                other.visibility == visibility) &&
+            (identical(other.owner, owner) || other.owner == owner) &&
+            (identical(other.quarter, quarter) || other.quarter == quarter) &&
+            const DeepCollectionEquality()
+                .equals(other._principles, _principles) &&
+            (identical(other.updated, updated) || other.updated == updated));
+  }
+
+  @override
+  int get hashCode => Object.hash(
+      runtimeType,
+      id,
+      title,
+      description,
+      const DeepCollectionEquality().hash(_keyResults),
+      visibility,
+      owner,
+      quarter,
+      const DeepCollectionEquality().hash(_principles),
+      updated);
+
+  /// Create a copy of Objective
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$ObjectiveImplCopyWith<_$ObjectiveImpl> get copyWith =>
+      __$$ObjectiveImplCopyWithImpl<_$ObjectiveImpl>(this, _$identity);
+}
+
+abstract class _Objective extends Objective {
+  const factory _Objective(
+      {required final String id,
+      required final String title,
+      final String? description,
+      required final List<KeyResult> keyResults,
+      required final OkrVisibility visibility,
+      final String? owner,
+      final String? quarter,
+      required final List<String> principles,
+      final String? updated}) = _$ObjectiveImpl;
+  const _Objective._() : super._();
+
+  @override
+  String get id;
+  @override
+  String get title;
+  @override
+  String? get description;
+  @override
+  List<KeyResult> get keyResults;
+  @override
+  OkrVisibility get visibility;
+  @override
+  String? get owner;
+  @override
+  String? get quarter;
+  @override
+  List<String> get principles;
+  @override
+  String? get updated;
+
+  /// Create a copy of Objective
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$ObjectiveImplCopyWith<_$ObjectiveImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+mixin _$PincodeCheck {
+  bool get verified => throw _privateConstructorUsedError;
+  String get email => throw _privateConstructorUsedError;
+  BigInt get retryAfterSecs => throw _privateConstructorUsedError;
+
+  /// Create a copy of PincodeCheck
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  $PincodeCheckCopyWith<PincodeCheck> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $PincodeCheckCopyWith<$Res> {
+  factory $PincodeCheckCopyWith(
+          PincodeCheck value, $Res Function(PincodeCheck) then) =
+      _$PincodeCheckCopyWithImpl<$Res, PincodeCheck>;
+  @useResult
+  $Res call({bool verified, String email, BigInt retryAfterSecs});
+}
+
+/// @nodoc
+class _$PincodeCheckCopyWithImpl<$Res, $Val extends PincodeCheck>
+    implements $PincodeCheckCopyWith<$Res> {
+  _$PincodeCheckCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of PincodeCheck
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? verified = null,
+    Object? email = null,
+    Object? retryAfterSecs = null,
+  }) {
+    return _then(_value.copyWith(
+      verified: null == verified
+          ? _value.verified
+          : verified // ignore: cast_nullable_to_non_nullable
+              as bool,
+      email: null == email
+          ? _value.email
+          : email // ignore: cast_nullable_to_non_nullable
+              as String,
+      retryAfterSecs: null == retryAfterSecs
+          ? _value.retryAfterSecs
+          : retryAfterSecs // ignore: cast_nullable_to_non_nullable
+              as BigInt,
+    ) as $Val);
+  }
+}
+
+/// @nodoc
+abstract class _$$PincodeCheckImplCopyWith<$Res>
+    implements $PincodeCheckCopyWith<$Res> {
+  factory _$$PincodeCheckImplCopyWith(
+          _$PincodeCheckImpl value, $Res Function(_$PincodeCheckImpl) then) =
+      __$$PincodeCheckImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call({bool verified, String email, BigInt retryAfterSecs});
+}
+
+/// @nodoc
+class __$$PincodeCheckImplCopyWithImpl<$Res>
+    extends _$PincodeCheckCopyWithImpl<$Res, _$PincodeCheckImpl>
+    implements _$$PincodeCheckImplCopyWith<$Res> {
+  __$$PincodeCheckImplCopyWithImpl(
+      _$PincodeCheckImpl _value, $Res Function(_$PincodeCheckImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of PincodeCheck
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? verified = null,
+    Object? email = null,
+    Object? retryAfterSecs = null,
+  }) {
+    return _then(_$PincodeCheckImpl(
+      verified: null == verified
+          ? _value.verified
+          : verified // ignore: cast_nullable_to_non_nullable
+              as bool,
+      email: null == email
+          ? _value.email
+          : email // ignore: cast_nullable_to_non_nullable
+              as String,
+      retryAfterSecs: null == retryAfterSecs
+          ? _value.retryAfterSecs
+          : retryAfterSecs // ignore: cast_nullable_to_non_nullable
+              as BigInt,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$PincodeCheckImpl implements _PincodeCheck {
+  const _$PincodeCheckImpl(
+      {required this.verified,
+      required this.email,
+      required this.retryAfterSecs});
+
+  @override
+  final bool verified;
+  @override
+  final String email;
+  @override
+  final BigInt retryAfterSecs;
+
+  @override
+  String toString() {
+    return 'PincodeCheck(verified: $verified, email: $email, retryAfterSecs: $retryAfterSecs)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$PincodeCheckImpl &&
+            (identical(other.verified, verified) ||
+                other.verified == verified) &&
+            (identical(other.email, email) || other.email == email) &&
+            (identical(other.retryAfterSecs, retryAfterSecs) ||
+                other.retryAfterSecs == retryAfterSecs));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, verified, email, retryAfterSecs);
+
+  /// Create a copy of PincodeCheck
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$PincodeCheckImplCopyWith<_$PincodeCheckImpl> get copyWith =>
+      __$$PincodeCheckImplCopyWithImpl<_$PincodeCheckImpl>(this, _$identity);
+}
+
+abstract class _PincodeCheck implements PincodeCheck {
+  const factory _PincodeCheck(
+      {required final bool verified,
+      required final String email,
+      required final BigInt retryAfterSecs}) = _$PincodeCheckImpl;
+
+  @override
+  bool get verified;
+  @override
+  String get email;
+  @override
+  BigInt get retryAfterSecs;
+
+  /// Create a copy of PincodeCheck
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$PincodeCheckImplCopyWith<_$PincodeCheckImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+mixin _$Principle {
+  String get id => throw _privateConstructorUsedError;
+  String get title => throw _privateConstructorUsedError;
+  String? get description => throw _privateConstructorUsedError;
+
+  /// Create a copy of Principle
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  $PrincipleCopyWith<Principle> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $PrincipleCopyWith<$Res> {
+  factory $PrincipleCopyWith(Principle value, $Res Function(Principle) then) =
+      _$PrincipleCopyWithImpl<$Res, Principle>;
+  @useResult
+  $Res call({String id, String title, String? description});
+}
+
+/// @nodoc
+class _$PrincipleCopyWithImpl<$Res, $Val extends Principle>
+    implements $PrincipleCopyWith<$Res> {
+  _$PrincipleCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of Principle
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? id = null,
+    Object? title = null,
+    Object? description = freezed,
+  }) {
+    return _then(_value.copyWith(
+      id: null == id
+          ? _value.id
+          : id // ignore: cast_nullable_to_non_nullable
+              as String,
+      title: null == title
+          ? _value.title
+          : title // ignore: cast_nullable_to_non_nullable
+              as String,
+      description: freezed == description
+          ? _value.description
+          : description // ignore: cast_nullable_to_non_nullable
+              as String?,
+    ) as $Val);
+  }
+}
+
+/// @nodoc
+abstract class _$$PrincipleImplCopyWith<$Res>
+    implements $PrincipleCopyWith<$Res> {
+  factory _$$PrincipleImplCopyWith(
+          _$PrincipleImpl value, $Res Function(_$PrincipleImpl) then) =
+      __$$PrincipleImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call({String id, String title, String? description});
+}
+
+/// @nodoc
+class __$$PrincipleImplCopyWithImpl<$Res>
+    extends _$PrincipleCopyWithImpl<$Res, _$PrincipleImpl>
+    implements _$$PrincipleImplCopyWith<$Res> {
+  __$$PrincipleImplCopyWithImpl(
+      _$PrincipleImpl _value, $Res Function(_$PrincipleImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of Principle
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? id = null,
+    Object? title = null,
+    Object? description = freezed,
+  }) {
+    return _then(_$PrincipleImpl(
+      id: null == id
+          ? _value.id
+          : id // ignore: cast_nullable_to_non_nullable
+              as String,
+      title: null == title
+          ? _value.title
+          : title // ignore: cast_nullable_to_non_nullable
+              as String,
+      description: freezed == description
+          ? _value.description
+          : description // ignore: cast_nullable_to_non_nullable
+              as String?,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$PrincipleImpl implements _Principle {
+  const _$PrincipleImpl(
+      {required this.id, required this.title, this.description});
+
+  @override
+  final String id;
+  @override
+  final String title;
+  @override
+  final String? description;
+
+  @override
+  String toString() {
+    return 'Principle(id: $id, title: $title, description: $description)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$PrincipleImpl &&
+            (identical(other.id, id) || other.id == id) &&
+            (identical(other.title, title) || other.title == title) &&
+            (identical(other.description, description) ||
+                other.description == description));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, id, title, description);
+
+  /// Create a copy of Principle
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$PrincipleImplCopyWith<_$PrincipleImpl> get copyWith =>
+      __$$PrincipleImplCopyWithImpl<_$PrincipleImpl>(this, _$identity);
+}
+
+abstract class _Principle implements Principle {
+  const factory _Principle(
+      {required final String id,
+      required final String title,
+      final String? description}) = _$PrincipleImpl;
+
+  @override
+  String get id;
+  @override
+  String get title;
+  @override
+  String? get description;
+
+  /// Create a copy of Principle
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$PrincipleImplCopyWith<_$PrincipleImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+mixin _$PrincipleRecognition {
+  String get id => throw _privateConstructorUsedError;
+  String get title => throw _privateConstructorUsedError;
+  int get count => throw _privateConstructorUsedError;
+
+  /// Create a copy of PrincipleRecognition
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  $PrincipleRecognitionCopyWith<PrincipleRecognition> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $PrincipleRecognitionCopyWith<$Res> {
+  factory $PrincipleRecognitionCopyWith(PrincipleRecognition value,
+          $Res Function(PrincipleRecognition) then) =
+      _$PrincipleRecognitionCopyWithImpl<$Res, PrincipleRecognition>;
+  @useResult
+  $Res call({String id, String title, int count});
+}
+
+/// @nodoc
+class _$PrincipleRecognitionCopyWithImpl<$Res, $Val extends PrincipleRecognition>
+    implements $PrincipleRecognitionCopyWith<$Res> {
+  _$PrincipleRecognitionCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of PrincipleRecognition
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? id = null,
+    Object? title = null,
+    Object? count = null,
+  }) {
+    return _then(_value.copyWith(
+      id: null == id
+          ? _value.id
+          : id // ignore: cast_nullable_to_non_nullable
+              as String,
+      title: null == title
+          ? _value.title
+          : title // ignore: cast_nullable_to_non_nullable
+              as String,
+      count: null == count
+          ? _value.count
+          : count // ignore: cast_nullable_to_non_nullable
+              as int,
+    ) as $Val);
+  }
+}
+
+/// @nodoc
+abstract class _$$PrincipleRecognitionImplCopyWith<$Res>
+    implements $PrincipleRecognitionCopyWith<$Res> {
+  factory _$$PrincipleRecognitionImplCopyWith(_$PrincipleRecognitionImpl value,
+          $Res Function(_$PrincipleRecognitionImpl) then) =
+      __$$PrincipleRecognitionImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call({String id, String title, int count});
+}
+
+/// @nodoc
+class __$$PrincipleRecognitionImplCopyWithImpl<$Res>
+    extends _$PrincipleRecognitionCopyWithImpl<$Res, _$PrincipleRecognitionImpl>
+    implements _$$PrincipleRecognitionImplCopyWith<$Res> {
+  __$$PrincipleRecognitionImplCopyWithImpl(_$PrincipleRecognitionImpl _value,
+      $Res Function(_$PrincipleRecognitionImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of PrincipleRecognition
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? id = null,
+    Object? title = null,
+    Object? count = null,
+  }) {
+    return _then(_$PrincipleRecognitionImpl(
+      id: null == id
+          ? _value.id
+          : id // ignore: cast_nullable_to_non_nullable
+              as String,
+      title: null == title
+          ? _value.title
+          : title // ignore: cast_nullable_to_non_nullable
+              as String,
+      count: null == count
+          ? _value.count
+          : count // ignore: cast_nullable_to_non_nullable
+              as int,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$PrincipleRecognitionImpl implements _PrincipleRecognition {
+  const _$PrincipleRecognitionImpl(
+      {required this.id, required this.title, required this.count});
+
+  @override
+  final String id;
+  @override
+  final String title;
+  @override
+  final int count;
+
+  @override
+  String toString() {
+    return 'PrincipleRecognition(id: $id, title: $title, count: $count)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$PrincipleRecognitionImpl &&
+            (identical(other.id, id) || other.id == id) &&
+            (identical(other.title, title) || other.title == title) &&
+            (identical(other.count, count) || other.count == count));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, id, title, count);
+
+  /// Create a copy of PrincipleRecognition
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$PrincipleRecognitionImplCopyWith<_$PrincipleRecognitionImpl> get copyWith =>
+      __$$PrincipleRecognitionImplCopyWithImpl<_$PrincipleRecognitionImpl>(
+          this,
+          _$identity);
+}
+
+abstract class _PrincipleRecognition implements PrincipleRecognition {
+  const factory _PrincipleRecognition(
+      {required final String id,
+      required final String title,
+      required final int count}) = _$PrincipleRecognitionImpl;
+
+  @override
+  String get id;
+  @override
+  String get title;
+  @override
+  int get count;
+
+  /// Create a copy of PrincipleRecognition
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$PrincipleRecognitionImplCopyWith<_$PrincipleRecognitionImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+mixin _$PublishConfig {
+  String? get manifesto => throw _privateConstructorUsedError;
+  String? get vision => throw _privateConstructorUsedError;
+  String? get okrs => throw _privateConstructorUsedError;
+  String? get interactions => throw _privateConstructorUsedError;
+  String? get timezone => throw _privateConstructorUsedError;
+
+  /// Create a copy of PublishConfig
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  $PublishConfigCopyWith<PublishConfig> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $PublishConfigCopyWith<$Res> {
+  factory $PublishConfigCopyWith(
+          PublishConfig value, $Res Function(PublishConfig) then) =
+      _$PublishConfigCopyWithImpl<$Res, PublishConfig>;
+  @useResult
+  $Res call(
+      {String? manifesto,
+      String? vision,
+      String? okrs,
+      String? interactions,
+      String? timezone});
+}
+
+/// @nodoc
+class _$PublishConfigCopyWithImpl<$Res, $Val extends PublishConfig>
+    implements $PublishConfigCopyWith<$Res> {
+  _$PublishConfigCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of PublishConfig
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? manifesto = freezed,
+    Object? vision = freezed,
+    Object? okrs = freezed,
+    Object? interactions = freezed,
+    Object? timezone = freezed,
+  }) {
+    return _then(_value.copyWith(
+      manifesto: freezed == manifesto
+          ? _value.manifesto
+          : manifesto // ignore: cast_nullable_to_non_nullable
+              as String?,
+      vision: freezed == vision
+          ? _value.vision
+          : vision // ignore: cast_nullable_to_non_nullable
+              as String?,
+      okrs: freezed == okrs
+          ? _value.okrs
+          : okrs // ignore: cast_nullable_to_non_nullable
+              as String?,
+      interactions: freezed == interactions
+          ? _value.interactions
+          : interactions // ignore: cast_nullable_to_non_nullable
+              as String?,
+      timezone: freezed == timezone
+          ? _value.timezone
+          : timezone // ignore: cast_nullable_to_non_nullable
+              as String?,
+    ) as $Val);
+  }
+}
+
+/// @nodoc
+abstract class _$$PublishConfigImplCopyWith<$Res>
+    implements $PublishConfigCopyWith<$Res> {
+  factory _$$PublishConfigImplCopyWith(
+          _$PublishConfigImpl value, $Res Function(_$PublishConfigImpl) then) =
+      __$$PublishConfigImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call(
+      {String? manifesto,
+      String? vision,
+      String? okrs,
+      String? interactions,
+      String? timezone});
+}
+
+/// @nodoc
+class __$$PublishConfigImplCopyWithImpl<$Res>
+    extends _$PublishConfigCopyWithImpl<$Res, _$PublishConfigImpl>
+    implements _$$PublishConfigImplCopyWith<$Res> {
+  __$$PublishConfigImplCopyWithImpl(
+      _$PublishConfigImpl _value, $Res Function(_$PublishConfigImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of PublishConfig
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? manifesto = freezed,
+    Object? vision = freezed,
+    Object? okrs = freezed,
+    Object? interactions = freezed,
+    Object? timezone = freezed,
+  }) {
+    return _then(_$PublishConfigImpl(
+      manifesto: freezed == manifesto
+          ? _value.manifesto
+          : manifesto // ignore: cast_nullable_to_non_nullable
+              as String?,
+      vision: freezed == vision
+          ? _value.vision
+          : vision // ignore: cast_nullable_to_non_nullable
+              as String?,
+      okrs: freezed == okrs
+          ? _value.okrs
+          : okrs // ignore: cast_nullable_to_non_nullable
+              as String?,
+      interactions: freezed == interactions
+          ? _value.interactions
+          : interactions // ignore: cast_nullable_to_non_nullable
+              as String?,
+      timezone: freezed == timezone
+          ? _value.timezone
+          : timezone // ignore: cast_nullable_to_non_nullable
+              as String?,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$PublishConfigImpl implements _PublishConfig {
+  const _$PublishConfigImpl(
+      {this.manifesto,
+      this.vision,
+      this.okrs,
+      this.interactions,
+      this.timezone});
+
+  @override
+  final String? manifesto;
+  @override
+  final String? vision;
+  @override
+  final String? okrs;
+  @override
+  final String? interactions;
+  @override
+  final String? timezone;
+
+  @override
+  String toString() {
+    return 'PublishConfig(manifesto: $manifesto, vision: $vision, okrs: $okrs, interactions: $interactions, timezone: $timezone)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$PublishConfigImpl &&
+            (identical(other.manifesto, manifesto) ||
+                other.manifesto == manifesto) &&
+            (identical(other.vision, vision) || other.vision == vision) &&
+            (identical(other.okrs, okrs) || other.okrs == okrs) &&
+            (identical(other.interactions, interactions) ||
+                other.interactions == interactions) &&
+            (identical(other.timezone, timezone) ||
+                other.timezone == timezone));
+  }
+
+  @override
+  int get hashCode =>
+      Object.hash(runtimeType, manifesto, vision, okrs, interactions, timezone);
+
+  /// Create a copy of PublishConfig
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$PublishConfigImplCopyWith<_$PublishConfigImpl> get copyWith =>
+      __$$PublishConfigImplCopyWithImpl<_$PublishConfigImpl>(this, _$identity);
+}
+
+abstract class _PublishConfig implements PublishConfig {
+  const factory _PublishConfig(
+      {final String? manifesto,
+      final String? vision,
+      final String? okrs,
+      final String? interactions,
+      final String? timezone}) = _$PublishConfigImpl;
+
+  @override
+  String? get manifesto;
+  @override
+  String? get vision;
+  @override
+  String? get okrs;
+  @override
+  String? get interactions;
+  @override
+  String? get timezone;
+
+  /// Create a copy of PublishConfig
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$PublishConfigImplCopyWith<_$PublishConfigImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+mixin _$PulseCadences {
+  String? get checkin => throw _privateConstructorUsedError;
+  String? get okrUpdate => throw _privateConstructorUsedError;
+  String? get retro => throw _privateConstructorUsedError;
+
+  /// Create a copy of PulseCadences
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  $PulseCadencesCopyWith<PulseCadences> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $PulseCadencesCopyWith<$Res> {
+  factory $PulseCadencesCopyWith(
+          PulseCadences value, $Res Function(PulseCadences) then) =
+      _$PulseCadencesCopyWithImpl<$Res, PulseCadences>;
+  @useResult
+  $Res call({String? checkin, String? okrUpdate, String? retro});
+}
+
+/// @nodoc
+class _$PulseCadencesCopyWithImpl<$Res, $Val extends PulseCadences>
+    implements $PulseCadencesCopyWith<$Res> {
+  _$PulseCadencesCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of PulseCadences
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? checkin = freezed,
+    Object? okrUpdate = freezed,
+    Object? retro = freezed,
+  }) {
+    return _then(_value.copyWith(
+      checkin: freezed == checkin
+          ? _value.checkin
+          : checkin // ignore: cast_nullable_to_non_nullable
+              as String?,
+      okrUpdate: freezed == okrUpdate
+          ? _value.okrUpdate
+          : okrUpdate // ignore: cast_nullable_to_non_nullable
+              as String?,
+      retro: freezed == retro
+          ? _value.retro
+          : retro // ignore: cast_nullable_to_non_nullable
+              as String?,
+    ) as $Val);
+  }
+}
+
+/// @nodoc
+abstract class _$$PulseCadencesImplCopyWith<$Res>
+    implements $PulseCadencesCopyWith<$Res> {
+  factory _$$PulseCadencesImplCopyWith(
+          _$PulseCadencesImpl value, $Res Function(_$PulseCadencesImpl) then) =
+      __$$PulseCadencesImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call({String? checkin, String? okrUpdate, String? retro});
+}
+
+/// @nodoc
+class __$$PulseCadencesImplCopyWithImpl<$Res>
+    extends _$PulseCadencesCopyWithImpl<$Res, _$PulseCadencesImpl>
+    implements _$$PulseCadencesImplCopyWith<$Res> {
+  __$$PulseCadencesImplCopyWithImpl(
+      _$PulseCadencesImpl _value, $Res Function(_$PulseCadencesImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of PulseCadences
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? checkin = freezed,
+    Object? okrUpdate = freezed,
+    Object? retro = freezed,
+  }) {
+    return _then(_$PulseCadencesImpl(
+      checkin: freezed == checkin
+          ? _value.checkin
+          : checkin // ignore: cast_nullable_to_non_nullable
+              as String?,
+      okrUpdate: freezed == okrUpdate
+          ? _value.okrUpdate
+          : okrUpdate // ignore: cast_nullable_to_non_nullable
+              as String?,
+      retro: freezed == retro
+          ? _value.retro
+          : retro // ignore: cast_nullable_to_non_nullable
+              as String?,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$PulseCadencesImpl implements _PulseCadences {
+  const _$PulseCadencesImpl({this.checkin, this.okrUpdate, this.retro});
+
+  @override
+  final String? checkin;
+  @override
+  final String? okrUpdate;
+  @override
+  final String? retro;
+
+  @override
+  String toString() {
+    return 'PulseCadences(checkin: $checkin, okrUpdate: $okrUpdate, retro: $retro)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$PulseCadencesImpl &&
+            (identical(other.checkin, checkin) || other.checkin == checkin) &&
+            (identical(other.okrUpdate, okrUpdate) ||
+                other.okrUpdate == okrUpdate) &&
+            (identical(other.retro, retro) || other.retro == retro));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, checkin, okrUpdate, retro);
+
+  /// Create a copy of PulseCadences
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$PulseCadencesImplCopyWith<_$PulseCadencesImpl> get copyWith =>
+      __$$PulseCadencesImplCopyWithImpl<_$PulseCadencesImpl>(this, _$identity);
+}
+
+abstract class _PulseCadences implements PulseCadences {
+  const factory _PulseCadences(
+      {final String? checkin,
+      final String? okrUpdate,
+      final String? retro}) = _$PulseCadencesImpl;
+
+  @override
+  String? get checkin;
+  @override
+  String? get okrUpdate;
+  @override
+  String? get retro;
+
+  /// Create a copy of PulseCadences
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$PulseCadencesImplCopyWith<_$PulseCadencesImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+mixin _$PulseConfig {
+  PulseCadences get team => throw _privateConstructorUsedError;
+  List<MemberPulse> get members => throw _privateConstructorUsedError;
+
+  /// Create a copy of PulseConfig
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  $PulseConfigCopyWith<PulseConfig> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $PulseConfigCopyWith<$Res> {
+  factory $PulseConfigCopyWith(
+          PulseConfig value, $Res Function(PulseConfig) then) =
+      _$PulseConfigCopyWithImpl<$Res, PulseConfig>;
+  @useResult
+  $Res call({PulseCadences team, List<MemberPulse> members});
+
+  $PulseCadencesCopyWith<$Res> get team;
+}
+
+/// @nodoc
+class _$PulseConfigCopyWithImpl<$Res, $Val extends PulseConfig>
+    implements $PulseConfigCopyWith<$Res> {
+  _$PulseConfigCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of PulseConfig
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? team = null,
+    Object? members = null,
+  }) {
+    return _then(_value.copyWith(
+      team: null == team
+          ? _value.team
+          : team // ignore: cast_nullable_to_non_nullable
+              as PulseCadences,
+      members: null == members
+          ? _value.members
+          : members // ignore: cast_nullable_to_non_nullable
+              as List<MemberPulse>,
+    ) as $Val);
+  }
+
+  /// Create a copy of PulseConfig
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @pragma('vm:prefer-inline')
+  $PulseCadencesCopyWith<$Res> get team {
+    return $PulseCadencesCopyWith<$Res>(_value.team, (value) {
+      return _then(_value.copyWith(team: value) as $Val);
+    });
+  }
+}
+
+/// @nodoc
+abstract class _$$PulseConfigImplCopyWith<$Res>
+    implements $PulseConfigCopyWith<$Res> {
+  factory _$$PulseConfigImplCopyWith(
+          _$PulseConfigImpl value, $Res Function(_$PulseConfigImpl) then) =
+      __$$PulseConfigImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call({PulseCadences team, List<MemberPulse> members});
+
+  @override
+  $PulseCadencesCopyWith<$Res> get team;
+}
+
+/// @nodoc
+class __$$PulseConfigImplCopyWithImpl<$Res>
+    extends _$PulseConfigCopyWithImpl<$Res, _$PulseConfigImpl>
+    implements _$$PulseConfigImplCopyWith<$Res> {
+  __$$PulseConfigImplCopyWithImpl(
+      _$PulseConfigImpl _value, $Res Function(_$PulseConfigImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of PulseConfig
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? team = null,
+    Object? members = null,
+  }) {
+    return _then(_$PulseConfigImpl(
+      team: null == team
+          ? _value.team
+          : team // ignore: cast_nullable_to_non_nullable
+              as PulseCadences,
+      members: null == members
+          ? _value._members
+          : members // ignore: cast_nullable_to_non_nullable
+              as List<MemberPulse>,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$PulseConfigImpl implements _PulseConfig {
+  const _$PulseConfigImpl(
+      {required this.team, required final List<MemberPulse> members})
+      : _members = members;
+
+  @override
+  final PulseCadences team;
+  final List<MemberPulse> _members;
+  @override
+  List<MemberPulse> get members {
+    if (_members is EqualUnmodifiableListView) return _members;
+    // ignore: implicit_dynamic_type
+    return EqualUnmodifiableListView(_members);
+  }
+
+  @override
+  String toString() {
+    return 'PulseConfig(team: $team, members: $members)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$PulseConfigImpl &&
+            (identical(other.team, team) || other.team == team) &&
+            const DeepCollectionEquality().equals(other._members, _members));
+  }
+
+  @override
+  int get hashCode => Object.hash(
+      runtimeType, team, const DeepCollectionEquality().hash(_members));
+
+  /// Create a copy of PulseConfig
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$PulseConfigImplCopyWith<_$PulseConfigImpl> get copyWith =>
+      __$$PulseConfigImplCopyWithImpl<_$PulseConfigImpl>(this, _$identity);
+}
+
+abstract class _PulseConfig implements PulseConfig {
+  const factory _PulseConfig(
+      {required final PulseCadences team,
+      required final List<MemberPulse> members}) = _$PulseConfigImpl;
+
+  @override
+  PulseCadences get team;
+  @override
+  List<MemberPulse> get members;
+
+  /// Create a copy of PulseConfig
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$PulseConfigImplCopyWith<_$PulseConfigImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+mixin _$ShellHook {
+  List<String> get on => throw _privateConstructorUsedError;
+  String get run => throw _privateConstructorUsedError;
+
+  /// Create a copy of ShellHook
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  $ShellHookCopyWith<ShellHook> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $ShellHookCopyWith<$Res> {
+  factory $ShellHookCopyWith(ShellHook value, $Res Function(ShellHook) then) =
+      _$ShellHookCopyWithImpl<$Res, ShellHook>;
+  @useResult
+  $Res call({List<String> on, String run});
+}
+
+/// @nodoc
+class _$ShellHookCopyWithImpl<$Res, $Val extends ShellHook>
+    implements $ShellHookCopyWith<$Res> {
+  _$ShellHookCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of ShellHook
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? on = null,
+    Object? run = null,
+  }) {
+    return _then(_value.copyWith(
+      on: null == on
+          ? _value.on
+          : on // ignore: cast_nullable_to_non_nullable
+              as List<String>,
+      run: null == run
+          ? _value.run
+          : run // ignore: cast_nullable_to_non_nullable
+              as String,
+    ) as $Val);
+  }
+}
+
+/// @nodoc
+abstract class _$$ShellHookImplCopyWith<$Res>
+    implements $ShellHookCopyWith<$Res> {
+  factory _$$ShellHookImplCopyWith(
+          _$ShellHookImpl value, $Res Function(_$ShellHookImpl) then) =
+      __$$ShellHookImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call({List<String> on, String run});
+}
+
+/// @nodoc
+class __$$ShellHookImplCopyWithImpl<$Res>
+    extends _$ShellHookCopyWithImpl<$Res, _$ShellHookImpl>
+    implements _$$ShellHookImplCopyWith<$Res> {
+  __$$ShellHookImplCopyWithImpl(
+      _$ShellHookImpl _value, $Res Function(_$ShellHookImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of ShellHook
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? on = null,
+    Object? run = null,
+  }) {
+    return _then(_$ShellHookImpl(
+      on: null == on
+          ? _value._on
+          : on // ignore: cast_nullable_to_non_nullable
+              as List<String>,
+      run: null == run
+          ? _value.run
+          : run // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$ShellHookImpl implements _ShellHook {
+  const _$ShellHookImpl({required final List<String> on, required this.run})
+      : _on = on;
+
+  final List<String> _on;
+  @override
+  List<String> get on {
+    if (_on is EqualUnmodifiableListView) return _on;
+    // ignore: implicit_dynamic_type
+    return EqualUnmodifiableListView(_on);
+  }
+
+  @override
+  final String run;
+
+  @override
+  String toString() {
+    return 'ShellHook(on: $on, run: $run)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$ShellHookImpl &&
+            const DeepCollectionEquality().equals(other._on, _on) &&
+            (identical(other.run, run) || other.run == run));
+  }
+
+  @override
+  int get hashCode =>
+      Object.hash(runtimeType, const DeepCollectionEquality().hash(_on), run);
+
+  /// Create a copy of ShellHook
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$ShellHookImplCopyWith<_$ShellHookImpl> get copyWith =>
+      __$$ShellHookImplCopyWithImpl<_$ShellHookImpl>(this, _$identity);
+}
+
+abstract class _ShellHook implements ShellHook {
+  const factory _ShellHook(
+      {required final List<String> on,
+      required final String run}) = _$ShellHookImpl;
+
+  @override
+  List<String> get on;
+  @override
+  String get run;
+
+  /// Create a copy of ShellHook
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$ShellHookImplCopyWith<_$ShellHookImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+mixin _$Team {
+  String get name => throw _privateConstructorUsedError;
+  Manifesto? get manifesto => throw _privateConstructorUsedError;
+  String? get vision => throw _privateConstructorUsedError;
+  List<String> get leaders => throw _privateConstructorUsedError;
+  List<String> get members => throw _privateConstructorUsedError;
+  List<String> get archived => throw _privateConstructorUsedError;
+
+  /// Create a copy of Team
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  $TeamCopyWith<Team> get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $TeamCopyWith<$Res> {
+  factory $TeamCopyWith(Team value, $Res Function(Team) then) =
+      _$TeamCopyWithImpl<$Res, Team>;
+  @useResult
+  $Res call(
+      {String name,
+      Manifesto? manifesto,
+      String? vision,
+      List<String> leaders,
+      List<String> members,
+      List<String> archived});
+
+  $ManifestoCopyWith<$Res>? get manifesto;
+}
+
+/// @nodoc
+class _$TeamCopyWithImpl<$Res, $Val extends Team>
+    implements $TeamCopyWith<$Res> {
+  _$TeamCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of Team
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? name = null,
+    Object? manifesto = freezed,
+    Object? vision = freezed,
+    Object? leaders = null,
+    Object? members = null,
+    Object? archived = null,
+  }) {
+    return _then(_value.copyWith(
+      name: null == name
+          ? _value.name
+          : name // ignore: cast_nullable_to_non_nullable
+              as String,
+      manifesto: freezed == manifesto
+          ? _value.manifesto
+          : manifesto // ignore: cast_nullable_to_non_nullable
+              as Manifesto?,
+      vision: freezed == vision
+          ? _value.vision
+          : vision // ignore: cast_nullable_to_non_nullable
+              as String?,
+      leaders: null == leaders
+          ? _value.leaders
+          : leaders // ignore: cast_nullable_to_non_nullable
+              as List<String>,
+      members: null == members
+          ? _value.members
+          : members // ignore: cast_nullable_to_non_nullable
+              as List<String>,
+      archived: null == archived
+          ? _value.archived
+          : archived // ignore: cast_nullable_to_non_nullable
+              as List<String>,
+    ) as $Val);
+  }
+
+  /// Create a copy of Team
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @pragma('vm:prefer-inline')
+  $ManifestoCopyWith<$Res>? get manifesto {
+    if (_value.manifesto == null) {
+      return null;
+    }
+
+    return $ManifestoCopyWith<$Res>(_value.manifesto!, (value) {
+      return _then(_value.copyWith(manifesto: value) as $Val);
+    });
+  }
+}
+
+/// @nodoc
+abstract class _$$TeamImplCopyWith<$Res> implements $TeamCopyWith<$Res> {
+  factory _$$TeamImplCopyWith(
+          _$TeamImpl value, $Res Function(_$TeamImpl) then) =
+      __$$TeamImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call(
+      {String name,
+      Manifesto? manifesto,
+      String? vision,
+      List<String> leaders,
+      List<String> members,
+      List<String> archived});
+
+  @override
+  $ManifestoCopyWith<$Res>? get manifesto;
+}
+
+/// @nodoc
+class __$$TeamImplCopyWithImpl<$Res>
+    extends _$TeamCopyWithImpl<$Res, _$TeamImpl>
+    implements _$$TeamImplCopyWith<$Res> {
+  __$$TeamImplCopyWithImpl(_$TeamImpl _value, $Res Function(_$TeamImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of Team
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? name = null,
+    Object? manifesto = freezed,
+    Object? vision = freezed,
+    Object? leaders = null,
+    Object? members = null,
+    Object? archived = null,
+  }) {
+    return _then(_$TeamImpl(
+      name: null == name
+          ? _value.name
+          : name // ignore: cast_nullable_to_non_nullable
+              as String,
+      manifesto: freezed == manifesto
+          ? _value.manifesto
+          : manifesto // ignore: cast_nullable_to_non_nullable
+              as Manifesto?,
+      vision: freezed == vision
+          ? _value.vision
+          : vision // ignore: cast_nullable_to_non_nullable
+              as String?,
+      leaders: null == leaders
+          ? _value._leaders
+          : leaders // ignore: cast_nullable_to_non_nullable
+              as List<String>,
+      members: null == members
+          ? _value._members
+          : members // ignore: cast_nullable_to_non_nullable
+              as List<String>,
+      archived: null == archived
+          ? _value._archived
+          : archived // ignore: cast_nullable_to_non_nullable
+              as List<String>,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$TeamImpl extends _Team {
+  const _$TeamImpl(
+      {required this.name,
+      this.manifesto,
+      this.vision,
+      required final List<String> leaders,
+      required final List<String> members,
+      required final List<String> archived})
+      : _leaders = leaders,
+        _members = members,
+        _archived = archived,
+        super._();
+
+  @override
+  final String name;
+  @override
+  final Manifesto? manifesto;
+  @override
+  final String? vision;
+  final List<String> _leaders;
+  @override
+  List<String> get leaders {
+    if (_leaders is EqualUnmodifiableListView) return _leaders;
+    // ignore: implicit_dynamic_type
+    return EqualUnmodifiableListView(_leaders);
+  }
+
+  final List<String> _members;
+  @override
+  List<String> get members {
+    if (_members is EqualUnmodifiableListView) return _members;
+    // ignore: implicit_dynamic_type
+    return EqualUnmodifiableListView(_members);
+  }
+
+  final List<String> _archived;
+  @override
+  List<String> get archived {
+    if (_archived is EqualUnmodifiableListView) return _archived;
+    // ignore: implicit_dynamic_type
+    return EqualUnmodifiableListView(_archived);
+  }
+
+  @override
+  String toString() {
+    return 'Team(name: $name, manifesto: $manifesto, vision: $vision, leaders: $leaders, members: $members, archived: $archived)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$TeamImpl &&
+            (identical(other.name, name) || other.name == name) &&
+            (identical(other.manifesto, manifesto) ||
+                other.manifesto == manifesto) &&
+            (identical(other.vision, vision) || other.vision == vision) &&
+            const DeepCollectionEquality().equals(other._leaders, _leaders) &&
+            const DeepCollectionEquality().equals(other._members, _members) &&
+            const DeepCollectionEquality().equals(other._archived, _archived));
+  }
+
+  @override
+  int get hashCode => Object.hash(
+      runtimeType,
+      name,
+      manifesto,
+      vision,
+      const DeepCollectionEquality().hash(_leaders),
+      const DeepCollectionEquality().hash(_members),
+      const DeepCollectionEquality().hash(_archived));
+
+  /// Create a copy of Team
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$TeamImplCopyWith<_$TeamImpl> get copyWith =>
+      __$$TeamImplCopyWithImpl<_$TeamImpl>(this, _$identity);
+}
+
+abstract class _Team extends Team {
+  const factory _Team(
+      {required final String name,
+      final Manifesto? manifesto,
+      final String? vision,
+      required final List<String> leaders,
+      required final List<String> members,
+      required final List<String> archived}) = _$TeamImpl;
+  const _Team._() : super._();
+
+  @override
+  String get name;
+  @override
+  Manifesto? get manifesto;
+  @override
+  String? get vision;
+  @override
+  List<String> get leaders;
+  @override
+  List<String> get members;
+  @override
+  List<String> get archived;
+
+  /// Create a copy of Team
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$TeamImplCopyWith<_$TeamImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+mixin _$TeamConfig {
+  PublishConfig? get publish => throw _privateConstructorUsedError;
+  WebhookConfig? get webhooks => throw _privateConstructorUsedError;
+  LintingConfig? get linting => throw _privateConstructorUsedError;
+  BackupConfig? get backup => throw _privateConstructorUsedError;
+  PulseConfig? get pulse => throw _privateConstructorUsedError;
+  List<ShellHook> get hooks => throw _privateConstructorUsedError;
+
+  /// Create a copy of TeamConfig
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  $TeamConfigCopyWith<TeamConfig> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $TeamConfigCopyWith<$Res> {
+  factory $TeamConfigCopyWith(
+          TeamConfig value, $Res Function(TeamConfig) then) =
+      _$TeamConfigCopyWithImpl<$Res, TeamConfig>;
+  @useResult
+  $Res call(
+      {PublishConfig? publish,
+      WebhookConfig? webhooks,
+      LintingConfig? linting,
+      BackupConfig? backup,
+      PulseConfig? pulse,
+      List<ShellHook> hooks});
+
+  $PublishConfigCopyWith<$Res>? get publish;
+  $WebhookConfigCopyWith<$Res>? get webhooks;
+  $LintingConfigCopyWith<$Res>? get linting;
+  $BackupConfigCopyWith<$Res>? get backup;
+  $PulseConfigCopyWith<$Res>? get pulse;
+}
+
+/// @nodoc
+class _$TeamConfigCopyWithImpl<$Res, $Val extends TeamConfig>
+    implements $TeamConfigCopyWith<$Res> {
+  _$TeamConfigCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of TeamConfig
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? publish = freezed,
+    Object? webhooks = freezed,
+    Object? linting = freezed,
+    Object? backup = freezed,
+    Object? pulse = freezed,
+    Object? hooks = null,
+  }) {
+    return _then(_value.copyWith(
+      publish: freezed == publish
+          ? _value.publish
+          : publish // ignore: cast_nullable_to_non_nullable
+              as PublishConfig?,
+      webhooks: freezed == webhooks
+          ? _value.webhooks
+          : webhooks // ignore: cast_nullable_to_non_nullable
+              as WebhookConfig?,
+      linting: freezed == linting
+          ? _value.linting
+          : linting // ignore: cast_nullable_to_non_nullable
+              as LintingConfig?,
+      backup: freezed == backup
+          ? _value.backup
+          : backup // ignore: cast_nullable_to_non_nullable
+              as BackupConfig?,
+      pulse: freezed == pulse
+          ? _value.pulse
+          : pulse // ignore: cast_nullable_to_non_nullable
+              as PulseConfig?,
+      hooks: null == hooks
+          ? _value.hooks
+          : hooks // ignore: cast_nullable_to_non_nullable
+              as List<ShellHook>,
+    ) as $Val);
+  }
+
+  /// Create a copy of TeamConfig
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @pragma('vm:prefer-inline')
+  $PublishConfigCopyWith<$Res>? get publish {
+    if (_value.publish == null) {
+      return null;
+    }
+
+    return $PublishConfigCopyWith<$Res>(_value.publish!, (value) {
+      return _then(_value.copyWith(publish: value) as $Val);
+    });
+  }
+
+  /// Create a copy of TeamConfig
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @pragma('vm:prefer-inline')
+  $WebhookConfigCopyWith<$Res>? get webhooks {
+    if (_value.webhooks == null) {
+      return null;
+    }
+
+    return $WebhookConfigCopyWith<$Res>(_value.webhooks!, (value) {
+      return _then(_value.copyWith(webhooks: value) as $Val);
+    });
+  }
+
+  /// Create a copy of TeamConfig
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @pragma('vm:prefer-inline')
+  $LintingConfigCopyWith<$Res>? get linting {
+    if (_value.linting == null) {
+      return null;
+    }
+
+    return $LintingConfigCopyWith<$Res>(_value.linting!, (value) {
+      return _then(_value.copyWith(linting: value) as $Val);
+    });
+  }
+
+  /// Create a copy of TeamConfig
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @pragma('vm:prefer-inline')
+  $BackupConfigCopyWith<$Res>? get backup {
+    if (_value.backup == null) {
+      return null;
+    }
+
+    return $BackupConfigCopyWith<$Res>(_value.backup!, (value) {
+      return _then(_value.copyWith(backup: value) as $Val);
+    });
+  }
+
+  /// Create a copy of TeamConfig
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @pragma('vm:prefer-inline')
+  $PulseConfigCopyWith<$Res>? get pulse {
+    if (_value.pulse == null) {
+      return null;
+    }
+
+    return $PulseConfigCopyWith<$Res>(_value.pulse!, (value) {
+      return _then(_value.copyWith(pulse: value) as $Val);
+    });
+  }
+}
+
+/// @nodoc
+abstract class _$$TeamConfigImplCopyWith<$Res>
+    implements $TeamConfigCopyWith<$Res> {
+  factory _$$TeamConfigImplCopyWith(
+          _$TeamConfigImpl value, $Res Function(_$TeamConfigImpl) then) =
+      __$$TeamConfigImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call(
+      {PublishConfig? publish,
+      WebhookConfig? webhooks,
+      LintingConfig? linting,
+      BackupConfig? backup,
+      PulseConfig? pulse,
+      List<ShellHook> hooks});
+
+  @override
+  $PublishConfigCopyWith<$Res>? get publish;
+  @override
+  $WebhookConfigCopyWith<$Res>? get webhooks;
+  @override
+  $LintingConfigCopyWith<$Res>? get linting;
+  @override
+  $BackupConfigCopyWith<$Res>? get backup;
+  @override
+  $PulseConfigCopyWith<$Res>? get pulse;
+}
+
+/// @nodoc
+class __$$TeamConfigImplCopyWithImpl<$Res>
+    extends _$TeamConfigCopyWithImpl<$Res, _$TeamConfigImpl>
+    implements _$$TeamConfigImplCopyWith<$Res> {
+  __$$TeamConfigImplCopyWithImpl(
+      _$TeamConfigImpl _value, $Res Function(_$TeamConfigImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of TeamConfig
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? publish = freezed,
+    Object? webhooks = freezed,
+    Object? linting = freezed,
+    Object? backup = freezed,
+    Object? pulse = freezed,
+    Object? hooks = null,
+  }) {
+    return _then(_$TeamConfigImpl(
+      publish: freezed == publish
+          ? _value.publish
+          : publish // ignore: cast_nullable_to_non_nullable
+              as PublishConfig?,
+      webhooks: freezed == webhooks
+          ? _value.webhooks
+          : webhooks // ignore: cast_nullable_to_non_nullable
+              as WebhookConfig?,
+      linting: freezed == linting
+          ? _value.linting
+          : linting // ignore: cast_nullable_to_non_nullable
+              as LintingConfig?,
+      backup: freezed == backup
+          ? _value.backup
+          : backup // ignore: cast_nullable_to_non_nullable
+              as BackupConfig?,
+      pulse: freezed == pulse
+          ? _value.pulse
+          : pulse // ignore: cast_nullable_to_non_nullable
+              as PulseConfig?,
+      hooks: null == hooks
+          ? _value._hooks
+          : hooks // ignore: cast_nullable_to_non_nullable
+              as List<ShellHook>,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$TeamConfigImpl extends _TeamConfig {
+  const _$TeamConfigImpl(
+      {this.publish,
+      this.webhooks,
+      this.linting,
+      this.backup,
+      this.pulse,
+      required final List<ShellHook> hooks})
+      : _hooks = hooks,
+        super._();
+
+  @override
+  final PublishConfig? publish;
+  @override
+  final WebhookConfig? webhooks;
+  @override
+  final LintingConfig? linting;
+  @override
+  final BackupConfig? backup;
+  @override
+  final PulseConfig? pulse;
+  final List<ShellHook> _hooks;
+  @override
+  List<ShellHook> get hooks {
+    if (_hooks is EqualUnmodifiableListView) return _hooks;
+    // ignore: implicit_dynamic_type
+    return EqualUnmodifiableListView(_hooks);
+  }
+
+  @override
+  String toString() {
+    return 'TeamConfig(publish: $publish, webhooks: $webhooks, linting: $linting, backup: $backup, pulse: $pulse, hooks: $hooks)';
+  }
 
   @override
   bool operator ==(Object other) {
     return identical(this, other) ||
         (other.runtimeType == runtimeType &&
-            other is _$PublishConfigImpl &&
-            (identical(other.manifesto, manifesto) ||
-                other.manifesto == manifesto) &&
-            (identical(other.vision, vision) || other.vision == vision) &&
-            (identical(other.okrs, okrs) || other.okrs == okrs));
+            other is _$TeamConfigImpl &&
+            (identical(other.publish, publish) || other.publish == publish) &&
+            (identical(other.webhooks, webhooks) ||
+                other.webhooks == webhooks) &&
+            (identical(other.linting, linting) || other.linting == linting) &&
+            (identical(other.backup, backup) || other.backup == backup) &&
+            (identical(other.pulse, pulse) || other.pulse == pulse) &&
+            const DeepCollectionEquality().equals(other._hooks, _hooks));
   }
 
   @override
-  int get hashCode => Object.hash(runtimeType, manifesto, vision, okrs);
+  int get hashCode => Object.hash(runtimeType, publish, webhooks, linting,
+      backup, pulse, const DeepCollectionEquality().hash(_hooks));
 
-  /// Create a copy of PublishConfig
+  /// Create a copy of TeamConfig
   /// with the given fields replaced by the non-null parameter values.
   @JsonKey(includeFromJson: false, includeToJson: false)
   @override
   @pragma('vm:prefer-inline')
-  _$$PublishConfigImplCopyWith<_$PublishConfigImpl> get copyWith =>
-      __$$PublishConfigImplCopyWithImpl<_$PublishConfigImpl>(this, _$identity);
+  _$$TeamConfigImplCopyWith<_$TeamConfigImpl> get copyWith =>
+      __$$TeamConfigImplCopyWithImpl<_$TeamConfigImpl>(this, _$identity);
 }
 
-abstract class _PublishConfig implements PublishConfig {
-  const factory _PublishConfig(
-      {final String? manifesto,
-      final String? vision,
-      final String? okrs}) = _$PublishConfigImpl;
+abstract class _TeamConfig extends TeamConfig {
+  const factory _TeamConfig(
+      {final PublishConfig? publish,
+      final WebhookConfig? webhooks,
+      final LintingConfig? linting,
+      final BackupConfig? backup,
+      final PulseConfig? pulse,
+      required final List<ShellHook> hooks}) = _$TeamConfigImpl;
+  const _TeamConfig._() : super._();
 
   @override
-  String? get manifesto;
+  PublishConfig? get publish;
   @override
-  String? get vision;
+  WebhookConfig? get webhooks;
   @override
-  String? get okrs;
+  LintingConfig? get linting;
+  @override
+  BackupConfig? get backup;
+  @override
+  PulseConfig? get pulse;
+  @override
+  List<ShellHook> get hooks;
 
-  /// Create a copy of PublishConfig
+  /// Create a copy of TeamConfig
   /// with the given fields replaced by the non-null parameter values.
   @override
   @JsonKey(includeFromJson: false, includeToJson: false)
-  _$$PublishConfigImplCopyWith<_$PublishConfigImpl> get copyWith =>
+  _$$TeamConfigImplCopyWith<_$TeamConfigImpl> get copyWith =>
       throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-mixin _$Team {
+mixin _$TeamEvent {
+  String get id => throw _privateConstructorUsedError;
   String get name => throw _privateConstructorUsedError;
-  String? get manifesto => throw _privateConstructorUsedError;
-  String? get vision => throw _privateConstructorUsedError;
-  List<String> get leaders => throw _privateConstructorUsedError;
-  List<String> get members => throw _privateConstructorUsedError;
+  String get text => throw _privateConstructorUsedError;
+  List<TeamEventField> get fields => throw _privateConstructorUsedError;
 
-  /// Create a copy of Team
+  /// Create a copy of TeamEvent
   /// with the given fields replaced by the non-null parameter values.
   @JsonKey(includeFromJson: false, includeToJson: false)
-  $TeamCopyWith<Team> get copyWith => throw _privateConstructorUsedError;
+  $TeamEventCopyWith<TeamEvent> get copyWith =>
+      throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-abstract class $TeamCopyWith<$Res> {
-  factory $TeamCopyWith(Team value, $Res Function(Team) then) =
-      _$TeamCopyWithImpl<$Res, Team>;
+abstract class $TeamEventCopyWith<$Res> {
+  factory $TeamEventCopyWith(TeamEvent value, $Res Function(TeamEvent) then) =
+      _$TeamEventCopyWithImpl<$Res, TeamEvent>;
   @useResult
-  $Res call(
-      {String name,
-      String? manifesto,
-      String? vision,
-      List<String> leaders,
-      List<String> members});
+  $Res call({String id, String name, String text, List<TeamEventField> fields});
 }
 
 /// @nodoc
-class _$TeamCopyWithImpl<$Res, $Val extends Team>
-    implements $TeamCopyWith<$Res> {
-  _$TeamCopyWithImpl(this._value, this._then);
+class _$TeamEventCopyWithImpl<$Res, $Val extends TeamEvent>
+    implements $TeamEventCopyWith<$Res> {
+  _$TeamEventCopyWithImpl(this._value, this._then);
 
   // ignore: unused_field
   final $Val _value;
   // ignore: unused_field
   final $Res Function($Val) _then;
 
-  /// Create a copy of Team
+  /// Create a copy of TeamEvent
   /// with the given fields replaced by the non-null parameter values.
   @pragma('vm:prefer-inline')
   @override
   $Res call({
+    Object? id = null,
     Object? name = null,
-    Object? manifesto = freezed,
-    Object? vision = freezed,
-    Object? leaders = null,
-    Object? members = null,
+    Object? text = null,
+    Object? fields = null,
   }) {
     return _then(_value.copyWith(
+      id: null == id
+          ? _value.id
+          : id // ignore: cast_nullable_to_non_nullable
+              as String,
       name: null == name
           ? _value.name
           : name // ignore: cast_nullable_to_non_nullable
               as String,
-      manifesto: freezed == manifesto
-          ? _value.manifesto
-          : manifesto // ignore: cast_nullable_to_non_nullable
-              as String?,
-      vision: freezed == vision
-          ? _value.vision
-          : vision // ignore: cast_nullable_to_non_nullable
-              as String?,
-      leaders: null == leaders
-          ? _value.leaders
-          : leaders // ignore: cast_nullable_to_non_nullable
-              as List<String>,
-      members: null == members
-          ? _value.members
-          : members // ignore: cast_nullable_to_non_nullable
-              as List<String>,
+      text: null == text
+          ? _value.text
+          : text // ignore: cast_nullable_to_non_nullable
+              as String,
+      fields: null == fields
+          ? _value.fields
+          : fields // ignore: cast_nullable_to_non_nullable
+              as List<TeamEventField>,
     ) as $Val);
   }
 }
 
 /// @nodoc
-abstract class _$$TeamImplCopyWith<$Res> implements $TeamCopyWith<$Res> {
-  factory _$$TeamImplCopyWith(
-          _$TeamImpl value, $Res Function(_$TeamImpl) then) =
-      __$$TeamImplCopyWithImpl<$Res>;
+abstract class _$$TeamEventImplCopyWith<$Res>
+    implements $TeamEventCopyWith<$Res> {
+  factory _$$TeamEventImplCopyWith(
+          _$TeamEventImpl value, $Res Function(_$TeamEventImpl) then) =
+      __$$TeamEventImplCopyWithImpl<$Res>;
   @override
   @useResult
-  $Res call(
-      {String name,
-      String? manifesto,
-      String? vision,
-      List<String> leaders,
-      List<String> members});
+  $Res call({String id, String name, String text, List<TeamEventField> fields});
 }
 
 /// @nodoc
-class __$$TeamImplCopyWithImpl<$Res>
-    extends _$TeamCopyWithImpl<$Res, _$TeamImpl>
-    implements _$$TeamImplCopyWith<$Res> {
-  __$$TeamImplCopyWithImpl(_$TeamImpl _value, $Res Function(_$TeamImpl) _then)
+class __$$TeamEventImplCopyWithImpl<$Res>
+    extends _$TeamEventCopyWithImpl<$Res, _$TeamEventImpl>
+    implements _$$TeamEventImplCopyWith<$Res> {
+  __$$TeamEventImplCopyWithImpl(
+      _$TeamEventImpl _value, $Res Function(_$TeamEventImpl) _then)
       : super(_value, _then);
 
-  /// Create a copy of Team
+  /// Create a copy of TeamEvent
   /// with the given fields replaced by the non-null parameter values.
   @pragma('vm:prefer-inline')
   @override
   $Res call({
+    Object? id = null,
     Object? name = null,
-    Object? manifesto = freezed,
-    Object? vision = freezed,
-    Object? leaders = null,
-    Object? members = null,
+    Object? text = null,
+    Object? fields = null,
   }) {
-    return _then(_$TeamImpl(
+    return _then(_$TeamEventImpl(
+      id: null == id
+          ? _value.id
+          : id // ignore: cast_nullable_to_non_nullable
+              as String,
       name: null == name
           ? _value.name
           : name // ignore: cast_nullable_to_non_nullable
               as String,
-      manifesto: freezed == manifesto
-          ? _value.manifesto
-          : manifesto // ignore: cast_nullable_to_non_nullable
-              as String?,
-      vision: freezed == vision
-          ? _value.vision
-          : vision // ignore: cast_nullable_to_non_nullable
-              as String?,
-      leaders: null == leaders
-          ? _value._leaders
-          : leaders // ignore: cast_nullable_to_non_nullable
-              as List<String>,
-      members: null == members
-          ? _value._members
-          : members // ignore: cast_nullable_to_non_nullable
-              as List<String>,
+      text: null == text
+          ? _value.text
+          : text // ignore: cast_nullable_to_non_nullable
+              as String,
+      fields: null == fields
+          ? _value._fields
+          : fields // ignore: cast_nullable_to_non_nullable
+              as List<TeamEventField>,
     ));
   }
 }
 
 /// @nodoc
 
-class _$TeamImpl extends _Team {
-  const _$TeamImpl(
-      {required this.name,
-      this.manifesto,
-      this.vision,
-      required final List<String> leaders,
-      required final List<String> members})
-      : _leaders = leaders,
-        _members = members,
-        super._();
+class _$TeamEventImpl implements _TeamEvent {
+  const _$TeamEventImpl(
+      {required this.id,
+      required this.name,
+      required this.text,
+      required final List<TeamEventField> fields})
+      : _fields = fields;
 
   @override
-  final String name;
-  @override
-  final String? manifesto;
+  final String id;
   @override
-  final String? vision;
-  final List<String> _leaders;
+  final String name;
   @override
-  List<String> get leaders {
-    if (_leaders is EqualUnmodifiableListView) return _leaders;
-    // ignore: implicit_dynamic_type
-    return EqualUnmodifiableListView(_leaders);
-  }
-
-  final List<String> _members;
+  final String text;
+  final List<TeamEventField> _fields;
   @override
-  List<String> get members {
-    if (_members is EqualUnmodifiableListView) return _members;
+  List<TeamEventField> get fields {
+    if (_fields is EqualUnmodifiableListView) return _fields;
     // ignore: implicit_dynamic_type
-    return EqualUnmodifiableListView(_members);
+    return EqualUnmodifiableListView(_fields);
   }
 
   @override
   String toString() {
-    return 'Team(name: $name, manifesto: $manifesto, vision: $vision, leaders: $leaders, members: $members)';
+    return 'TeamEvent(id: $id, name: $name, text: $text, fields: $fields)';
   }
 
   @override
   bool operator ==(Object other) {
     return identical(this, other) ||
         (other.runtimeType == runtimeType &&
-            other is _$TeamImpl &&
+            other is _$TeamEventImpl &&
+            (identical(other.id, id) || other.id == id) &&
             (identical(other.name, name) || other.name == name) &&
-            (identical(other.manifesto, manifesto) ||
-                other.manifesto == manifesto) &&
-            (identical(other.vision, vision) || other.vision == vision) &&
-            const DeepCollectionEquality().equals(other._leaders, _leaders) &&
-            const DeepCollectionEquality().equals(other._members, _members));
+            (identical(other.text, text) || other.text == text) &&
+            const DeepCollectionEquality().equals(other._fields, _fields));
   }
 
   @override
-  int get hashCode => Object.hash(
-      runtimeType,
-      name,
-      manifesto,
-      vision,
-      const DeepCollectionEquality().hash(_leaders),
-      const DeepCollectionEquality().hash(_members));
+  int get hashCode => Object.hash(runtimeType, id, name, text,
+      const DeepCollectionEquality().hash(_fields));
 
-  /// Create a copy of Team
+  /// Create a copy of TeamEvent
   /// with the given fields replaced by the non-null parameter values.
   @JsonKey(includeFromJson: false, includeToJson: false)
   @override
   @pragma('vm:prefer-inline')
-  _$$TeamImplCopyWith<_$TeamImpl> get copyWith =>
-      __$$TeamImplCopyWithImpl<_$TeamImpl>(this, _$identity);
+  _$$TeamEventImplCopyWith<_$TeamEventImpl> get copyWith =>
+      __$$TeamEventImplCopyWithImpl<_$TeamEventImpl>(this, _$identity);
 }
 
-abstract class _Team extends Team {
-  const factory _Team(
-      {required final String name,
-      final String? manifesto,
-      final String? vision,
-      required final List<String> leaders,
-      required final List<String> members}) = _$TeamImpl;
-  const _Team._() : super._();
+abstract class _TeamEvent implements TeamEvent {
+  const factory _TeamEvent(
+      {required final String id,
+      required final String name,
+      required final String text,
+      required final List<TeamEventField> fields}) = _$TeamEventImpl;
 
   @override
-  String get name;
-  @override
-  String? get manifesto;
+  String get id;
   @override
-  String? get vision;
+  String get name;
   @override
-  List<String> get leaders;
+  String get text;
   @override
-  List<String> get members;
+  List<TeamEventField> get fields;
 
-  /// Create a copy of Team
+  /// Create a copy of TeamEvent
   /// with the given fields replaced by the non-null parameter values.
   @override
   @JsonKey(includeFromJson: false, includeToJson: false)
-  _$$TeamImplCopyWith<_$TeamImpl> get copyWith =>
+  _$$TeamEventImplCopyWith<_$TeamEventImpl> get copyWith =>
       throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-mixin _$TeamConfig {
-  PublishConfig? get publish => throw _privateConstructorUsedError;
-  WebhookConfig? get webhooks => throw _privateConstructorUsedError;
-  LintingConfig? get linting => throw _privateConstructorUsedError;
-  BackupConfig? get backup => throw _privateConstructorUsedError;
+mixin _$TeamEventField {
+  String get key => throw _privateConstructorUsedError;
+  String get value => throw _privateConstructorUsedError;
 
-  /// Create a copy of TeamConfig
+  /// Create a copy of TeamEventField
   /// with the given fields replaced by the non-null parameter values.
   @JsonKey(includeFromJson: false, includeToJson: false)
-  $TeamConfigCopyWith<TeamConfig> get copyWith =>
+  $TeamEventFieldCopyWith<TeamEventField> get copyWith =>
       throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-abstract class $TeamConfigCopyWith<$Res> {
-  factory $TeamConfigCopyWith(
-          TeamConfig value, $Res Function(TeamConfig) then) =
-      _$TeamConfigCopyWithImpl<$Res, TeamConfig>;
+abstract class $TeamEventFieldCopyWith<$Res> {
+  factory $TeamEventFieldCopyWith(
+          TeamEventField value, $Res Function(TeamEventField) then) =
+      _$TeamEventFieldCopyWithImpl<$Res, TeamEventField>;
   @useResult
-  $Res call(
-      {PublishConfig? publish,
-      WebhookConfig? webhooks,
-      LintingConfig? linting,
-      BackupConfig? backup});
-
-  $PublishConfigCopyWith<$Res>? get publish;
-  $WebhookConfigCopyWith<$Res>? get webhooks;
-  $LintingConfigCopyWith<$Res>? get linting;
-  $BackupConfigCopyWith<$Res>? get backup;
+  $Res call({String key, String value});
 }
 
 /// @nodoc
-class _$TeamConfigCopyWithImpl<$Res, $Val extends TeamConfig>
-    implements $TeamConfigCopyWith<$Res> {
-  _$TeamConfigCopyWithImpl(this._value, this._then);
+class _$TeamEventFieldCopyWithImpl<$Res, $Val extends TeamEventField>
+    implements $TeamEventFieldCopyWith<$Res> {
+  _$TeamEventFieldCopyWithImpl(this._value, this._then);
 
   // ignore: unused_field
   final $Val _value;
   // ignore: unused_field
   final $Res Function($Val) _then;
 
-  /// Create a copy of TeamConfig
+  /// Create a copy of TeamEventField
   /// with the given fields replaced by the non-null parameter values.
   @pragma('vm:prefer-inline')
   @override
   $Res call({
-    Object? publish = freezed,
-    Object? webhooks = freezed,
-    Object? linting = freezed,
-    Object? backup = freezed,
+    Object? key = null,
+    Object? value = null,
   }) {
     return _then(_value.copyWith(
-      publish: freezed == publish
-          ? _value.publish
-          : publish // ignore: cast_nullable_to_non_nullable
-              as PublishConfig?,
-      webhooks: freezed == webhooks
-          ? _value.webhooks
-          : webhooks // ignore: cast_nullable_to_non_nullable
-              as WebhookConfig?,
-      linting: freezed == linting
-          ? _value.linting
-          : linting // ignore: cast_nullable_to_non_nullable
-              as LintingConfig?,
-      backup: freezed == backup
-          ? _value.backup
-          : backup // ignore: cast_nullable_to_non_nullable
-              as BackupConfig?,
+      key: null == key
+          ? _value.key
+          : key // ignore: cast_nullable_to_non_nullable
+              as String,
+      value: null == value
+          ? _value.value
+          : value // ignore: cast_nullable_to_non_nullable
+              as String,
     ) as $Val);
-  }
-
-  /// Create a copy of TeamConfig
-  /// with the given fields replaced by the non-null parameter values.
-  @override
-  @pragma('vm:prefer-inline')
-  $PublishConfigCopyWith<$Res>? get publish {
-    if (_value.publish == null) {
-      return null;
-    }
-
-    return $PublishConfigCopyWith<$Res>(_value.publish!, (value) {
-      return _then(_value.copyWith(publish: value) as $Val);
-    });
-  }
-
-  /// Create a copy of TeamConfig
-  /// with the given fields replaced by the non-null parameter values.
-  @override
-  @pragma('vm:prefer-inline')
-  $WebhookConfigCopyWith<$Res>? get webhooks {
-    if (_value.webhooks == null) {
-      return null;
-    }
-
-    return $WebhookConfigCopyWith<$Res>(_value.webhooks!, (value) {
-      return _then(_value.copyWith(webhooks: value) as $Val);
-    });
-  }
-
-  /// Create a copy of TeamConfig
-  /// with the given fields replaced by the non-null parameter values.
-  @override
-  @pragma('vm:prefer-inline')
-  $LintingConfigCopyWith<$Res>? get linting {
-    if (_value.linting == null) {
-      return null;
-    }
-
-    return $LintingConfigCopyWith<$Res>(_value.linting!, (value) {
-      return _then(_value.copyWith(linting: value) as $Val);
-    });
-  }
-
-  /// Create a copy of TeamConfig
-  /// with the given fields replaced by the non-null parameter values.
-  @override
-  @pragma('vm:prefer-inline')
-  $BackupConfigCopyWith<$Res>? get backup {
-    if (_value.backup == null) {
-      return null;
-    }
-
-    return $BackupConfigCopyWith<$Res>(_value.backup!, (value) {
-      return _then(_value.copyWith(backup: value) as $Val);
-    });
-  }
-}
-
-/// @nodoc
-abstract class _$$TeamConfigImplCopyWith<$Res>
-    implements $TeamConfigCopyWith<$Res> {
-  factory _$$TeamConfigImplCopyWith(
-          _$TeamConfigImpl value, $Res Function(_$TeamConfigImpl) then) =
-      __$$TeamConfigImplCopyWithImpl<$Res>;
-  @override
-  @useResult
-  $Res call(
-      {PublishConfig? publish,
-      WebhookConfig? webhooks,
-      LintingConfig? linting,
-      BackupConfig? backup});
+  }
+}
 
+/// @nodoc
+abstract class _$$TeamEventFieldImplCopyWith<$Res>
+    implements $TeamEventFieldCopyWith<$Res> {
+  factory _$$TeamEventFieldImplCopyWith(_$TeamEventFieldImpl value,
+          $Res Function(_$TeamEventFieldImpl) then) =
+      __$$TeamEventFieldImplCopyWithImpl<$Res>;
   @override
-  $PublishConfigCopyWith<$Res>? get publish;
-  @override
-  $WebhookConfigCopyWith<$Res>? get webhooks;
-  @override
-  $LintingConfigCopyWith<$Res>? get linting;
-  @override
-  $BackupConfigCopyWith<$Res>? get backup;
+  @useResult
+  $Res call({String key, String value});
 }
 
 /// @nodoc
-class __$$TeamConfigImplCopyWithImpl<$Res>
-    extends _$TeamConfigCopyWithImpl<$Res, _$TeamConfigImpl>
-    implements _$$TeamConfigImplCopyWith<$Res> {
-  __$$TeamConfigImplCopyWithImpl(
-      _$TeamConfigImpl _value, $Res Function(_$TeamConfigImpl) _then)
+class __$$TeamEventFieldImplCopyWithImpl<$Res>
+    extends _$TeamEventFieldCopyWithImpl<$Res, _$TeamEventFieldImpl>
+    implements _$$TeamEventFieldImplCopyWith<$Res> {
+  __$$TeamEventFieldImplCopyWithImpl(
+      _$TeamEventFieldImpl _value, $Res Function(_$TeamEventFieldImpl) _then)
       : super(_value, _then);
 
-  /// Create a copy of TeamConfig
+  /// Create a copy of TeamEventField
   /// with the given fields replaced by the non-null parameter values.
   @pragma('vm:prefer-inline')
   @override
   $Res call({
-    Object? publish = freezed,
-    Object? webhooks = freezed,
-    Object? linting = freezed,
-    Object? backup = freezed,
+    Object? key = null,
+    Object? value = null,
   }) {
-    return _then(_$TeamConfigImpl(
-      publish: freezed == publish
-          ? _value.publish
-          : publish // ignore: cast_nullable_to_non_nullable
-              as PublishConfig?,
-      webhooks: freezed == webhooks
-          ? _value.webhooks
-          : webhooks // ignore: cast_nullable_to_non_nullable
-              as WebhookConfig?,
-      linting: freezed == linting
-          ? _value.linting
-          : linting // ignore: cast_nullable_to_non_nullable
-              as LintingConfig?,
-      backup: freezed == backup
-          ? _value.backup
-          : backup // ignore: cast_nullable_to_non_nullable
-              as BackupConfig?,
+    return _then(_$TeamEventFieldImpl(
+      key: null == key
+          ? _value.key
+          : key // ignore: cast_nullable_to_non_nullable
+              as String,
+      value: null == value
+          ? _value.value
+          : value // ignore: cast_nullable_to_non_nullable
+              as String,
     ));
   }
 }
 
 /// @nodoc
 
-class _$TeamConfigImpl extends _TeamConfig {
-  const _$TeamConfigImpl(
-      {this.publish, this.webhooks, this.linting, this.backup})
-      : super._();
+class _$TeamEventFieldImpl implements _TeamEventField {
+  const _$TeamEventFieldImpl({required this.key, required this.value});
 
   @override
-  final PublishConfig? publish;
-  @override
-  final WebhookConfig? webhooks;
-  @override
-  final LintingConfig? linting;
+  final String key;
   @override
-  final BackupConfig? backup;
+  final String value;
 
   @override
   String toString() {
-    return 'TeamConfig(publish: $publish, webhooks: $webhooks, linting: $linting, backup: $backup)';
+    return 'TeamEventField(key: $key, value: $value)';
   }
 
   @override
   bool operator ==(Object other) {
     return identical(this, other) ||
         (other.runtimeType == runtimeType &&
-            other is _$TeamConfigImpl &&
-            (identical(other.publish, publish) || other.publish == publish) &&
-            (identical(other.webhooks, webhooks) ||
-                other.webhooks == webhooks) &&
-            (identical(other.linting, linting) || other.linting == linting) &&
-            (identical(other.backup, backup) || other.backup == backup));
+            other is _$TeamEventFieldImpl &&
+            (identical(other.key, key) || other.key == key) &&
+            (identical(other.value, value) || other.value == value));
   }
 
   @override
-  int get hashCode =>
-      Object.hash(runtimeType, publish, webhooks, linting, backup);
+  int get hashCode => Object.hash(runtimeType, key, value);
 
-  /// Create a copy of TeamConfig
+  /// Create a copy of TeamEventField
   /// with the given fields replaced by the non-null parameter values.
   @JsonKey(includeFromJson: false, includeToJson: false)
   @override
   @pragma('vm:prefer-inline')
-  _$$TeamConfigImplCopyWith<_$TeamConfigImpl> get copyWith =>
-      __$$TeamConfigImplCopyWithImpl<_$TeamConfigImpl>(this, _$identity);
+  _$$TeamEventFieldImplCopyWith<_$TeamEventFieldImpl> get copyWith =>
+      __$$TeamEventFieldImplCopyWithImpl<_$TeamEventFieldImpl>(
+          this, _$identity);
 }
 
-abstract class _TeamConfig extends TeamConfig {
-  const factory _TeamConfig(
-      {final PublishConfig? publish,
-      final WebhookConfig? webhooks,
-      final LintingConfig? linting,
-      final BackupConfig? backup}) = _$TeamConfigImpl;
-  const _TeamConfig._() : super._();
+abstract class _TeamEventField implements TeamEventField {
+  const factory _TeamEventField(
+      {required final String key,
+      required final String value}) = _$TeamEventFieldImpl;
 
   @override
-  PublishConfig? get publish;
-  @override
-  WebhookConfig? get webhooks;
-  @override
-  LintingConfig? get linting;
+  String get key;
   @override
-  BackupConfig? get backup;
+  String get value;
 
-  /// Create a copy of TeamConfig
+  /// Create a copy of TeamEventField
   /// with the given fields replaced by the non-null parameter values.
   @override
   @JsonKey(includeFromJson: false, includeToJson: false)
-  _$$TeamConfigImplCopyWith<_$TeamConfigImpl> get copyWith =>
+  _$$TeamEventFieldImplCopyWith<_$TeamEventFieldImpl> get copyWith =>
       throw _privateConstructorUsedError;
 }
 
@@ -2157,6 +4991,7 @@ mixin _$WebhookConfig {
   String? get discord => throw _privateConstructorUsedError;
   String? get slack => throw _privateConstructorUsedError;
   String? get signal => throw _privateConstructorUsedError;
+  List<CustomWebhook> get custom => throw _privateConstructorUsedError;
 
   /// Create a copy of WebhookConfig
   /// with the given fields replaced by the non-null parameter values.
@@ -2171,7 +5006,11 @@ abstract class $WebhookConfigCopyWith<$Res> {
           WebhookConfig value, $Res Function(WebhookConfig) then) =
       _$WebhookConfigCopyWithImpl<$Res, WebhookConfig>;
   @useResult
-  $Res call({String? discord, String? slack, String? signal});
+  $Res call(
+      {String? discord,
+      String? slack,
+      String? signal,
+      List<CustomWebhook> custom});
 }
 
 /// @nodoc
@@ -2192,6 +5031,7 @@ class _$WebhookConfigCopyWithImpl<$Res, $Val extends WebhookConfig>
     Object? discord = freezed,
     Object? slack = freezed,
     Object? signal = freezed,
+    Object? custom = null,
   }) {
     return _then(_value.copyWith(
       discord: freezed == discord
@@ -2206,6 +5046,10 @@ class _$WebhookConfigCopyWithImpl<$Res, $Val extends WebhookConfig>
           ? _value.signal
           : signal // ignore: cast_nullable_to_non_nullable
               as String?,
+      custom: null == custom
+          ? _value.custom
+          : custom // ignore: cast_nullable_to_non_nullable
+              as List<CustomWebhook>,
     ) as $Val);
   }
 }
@@ -2218,7 +5062,11 @@ abstract class _$$WebhookConfigImplCopyWith<$Res>
       __$$WebhookConfigImplCopyWithImpl<$Res>;
   @override
   @useResult
-  $Res call({String? discord, String? slack, String? signal});
+  $Res call(
+      {String? discord,
+      String? slack,
+      String? signal,
+      List<CustomWebhook> custom});
 }
 
 /// @nodoc
@@ -2237,6 +5085,7 @@ class __$$WebhookConfigImplCopyWithImpl<$Res>
     Object? discord = freezed,
     Object? slack = freezed,
     Object? signal = freezed,
+    Object? custom = null,
   }) {
     return _then(_$WebhookConfigImpl(
       discord: freezed == discord
@@ -2251,6 +5100,10 @@ class __$$WebhookConfigImplCopyWithImpl<$Res>
           ? _value.signal
           : signal // ignore: cast_nullable_to_non_nullable
               as String?,
+      custom: null == custom
+          ? _value._custom
+          : custom // ignore: cast_nullable_to_non_nullable
+              as List<CustomWebhook>,
     ));
   }
 }
@@ -2258,7 +5111,12 @@ class __$$WebhookConfigImplCopyWithImpl<$Res>
 /// @nodoc
 
 class _$WebhookConfigImpl implements _WebhookConfig {
-  const _$WebhookConfigImpl({this.discord, this.slack, this.signal});
+  const _$WebhookConfigImpl(
+      {this.discord,
+      this.slack,
+      this.signal,
+      required final List<CustomWebhook> custom})
+      : _custom = custom;
 
   @override
   final String? discord;
@@ -2266,10 +5124,17 @@ class _$WebhookConfigImpl implements _WebhookConfig {
   final String? slack;
   @override
   final String? signal;
+  final List<CustomWebhook> _custom;
+  @override
+  List<CustomWebhook> get custom {
+    if (_custom is EqualUnmodifiableListView) return _custom;
+    // ignore: implicit_dynamic_type
+    return EqualUnmodifiableListView(_custom);
+  }
 
   @override
   String toString() {
-    return 'WebhookConfig(discord: $discord, slack: $slack, signal: $signal)';
+    return 'WebhookConfig(discord: $discord, slack: $slack, signal: $signal, custom: $custom)';
   }
 
   @override
@@ -2279,11 +5144,13 @@ class _$WebhookConfigImpl implements _WebhookConfig {
             other is _$WebhookConfigImpl &&
             (identical(other.discord, discord) || other.discord == discord) &&
             (identical(other.slack, slack) || other.slack == slack) &&
-            (identical(other.signal, signal) || other.signal == signal));
+            (identical(other.signal, signal) || other.signal == signal) &&
+            const DeepCollectionEquality().equals(other._custom, _custom));
   }
 
   @override
-  int get hashCode => Object.hash(runtimeType, discord, slack, signal);
+  int get hashCode => Object.hash(runtimeType, discord, slack, signal,
+      const DeepCollectionEquality().hash(_custom));
 
   /// Create a copy of WebhookConfig
   /// with the given fields replaced by the non-null parameter values.
@@ -2298,7 +5165,8 @@ abstract class _WebhookConfig implements WebhookConfig {
   const factory _WebhookConfig(
       {final String? discord,
       final String? slack,
-      final String? signal}) = _$WebhookConfigImpl;
+      final String? signal,
+      required final List<CustomWebhook> custom}) = _$WebhookConfigImpl;
 
   @override
   String? get discord;
@@ -2306,6 +5174,8 @@ abstract class _WebhookConfig implements WebhookConfig {
   String? get slack;
   @override
   String? get signal;
+  @override
+  List<CustomWebhook> get custom;
 
   /// Create a copy of WebhookConfig
   /// with the given fields replaced by the non-null parameter values.
@@ -2314,3 +5184,296 @@ abstract class _WebhookConfig implements WebhookConfig {
   _$$WebhookConfigImplCopyWith<_$WebhookConfigImpl> get copyWith =>
       throw _privateConstructorUsedError;
 }
+
+/// @nodoc
+mixin _$WebhookHeader {
+  String get name => throw _privateConstructorUsedError;
+  String get value => throw _privateConstructorUsedError;
+
+  /// Create a copy of WebhookHeader
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  $WebhookHeaderCopyWith<WebhookHeader> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $WebhookHeaderCopyWith<$Res> {
+  factory $WebhookHeaderCopyWith(
+          WebhookHeader value, $Res Function(WebhookHeader) then) =
+      _$WebhookHeaderCopyWithImpl<$Res, WebhookHeader>;
+  @useResult
+  $Res call({String name, String value});
+}
+
+/// @nodoc
+class _$WebhookHeaderCopyWithImpl<$Res, $Val extends WebhookHeader>
+    implements $WebhookHeaderCopyWith<$Res> {
+  _$WebhookHeaderCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of WebhookHeader
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? name = null,
+    Object? value = null,
+  }) {
+    return _then(_value.copyWith(
+      name: null == name
+          ? _value.name
+          : name // ignore: cast_nullable_to_non_nullable
+              as String,
+      value: null == value
+          ? _value.value
+          : value // ignore: cast_nullable_to_non_nullable
+              as String,
+    ) as $Val);
+  }
+}
+
+/// @nodoc
+abstract class _$$WebhookHeaderImplCopyWith<$Res>
+    implements $WebhookHeaderCopyWith<$Res> {
+  factory _$$WebhookHeaderImplCopyWith(
+          _$WebhookHeaderImpl value, $Res Function(_$WebhookHeaderImpl) then) =
+      __$$WebhookHeaderImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call({String name, String value});
+}
+
+/// @nodoc
+class __$$WebhookHeaderImplCopyWithImpl<$Res>
+    extends _$WebhookHeaderCopyWithImpl<$Res, _$WebhookHeaderImpl>
+    implements _$$WebhookHeaderImplCopyWith<$Res> {
+  __$$WebhookHeaderImplCopyWithImpl(
+      _$WebhookHeaderImpl _value, $Res Function(_$WebhookHeaderImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of WebhookHeader
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? name = null,
+    Object? value = null,
+  }) {
+    return _then(_$WebhookHeaderImpl(
+      name: null == name
+          ? _value.name
+          : name // ignore: cast_nullable_to_non_nullable
+              as String,
+      value: null == value
+          ? _value.value
+          : value // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$WebhookHeaderImpl implements _WebhookHeader {
+  const _$WebhookHeaderImpl({required this.name, required this.value});
+
+  @override
+  final String name;
+  @override
+  final String value;
+
+  @override
+  String toString() {
+    return 'WebhookHeader(name: $name, value: $value)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$WebhookHeaderImpl &&
+            (identical(other.name, name) || other.name == name) &&
+            (identical(other.value, value) || other.value == value));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, name, value);
+
+  /// Create a copy of WebhookHeader
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$WebhookHeaderImplCopyWith<_$WebhookHeaderImpl> get copyWith =>
+      __$$WebhookHeaderImplCopyWithImpl<_$WebhookHeaderImpl>(this, _$identity);
+}
+
+abstract class _WebhookHeader implements WebhookHeader {
+  const factory _WebhookHeader(
+      {required final String name,
+      required final String value}) = _$WebhookHeaderImpl;
+
+  @override
+  String get name;
+  @override
+  String get value;
+
+  /// Create a copy of WebhookHeader
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$WebhookHeaderImplCopyWith<_$WebhookHeaderImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+mixin _$WeekActivity {
+  String get weekStart => throw _privateConstructorUsedError;
+  int get count => throw _privateConstructorUsedError;
+
+  /// Create a copy of WeekActivity
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  $WeekActivityCopyWith<WeekActivity> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $WeekActivityCopyWith<$Res> {
+  factory $WeekActivityCopyWith(
+          WeekActivity value, $Res Function(WeekActivity) then) =
+      _$WeekActivityCopyWithImpl<$Res, WeekActivity>;
+  @useResult
+  $Res call({String weekStart, int count});
+}
+
+/// @nodoc
+class _$WeekActivityCopyWithImpl<$Res, $Val extends WeekActivity>
+    implements $WeekActivityCopyWith<$Res> {
+  _$WeekActivityCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of WeekActivity
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? weekStart = null,
+    Object? count = null,
+  }) {
+    return _then(_value.copyWith(
+      weekStart: null == weekStart
+          ? _value.weekStart
+          : weekStart // ignore: cast_nullable_to_non_nullable
+              as String,
+      count: null == count
+          ? _value.count
+          : count // ignore: cast_nullable_to_non_nullable
+              as int,
+    ) as $Val);
+  }
+}
+
+/// @nodoc
+abstract class _$$WeekActivityImplCopyWith<$Res>
+    implements $WeekActivityCopyWith<$Res> {
+  factory _$$WeekActivityImplCopyWith(
+          _$WeekActivityImpl value, $Res Function(_$WeekActivityImpl) then) =
+      __$$WeekActivityImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call({String weekStart, int count});
+}
+
+/// @nodoc
+class __$$WeekActivityImplCopyWithImpl<$Res>
+    extends _$WeekActivityCopyWithImpl<$Res, _$WeekActivityImpl>
+    implements _$$WeekActivityImplCopyWith<$Res> {
+  __$$WeekActivityImplCopyWithImpl(
+      _$WeekActivityImpl _value, $Res Function(_$WeekActivityImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of WeekActivity
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? weekStart = null,
+    Object? count = null,
+  }) {
+    return _then(_$WeekActivityImpl(
+      weekStart: null == weekStart
+          ? _value.weekStart
+          : weekStart // ignore: cast_nullable_to_non_nullable
+              as String,
+      count: null == count
+          ? _value.count
+          : count // ignore: cast_nullable_to_non_nullable
+              as int,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$WeekActivityImpl implements _WeekActivity {
+  const _$WeekActivityImpl({required this.weekStart, required this.count});
+
+  @override
+  final String weekStart;
+  @override
+  final int count;
+
+  @override
+  String toString() {
+    return 'WeekActivity(weekStart: $weekStart, count: $count)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$WeekActivityImpl &&
+            (identical(other.weekStart, weekStart) ||
+                other.weekStart == weekStart) &&
+            (identical(other.count, count) || other.count == count));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, weekStart, count);
+
+  /// Create a copy of WeekActivity
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$WeekActivityImplCopyWith<_$WeekActivityImpl> get copyWith =>
+      __$$WeekActivityImplCopyWithImpl<_$WeekActivityImpl>(this, _$identity);
+}
+
+abstract class _WeekActivity implements WeekActivity {
+  const factory _WeekActivity(
+      {required final String weekStart,
+      required final int count}) = _$WeekActivityImpl;
+
+  @override
+  String get weekStart;
+  @override
+  int get count;
+
+  /// Create a copy of WeekActivity
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$WeekActivityImplCopyWith<_$WeekActivityImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
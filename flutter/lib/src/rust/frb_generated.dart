@@ -68,7 +68,7 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
   String get codegenVersion => '2.11.1';
 
   @override
-  int get rustContentHash => 1882716962;
+  int get rustContentHash => 348746638;
 
   static const kDefaultExternalLibraryLoaderConfig =
       ExternalLibraryLoaderConfig(
@@ -79,6 +79,17 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
 }
 
 abstract class RustLibApi extends BaseApi {
+  Future<List<String>> crateApiAnnounceInteraction(
+      {required String root, required Interaction interaction});
+
+  Future<List<AppreciationEdge>> crateApiAppreciationGraph(
+      {required String root});
+
+  Future<double?> crateApiAppreciationReciprocity({required String root});
+
+  Future<void> crateApiApproveShellHook(
+      {required String root, required ShellHook hook});
+
   Credentials crateApiCredentialsCreate({required String pincode});
 
   Credentials crateApiCredentialsFromYaml({required String yaml});
@@ -88,6 +99,17 @@ abstract class RustLibApi extends BaseApi {
   bool crateApiCredentialsVerify(
       {required Credentials that, required String pincode});
 
+  Future<List<DuePulsePrompt>> crateApiDuePulsePrompts(
+      {required String root, required String email});
+
+  Future<String> crateApiExportTeamData(
+      {required String root,
+      required String format,
+      String? kinds,
+      String? since,
+      String? email,
+      String? pincode});
+
   Interaction crateApiInteractionAppreciation(
       {required String from,
       required List<String> withMembers,
@@ -114,6 +136,8 @@ abstract class RustLibApi extends BaseApi {
 
   KeyResult crateApiKeyResultCreate({required String description});
 
+  Future<Manifesto?> crateApiLoadTeamManifesto({required String root});
+
   Member crateApiMemberCreate({required String email});
 
   MemberCredentials crateApiMemberCredentialsCreate(
@@ -130,8 +154,13 @@ abstract class RustLibApi extends BaseApi {
 
   Member crateApiMemberFromYaml({required String yaml});
 
+  Future<List<MemberInteractionStats>> crateApiMemberInteractionStats(
+      {required String root});
+
   String crateApiMemberToYaml({required Member member});
 
+  String crateApiNormalizeEmail({required String email});
+
   Objective crateApiObjectiveCreate({required String title});
 
   Objective crateApiObjectiveFromYaml({required String yaml});
@@ -142,6 +171,17 @@ abstract class RustLibApi extends BaseApi {
 
   Future<OkrVisibility> crateApiOkrVisibilityDefault();
 
+  Future<BigInt> crateApiPincodeRetryAfterSecs(
+      {required String root, required String email});
+
+  Future<List<PrincipleRecognition>> crateApiPrincipleReport(
+      {required String root});
+
+  Future<bool> crateApiShellHookApproved(
+      {required String root, required ShellHook hook});
+
+  Future<List<TeamEvent>> crateApiTakeTeamEvents();
+
   TeamConfig crateApiTeamConfigCreate();
 
   TeamConfig crateApiTeamConfigFromYaml({required String yaml});
@@ -159,6 +199,17 @@ abstract class RustLibApi extends BaseApi {
   bool crateApiTeamIsMember({required Team that, required String email});
 
   String crateApiTeamToYaml({required Team team});
+
+  Objective crateApiTouchObjective({required Objective objective});
+
+  Future<List<String>> crateApiUnrecognisedMembers(
+      {required String root, required int weeks});
+
+  Future<PincodeCheck> crateApiVerifyMemberPincode(
+      {required String root, required String email, required String pincode});
+
+  Future<List<WeekActivity>> crateApiWeeklyActivity(
+      {required String root, required int weeks, String? timezone});
 }
 
 class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
@@ -169,6 +220,101 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     required super.portManager,
   });
 
+  @override
+  Future<List<String>> crateApiAnnounceInteraction(
+      {required String root, required Interaction interaction}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final arg0 = cst_encode_String(root);
+        final arg1 = cst_encode_box_autoadd_interaction(interaction);
+        return wire.wire__crate__api__announce_interaction(port_, arg0, arg1);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_list_String,
+        decodeErrorData: dco_decode_String,
+      ),
+      constMeta: kCrateApiAnnounceInteractionConstMeta,
+      argValues: [root, interaction],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiAnnounceInteractionConstMeta =>
+      const TaskConstMeta(
+        debugName: 'announce_interaction',
+        argNames: ['root', 'interaction'],
+      );
+
+  @override
+  Future<List<AppreciationEdge>> crateApiAppreciationGraph(
+      {required String root}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final arg0 = cst_encode_String(root);
+        return wire.wire__crate__api__appreciation_graph(port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_list_appreciation_edge,
+        decodeErrorData: dco_decode_String,
+      ),
+      constMeta: kCrateApiAppreciationGraphConstMeta,
+      argValues: [root],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiAppreciationGraphConstMeta => const TaskConstMeta(
+        debugName: 'appreciation_graph',
+        argNames: ['root'],
+      );
+
+  @override
+  Future<double?> crateApiAppreciationReciprocity({required String root}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final arg0 = cst_encode_String(root);
+        return wire.wire__crate__api__appreciation_reciprocity(port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_opt_box_autoadd_f_32,
+        decodeErrorData: dco_decode_String,
+      ),
+      constMeta: kCrateApiAppreciationReciprocityConstMeta,
+      argValues: [root],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiAppreciationReciprocityConstMeta =>
+      const TaskConstMeta(
+        debugName: 'appreciation_reciprocity',
+        argNames: ['root'],
+      );
+
+  @override
+  Future<void> crateApiApproveShellHook(
+      {required String root, required ShellHook hook}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final arg0 = cst_encode_String(root);
+        final arg1 = cst_encode_box_autoadd_shell_hook(hook);
+        return wire.wire__crate__api__approve_shell_hook(port_, arg0, arg1);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_unit,
+        decodeErrorData: dco_decode_String,
+      ),
+      constMeta: kCrateApiApproveShellHookConstMeta,
+      argValues: [root, hook],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiApproveShellHookConstMeta => const TaskConstMeta(
+        debugName: 'approve_shell_hook',
+        argNames: ['root', 'hook'],
+      );
+
   @override
   Credentials crateApiCredentialsCreate({required String pincode}) {
     return handler.executeSync(SyncTask(
@@ -260,6 +406,64 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ['that', 'pincode'],
       );
 
+  @override
+  Future<List<DuePulsePrompt>> crateApiDuePulsePrompts(
+      {required String root, required String email}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final arg0 = cst_encode_String(root);
+        final arg1 = cst_encode_String(email);
+        return wire.wire__crate__api__due_pulse_prompts(port_, arg0, arg1);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_list_due_pulse_prompt,
+        decodeErrorData: dco_decode_String,
+      ),
+      constMeta: kCrateApiDuePulsePromptsConstMeta,
+      argValues: [root, email],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiDuePulsePromptsConstMeta => const TaskConstMeta(
+        debugName: 'due_pulse_prompts',
+        argNames: ['root', 'email'],
+      );
+
+  @override
+  Future<String> crateApiExportTeamData(
+      {required String root,
+      required String format,
+      String? kinds,
+      String? since,
+      String? email,
+      String? pincode}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final arg0 = cst_encode_String(root);
+        final arg1 = cst_encode_String(format);
+        final arg2 = cst_encode_opt_String(kinds);
+        final arg3 = cst_encode_opt_String(since);
+        final arg4 = cst_encode_opt_String(email);
+        final arg5 = cst_encode_opt_String(pincode);
+        return wire.wire__crate__api__export_team_data(
+            port_, arg0, arg1, arg2, arg3, arg4, arg5);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_String,
+        decodeErrorData: dco_decode_String,
+      ),
+      constMeta: kCrateApiExportTeamDataConstMeta,
+      argValues: [root, format, kinds, since, email, pincode],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiExportTeamDataConstMeta => const TaskConstMeta(
+        debugName: 'export_team_data',
+        argNames: ['root', 'format', 'kinds', 'since', 'email', 'pincode'],
+      );
+
   @override
   Interaction crateApiInteractionAppreciation(
       {required String from,
@@ -460,6 +664,28 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ['description'],
       );
 
+  @override
+  Future<Manifesto?> crateApiLoadTeamManifesto({required String root}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final arg0 = cst_encode_String(root);
+        return wire.wire__crate__api__load_team_manifesto(port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_opt_box_autoadd_manifesto,
+        decodeErrorData: dco_decode_String,
+      ),
+      constMeta: kCrateApiLoadTeamManifestoConstMeta,
+      argValues: [root],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiLoadTeamManifestoConstMeta => const TaskConstMeta(
+        debugName: 'load_team_manifesto',
+        argNames: ['root'],
+      );
+
   @override
   Member crateApiMemberCreate({required String email}) {
     return handler.executeSync(SyncTask(
@@ -622,6 +848,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ['yaml'],
       );
 
+  @override
+  Future<List<MemberInteractionStats>> crateApiMemberInteractionStats(
+      {required String root}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final arg0 = cst_encode_String(root);
+        return wire.wire__crate__api__member_interaction_stats(port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_list_member_interaction_stats,
+        decodeErrorData: dco_decode_String,
+      ),
+      constMeta: kCrateApiMemberInteractionStatsConstMeta,
+      argValues: [root],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiMemberInteractionStatsConstMeta =>
+      const TaskConstMeta(
+        debugName: 'member_interaction_stats',
+        argNames: ['root'],
+      );
+
   @override
   String crateApiMemberToYaml({required Member member}) {
     return handler.executeSync(SyncTask(
@@ -644,6 +894,28 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ['member'],
       );
 
+  @override
+  String crateApiNormalizeEmail({required String email}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final arg0 = cst_encode_String(email);
+        return wire.wire__crate__api__normalize_email(arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_String,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiNormalizeEmailConstMeta,
+      argValues: [email],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiNormalizeEmailConstMeta => const TaskConstMeta(
+        debugName: 'normalize_email',
+        argNames: ['email'],
+      );
+
   @override
   Objective crateApiObjectiveCreate({required String title}) {
     return handler.executeSync(SyncTask(
@@ -755,6 +1027,100 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: [],
       );
 
+  @override
+  Future<BigInt> crateApiPincodeRetryAfterSecs(
+      {required String root, required String email}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final arg0 = cst_encode_String(root);
+        final arg1 = cst_encode_String(email);
+        return wire.wire__crate__api__pincode_retry_after_secs(
+            port_, arg0, arg1);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_u_64,
+        decodeErrorData: dco_decode_String,
+      ),
+      constMeta: kCrateApiPincodeRetryAfterSecsConstMeta,
+      argValues: [root, email],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiPincodeRetryAfterSecsConstMeta =>
+      const TaskConstMeta(
+        debugName: 'pincode_retry_after_secs',
+        argNames: ['root', 'email'],
+      );
+
+  @override
+  Future<List<PrincipleRecognition>> crateApiPrincipleReport(
+      {required String root}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final arg0 = cst_encode_String(root);
+        return wire.wire__crate__api__principle_report(port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_list_principle_recognition,
+        decodeErrorData: dco_decode_String,
+      ),
+      constMeta: kCrateApiPrincipleReportConstMeta,
+      argValues: [root],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiPrincipleReportConstMeta => const TaskConstMeta(
+        debugName: 'principle_report',
+        argNames: ['root'],
+      );
+
+  @override
+  Future<bool> crateApiShellHookApproved(
+      {required String root, required ShellHook hook}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final arg0 = cst_encode_String(root);
+        final arg1 = cst_encode_box_autoadd_shell_hook(hook);
+        return wire.wire__crate__api__shell_hook_approved(port_, arg0, arg1);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_bool,
+        decodeErrorData: dco_decode_String,
+      ),
+      constMeta: kCrateApiShellHookApprovedConstMeta,
+      argValues: [root, hook],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiShellHookApprovedConstMeta => const TaskConstMeta(
+        debugName: 'shell_hook_approved',
+        argNames: ['root', 'hook'],
+      );
+
+  @override
+  Future<List<TeamEvent>> crateApiTakeTeamEvents() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        return wire.wire__crate__api__take_team_events(port_);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_list_team_event,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiTakeTeamEventsConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiTakeTeamEventsConstMeta => const TaskConstMeta(
+        debugName: 'take_team_events',
+        argNames: [],
+      );
+
   @override
   TeamConfig crateApiTeamConfigCreate() {
     return handler.executeSync(SyncTask(
@@ -954,43 +1320,161 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ['team'],
       );
 
-  @protected
-  String dco_decode_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as String;
-  }
-
-  @protected
-  BackupConfig dco_decode_backup_config(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 1)
-      throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
-    return BackupConfig(
-      protectedBranch: dco_decode_opt_String(arr[0]),
-    );
+  @override
+  Objective crateApiTouchObjective({required Objective objective}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final arg0 = cst_encode_box_autoadd_objective(objective);
+        return wire.wire__crate__api__touch_objective(arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_objective,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiTouchObjectiveConstMeta,
+      argValues: [objective],
+      apiImpl: this,
+    ));
   }
 
-  @protected
-  bool dco_decode_bool(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as bool;
-  }
+  TaskConstMeta get kCrateApiTouchObjectiveConstMeta => const TaskConstMeta(
+        debugName: 'touch_objective',
+        argNames: ['objective'],
+      );
 
-  @protected
-  BackupConfig dco_decode_box_autoadd_backup_config(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_backup_config(raw);
+  @override
+  Future<List<String>> crateApiUnrecognisedMembers(
+      {required String root, required int weeks}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final arg0 = cst_encode_String(root);
+        final arg1 = cst_encode_u_32(weeks);
+        return wire.wire__crate__api__unrecognised_members(port_, arg0, arg1);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_list_String,
+        decodeErrorData: dco_decode_String,
+      ),
+      constMeta: kCrateApiUnrecognisedMembersConstMeta,
+      argValues: [root, weeks],
+      apiImpl: this,
+    ));
   }
 
-  @protected
-  Credentials dco_decode_box_autoadd_credentials(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_credentials(raw);
-  }
+  TaskConstMeta get kCrateApiUnrecognisedMembersConstMeta =>
+      const TaskConstMeta(
+        debugName: 'unrecognised_members',
+        argNames: ['root', 'weeks'],
+      );
 
-  @protected
-  Interaction dco_decode_box_autoadd_interaction(dynamic raw) {
+  @override
+  Future<PincodeCheck> crateApiVerifyMemberPincode(
+      {required String root, required String email, required String pincode}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final arg0 = cst_encode_String(root);
+        final arg1 = cst_encode_String(email);
+        final arg2 = cst_encode_String(pincode);
+        return wire.wire__crate__api__verify_member_pincode(
+            port_, arg0, arg1, arg2);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_pincode_check,
+        decodeErrorData: dco_decode_String,
+      ),
+      constMeta: kCrateApiVerifyMemberPincodeConstMeta,
+      argValues: [root, email, pincode],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiVerifyMemberPincodeConstMeta =>
+      const TaskConstMeta(
+        debugName: 'verify_member_pincode',
+        argNames: ['root', 'email', 'pincode'],
+      );
+
+  @override
+  Future<List<WeekActivity>> crateApiWeeklyActivity(
+      {required String root, required int weeks, String? timezone}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final arg0 = cst_encode_String(root);
+        final arg1 = cst_encode_u_32(weeks);
+        final arg2 = cst_encode_opt_String(timezone);
+        return wire.wire__crate__api__weekly_activity(port_, arg0, arg1, arg2);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_list_week_activity,
+        decodeErrorData: dco_decode_String,
+      ),
+      constMeta: kCrateApiWeeklyActivityConstMeta,
+      argValues: [root, weeks, timezone],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiWeeklyActivityConstMeta => const TaskConstMeta(
+        debugName: 'weekly_activity',
+        argNames: ['root', 'weeks', 'timezone'],
+      );
+
+  @protected
+  String dco_decode_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as String;
+  }
+
+  @protected
+  AppreciationEdge dco_decode_appreciation_edge(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return AppreciationEdge(
+      from: dco_decode_String(arr[0]),
+      to: dco_decode_String(arr[1]),
+      count: dco_decode_u_32(arr[2]),
+    );
+  }
+
+  @protected
+  BackupConfig dco_decode_backup_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1)
+      throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return BackupConfig(
+      protectedBranch: dco_decode_opt_String(arr[0]),
+    );
+  }
+
+  @protected
+  bool dco_decode_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as bool;
+  }
+
+  @protected
+  BackupConfig dco_decode_box_autoadd_backup_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_backup_config(raw);
+  }
+
+  @protected
+  Credentials dco_decode_box_autoadd_credentials(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_credentials(raw);
+  }
+
+  @protected
+  double dco_decode_box_autoadd_f_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as double;
+  }
+
+  @protected
+  Interaction dco_decode_box_autoadd_interaction(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return dco_decode_interaction(raw);
   }
@@ -1001,6 +1485,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return dco_decode_linting_config(raw);
   }
 
+  @protected
+  Manifesto dco_decode_box_autoadd_manifesto(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_manifesto(raw);
+  }
+
   @protected
   Member dco_decode_box_autoadd_member(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1025,6 +1515,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return dco_decode_publish_config(raw);
   }
 
+  @protected
+  PulseConfig dco_decode_box_autoadd_pulse_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_pulse_config(raw);
+  }
+
+  @protected
+  ShellHook dco_decode_box_autoadd_shell_hook(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_shell_hook(raw);
+  }
+
   @protected
   Team dco_decode_box_autoadd_team(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1055,6 +1557,37 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  CustomWebhook dco_decode_custom_webhook(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return CustomWebhook(
+      name: dco_decode_opt_String(arr[0]),
+      url: dco_decode_String(arr[1]),
+      template: dco_decode_opt_String(arr[2]),
+      contentType: dco_decode_opt_String(arr[3]),
+      headers: dco_decode_list_webhook_header(arr[4]),
+      secret: dco_decode_opt_String(arr[5]),
+      signatureHeader: dco_decode_opt_String(arr[6]),
+      events: dco_decode_list_String(arr[7]),
+    );
+  }
+
+  @protected
+  DuePulsePrompt dco_decode_due_pulse_prompt(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return DuePulsePrompt(
+      prompt: dco_decode_String(arr[0]),
+      message: dco_decode_String(arr[1]),
+      objectiveId: dco_decode_opt_String(arr[2]),
+    );
+  }
+
   @protected
   double dco_decode_f_32(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1071,8 +1604,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   Interaction dco_decode_interaction(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 7)
-      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    if (arr.length != 9)
+      throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
     return Interaction(
       id: dco_decode_String(arr[0]),
       kind: dco_decode_interaction_kind(arr[1]),
@@ -1081,6 +1614,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       note: dco_decode_String(arr[4]),
       timestamp: dco_decode_String(arr[5]),
       shared: dco_decode_bool(arr[6]),
+      principles: dco_decode_list_String(arr[7]),
+      importedFrom: dco_decode_opt_String(arr[8]),
     );
   }
 
@@ -1121,29 +1656,118 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (raw as List<dynamic>).map(dco_decode_String).toList();
   }
 
+  @protected
+  List<AppreciationEdge> dco_decode_list_appreciation_edge(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_appreciation_edge).toList();
+  }
+
+  @protected
+  List<CustomWebhook> dco_decode_list_custom_webhook(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_custom_webhook).toList();
+  }
+
+  @protected
+  List<DuePulsePrompt> dco_decode_list_due_pulse_prompt(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_due_pulse_prompt).toList();
+  }
+
   @protected
   List<KeyResult> dco_decode_list_key_result(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return (raw as List<dynamic>).map(dco_decode_key_result).toList();
   }
 
+  @protected
+  List<MemberInteractionStats> dco_decode_list_member_interaction_stats(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(
+        dco_decode_member_interaction_stats).toList();
+  }
+
+  @protected
+  List<MemberPulse> dco_decode_list_member_pulse(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_member_pulse).toList();
+  }
+
   @protected
   Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return raw as Uint8List;
   }
 
+  @protected
+  List<Principle> dco_decode_list_principle(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_principle).toList();
+  }
+
+  @protected
+  List<PrincipleRecognition> dco_decode_list_principle_recognition(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(
+        dco_decode_principle_recognition).toList();
+  }
+
+  @protected
+  List<ShellHook> dco_decode_list_shell_hook(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_shell_hook).toList();
+  }
+
+  @protected
+  List<TeamEvent> dco_decode_list_team_event(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_team_event).toList();
+  }
+
+  @protected
+  List<TeamEventField> dco_decode_list_team_event_field(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_team_event_field).toList();
+  }
+
+  @protected
+  List<WebhookHeader> dco_decode_list_webhook_header(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_webhook_header).toList();
+  }
+
+  @protected
+  List<WeekActivity> dco_decode_list_week_activity(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_week_activity).toList();
+  }
+
+  @protected
+  Manifesto dco_decode_manifesto(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return Manifesto(
+      preamble: dco_decode_opt_String(arr[0]),
+      principles: dco_decode_list_principle(arr[1]),
+    );
+  }
+
   @protected
   Member dco_decode_member(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 4)
-      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
     return Member(
       email: dco_decode_String(arr[0]),
       name: dco_decode_opt_String(arr[1]),
       bio: dco_decode_opt_String(arr[2]),
       timezone: dco_decode_opt_String(arr[3]),
+      aliases: dco_decode_list_String(arr[4]),
     );
   }
 
@@ -1160,11 +1784,40 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  Objective dco_decode_objective(dynamic raw) {
+  MemberInteractionStats dco_decode_member_interaction_stats(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
     if (arr.length != 7)
       throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return MemberInteractionStats(
+      email: dco_decode_String(arr[0]),
+      kudosGiven: dco_decode_u_32(arr[1]),
+      kudosReceived: dco_decode_u_32(arr[2]),
+      feedbackGiven: dco_decode_u_32(arr[3]),
+      feedbackReceived: dco_decode_u_32(arr[4]),
+      totalSent: dco_decode_u_32(arr[5]),
+      totalReceived: dco_decode_u_32(arr[6]),
+    );
+  }
+
+  @protected
+  MemberPulse dco_decode_member_pulse(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return MemberPulse(
+      email: dco_decode_String(arr[0]),
+      cadences: dco_decode_pulse_cadences(arr[1]),
+    );
+  }
+
+  @protected
+  Objective dco_decode_objective(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 9)
+      throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
     return Objective(
       id: dco_decode_String(arr[0]),
       title: dco_decode_String(arr[1]),
@@ -1173,6 +1826,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       visibility: dco_decode_okr_visibility(arr[4]),
       owner: dco_decode_opt_String(arr[5]),
       quarter: dco_decode_opt_String(arr[6]),
+      principles: dco_decode_list_String(arr[7]),
+      updated: dco_decode_opt_String(arr[8]),
     );
   }
 
@@ -1194,18 +1849,36 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return raw == null ? null : dco_decode_box_autoadd_backup_config(raw);
   }
 
+  @protected
+  double? dco_decode_opt_box_autoadd_f_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_f_32(raw);
+  }
+
   @protected
   LintingConfig? dco_decode_opt_box_autoadd_linting_config(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return raw == null ? null : dco_decode_box_autoadd_linting_config(raw);
   }
 
+  @protected
+  Manifesto? dco_decode_opt_box_autoadd_manifesto(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_manifesto(raw);
+  }
+
   @protected
   PublishConfig? dco_decode_opt_box_autoadd_publish_config(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return raw == null ? null : dco_decode_box_autoadd_publish_config(raw);
   }
 
+  @protected
+  PulseConfig? dco_decode_opt_box_autoadd_pulse_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_pulse_config(raw);
+  }
+
   @protected
   WebhookConfig? dco_decode_opt_box_autoadd_webhook_config(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1213,15 +1886,93 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  PublishConfig dco_decode_publish_config(dynamic raw) {
+  PincodeCheck dco_decode_pincode_check(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return PincodeCheck(
+      verified: dco_decode_bool(arr[0]),
+      email: dco_decode_String(arr[1]),
+      retryAfterSecs: dco_decode_u_64(arr[2]),
+    );
+  }
+
+  @protected
+  Principle dco_decode_principle(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return Principle(
+      id: dco_decode_String(arr[0]),
+      title: dco_decode_String(arr[1]),
+      description: dco_decode_opt_String(arr[2]),
+    );
+  }
+
+  @protected
+  PrincipleRecognition dco_decode_principle_recognition(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
     if (arr.length != 3)
       throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return PrincipleRecognition(
+      id: dco_decode_String(arr[0]),
+      title: dco_decode_String(arr[1]),
+      count: dco_decode_u_32(arr[2]),
+    );
+  }
+
+  @protected
+  PublishConfig dco_decode_publish_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
     return PublishConfig(
       manifesto: dco_decode_opt_String(arr[0]),
       vision: dco_decode_opt_String(arr[1]),
       okrs: dco_decode_opt_String(arr[2]),
+      interactions: dco_decode_opt_String(arr[3]),
+      timezone: dco_decode_opt_String(arr[4]),
+    );
+  }
+
+  @protected
+  PulseCadences dco_decode_pulse_cadences(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return PulseCadences(
+      checkin: dco_decode_opt_String(arr[0]),
+      okrUpdate: dco_decode_opt_String(arr[1]),
+      retro: dco_decode_opt_String(arr[2]),
+    );
+  }
+
+  @protected
+  PulseConfig dco_decode_pulse_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return PulseConfig(
+      team: dco_decode_pulse_cadences(arr[0]),
+      members: dco_decode_list_member_pulse(arr[1]),
+    );
+  }
+
+  @protected
+  ShellHook dco_decode_shell_hook(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ShellHook(
+      on: dco_decode_list_String(arr[0]),
+      run: dco_decode_String(arr[1]),
     );
   }
 
@@ -1229,14 +1980,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   Team dco_decode_team(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 5)
-      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
     return Team(
       name: dco_decode_String(arr[0]),
-      manifesto: dco_decode_opt_String(arr[1]),
+      manifesto: dco_decode_opt_box_autoadd_manifesto(arr[1]),
       vision: dco_decode_opt_String(arr[2]),
       leaders: dco_decode_list_String(arr[3]),
       members: dco_decode_list_String(arr[4]),
+      archived: dco_decode_list_String(arr[5]),
     );
   }
 
@@ -1244,16 +1996,56 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   TeamConfig dco_decode_team_config(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 4)
-      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
     return TeamConfig(
       publish: dco_decode_opt_box_autoadd_publish_config(arr[0]),
       webhooks: dco_decode_opt_box_autoadd_webhook_config(arr[1]),
       linting: dco_decode_opt_box_autoadd_linting_config(arr[2]),
       backup: dco_decode_opt_box_autoadd_backup_config(arr[3]),
+      pulse: dco_decode_opt_box_autoadd_pulse_config(arr[4]),
+      hooks: dco_decode_list_shell_hook(arr[5]),
+    );
+  }
+
+  @protected
+  TeamEvent dco_decode_team_event(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return TeamEvent(
+      id: dco_decode_String(arr[0]),
+      name: dco_decode_String(arr[1]),
+      text: dco_decode_String(arr[2]),
+      fields: dco_decode_list_team_event_field(arr[3]),
     );
   }
 
+  @protected
+  TeamEventField dco_decode_team_event_field(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return TeamEventField(
+      key: dco_decode_String(arr[0]),
+      value: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  int dco_decode_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  BigInt dco_decode_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeU64(raw);
+  }
+
   @protected
   int dco_decode_u_8(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1270,12 +2062,37 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   WebhookConfig dco_decode_webhook_config(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
     return WebhookConfig(
       discord: dco_decode_opt_String(arr[0]),
       slack: dco_decode_opt_String(arr[1]),
       signal: dco_decode_opt_String(arr[2]),
+      custom: dco_decode_list_custom_webhook(arr[3]),
+    );
+  }
+
+  @protected
+  WebhookHeader dco_decode_webhook_header(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return WebhookHeader(
+      name: dco_decode_String(arr[0]),
+      value: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  WeekActivity dco_decode_week_activity(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return WeekActivity(
+      weekStart: dco_decode_String(arr[0]),
+      count: dco_decode_u_32(arr[1]),
     );
   }
 
@@ -1286,6 +2103,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return utf8.decoder.convert(inner);
   }
 
+  @protected
+  AppreciationEdge sse_decode_appreciation_edge(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_from = sse_decode_String(deserializer);
+    final var_to = sse_decode_String(deserializer);
+    final var_count = sse_decode_u_32(deserializer);
+    return AppreciationEdge(from: var_from, to: var_to, count: var_count);
+  }
+
   @protected
   BackupConfig sse_decode_backup_config(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -1312,6 +2138,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_credentials(deserializer));
   }
 
+  @protected
+  double sse_decode_box_autoadd_f_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_f_32(deserializer));
+  }
+
   @protected
   Interaction sse_decode_box_autoadd_interaction(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -1325,6 +2157,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_linting_config(deserializer));
   }
 
+  @protected
+  Manifesto sse_decode_box_autoadd_manifesto(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_manifesto(deserializer));
+  }
+
   @protected
   Member sse_decode_box_autoadd_member(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -1351,6 +2189,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_publish_config(deserializer));
   }
 
+  @protected
+  PulseConfig sse_decode_box_autoadd_pulse_config(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_pulse_config(deserializer));
+  }
+
+  @protected
+  ShellHook sse_decode_box_autoadd_shell_hook(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_shell_hook(deserializer));
+  }
+
   @protected
   Team sse_decode_box_autoadd_team(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -1378,6 +2229,38 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return Credentials(salt: var_salt, pincodeHash: var_pincodeHash);
   }
 
+  @protected
+  CustomWebhook sse_decode_custom_webhook(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_name = sse_decode_opt_String(deserializer);
+    final var_url = sse_decode_String(deserializer);
+    final var_template = sse_decode_opt_String(deserializer);
+    final var_contentType = sse_decode_opt_String(deserializer);
+    final var_headers = sse_decode_list_webhook_header(deserializer);
+    final var_secret = sse_decode_opt_String(deserializer);
+    final var_signatureHeader = sse_decode_opt_String(deserializer);
+    final var_events = sse_decode_list_String(deserializer);
+    return CustomWebhook(
+        name: var_name,
+        url: var_url,
+        template: var_template,
+        contentType: var_contentType,
+        headers: var_headers,
+        secret: var_secret,
+        signatureHeader: var_signatureHeader,
+        events: var_events);
+  }
+
+  @protected
+  DuePulsePrompt sse_decode_due_pulse_prompt(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_prompt = sse_decode_String(deserializer);
+    final var_message = sse_decode_String(deserializer);
+    final var_objectiveId = sse_decode_opt_String(deserializer);
+    return DuePulsePrompt(
+        prompt: var_prompt, message: var_message, objectiveId: var_objectiveId);
+  }
+
   @protected
   double sse_decode_f_32(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -1400,6 +2283,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     final var_note = sse_decode_String(deserializer);
     final var_timestamp = sse_decode_String(deserializer);
     final var_shared = sse_decode_bool(deserializer);
+    final var_principles = sse_decode_list_String(deserializer);
+    final var_importedFrom = sse_decode_opt_String(deserializer);
     return Interaction(
         id: var_id,
         kind: var_kind,
@@ -1407,7 +2292,9 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         withMembers: var_withMembers,
         note: var_note,
         timestamp: var_timestamp,
-        shared: var_shared);
+        shared: var_shared,
+        principles: var_principles,
+        importedFrom: var_importedFrom);
   }
 
   @protected
@@ -1447,6 +2334,45 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ans_;
   }
 
+  @protected
+  List<AppreciationEdge> sse_decode_list_appreciation_edge(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    final len_ = sse_decode_i_32(deserializer);
+    final ans_ = <AppreciationEdge>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_appreciation_edge(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<CustomWebhook> sse_decode_list_custom_webhook(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    final len_ = sse_decode_i_32(deserializer);
+    final ans_ = <CustomWebhook>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_custom_webhook(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<DuePulsePrompt> sse_decode_list_due_pulse_prompt(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    final len_ = sse_decode_i_32(deserializer);
+    final ans_ = <DuePulsePrompt>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_due_pulse_prompt(deserializer));
+    }
+    return ans_;
+  }
+
   @protected
   List<KeyResult> sse_decode_list_key_result(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -1459,6 +2385,31 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ans_;
   }
 
+  @protected
+  List<MemberInteractionStats> sse_decode_list_member_interaction_stats(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    final len_ = sse_decode_i_32(deserializer);
+    final ans_ = <MemberInteractionStats>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_member_interaction_stats(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<MemberPulse> sse_decode_list_member_pulse(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    final len_ = sse_decode_i_32(deserializer);
+    final ans_ = <MemberPulse>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_member_pulse(deserializer));
+    }
+    return ans_;
+  }
+
   @protected
   Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -1466,6 +2417,102 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return deserializer.buffer.getUint8List(len_);
   }
 
+  @protected
+  List<Principle> sse_decode_list_principle(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    final len_ = sse_decode_i_32(deserializer);
+    final ans_ = <Principle>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_principle(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<PrincipleRecognition> sse_decode_list_principle_recognition(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    final len_ = sse_decode_i_32(deserializer);
+    final ans_ = <PrincipleRecognition>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_principle_recognition(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ShellHook> sse_decode_list_shell_hook(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    final len_ = sse_decode_i_32(deserializer);
+    final ans_ = <ShellHook>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_shell_hook(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<TeamEvent> sse_decode_list_team_event(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    final len_ = sse_decode_i_32(deserializer);
+    final ans_ = <TeamEvent>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_team_event(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<TeamEventField> sse_decode_list_team_event_field(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    final len_ = sse_decode_i_32(deserializer);
+    final ans_ = <TeamEventField>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_team_event_field(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<WebhookHeader> sse_decode_list_webhook_header(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    final len_ = sse_decode_i_32(deserializer);
+    final ans_ = <WebhookHeader>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_webhook_header(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<WeekActivity> sse_decode_list_week_activity(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    final len_ = sse_decode_i_32(deserializer);
+    final ans_ = <WeekActivity>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_week_activity(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  Manifesto sse_decode_manifesto(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_preamble = sse_decode_opt_String(deserializer);
+    final var_principles = sse_decode_list_principle(deserializer);
+    return Manifesto(preamble: var_preamble, principles: var_principles);
+  }
+
   @protected
   Member sse_decode_member(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -1473,8 +2520,13 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     final var_name = sse_decode_opt_String(deserializer);
     final var_bio = sse_decode_opt_String(deserializer);
     final var_timezone = sse_decode_opt_String(deserializer);
+    final var_aliases = sse_decode_list_String(deserializer);
     return Member(
-        email: var_email, name: var_name, bio: var_bio, timezone: var_timezone);
+        email: var_email,
+        name: var_name,
+        bio: var_bio,
+        timezone: var_timezone,
+        aliases: var_aliases);
   }
 
   @protected
@@ -1486,6 +2538,35 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return MemberCredentials(email: var_email, credentials: var_credentials);
   }
 
+  @protected
+  MemberInteractionStats sse_decode_member_interaction_stats(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_email = sse_decode_String(deserializer);
+    final var_kudosGiven = sse_decode_u_32(deserializer);
+    final var_kudosReceived = sse_decode_u_32(deserializer);
+    final var_feedbackGiven = sse_decode_u_32(deserializer);
+    final var_feedbackReceived = sse_decode_u_32(deserializer);
+    final var_totalSent = sse_decode_u_32(deserializer);
+    final var_totalReceived = sse_decode_u_32(deserializer);
+    return MemberInteractionStats(
+        email: var_email,
+        kudosGiven: var_kudosGiven,
+        kudosReceived: var_kudosReceived,
+        feedbackGiven: var_feedbackGiven,
+        feedbackReceived: var_feedbackReceived,
+        totalSent: var_totalSent,
+        totalReceived: var_totalReceived);
+  }
+
+  @protected
+  MemberPulse sse_decode_member_pulse(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_email = sse_decode_String(deserializer);
+    final var_cadences = sse_decode_pulse_cadences(deserializer);
+    return MemberPulse(email: var_email, cadences: var_cadences);
+  }
+
   @protected
   Objective sse_decode_objective(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -1496,6 +2577,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     final var_visibility = sse_decode_okr_visibility(deserializer);
     final var_owner = sse_decode_opt_String(deserializer);
     final var_quarter = sse_decode_opt_String(deserializer);
+    final var_principles = sse_decode_list_String(deserializer);
+    final var_updated = sse_decode_opt_String(deserializer);
     return Objective(
         id: var_id,
         title: var_title,
@@ -1503,7 +2586,9 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         keyResults: var_keyResults,
         visibility: var_visibility,
         owner: var_owner,
-        quarter: var_quarter);
+        quarter: var_quarter,
+        principles: var_principles,
+        updated: var_updated);
   }
 
   @protected
@@ -1536,6 +2621,17 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  double? sse_decode_opt_box_autoadd_f_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_f_32(deserializer));
+    } else {
+      return null;
+    }
+  }
+
   @protected
   LintingConfig? sse_decode_opt_box_autoadd_linting_config(
       SseDeserializer deserializer) {
@@ -1548,6 +2644,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  Manifesto? sse_decode_opt_box_autoadd_manifesto(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_manifesto(deserializer));
+    } else {
+      return null;
+    }
+  }
+
   @protected
   PublishConfig? sse_decode_opt_box_autoadd_publish_config(
       SseDeserializer deserializer) {
@@ -1560,42 +2668,120 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  PulseConfig? sse_decode_opt_box_autoadd_pulse_config(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_pulse_config(deserializer));
+    } else {
+      return null;
+    }
+  }
+
   @protected
   WebhookConfig? sse_decode_opt_box_autoadd_webhook_config(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_webhook_config(deserializer));
-    } else {
-      return null;
-    }
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_webhook_config(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  PincodeCheck sse_decode_pincode_check(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_verified = sse_decode_bool(deserializer);
+    final var_email = sse_decode_String(deserializer);
+    final var_retryAfterSecs = sse_decode_u_64(deserializer);
+    return PincodeCheck(
+        verified: var_verified,
+        email: var_email,
+        retryAfterSecs: var_retryAfterSecs);
+  }
+
+  @protected
+  Principle sse_decode_principle(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_id = sse_decode_String(deserializer);
+    final var_title = sse_decode_String(deserializer);
+    final var_description = sse_decode_opt_String(deserializer);
+    return Principle(
+        id: var_id, title: var_title, description: var_description);
+  }
+
+  @protected
+  PrincipleRecognition sse_decode_principle_recognition(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_id = sse_decode_String(deserializer);
+    final var_title = sse_decode_String(deserializer);
+    final var_count = sse_decode_u_32(deserializer);
+    return PrincipleRecognition(id: var_id, title: var_title, count: var_count);
+  }
+
+  @protected
+  PublishConfig sse_decode_publish_config(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_manifesto = sse_decode_opt_String(deserializer);
+    final var_vision = sse_decode_opt_String(deserializer);
+    final var_okrs = sse_decode_opt_String(deserializer);
+    final var_interactions = sse_decode_opt_String(deserializer);
+    final var_timezone = sse_decode_opt_String(deserializer);
+    return PublishConfig(
+        manifesto: var_manifesto,
+        vision: var_vision,
+        okrs: var_okrs,
+        interactions: var_interactions,
+        timezone: var_timezone);
+  }
+
+  @protected
+  PulseCadences sse_decode_pulse_cadences(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_checkin = sse_decode_opt_String(deserializer);
+    final var_okrUpdate = sse_decode_opt_String(deserializer);
+    final var_retro = sse_decode_opt_String(deserializer);
+    return PulseCadences(
+        checkin: var_checkin, okrUpdate: var_okrUpdate, retro: var_retro);
+  }
+
+  @protected
+  PulseConfig sse_decode_pulse_config(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_team = sse_decode_pulse_cadences(deserializer);
+    final var_members = sse_decode_list_member_pulse(deserializer);
+    return PulseConfig(team: var_team, members: var_members);
   }
 
   @protected
-  PublishConfig sse_decode_publish_config(SseDeserializer deserializer) {
+  ShellHook sse_decode_shell_hook(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    final var_manifesto = sse_decode_opt_String(deserializer);
-    final var_vision = sse_decode_opt_String(deserializer);
-    final var_okrs = sse_decode_opt_String(deserializer);
-    return PublishConfig(
-        manifesto: var_manifesto, vision: var_vision, okrs: var_okrs);
+    final var_on = sse_decode_list_String(deserializer);
+    final var_run = sse_decode_String(deserializer);
+    return ShellHook(on: var_on, run: var_run);
   }
 
   @protected
   Team sse_decode_team(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     final var_name = sse_decode_String(deserializer);
-    final var_manifesto = sse_decode_opt_String(deserializer);
+    final var_manifesto = sse_decode_opt_box_autoadd_manifesto(deserializer);
     final var_vision = sse_decode_opt_String(deserializer);
     final var_leaders = sse_decode_list_String(deserializer);
     final var_members = sse_decode_list_String(deserializer);
+    final var_archived = sse_decode_list_String(deserializer);
     return Team(
         name: var_name,
         manifesto: var_manifesto,
         vision: var_vision,
         leaders: var_leaders,
-        members: var_members);
+        members: var_members,
+        archived: var_archived);
   }
 
   @protected
@@ -1606,11 +2792,46 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_decode_opt_box_autoadd_webhook_config(deserializer);
     final var_linting = sse_decode_opt_box_autoadd_linting_config(deserializer);
     final var_backup = sse_decode_opt_box_autoadd_backup_config(deserializer);
+    final var_pulse = sse_decode_opt_box_autoadd_pulse_config(deserializer);
+    final var_hooks = sse_decode_list_shell_hook(deserializer);
     return TeamConfig(
         publish: var_publish,
         webhooks: var_webhooks,
         linting: var_linting,
-        backup: var_backup);
+        backup: var_backup,
+        pulse: var_pulse,
+        hooks: var_hooks);
+  }
+
+  @protected
+  TeamEvent sse_decode_team_event(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_id = sse_decode_String(deserializer);
+    final var_name = sse_decode_String(deserializer);
+    final var_text = sse_decode_String(deserializer);
+    final var_fields = sse_decode_list_team_event_field(deserializer);
+    return TeamEvent(
+        id: var_id, name: var_name, text: var_text, fields: var_fields);
+  }
+
+  @protected
+  TeamEventField sse_decode_team_event_field(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_key = sse_decode_String(deserializer);
+    final var_value = sse_decode_String(deserializer);
+    return TeamEventField(key: var_key, value: var_value);
+  }
+
+  @protected
+  int sse_decode_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint32();
+  }
+
+  @protected
+  BigInt sse_decode_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getBigUint64();
   }
 
   @protected
@@ -1630,8 +2851,28 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     final var_discord = sse_decode_opt_String(deserializer);
     final var_slack = sse_decode_opt_String(deserializer);
     final var_signal = sse_decode_opt_String(deserializer);
+    final var_custom = sse_decode_list_custom_webhook(deserializer);
     return WebhookConfig(
-        discord: var_discord, slack: var_slack, signal: var_signal);
+        discord: var_discord,
+        slack: var_slack,
+        signal: var_signal,
+        custom: var_custom);
+  }
+
+  @protected
+  WebhookHeader sse_decode_webhook_header(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_name = sse_decode_String(deserializer);
+    final var_value = sse_decode_String(deserializer);
+    return WebhookHeader(name: var_name, value: var_value);
+  }
+
+  @protected
+  WeekActivity sse_decode_week_activity(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_weekStart = sse_decode_String(deserializer);
+    final var_count = sse_decode_u_32(deserializer);
+    return WeekActivity(weekStart: var_weekStart, count: var_count);
   }
 
   @protected
@@ -1664,6 +2905,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return cst_encode_i_32(raw.index);
   }
 
+  @protected
+  int cst_encode_u_32(int raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw;
+  }
+
   @protected
   int cst_encode_u_8(int raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
@@ -1682,6 +2929,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
   }
 
+  @protected
+  void sse_encode_appreciation_edge(
+      AppreciationEdge self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.from, serializer);
+    sse_encode_String(self.to, serializer);
+    sse_encode_u_32(self.count, serializer);
+  }
+
   @protected
   void sse_encode_backup_config(BackupConfig self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -1708,6 +2964,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_credentials(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_f_32(double self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_f_32(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_interaction(
       Interaction self, SseSerializer serializer) {
@@ -1722,6 +2984,13 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_linting_config(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_manifesto(
+      Manifesto self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_manifesto(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_member(Member self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -1749,6 +3018,20 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_publish_config(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_pulse_config(
+      PulseConfig self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_pulse_config(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_shell_hook(
+      ShellHook self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_shell_hook(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_team(Team self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -1776,6 +3059,28 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_String(self.pincodeHash, serializer);
   }
 
+  @protected
+  void sse_encode_custom_webhook(CustomWebhook self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_String(self.name, serializer);
+    sse_encode_String(self.url, serializer);
+    sse_encode_opt_String(self.template, serializer);
+    sse_encode_opt_String(self.contentType, serializer);
+    sse_encode_list_webhook_header(self.headers, serializer);
+    sse_encode_opt_String(self.secret, serializer);
+    sse_encode_opt_String(self.signatureHeader, serializer);
+    sse_encode_list_String(self.events, serializer);
+  }
+
+  @protected
+  void sse_encode_due_pulse_prompt(
+      DuePulsePrompt self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.prompt, serializer);
+    sse_encode_String(self.message, serializer);
+    sse_encode_opt_String(self.objectiveId, serializer);
+  }
+
   @protected
   void sse_encode_f_32(double self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -1798,6 +3103,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_String(self.note, serializer);
     sse_encode_String(self.timestamp, serializer);
     sse_encode_bool(self.shared, serializer);
+    sse_encode_list_String(self.principles, serializer);
+    sse_encode_opt_String(self.importedFrom, serializer);
   }
 
   @protected
@@ -1831,6 +3138,36 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_list_appreciation_edge(
+      List<AppreciationEdge> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_appreciation_edge(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_custom_webhook(
+      List<CustomWebhook> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_custom_webhook(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_due_pulse_prompt(
+      List<DuePulsePrompt> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_due_pulse_prompt(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_list_key_result(
       List<KeyResult> self, SseSerializer serializer) {
@@ -1841,6 +3178,26 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_list_member_interaction_stats(
+      List<MemberInteractionStats> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_member_interaction_stats(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_member_pulse(
+      List<MemberPulse> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_member_pulse(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_list_prim_u_8_strict(
       Uint8List self, SseSerializer serializer) {
@@ -1849,6 +3206,83 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     serializer.buffer.putUint8List(self);
   }
 
+  @protected
+  void sse_encode_list_principle(
+      List<Principle> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_principle(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_principle_recognition(
+      List<PrincipleRecognition> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_principle_recognition(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_shell_hook(
+      List<ShellHook> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_shell_hook(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_team_event(
+      List<TeamEvent> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_team_event(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_team_event_field(
+      List<TeamEventField> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_team_event_field(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_webhook_header(
+      List<WebhookHeader> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_webhook_header(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_week_activity(
+      List<WeekActivity> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_week_activity(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_manifesto(Manifesto self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_String(self.preamble, serializer);
+    sse_encode_list_principle(self.principles, serializer);
+  }
+
   @protected
   void sse_encode_member(Member self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -1856,6 +3290,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_opt_String(self.name, serializer);
     sse_encode_opt_String(self.bio, serializer);
     sse_encode_opt_String(self.timezone, serializer);
+    sse_encode_list_String(self.aliases, serializer);
   }
 
   @protected
@@ -1866,6 +3301,26 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_credentials(self.credentials, serializer);
   }
 
+  @protected
+  void sse_encode_member_interaction_stats(
+      MemberInteractionStats self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.email, serializer);
+    sse_encode_u_32(self.kudosGiven, serializer);
+    sse_encode_u_32(self.kudosReceived, serializer);
+    sse_encode_u_32(self.feedbackGiven, serializer);
+    sse_encode_u_32(self.feedbackReceived, serializer);
+    sse_encode_u_32(self.totalSent, serializer);
+    sse_encode_u_32(self.totalReceived, serializer);
+  }
+
+  @protected
+  void sse_encode_member_pulse(MemberPulse self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.email, serializer);
+    sse_encode_pulse_cadences(self.cadences, serializer);
+  }
+
   @protected
   void sse_encode_objective(Objective self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -1876,6 +3331,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_okr_visibility(self.visibility, serializer);
     sse_encode_opt_String(self.owner, serializer);
     sse_encode_opt_String(self.quarter, serializer);
+    sse_encode_list_String(self.principles, serializer);
+    sse_encode_opt_String(self.updated, serializer);
   }
 
   @protected
@@ -1905,6 +3362,16 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_opt_box_autoadd_f_32(double? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_f_32(self, serializer);
+    }
+  }
+
   @protected
   void sse_encode_opt_box_autoadd_linting_config(
       LintingConfig? self, SseSerializer serializer) {
@@ -1916,6 +3383,17 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_opt_box_autoadd_manifesto(
+      Manifesto? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_manifesto(self, serializer);
+    }
+  }
+
   @protected
   void sse_encode_opt_box_autoadd_publish_config(
       PublishConfig? self, SseSerializer serializer) {
@@ -1927,6 +3405,17 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_opt_box_autoadd_pulse_config(
+      PulseConfig? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_pulse_config(self, serializer);
+    }
+  }
+
   @protected
   void sse_encode_opt_box_autoadd_webhook_config(
       WebhookConfig? self, SseSerializer serializer) {
@@ -1938,22 +3427,72 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_pincode_check(PincodeCheck self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.verified, serializer);
+    sse_encode_String(self.email, serializer);
+    sse_encode_u_64(self.retryAfterSecs, serializer);
+  }
+
+  @protected
+  void sse_encode_principle(Principle self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.id, serializer);
+    sse_encode_String(self.title, serializer);
+    sse_encode_opt_String(self.description, serializer);
+  }
+
+  @protected
+  void sse_encode_principle_recognition(
+      PrincipleRecognition self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.id, serializer);
+    sse_encode_String(self.title, serializer);
+    sse_encode_u_32(self.count, serializer);
+  }
+
   @protected
   void sse_encode_publish_config(PublishConfig self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_opt_String(self.manifesto, serializer);
     sse_encode_opt_String(self.vision, serializer);
     sse_encode_opt_String(self.okrs, serializer);
+    sse_encode_opt_String(self.interactions, serializer);
+    sse_encode_opt_String(self.timezone, serializer);
+  }
+
+  @protected
+  void sse_encode_pulse_cadences(PulseCadences self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_String(self.checkin, serializer);
+    sse_encode_opt_String(self.okrUpdate, serializer);
+    sse_encode_opt_String(self.retro, serializer);
+  }
+
+  @protected
+  void sse_encode_pulse_config(PulseConfig self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_pulse_cadences(self.team, serializer);
+    sse_encode_list_member_pulse(self.members, serializer);
+  }
+
+  @protected
+  void sse_encode_shell_hook(ShellHook self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_String(self.on, serializer);
+    sse_encode_String(self.run, serializer);
   }
 
   @protected
   void sse_encode_team(Team self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_String(self.name, serializer);
-    sse_encode_opt_String(self.manifesto, serializer);
+    sse_encode_opt_box_autoadd_manifesto(self.manifesto, serializer);
     sse_encode_opt_String(self.vision, serializer);
     sse_encode_list_String(self.leaders, serializer);
     sse_encode_list_String(self.members, serializer);
+    sse_encode_list_String(self.archived, serializer);
   }
 
   @protected
@@ -1963,6 +3502,37 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_opt_box_autoadd_webhook_config(self.webhooks, serializer);
     sse_encode_opt_box_autoadd_linting_config(self.linting, serializer);
     sse_encode_opt_box_autoadd_backup_config(self.backup, serializer);
+    sse_encode_opt_box_autoadd_pulse_config(self.pulse, serializer);
+    sse_encode_list_shell_hook(self.hooks, serializer);
+  }
+
+  @protected
+  void sse_encode_team_event(TeamEvent self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.id, serializer);
+    sse_encode_String(self.name, serializer);
+    sse_encode_String(self.text, serializer);
+    sse_encode_list_team_event_field(self.fields, serializer);
+  }
+
+  @protected
+  void sse_encode_team_event_field(
+      TeamEventField self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.key, serializer);
+    sse_encode_String(self.value, serializer);
+  }
+
+  @protected
+  void sse_encode_u_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint32(self);
+  }
+
+  @protected
+  void sse_encode_u_64(BigInt self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putBigUint64(self);
   }
 
   @protected
@@ -1982,5 +3552,20 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_opt_String(self.discord, serializer);
     sse_encode_opt_String(self.slack, serializer);
     sse_encode_opt_String(self.signal, serializer);
+    sse_encode_list_custom_webhook(self.custom, serializer);
+  }
+
+  @protected
+  void sse_encode_webhook_header(WebhookHeader self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.name, serializer);
+    sse_encode_String(self.value, serializer);
+  }
+
+  @protected
+  void sse_encode_week_activity(WeekActivity self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.weekStart, serializer);
+    sse_encode_u_32(self.count, serializer);
   }
 }
@@ -21,6 +21,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   String dco_decode_String(dynamic raw);
 
+  @protected
+  AppreciationEdge dco_decode_appreciation_edge(dynamic raw);
+
   @protected
   BackupConfig dco_decode_backup_config(dynamic raw);
 
@@ -33,12 +36,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   Credentials dco_decode_box_autoadd_credentials(dynamic raw);
 
+  @protected
+  double dco_decode_box_autoadd_f_32(dynamic raw);
+
   @protected
   Interaction dco_decode_box_autoadd_interaction(dynamic raw);
 
   @protected
   LintingConfig dco_decode_box_autoadd_linting_config(dynamic raw);
 
+  @protected
+  Manifesto dco_decode_box_autoadd_manifesto(dynamic raw);
+
   @protected
   Member dco_decode_box_autoadd_member(dynamic raw);
 
@@ -51,6 +60,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   PublishConfig dco_decode_box_autoadd_publish_config(dynamic raw);
 
+  @protected
+  PulseConfig dco_decode_box_autoadd_pulse_config(dynamic raw);
+
+  @protected
+  ShellHook dco_decode_box_autoadd_shell_hook(dynamic raw);
+
   @protected
   Team dco_decode_box_autoadd_team(dynamic raw);
 
@@ -63,6 +78,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   Credentials dco_decode_credentials(dynamic raw);
 
+  @protected
+  CustomWebhook dco_decode_custom_webhook(dynamic raw);
+
+  @protected
+  DuePulsePrompt dco_decode_due_pulse_prompt(dynamic raw);
+
   @protected
   double dco_decode_f_32(dynamic raw);
 
@@ -84,18 +105,64 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   List<String> dco_decode_list_String(dynamic raw);
 
+  @protected
+  List<AppreciationEdge> dco_decode_list_appreciation_edge(dynamic raw);
+
+  @protected
+  List<CustomWebhook> dco_decode_list_custom_webhook(dynamic raw);
+
+  @protected
+  List<DuePulsePrompt> dco_decode_list_due_pulse_prompt(dynamic raw);
+
   @protected
   List<KeyResult> dco_decode_list_key_result(dynamic raw);
 
+  @protected
+  List<MemberInteractionStats> dco_decode_list_member_interaction_stats(
+      dynamic raw);
+
+  @protected
+  List<MemberPulse> dco_decode_list_member_pulse(dynamic raw);
+
   @protected
   Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
 
+  @protected
+  List<Principle> dco_decode_list_principle(dynamic raw);
+
+  @protected
+  List<PrincipleRecognition> dco_decode_list_principle_recognition(dynamic raw);
+
+  @protected
+  List<ShellHook> dco_decode_list_shell_hook(dynamic raw);
+
+  @protected
+  List<TeamEvent> dco_decode_list_team_event(dynamic raw);
+
+  @protected
+  List<TeamEventField> dco_decode_list_team_event_field(dynamic raw);
+
+  @protected
+  List<WebhookHeader> dco_decode_list_webhook_header(dynamic raw);
+
+  @protected
+  List<WeekActivity> dco_decode_list_week_activity(dynamic raw);
+
+  @protected
+  Manifesto dco_decode_manifesto(dynamic raw);
+
   @protected
   Member dco_decode_member(dynamic raw);
 
   @protected
   MemberCredentials dco_decode_member_credentials(dynamic raw);
 
+  @protected
+  MemberInteractionStats dco_decode_member_interaction_stats(dynamic raw);
+
+  @protected
+  MemberPulse dco_decode_member_pulse(dynamic raw);
+
   @protected
   Objective dco_decode_objective(dynamic raw);
 
@@ -108,24 +175,63 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   BackupConfig? dco_decode_opt_box_autoadd_backup_config(dynamic raw);
 
+  @protected
+  double? dco_decode_opt_box_autoadd_f_32(dynamic raw);
+
   @protected
   LintingConfig? dco_decode_opt_box_autoadd_linting_config(dynamic raw);
 
+  @protected
+  Manifesto? dco_decode_opt_box_autoadd_manifesto(dynamic raw);
+
   @protected
   PublishConfig? dco_decode_opt_box_autoadd_publish_config(dynamic raw);
 
+  @protected
+  PulseConfig? dco_decode_opt_box_autoadd_pulse_config(dynamic raw);
+
   @protected
   WebhookConfig? dco_decode_opt_box_autoadd_webhook_config(dynamic raw);
 
+  @protected
+  PincodeCheck dco_decode_pincode_check(dynamic raw);
+
+  @protected
+  Principle dco_decode_principle(dynamic raw);
+
+  @protected
+  PrincipleRecognition dco_decode_principle_recognition(dynamic raw);
+
   @protected
   PublishConfig dco_decode_publish_config(dynamic raw);
 
+  @protected
+  PulseCadences dco_decode_pulse_cadences(dynamic raw);
+
+  @protected
+  PulseConfig dco_decode_pulse_config(dynamic raw);
+
+  @protected
+  ShellHook dco_decode_shell_hook(dynamic raw);
+
   @protected
   Team dco_decode_team(dynamic raw);
 
   @protected
   TeamConfig dco_decode_team_config(dynamic raw);
 
+  @protected
+  TeamEvent dco_decode_team_event(dynamic raw);
+
+  @protected
+  TeamEventField dco_decode_team_event_field(dynamic raw);
+
+  @protected
+  int dco_decode_u_32(dynamic raw);
+
+  @protected
+  BigInt dco_decode_u_64(dynamic raw);
+
   @protected
   int dco_decode_u_8(dynamic raw);
 
@@ -135,9 +241,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   WebhookConfig dco_decode_webhook_config(dynamic raw);
 
+  @protected
+  WebhookHeader dco_decode_webhook_header(dynamic raw);
+
+  @protected
+  WeekActivity dco_decode_week_activity(dynamic raw);
+
   @protected
   String sse_decode_String(SseDeserializer deserializer);
 
+  @protected
+  AppreciationEdge sse_decode_appreciation_edge(SseDeserializer deserializer);
+
   @protected
   BackupConfig sse_decode_backup_config(SseDeserializer deserializer);
 
@@ -151,6 +266,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   Credentials sse_decode_box_autoadd_credentials(SseDeserializer deserializer);
 
+  @protected
+  double sse_decode_box_autoadd_f_32(SseDeserializer deserializer);
+
   @protected
   Interaction sse_decode_box_autoadd_interaction(SseDeserializer deserializer);
 
@@ -158,6 +276,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   LintingConfig sse_decode_box_autoadd_linting_config(
       SseDeserializer deserializer);
 
+  @protected
+  Manifesto sse_decode_box_autoadd_manifesto(SseDeserializer deserializer);
+
   @protected
   Member sse_decode_box_autoadd_member(SseDeserializer deserializer);
 
@@ -172,6 +293,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   PublishConfig sse_decode_box_autoadd_publish_config(
       SseDeserializer deserializer);
 
+  @protected
+  PulseConfig sse_decode_box_autoadd_pulse_config(SseDeserializer deserializer);
+
+  @protected
+  ShellHook sse_decode_box_autoadd_shell_hook(SseDeserializer deserializer);
+
   @protected
   Team sse_decode_box_autoadd_team(SseDeserializer deserializer);
 
@@ -185,6 +312,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   Credentials sse_decode_credentials(SseDeserializer deserializer);
 
+  @protected
+  CustomWebhook sse_decode_custom_webhook(SseDeserializer deserializer);
+
+  @protected
+  DuePulsePrompt sse_decode_due_pulse_prompt(SseDeserializer deserializer);
+
   @protected
   double sse_decode_f_32(SseDeserializer deserializer);
 
@@ -206,18 +339,72 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   List<String> sse_decode_list_String(SseDeserializer deserializer);
 
+  @protected
+  List<AppreciationEdge> sse_decode_list_appreciation_edge(
+      SseDeserializer deserializer);
+
+  @protected
+  List<CustomWebhook> sse_decode_list_custom_webhook(
+      SseDeserializer deserializer);
+
+  @protected
+  List<DuePulsePrompt> sse_decode_list_due_pulse_prompt(
+      SseDeserializer deserializer);
+
   @protected
   List<KeyResult> sse_decode_list_key_result(SseDeserializer deserializer);
 
+  @protected
+  List<MemberInteractionStats> sse_decode_list_member_interaction_stats(
+      SseDeserializer deserializer);
+
+  @protected
+  List<MemberPulse> sse_decode_list_member_pulse(SseDeserializer deserializer);
+
   @protected
   Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
 
+  @protected
+  List<Principle> sse_decode_list_principle(SseDeserializer deserializer);
+
+  @protected
+  List<PrincipleRecognition> sse_decode_list_principle_recognition(
+      SseDeserializer deserializer);
+
+  @protected
+  List<ShellHook> sse_decode_list_shell_hook(SseDeserializer deserializer);
+
+  @protected
+  List<TeamEvent> sse_decode_list_team_event(SseDeserializer deserializer);
+
+  @protected
+  List<TeamEventField> sse_decode_list_team_event_field(
+      SseDeserializer deserializer);
+
+  @protected
+  List<WebhookHeader> sse_decode_list_webhook_header(
+      SseDeserializer deserializer);
+
+  @protected
+  List<WeekActivity> sse_decode_list_week_activity(
+      SseDeserializer deserializer);
+
+  @protected
+  Manifesto sse_decode_manifesto(SseDeserializer deserializer);
+
   @protected
   Member sse_decode_member(SseDeserializer deserializer);
 
   @protected
   MemberCredentials sse_decode_member_credentials(SseDeserializer deserializer);
 
+  @protected
+  MemberInteractionStats sse_decode_member_interaction_stats(
+      SseDeserializer deserializer);
+
+  @protected
+  MemberPulse sse_decode_member_pulse(SseDeserializer deserializer);
+
   @protected
   Objective sse_decode_objective(SseDeserializer deserializer);
 
@@ -231,27 +418,68 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   BackupConfig? sse_decode_opt_box_autoadd_backup_config(
       SseDeserializer deserializer);
 
+  @protected
+  double? sse_decode_opt_box_autoadd_f_32(SseDeserializer deserializer);
+
   @protected
   LintingConfig? sse_decode_opt_box_autoadd_linting_config(
       SseDeserializer deserializer);
 
+  @protected
+  Manifesto? sse_decode_opt_box_autoadd_manifesto(SseDeserializer deserializer);
+
   @protected
   PublishConfig? sse_decode_opt_box_autoadd_publish_config(
       SseDeserializer deserializer);
 
+  @protected
+  PulseConfig? sse_decode_opt_box_autoadd_pulse_config(
+      SseDeserializer deserializer);
+
   @protected
   WebhookConfig? sse_decode_opt_box_autoadd_webhook_config(
       SseDeserializer deserializer);
 
+  @protected
+  PincodeCheck sse_decode_pincode_check(SseDeserializer deserializer);
+
+  @protected
+  Principle sse_decode_principle(SseDeserializer deserializer);
+
+  @protected
+  PrincipleRecognition sse_decode_principle_recognition(
+      SseDeserializer deserializer);
+
   @protected
   PublishConfig sse_decode_publish_config(SseDeserializer deserializer);
 
+  @protected
+  PulseCadences sse_decode_pulse_cadences(SseDeserializer deserializer);
+
+  @protected
+  PulseConfig sse_decode_pulse_config(SseDeserializer deserializer);
+
+  @protected
+  ShellHook sse_decode_shell_hook(SseDeserializer deserializer);
+
   @protected
   Team sse_decode_team(SseDeserializer deserializer);
 
   @protected
   TeamConfig sse_decode_team_config(SseDeserializer deserializer);
 
+  @protected
+  TeamEvent sse_decode_team_event(SseDeserializer deserializer);
+
+  @protected
+  TeamEventField sse_decode_team_event_field(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_32(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_u_64(SseDeserializer deserializer);
+
   @protected
   int sse_decode_u_8(SseDeserializer deserializer);
 
@@ -261,6 +489,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   WebhookConfig sse_decode_webhook_config(SseDeserializer deserializer);
 
+  @protected
+  WebhookHeader sse_decode_webhook_header(SseDeserializer deserializer);
+
+  @protected
+  WeekActivity sse_decode_week_activity(SseDeserializer deserializer);
+
   @protected
   ffi.Pointer<wire_cst_list_prim_u_8_strict> cst_encode_String(String raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
@@ -285,6 +519,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     return ptr;
   }
 
+  @protected
+  ffi.Pointer<ffi.Float> cst_encode_box_autoadd_f_32(double raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return wire.cst_new_box_autoadd_f_32(cst_encode_f_32(raw));
+  }
+
   @protected
   ffi.Pointer<wire_cst_interaction> cst_encode_box_autoadd_interaction(
       Interaction raw) {
@@ -303,6 +543,15 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     return ptr;
   }
 
+  @protected
+  ffi.Pointer<wire_cst_manifesto> cst_encode_box_autoadd_manifesto(
+      Manifesto raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_manifesto();
+    cst_api_fill_to_wire_manifesto(raw, ptr.ref);
+    return ptr;
+  }
+
   @protected
   ffi.Pointer<wire_cst_member> cst_encode_box_autoadd_member(Member raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
@@ -338,6 +587,24 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     return ptr;
   }
 
+  @protected
+  ffi.Pointer<wire_cst_pulse_config> cst_encode_box_autoadd_pulse_config(
+      PulseConfig raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_pulse_config();
+    cst_api_fill_to_wire_pulse_config(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_shell_hook> cst_encode_box_autoadd_shell_hook(
+      ShellHook raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_shell_hook();
+    cst_api_fill_to_wire_shell_hook(raw, ptr.ref);
+    return ptr;
+  }
+
   @protected
   ffi.Pointer<wire_cst_team> cst_encode_box_autoadd_team(Team raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
@@ -375,90 +642,257 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   }
 
   @protected
-  ffi.Pointer<wire_cst_list_key_result> cst_encode_list_key_result(
-      List<KeyResult> raw) {
+  ffi.Pointer<wire_cst_list_appreciation_edge>
+      cst_encode_list_appreciation_edge(List<AppreciationEdge> raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-    final ans = wire.cst_new_list_key_result(raw.length);
+    final ans = wire.cst_new_list_appreciation_edge(raw.length);
     for (var i = 0; i < raw.length; ++i) {
-      cst_api_fill_to_wire_key_result(raw[i], ans.ref.ptr[i]);
+      cst_api_fill_to_wire_appreciation_edge(raw[i], ans.ref.ptr[i]);
     }
     return ans;
   }
 
   @protected
-  ffi.Pointer<wire_cst_list_prim_u_8_strict> cst_encode_list_prim_u_8_strict(
-      Uint8List raw) {
+  ffi.Pointer<wire_cst_list_custom_webhook> cst_encode_list_custom_webhook(
+      List<CustomWebhook> raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-    final ans = wire.cst_new_list_prim_u_8_strict(raw.length);
-    ans.ref.ptr.asTypedList(raw.length).setAll(0, raw);
+    final ans = wire.cst_new_list_custom_webhook(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      cst_api_fill_to_wire_custom_webhook(raw[i], ans.ref.ptr[i]);
+    }
     return ans;
   }
 
   @protected
-  ffi.Pointer<wire_cst_list_prim_u_8_strict> cst_encode_opt_String(
-      String? raw) {
+  ffi.Pointer<wire_cst_list_due_pulse_prompt> cst_encode_list_due_pulse_prompt(
+      List<DuePulsePrompt> raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw == null ? ffi.nullptr : cst_encode_String(raw);
+    final ans = wire.cst_new_list_due_pulse_prompt(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      cst_api_fill_to_wire_due_pulse_prompt(raw[i], ans.ref.ptr[i]);
+    }
+    return ans;
   }
 
   @protected
-  ffi.Pointer<wire_cst_backup_config> cst_encode_opt_box_autoadd_backup_config(
-      BackupConfig? raw) {
+  ffi.Pointer<wire_cst_list_key_result> cst_encode_list_key_result(
+      List<KeyResult> raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw == null
-        ? ffi.nullptr
-        : cst_encode_box_autoadd_backup_config(raw);
+    final ans = wire.cst_new_list_key_result(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      cst_api_fill_to_wire_key_result(raw[i], ans.ref.ptr[i]);
+    }
+    return ans;
   }
 
   @protected
-  ffi.Pointer<wire_cst_linting_config>
-      cst_encode_opt_box_autoadd_linting_config(LintingConfig? raw) {
+  ffi.Pointer<wire_cst_list_member_interaction_stats>
+      cst_encode_list_member_interaction_stats(
+          List<MemberInteractionStats> raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw == null
-        ? ffi.nullptr
-        : cst_encode_box_autoadd_linting_config(raw);
+    final ans = wire.cst_new_list_member_interaction_stats(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      cst_api_fill_to_wire_member_interaction_stats(raw[i], ans.ref.ptr[i]);
+    }
+    return ans;
   }
 
   @protected
-  ffi.Pointer<wire_cst_publish_config>
-      cst_encode_opt_box_autoadd_publish_config(PublishConfig? raw) {
+  ffi.Pointer<wire_cst_list_member_pulse> cst_encode_list_member_pulse(
+      List<MemberPulse> raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw == null
-        ? ffi.nullptr
-        : cst_encode_box_autoadd_publish_config(raw);
+    final ans = wire.cst_new_list_member_pulse(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      cst_api_fill_to_wire_member_pulse(raw[i], ans.ref.ptr[i]);
+    }
+    return ans;
   }
 
   @protected
-  ffi.Pointer<wire_cst_webhook_config>
-      cst_encode_opt_box_autoadd_webhook_config(WebhookConfig? raw) {
+  ffi.Pointer<wire_cst_list_prim_u_8_strict> cst_encode_list_prim_u_8_strict(
+      Uint8List raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw == null
-        ? ffi.nullptr
-        : cst_encode_box_autoadd_webhook_config(raw);
+    final ans = wire.cst_new_list_prim_u_8_strict(raw.length);
+    ans.ref.ptr.asTypedList(raw.length).setAll(0, raw);
+    return ans;
   }
 
   @protected
-  void cst_api_fill_to_wire_backup_config(
-      BackupConfig apiObj, wire_cst_backup_config wireObj) {
-    wireObj.protected_branch = cst_encode_opt_String(apiObj.protectedBranch);
+  ffi.Pointer<wire_cst_list_principle> cst_encode_list_principle(
+      List<Principle> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_principle(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      cst_api_fill_to_wire_principle(raw[i], ans.ref.ptr[i]);
+    }
+    return ans;
   }
 
   @protected
-  void cst_api_fill_to_wire_box_autoadd_backup_config(
-      BackupConfig apiObj, ffi.Pointer<wire_cst_backup_config> wireObj) {
-    cst_api_fill_to_wire_backup_config(apiObj, wireObj.ref);
+  ffi.Pointer<wire_cst_list_principle_recognition>
+      cst_encode_list_principle_recognition(List<PrincipleRecognition> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_principle_recognition(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      cst_api_fill_to_wire_principle_recognition(raw[i], ans.ref.ptr[i]);
+    }
+    return ans;
   }
 
   @protected
-  void cst_api_fill_to_wire_box_autoadd_credentials(
-      Credentials apiObj, ffi.Pointer<wire_cst_credentials> wireObj) {
-    cst_api_fill_to_wire_credentials(apiObj, wireObj.ref);
+  ffi.Pointer<wire_cst_list_shell_hook> cst_encode_list_shell_hook(
+      List<ShellHook> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_shell_hook(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      cst_api_fill_to_wire_shell_hook(raw[i], ans.ref.ptr[i]);
+    }
+    return ans;
   }
 
   @protected
-  void cst_api_fill_to_wire_box_autoadd_interaction(
-      Interaction apiObj, ffi.Pointer<wire_cst_interaction> wireObj) {
-    cst_api_fill_to_wire_interaction(apiObj, wireObj.ref);
+  ffi.Pointer<wire_cst_list_team_event> cst_encode_list_team_event(
+      List<TeamEvent> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_team_event(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      cst_api_fill_to_wire_team_event(raw[i], ans.ref.ptr[i]);
+    }
+    return ans;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_list_team_event_field> cst_encode_list_team_event_field(
+      List<TeamEventField> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_team_event_field(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      cst_api_fill_to_wire_team_event_field(raw[i], ans.ref.ptr[i]);
+    }
+    return ans;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_list_webhook_header> cst_encode_list_webhook_header(
+      List<WebhookHeader> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_webhook_header(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      cst_api_fill_to_wire_webhook_header(raw[i], ans.ref.ptr[i]);
+    }
+    return ans;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_list_week_activity> cst_encode_list_week_activity(
+      List<WeekActivity> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_week_activity(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      cst_api_fill_to_wire_week_activity(raw[i], ans.ref.ptr[i]);
+    }
+    return ans;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_list_prim_u_8_strict> cst_encode_opt_String(
+      String? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? ffi.nullptr : cst_encode_String(raw);
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_backup_config> cst_encode_opt_box_autoadd_backup_config(
+      BackupConfig? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null
+        ? ffi.nullptr
+        : cst_encode_box_autoadd_backup_config(raw);
+  }
+
+  @protected
+  ffi.Pointer<ffi.Float> cst_encode_opt_box_autoadd_f_32(double? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? ffi.nullptr : cst_encode_box_autoadd_f_32(raw);
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_linting_config>
+      cst_encode_opt_box_autoadd_linting_config(LintingConfig? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null
+        ? ffi.nullptr
+        : cst_encode_box_autoadd_linting_config(raw);
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_manifesto> cst_encode_opt_box_autoadd_manifesto(
+      Manifesto? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? ffi.nullptr : cst_encode_box_autoadd_manifesto(raw);
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_publish_config>
+      cst_encode_opt_box_autoadd_publish_config(PublishConfig? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null
+        ? ffi.nullptr
+        : cst_encode_box_autoadd_publish_config(raw);
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_pulse_config> cst_encode_opt_box_autoadd_pulse_config(
+      PulseConfig? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? ffi.nullptr : cst_encode_box_autoadd_pulse_config(raw);
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_webhook_config>
+      cst_encode_opt_box_autoadd_webhook_config(WebhookConfig? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null
+        ? ffi.nullptr
+        : cst_encode_box_autoadd_webhook_config(raw);
+  }
+
+  @protected
+  int cst_encode_u_64(BigInt raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw.toSigned(64).toInt();
+  }
+
+  @protected
+  void cst_api_fill_to_wire_appreciation_edge(
+      AppreciationEdge apiObj, wire_cst_appreciation_edge wireObj) {
+    wireObj.from = cst_encode_String(apiObj.from);
+    wireObj.to = cst_encode_String(apiObj.to);
+    wireObj.count = cst_encode_u_32(apiObj.count);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_backup_config(
+      BackupConfig apiObj, wire_cst_backup_config wireObj) {
+    wireObj.protected_branch = cst_encode_opt_String(apiObj.protectedBranch);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_backup_config(
+      BackupConfig apiObj, ffi.Pointer<wire_cst_backup_config> wireObj) {
+    cst_api_fill_to_wire_backup_config(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_credentials(
+      Credentials apiObj, ffi.Pointer<wire_cst_credentials> wireObj) {
+    cst_api_fill_to_wire_credentials(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_interaction(
+      Interaction apiObj, ffi.Pointer<wire_cst_interaction> wireObj) {
+    cst_api_fill_to_wire_interaction(apiObj, wireObj.ref);
   }
 
   @protected
@@ -467,6 +901,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     cst_api_fill_to_wire_linting_config(apiObj, wireObj.ref);
   }
 
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_manifesto(
+      Manifesto apiObj, ffi.Pointer<wire_cst_manifesto> wireObj) {
+    cst_api_fill_to_wire_manifesto(apiObj, wireObj.ref);
+  }
+
   @protected
   void cst_api_fill_to_wire_box_autoadd_member(
       Member apiObj, ffi.Pointer<wire_cst_member> wireObj) {
@@ -492,6 +932,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     cst_api_fill_to_wire_publish_config(apiObj, wireObj.ref);
   }
 
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_pulse_config(
+      PulseConfig apiObj, ffi.Pointer<wire_cst_pulse_config> wireObj) {
+    cst_api_fill_to_wire_pulse_config(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_shell_hook(
+      ShellHook apiObj, ffi.Pointer<wire_cst_shell_hook> wireObj) {
+    cst_api_fill_to_wire_shell_hook(apiObj, wireObj.ref);
+  }
+
   @protected
   void cst_api_fill_to_wire_box_autoadd_team(
       Team apiObj, ffi.Pointer<wire_cst_team> wireObj) {
@@ -517,6 +969,27 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     wireObj.pincode_hash = cst_encode_String(apiObj.pincodeHash);
   }
 
+  @protected
+  void cst_api_fill_to_wire_custom_webhook(
+      CustomWebhook apiObj, wire_cst_custom_webhook wireObj) {
+    wireObj.name = cst_encode_opt_String(apiObj.name);
+    wireObj.url = cst_encode_String(apiObj.url);
+    wireObj.template = cst_encode_opt_String(apiObj.template);
+    wireObj.content_type = cst_encode_opt_String(apiObj.contentType);
+    wireObj.headers = cst_encode_list_webhook_header(apiObj.headers);
+    wireObj.secret = cst_encode_opt_String(apiObj.secret);
+    wireObj.signature_header = cst_encode_opt_String(apiObj.signatureHeader);
+    wireObj.events = cst_encode_list_String(apiObj.events);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_due_pulse_prompt(
+      DuePulsePrompt apiObj, wire_cst_due_pulse_prompt wireObj) {
+    wireObj.prompt = cst_encode_String(apiObj.prompt);
+    wireObj.message = cst_encode_String(apiObj.message);
+    wireObj.objective_id = cst_encode_opt_String(apiObj.objectiveId);
+  }
+
   @protected
   void cst_api_fill_to_wire_interaction(
       Interaction apiObj, wire_cst_interaction wireObj) {
@@ -527,6 +1000,8 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     wireObj.note = cst_encode_String(apiObj.note);
     wireObj.timestamp = cst_encode_String(apiObj.timestamp);
     wireObj.shared = cst_encode_bool(apiObj.shared);
+    wireObj.principles = cst_encode_list_String(apiObj.principles);
+    wireObj.imported_from = cst_encode_opt_String(apiObj.importedFrom);
   }
 
   @protected
@@ -544,12 +1019,20 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     wireObj.target_branch = cst_encode_opt_String(apiObj.targetBranch);
   }
 
+  @protected
+  void cst_api_fill_to_wire_manifesto(
+      Manifesto apiObj, wire_cst_manifesto wireObj) {
+    wireObj.preamble = cst_encode_opt_String(apiObj.preamble);
+    wireObj.principles = cst_encode_list_principle(apiObj.principles);
+  }
+
   @protected
   void cst_api_fill_to_wire_member(Member apiObj, wire_cst_member wireObj) {
     wireObj.email = cst_encode_String(apiObj.email);
     wireObj.name = cst_encode_opt_String(apiObj.name);
     wireObj.bio = cst_encode_opt_String(apiObj.bio);
     wireObj.timezone = cst_encode_opt_String(apiObj.timezone);
+    wireObj.aliases = cst_encode_list_String(apiObj.aliases);
   }
 
   @protected
@@ -559,6 +1042,26 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     cst_api_fill_to_wire_credentials(apiObj.credentials, wireObj.credentials);
   }
 
+  @protected
+  void cst_api_fill_to_wire_member_interaction_stats(
+      MemberInteractionStats apiObj,
+      wire_cst_member_interaction_stats wireObj) {
+    wireObj.email = cst_encode_String(apiObj.email);
+    wireObj.kudos_given = cst_encode_u_32(apiObj.kudosGiven);
+    wireObj.kudos_received = cst_encode_u_32(apiObj.kudosReceived);
+    wireObj.feedback_given = cst_encode_u_32(apiObj.feedbackGiven);
+    wireObj.feedback_received = cst_encode_u_32(apiObj.feedbackReceived);
+    wireObj.total_sent = cst_encode_u_32(apiObj.totalSent);
+    wireObj.total_received = cst_encode_u_32(apiObj.totalReceived);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_member_pulse(
+      MemberPulse apiObj, wire_cst_member_pulse wireObj) {
+    wireObj.email = cst_encode_String(apiObj.email);
+    cst_api_fill_to_wire_pulse_cadences(apiObj.cadences, wireObj.cadences);
+  }
+
   @protected
   void cst_api_fill_to_wire_objective(
       Objective apiObj, wire_cst_objective wireObj) {
@@ -569,6 +1072,32 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     wireObj.visibility = cst_encode_okr_visibility(apiObj.visibility);
     wireObj.owner = cst_encode_opt_String(apiObj.owner);
     wireObj.quarter = cst_encode_opt_String(apiObj.quarter);
+    wireObj.principles = cst_encode_list_String(apiObj.principles);
+    wireObj.updated = cst_encode_opt_String(apiObj.updated);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_pincode_check(
+      PincodeCheck apiObj, wire_cst_pincode_check wireObj) {
+    wireObj.verified = cst_encode_bool(apiObj.verified);
+    wireObj.email = cst_encode_String(apiObj.email);
+    wireObj.retry_after_secs = cst_encode_u_64(apiObj.retryAfterSecs);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_principle(
+      Principle apiObj, wire_cst_principle wireObj) {
+    wireObj.id = cst_encode_String(apiObj.id);
+    wireObj.title = cst_encode_String(apiObj.title);
+    wireObj.description = cst_encode_opt_String(apiObj.description);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_principle_recognition(
+      PrincipleRecognition apiObj, wire_cst_principle_recognition wireObj) {
+    wireObj.id = cst_encode_String(apiObj.id);
+    wireObj.title = cst_encode_String(apiObj.title);
+    wireObj.count = cst_encode_u_32(apiObj.count);
   }
 
   @protected
@@ -577,15 +1106,40 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     wireObj.manifesto = cst_encode_opt_String(apiObj.manifesto);
     wireObj.vision = cst_encode_opt_String(apiObj.vision);
     wireObj.okrs = cst_encode_opt_String(apiObj.okrs);
+    wireObj.interactions = cst_encode_opt_String(apiObj.interactions);
+    wireObj.timezone = cst_encode_opt_String(apiObj.timezone);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_pulse_cadences(
+      PulseCadences apiObj, wire_cst_pulse_cadences wireObj) {
+    wireObj.checkin = cst_encode_opt_String(apiObj.checkin);
+    wireObj.okr_update = cst_encode_opt_String(apiObj.okrUpdate);
+    wireObj.retro = cst_encode_opt_String(apiObj.retro);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_pulse_config(
+      PulseConfig apiObj, wire_cst_pulse_config wireObj) {
+    cst_api_fill_to_wire_pulse_cadences(apiObj.team, wireObj.team);
+    wireObj.members = cst_encode_list_member_pulse(apiObj.members);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_shell_hook(
+      ShellHook apiObj, wire_cst_shell_hook wireObj) {
+    wireObj.on = cst_encode_list_String(apiObj.on);
+    wireObj.run = cst_encode_String(apiObj.run);
   }
 
   @protected
   void cst_api_fill_to_wire_team(Team apiObj, wire_cst_team wireObj) {
     wireObj.name = cst_encode_String(apiObj.name);
-    wireObj.manifesto = cst_encode_opt_String(apiObj.manifesto);
+    wireObj.manifesto = cst_encode_opt_box_autoadd_manifesto(apiObj.manifesto);
     wireObj.vision = cst_encode_opt_String(apiObj.vision);
     wireObj.leaders = cst_encode_list_String(apiObj.leaders);
     wireObj.members = cst_encode_list_String(apiObj.members);
+    wireObj.archived = cst_encode_list_String(apiObj.archived);
   }
 
   @protected
@@ -596,6 +1150,24 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
         cst_encode_opt_box_autoadd_webhook_config(apiObj.webhooks);
     wireObj.linting = cst_encode_opt_box_autoadd_linting_config(apiObj.linting);
     wireObj.backup = cst_encode_opt_box_autoadd_backup_config(apiObj.backup);
+    wireObj.pulse = cst_encode_opt_box_autoadd_pulse_config(apiObj.pulse);
+    wireObj.hooks = cst_encode_list_shell_hook(apiObj.hooks);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_team_event(
+      TeamEvent apiObj, wire_cst_team_event wireObj) {
+    wireObj.id = cst_encode_String(apiObj.id);
+    wireObj.name = cst_encode_String(apiObj.name);
+    wireObj.text = cst_encode_String(apiObj.text);
+    wireObj.fields = cst_encode_list_team_event_field(apiObj.fields);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_team_event_field(
+      TeamEventField apiObj, wire_cst_team_event_field wireObj) {
+    wireObj.key = cst_encode_String(apiObj.key);
+    wireObj.value = cst_encode_String(apiObj.value);
   }
 
   @protected
@@ -604,6 +1176,21 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     wireObj.discord = cst_encode_opt_String(apiObj.discord);
     wireObj.slack = cst_encode_opt_String(apiObj.slack);
     wireObj.signal = cst_encode_opt_String(apiObj.signal);
+    wireObj.custom = cst_encode_list_custom_webhook(apiObj.custom);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_webhook_header(
+      WebhookHeader apiObj, wire_cst_webhook_header wireObj) {
+    wireObj.name = cst_encode_String(apiObj.name);
+    wireObj.value = cst_encode_String(apiObj.value);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_week_activity(
+      WeekActivity apiObj, wire_cst_week_activity wireObj) {
+    wireObj.week_start = cst_encode_String(apiObj.weekStart);
+    wireObj.count = cst_encode_u_32(apiObj.count);
   }
 
   @protected
@@ -621,6 +1208,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   int cst_encode_okr_visibility(OkrVisibility raw);
 
+  @protected
+  int cst_encode_u_32(int raw);
+
   @protected
   int cst_encode_u_8(int raw);
 
@@ -630,6 +1220,10 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_String(String self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_appreciation_edge(
+      AppreciationEdge self, SseSerializer serializer);
+
   @protected
   void sse_encode_backup_config(BackupConfig self, SseSerializer serializer);
 
@@ -644,6 +1238,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_box_autoadd_credentials(
       Credentials self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_f_32(double self, SseSerializer serializer);
+
   @protected
   void sse_encode_box_autoadd_interaction(
       Interaction self, SseSerializer serializer);
@@ -652,6 +1249,10 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_box_autoadd_linting_config(
       LintingConfig self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_manifesto(
+      Manifesto self, SseSerializer serializer);
+
   @protected
   void sse_encode_box_autoadd_member(Member self, SseSerializer serializer);
 
@@ -667,6 +1268,14 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_box_autoadd_publish_config(
       PublishConfig self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_pulse_config(
+      PulseConfig self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_shell_hook(
+      ShellHook self, SseSerializer serializer);
+
   @protected
   void sse_encode_box_autoadd_team(Team self, SseSerializer serializer);
 
@@ -681,6 +1290,13 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_credentials(Credentials self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_custom_webhook(CustomWebhook self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_due_pulse_prompt(
+      DuePulsePrompt self, SseSerializer serializer);
+
   @protected
   void sse_encode_f_32(double self, SseSerializer serializer);
 
@@ -703,14 +1319,65 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_list_String(List<String> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_appreciation_edge(
+      List<AppreciationEdge> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_custom_webhook(
+      List<CustomWebhook> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_due_pulse_prompt(
+      List<DuePulsePrompt> self, SseSerializer serializer);
+
   @protected
   void sse_encode_list_key_result(
       List<KeyResult> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_member_interaction_stats(
+      List<MemberInteractionStats> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_member_pulse(
+      List<MemberPulse> self, SseSerializer serializer);
+
   @protected
   void sse_encode_list_prim_u_8_strict(
       Uint8List self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_principle(
+      List<Principle> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_principle_recognition(
+      List<PrincipleRecognition> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_shell_hook(
+      List<ShellHook> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_team_event(
+      List<TeamEvent> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_team_event_field(
+      List<TeamEventField> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_webhook_header(
+      List<WebhookHeader> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_week_activity(
+      List<WeekActivity> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_manifesto(Manifesto self, SseSerializer serializer);
+
   @protected
   void sse_encode_member(Member self, SseSerializer serializer);
 
@@ -718,6 +1385,13 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_member_credentials(
       MemberCredentials self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_member_interaction_stats(
+      MemberInteractionStats self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_member_pulse(MemberPulse self, SseSerializer serializer);
+
   @protected
   void sse_encode_objective(Objective self, SseSerializer serializer);
 
@@ -731,27 +1405,70 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_opt_box_autoadd_backup_config(
       BackupConfig? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_box_autoadd_f_32(double? self, SseSerializer serializer);
+
   @protected
   void sse_encode_opt_box_autoadd_linting_config(
       LintingConfig? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_box_autoadd_manifesto(
+      Manifesto? self, SseSerializer serializer);
+
   @protected
   void sse_encode_opt_box_autoadd_publish_config(
       PublishConfig? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_box_autoadd_pulse_config(
+      PulseConfig? self, SseSerializer serializer);
+
   @protected
   void sse_encode_opt_box_autoadd_webhook_config(
       WebhookConfig? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_pincode_check(PincodeCheck self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_principle(Principle self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_principle_recognition(
+      PrincipleRecognition self, SseSerializer serializer);
+
   @protected
   void sse_encode_publish_config(PublishConfig self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_pulse_cadences(PulseCadences self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_pulse_config(PulseConfig self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_shell_hook(ShellHook self, SseSerializer serializer);
+
   @protected
   void sse_encode_team(Team self, SseSerializer serializer);
 
   @protected
   void sse_encode_team_config(TeamConfig self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_team_event(TeamEvent self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_team_event_field(
+      TeamEventField self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_u_32(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_u_64(BigInt self, SseSerializer serializer);
+
   @protected
   void sse_encode_u_8(int self, SseSerializer serializer);
 
@@ -760,6 +1477,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
 
   @protected
   void sse_encode_webhook_config(WebhookConfig self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_webhook_header(WebhookHeader self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_week_activity(WeekActivity self, SseSerializer serializer);
 }
 
 // Section: wire_class
@@ -799,6 +1522,86 @@ class RustLibWire implements BaseWire {
   late final _store_dart_post_cobject = _store_dart_post_cobjectPtr
       .asFunction<void Function(DartPostCObjectFnType)>();
 
+  void wire__crate__api__announce_interaction(
+    int port_,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> root,
+    ffi.Pointer<wire_cst_interaction> interaction,
+  ) {
+    return _wire__crate__api__announce_interaction(port_, root, interaction);
+  }
+
+  late final _wire__crate__api__announce_interactionPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Pointer<wire_cst_interaction>,
+          )>>('frbgen_interactions_wire__crate__api__announce_interaction');
+  late final _wire__crate__api__announce_interaction =
+      _wire__crate__api__announce_interactionPtr.asFunction<
+          void Function(
+            int,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Pointer<wire_cst_interaction>,
+          )>();
+
+  void wire__crate__api__appreciation_graph(
+    int port_,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> root,
+  ) {
+    return _wire__crate__api__appreciation_graph(port_, root);
+  }
+
+  late final _wire__crate__api__appreciation_graphPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+          )>>('frbgen_interactions_wire__crate__api__appreciation_graph');
+  late final _wire__crate__api__appreciation_graph =
+      _wire__crate__api__appreciation_graphPtr.asFunction<
+          void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__api__appreciation_reciprocity(
+    int port_,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> root,
+  ) {
+    return _wire__crate__api__appreciation_reciprocity(port_, root);
+  }
+
+  late final _wire__crate__api__appreciation_reciprocityPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+          )>>('frbgen_interactions_wire__crate__api__appreciation_reciprocity');
+  late final _wire__crate__api__appreciation_reciprocity =
+      _wire__crate__api__appreciation_reciprocityPtr.asFunction<
+          void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__api__approve_shell_hook(
+    int port_,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> root,
+    ffi.Pointer<wire_cst_shell_hook> hook,
+  ) {
+    return _wire__crate__api__approve_shell_hook(port_, root, hook);
+  }
+
+  late final _wire__crate__api__approve_shell_hookPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Pointer<wire_cst_shell_hook>,
+          )>>('frbgen_interactions_wire__crate__api__approve_shell_hook');
+  late final _wire__crate__api__approve_shell_hook =
+      _wire__crate__api__approve_shell_hookPtr.asFunction<
+          void Function(
+            int,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Pointer<wire_cst_shell_hook>,
+          )>();
+
   WireSyncRust2DartDco wire__crate__api__credentials_create(
     ffi.Pointer<wire_cst_list_prim_u_8_strict> pincode,
   ) {
@@ -868,6 +1671,72 @@ class RustLibWire implements BaseWire {
             ffi.Pointer<wire_cst_list_prim_u_8_strict>,
           )>();
 
+  void wire__crate__api__due_pulse_prompts(
+    int port_,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> root,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> email,
+  ) {
+    return _wire__crate__api__due_pulse_prompts(port_, root, email);
+  }
+
+  late final _wire__crate__api__due_pulse_promptsPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+          )>>('frbgen_interactions_wire__crate__api__due_pulse_prompts');
+  late final _wire__crate__api__due_pulse_prompts =
+      _wire__crate__api__due_pulse_promptsPtr.asFunction<
+          void Function(
+            int,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+          )>();
+
+  void wire__crate__api__export_team_data(
+    int port_,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> root,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> format,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> kinds,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> since,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> email,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> pincode,
+  ) {
+    return _wire__crate__api__export_team_data(
+      port_,
+      root,
+      format,
+      kinds,
+      since,
+      email,
+      pincode,
+    );
+  }
+
+  late final _wire__crate__api__export_team_dataPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+          )>>('frbgen_interactions_wire__crate__api__export_team_data');
+  late final _wire__crate__api__export_team_data =
+      _wire__crate__api__export_team_dataPtr.asFunction<
+          void Function(
+            int,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+          )>();
+
   WireSyncRust2DartDco wire__crate__api__interaction_appreciation(
     ffi.Pointer<wire_cst_list_prim_u_8_strict> from,
     ffi.Pointer<wire_cst_list_String> with_members,
@@ -1024,6 +1893,23 @@ class RustLibWire implements BaseWire {
             ffi.Pointer<wire_cst_list_prim_u_8_strict>,
           )>();
 
+  void wire__crate__api__load_team_manifesto(
+    int port_,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> root,
+  ) {
+    return _wire__crate__api__load_team_manifesto(port_, root);
+  }
+
+  late final _wire__crate__api__load_team_manifestoPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+          )>>('frbgen_interactions_wire__crate__api__load_team_manifesto');
+  late final _wire__crate__api__load_team_manifesto =
+      _wire__crate__api__load_team_manifestoPtr.asFunction<
+          void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
   WireSyncRust2DartDco wire__crate__api__member_create(
     ffi.Pointer<wire_cst_list_prim_u_8_strict> email,
   ) {
@@ -1150,6 +2036,23 @@ class RustLibWire implements BaseWire {
             ffi.Pointer<wire_cst_list_prim_u_8_strict>,
           )>();
 
+  void wire__crate__api__member_interaction_stats(
+    int port_,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> root,
+  ) {
+    return _wire__crate__api__member_interaction_stats(port_, root);
+  }
+
+  late final _wire__crate__api__member_interaction_statsPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+          )>>('frbgen_interactions_wire__crate__api__member_interaction_stats');
+  late final _wire__crate__api__member_interaction_stats =
+      _wire__crate__api__member_interaction_statsPtr.asFunction<
+          void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
   WireSyncRust2DartDco wire__crate__api__member_to_yaml(
     ffi.Pointer<wire_cst_member> member,
   ) {
@@ -1164,6 +2067,23 @@ class RustLibWire implements BaseWire {
       _wire__crate__api__member_to_yamlPtr.asFunction<
           WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_member>)>();
 
+  WireSyncRust2DartDco wire__crate__api__normalize_email(
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> email,
+  ) {
+    return _wire__crate__api__normalize_email(email);
+  }
+
+  late final _wire__crate__api__normalize_emailPtr = _lookup<
+      ffi.NativeFunction<
+          WireSyncRust2DartDco Function(
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+          )>>('frbgen_interactions_wire__crate__api__normalize_email');
+  late final _wire__crate__api__normalize_email =
+      _wire__crate__api__normalize_emailPtr.asFunction<
+          WireSyncRust2DartDco Function(
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+          )>();
+
   WireSyncRust2DartDco wire__crate__api__objective_create(
     ffi.Pointer<wire_cst_list_prim_u_8_strict> title,
   ) {
@@ -1238,6 +2158,80 @@ class RustLibWire implements BaseWire {
       _wire__crate__api__okr_visibility_defaultPtr
           .asFunction<void Function(int)>();
 
+  void wire__crate__api__pincode_retry_after_secs(
+    int port_,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> root,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> email,
+  ) {
+    return _wire__crate__api__pincode_retry_after_secs(port_, root, email);
+  }
+
+  late final _wire__crate__api__pincode_retry_after_secsPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+          )>>('frbgen_interactions_wire__crate__api__pincode_retry_after_secs');
+  late final _wire__crate__api__pincode_retry_after_secs =
+      _wire__crate__api__pincode_retry_after_secsPtr.asFunction<
+          void Function(
+            int,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+          )>();
+
+  void wire__crate__api__principle_report(
+    int port_,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> root,
+  ) {
+    return _wire__crate__api__principle_report(port_, root);
+  }
+
+  late final _wire__crate__api__principle_reportPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+          )>>('frbgen_interactions_wire__crate__api__principle_report');
+  late final _wire__crate__api__principle_report =
+      _wire__crate__api__principle_reportPtr.asFunction<
+          void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__api__shell_hook_approved(
+    int port_,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> root,
+    ffi.Pointer<wire_cst_shell_hook> hook,
+  ) {
+    return _wire__crate__api__shell_hook_approved(port_, root, hook);
+  }
+
+  late final _wire__crate__api__shell_hook_approvedPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Pointer<wire_cst_shell_hook>,
+          )>>('frbgen_interactions_wire__crate__api__shell_hook_approved');
+  late final _wire__crate__api__shell_hook_approved =
+      _wire__crate__api__shell_hook_approvedPtr.asFunction<
+          void Function(
+            int,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Pointer<wire_cst_shell_hook>,
+          )>();
+
+  void wire__crate__api__take_team_events(int port_) {
+    return _wire__crate__api__take_team_events(port_);
+  }
+
+  late final _wire__crate__api__take_team_eventsPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>(
+    'frbgen_interactions_wire__crate__api__take_team_events',
+  );
+  late final _wire__crate__api__take_team_events =
+      _wire__crate__api__take_team_eventsPtr.asFunction<void Function(int)>();
+
   WireSyncRust2DartDco wire__crate__api__team_config_create() {
     return _wire__crate__api__team_config_create();
   }
@@ -1382,6 +2376,97 @@ class RustLibWire implements BaseWire {
       _wire__crate__api__team_to_yamlPtr.asFunction<
           WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_team>)>();
 
+  WireSyncRust2DartDco wire__crate__api__touch_objective(
+    ffi.Pointer<wire_cst_objective> objective,
+  ) {
+    return _wire__crate__api__touch_objective(objective);
+  }
+
+  late final _wire__crate__api__touch_objectivePtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_objective>)>>(
+      'frbgen_interactions_wire__crate__api__touch_objective');
+  late final _wire__crate__api__touch_objective =
+      _wire__crate__api__touch_objectivePtr.asFunction<
+          WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_objective>)>();
+
+  void wire__crate__api__unrecognised_members(
+    int port_,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> root,
+    int weeks,
+  ) {
+    return _wire__crate__api__unrecognised_members(port_, root, weeks);
+  }
+
+  late final _wire__crate__api__unrecognised_membersPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Uint32,
+          )>>('frbgen_interactions_wire__crate__api__unrecognised_members');
+  late final _wire__crate__api__unrecognised_members =
+      _wire__crate__api__unrecognised_membersPtr.asFunction<
+          void Function(
+              int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, int)>();
+
+  void wire__crate__api__verify_member_pincode(
+    int port_,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> root,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> email,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> pincode,
+  ) {
+    return _wire__crate__api__verify_member_pincode(
+      port_,
+      root,
+      email,
+      pincode,
+    );
+  }
+
+  late final _wire__crate__api__verify_member_pincodePtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+          )>>('frbgen_interactions_wire__crate__api__verify_member_pincode');
+  late final _wire__crate__api__verify_member_pincode =
+      _wire__crate__api__verify_member_pincodePtr.asFunction<
+          void Function(
+            int,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+          )>();
+
+  void wire__crate__api__weekly_activity(
+    int port_,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> root,
+    int weeks,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> timezone,
+  ) {
+    return _wire__crate__api__weekly_activity(port_, root, weeks, timezone);
+  }
+
+  late final _wire__crate__api__weekly_activityPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Uint32,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+          )>>('frbgen_interactions_wire__crate__api__weekly_activity');
+  late final _wire__crate__api__weekly_activity =
+      _wire__crate__api__weekly_activityPtr.asFunction<
+          void Function(
+            int,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            int,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+          )>();
+
   ffi.Pointer<wire_cst_backup_config> cst_new_box_autoadd_backup_config() {
     return _cst_new_box_autoadd_backup_config();
   }
@@ -1405,6 +2490,17 @@ class RustLibWire implements BaseWire {
       _cst_new_box_autoadd_credentialsPtr
           .asFunction<ffi.Pointer<wire_cst_credentials> Function()>();
 
+  ffi.Pointer<ffi.Float> cst_new_box_autoadd_f_32(double value) {
+    return _cst_new_box_autoadd_f_32(value);
+  }
+
+  late final _cst_new_box_autoadd_f_32Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Float> Function(ffi.Float)>>(
+    'frbgen_interactions_cst_new_box_autoadd_f_32',
+  );
+  late final _cst_new_box_autoadd_f_32 = _cst_new_box_autoadd_f_32Ptr
+      .asFunction<ffi.Pointer<ffi.Float> Function(double)>();
+
   ffi.Pointer<wire_cst_interaction> cst_new_box_autoadd_interaction() {
     return _cst_new_box_autoadd_interaction();
   }
@@ -1428,6 +2524,17 @@ class RustLibWire implements BaseWire {
       _cst_new_box_autoadd_linting_configPtr
           .asFunction<ffi.Pointer<wire_cst_linting_config> Function()>();
 
+  ffi.Pointer<wire_cst_manifesto> cst_new_box_autoadd_manifesto() {
+    return _cst_new_box_autoadd_manifesto();
+  }
+
+  late final _cst_new_box_autoadd_manifestoPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_manifesto> Function()>>(
+    'frbgen_interactions_cst_new_box_autoadd_manifesto',
+  );
+  late final _cst_new_box_autoadd_manifesto = _cst_new_box_autoadd_manifestoPtr
+      .asFunction<ffi.Pointer<wire_cst_manifesto> Function()>();
+
   ffi.Pointer<wire_cst_member> cst_new_box_autoadd_member() {
     return _cst_new_box_autoadd_member();
   }
@@ -1474,6 +2581,29 @@ class RustLibWire implements BaseWire {
       _cst_new_box_autoadd_publish_configPtr
           .asFunction<ffi.Pointer<wire_cst_publish_config> Function()>();
 
+  ffi.Pointer<wire_cst_pulse_config> cst_new_box_autoadd_pulse_config() {
+    return _cst_new_box_autoadd_pulse_config();
+  }
+
+  late final _cst_new_box_autoadd_pulse_configPtr = _lookup<
+          ffi.NativeFunction<ffi.Pointer<wire_cst_pulse_config> Function()>>(
+      'frbgen_interactions_cst_new_box_autoadd_pulse_config');
+  late final _cst_new_box_autoadd_pulse_config =
+      _cst_new_box_autoadd_pulse_configPtr
+          .asFunction<ffi.Pointer<wire_cst_pulse_config> Function()>();
+
+  ffi.Pointer<wire_cst_shell_hook> cst_new_box_autoadd_shell_hook() {
+    return _cst_new_box_autoadd_shell_hook();
+  }
+
+  late final _cst_new_box_autoadd_shell_hookPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_shell_hook> Function()>>(
+    'frbgen_interactions_cst_new_box_autoadd_shell_hook',
+  );
+  late final _cst_new_box_autoadd_shell_hook =
+      _cst_new_box_autoadd_shell_hookPtr
+          .asFunction<ffi.Pointer<wire_cst_shell_hook> Function()>();
+
   ffi.Pointer<wire_cst_team> cst_new_box_autoadd_team() {
     return _cst_new_box_autoadd_team();
   }
@@ -1519,6 +2649,47 @@ class RustLibWire implements BaseWire {
   late final _cst_new_list_String = _cst_new_list_StringPtr
       .asFunction<ffi.Pointer<wire_cst_list_String> Function(int)>();
 
+  ffi.Pointer<wire_cst_list_appreciation_edge> cst_new_list_appreciation_edge(
+    int len,
+  ) {
+    return _cst_new_list_appreciation_edge(len);
+  }
+
+  late final _cst_new_list_appreciation_edgePtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Pointer<wire_cst_list_appreciation_edge> Function(
+                  ffi.Int32)>>(
+      'frbgen_interactions_cst_new_list_appreciation_edge');
+  late final _cst_new_list_appreciation_edge =
+      _cst_new_list_appreciation_edgePtr.asFunction<
+          ffi.Pointer<wire_cst_list_appreciation_edge> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_custom_webhook> cst_new_list_custom_webhook(
+    int len,
+  ) {
+    return _cst_new_list_custom_webhook(len);
+  }
+
+  late final _cst_new_list_custom_webhookPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Pointer<wire_cst_list_custom_webhook> Function(
+              ffi.Int32)>>('frbgen_interactions_cst_new_list_custom_webhook');
+  late final _cst_new_list_custom_webhook = _cst_new_list_custom_webhookPtr
+      .asFunction<ffi.Pointer<wire_cst_list_custom_webhook> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_due_pulse_prompt> cst_new_list_due_pulse_prompt(
+    int len,
+  ) {
+    return _cst_new_list_due_pulse_prompt(len);
+  }
+
+  late final _cst_new_list_due_pulse_promptPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Pointer<wire_cst_list_due_pulse_prompt> Function(
+              ffi.Int32)>>('frbgen_interactions_cst_new_list_due_pulse_prompt');
+  late final _cst_new_list_due_pulse_prompt = _cst_new_list_due_pulse_promptPtr
+      .asFunction<ffi.Pointer<wire_cst_list_due_pulse_prompt> Function(int)>();
+
   ffi.Pointer<wire_cst_list_key_result> cst_new_list_key_result(int len) {
     return _cst_new_list_key_result(len);
   }
@@ -1530,6 +2701,31 @@ class RustLibWire implements BaseWire {
   late final _cst_new_list_key_result = _cst_new_list_key_resultPtr
       .asFunction<ffi.Pointer<wire_cst_list_key_result> Function(int)>();
 
+  ffi.Pointer<wire_cst_list_member_interaction_stats>
+      cst_new_list_member_interaction_stats(int len) {
+    return _cst_new_list_member_interaction_stats(len);
+  }
+
+  late final _cst_new_list_member_interaction_statsPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Pointer<wire_cst_list_member_interaction_stats> Function(
+            ffi.Int32,
+          )>>('frbgen_interactions_cst_new_list_member_interaction_stats');
+  late final _cst_new_list_member_interaction_stats =
+      _cst_new_list_member_interaction_statsPtr.asFunction<
+          ffi.Pointer<wire_cst_list_member_interaction_stats> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_member_pulse> cst_new_list_member_pulse(int len) {
+    return _cst_new_list_member_pulse(len);
+  }
+
+  late final _cst_new_list_member_pulsePtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Pointer<wire_cst_list_member_pulse> Function(
+              ffi.Int32)>>('frbgen_interactions_cst_new_list_member_pulse');
+  late final _cst_new_list_member_pulse = _cst_new_list_member_pulsePtr
+      .asFunction<ffi.Pointer<wire_cst_list_member_pulse> Function(int)>();
+
   ffi.Pointer<wire_cst_list_prim_u_8_strict> cst_new_list_prim_u_8_strict(
     int len,
   ) {
@@ -1543,6 +2739,90 @@ class RustLibWire implements BaseWire {
   late final _cst_new_list_prim_u_8_strict = _cst_new_list_prim_u_8_strictPtr
       .asFunction<ffi.Pointer<wire_cst_list_prim_u_8_strict> Function(int)>();
 
+  ffi.Pointer<wire_cst_list_principle> cst_new_list_principle(int len) {
+    return _cst_new_list_principle(len);
+  }
+
+  late final _cst_new_list_principlePtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Pointer<wire_cst_list_principle> Function(
+              ffi.Int32)>>('frbgen_interactions_cst_new_list_principle');
+  late final _cst_new_list_principle = _cst_new_list_principlePtr
+      .asFunction<ffi.Pointer<wire_cst_list_principle> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_principle_recognition>
+      cst_new_list_principle_recognition(int len) {
+    return _cst_new_list_principle_recognition(len);
+  }
+
+  late final _cst_new_list_principle_recognitionPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Pointer<wire_cst_list_principle_recognition> Function(
+                  ffi.Int32)>>(
+      'frbgen_interactions_cst_new_list_principle_recognition');
+  late final _cst_new_list_principle_recognition =
+      _cst_new_list_principle_recognitionPtr.asFunction<
+          ffi.Pointer<wire_cst_list_principle_recognition> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_shell_hook> cst_new_list_shell_hook(int len) {
+    return _cst_new_list_shell_hook(len);
+  }
+
+  late final _cst_new_list_shell_hookPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Pointer<wire_cst_list_shell_hook> Function(
+              ffi.Int32)>>('frbgen_interactions_cst_new_list_shell_hook');
+  late final _cst_new_list_shell_hook = _cst_new_list_shell_hookPtr
+      .asFunction<ffi.Pointer<wire_cst_list_shell_hook> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_team_event> cst_new_list_team_event(int len) {
+    return _cst_new_list_team_event(len);
+  }
+
+  late final _cst_new_list_team_eventPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Pointer<wire_cst_list_team_event> Function(
+              ffi.Int32)>>('frbgen_interactions_cst_new_list_team_event');
+  late final _cst_new_list_team_event = _cst_new_list_team_eventPtr
+      .asFunction<ffi.Pointer<wire_cst_list_team_event> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_team_event_field> cst_new_list_team_event_field(
+    int len,
+  ) {
+    return _cst_new_list_team_event_field(len);
+  }
+
+  late final _cst_new_list_team_event_fieldPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Pointer<wire_cst_list_team_event_field> Function(
+              ffi.Int32)>>('frbgen_interactions_cst_new_list_team_event_field');
+  late final _cst_new_list_team_event_field = _cst_new_list_team_event_fieldPtr
+      .asFunction<ffi.Pointer<wire_cst_list_team_event_field> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_webhook_header> cst_new_list_webhook_header(
+    int len,
+  ) {
+    return _cst_new_list_webhook_header(len);
+  }
+
+  late final _cst_new_list_webhook_headerPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Pointer<wire_cst_list_webhook_header> Function(
+              ffi.Int32)>>('frbgen_interactions_cst_new_list_webhook_header');
+  late final _cst_new_list_webhook_header = _cst_new_list_webhook_headerPtr
+      .asFunction<ffi.Pointer<wire_cst_list_webhook_header> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_week_activity> cst_new_list_week_activity(int len) {
+    return _cst_new_list_week_activity(len);
+  }
+
+  late final _cst_new_list_week_activityPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Pointer<wire_cst_list_week_activity> Function(
+              ffi.Int32)>>('frbgen_interactions_cst_new_list_week_activity');
+  late final _cst_new_list_week_activity = _cst_new_list_week_activityPtr
+      .asFunction<ffi.Pointer<wire_cst_list_week_activity> Function(int)>();
+
   int dummy_method_to_enforce_bundling() {
     return _dummy_method_to_enforce_bundling();
   }
@@ -1567,12 +2847,6 @@ final class wire_cst_list_prim_u_8_strict extends ffi.Struct {
   external int len;
 }
 
-final class wire_cst_credentials extends ffi.Struct {
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> salt;
-
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> pincode_hash;
-}
-
 final class wire_cst_list_String extends ffi.Struct {
   external ffi.Pointer<ffi.Pointer<wire_cst_list_prim_u_8_strict>> ptr;
 
@@ -1596,6 +2870,22 @@ final class wire_cst_interaction extends ffi.Struct {
 
   @ffi.Bool()
   external bool shared;
+
+  external ffi.Pointer<wire_cst_list_String> principles;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> imported_from;
+}
+
+final class wire_cst_shell_hook extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_String> on;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> run;
+}
+
+final class wire_cst_credentials extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> salt;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> pincode_hash;
 }
 
 final class wire_cst_member_credentials extends ffi.Struct {
@@ -1612,6 +2902,8 @@ final class wire_cst_member extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> bio;
 
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> timezone;
+
+  external ffi.Pointer<wire_cst_list_String> aliases;
 }
 
 final class wire_cst_key_result extends ffi.Struct {
@@ -1645,6 +2937,10 @@ final class wire_cst_objective extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> owner;
 
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> quarter;
+
+  external ffi.Pointer<wire_cst_list_String> principles;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> updated;
 }
 
 final class wire_cst_publish_config extends ffi.Struct {
@@ -1653,6 +2949,48 @@ final class wire_cst_publish_config extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> vision;
 
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> okrs;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> interactions;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> timezone;
+}
+
+final class wire_cst_webhook_header extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> name;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> value;
+}
+
+final class wire_cst_list_webhook_header extends ffi.Struct {
+  external ffi.Pointer<wire_cst_webhook_header> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_custom_webhook extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> name;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> url;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> template;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> content_type;
+
+  external ffi.Pointer<wire_cst_list_webhook_header> headers;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> secret;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> signature_header;
+
+  external ffi.Pointer<wire_cst_list_String> events;
+}
+
+final class wire_cst_list_custom_webhook extends ffi.Struct {
+  external ffi.Pointer<wire_cst_custom_webhook> ptr;
+
+  @ffi.Int32()
+  external int len;
 }
 
 final class wire_cst_webhook_config extends ffi.Struct {
@@ -1661,6 +2999,8 @@ final class wire_cst_webhook_config extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> slack;
 
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> signal;
+
+  external ffi.Pointer<wire_cst_list_custom_webhook> custom;
 }
 
 final class wire_cst_linting_config extends ffi.Struct {
@@ -1674,6 +3014,40 @@ final class wire_cst_backup_config extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> protected_branch;
 }
 
+final class wire_cst_pulse_cadences extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> checkin;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> okr_update;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> retro;
+}
+
+final class wire_cst_member_pulse extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> email;
+
+  external wire_cst_pulse_cadences cadences;
+}
+
+final class wire_cst_list_member_pulse extends ffi.Struct {
+  external ffi.Pointer<wire_cst_member_pulse> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_pulse_config extends ffi.Struct {
+  external wire_cst_pulse_cadences team;
+
+  external ffi.Pointer<wire_cst_list_member_pulse> members;
+}
+
+final class wire_cst_list_shell_hook extends ffi.Struct {
+  external ffi.Pointer<wire_cst_shell_hook> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
 final class wire_cst_team_config extends ffi.Struct {
   external ffi.Pointer<wire_cst_publish_config> publish;
 
@@ -1682,16 +3056,173 @@ final class wire_cst_team_config extends ffi.Struct {
   external ffi.Pointer<wire_cst_linting_config> linting;
 
   external ffi.Pointer<wire_cst_backup_config> backup;
+
+  external ffi.Pointer<wire_cst_pulse_config> pulse;
+
+  external ffi.Pointer<wire_cst_list_shell_hook> hooks;
+}
+
+final class wire_cst_principle extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> id;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> title;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> description;
+}
+
+final class wire_cst_list_principle extends ffi.Struct {
+  external ffi.Pointer<wire_cst_principle> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_manifesto extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> preamble;
+
+  external ffi.Pointer<wire_cst_list_principle> principles;
 }
 
 final class wire_cst_team extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> name;
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> manifesto;
+  external ffi.Pointer<wire_cst_manifesto> manifesto;
 
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> vision;
 
   external ffi.Pointer<wire_cst_list_String> leaders;
 
   external ffi.Pointer<wire_cst_list_String> members;
+
+  external ffi.Pointer<wire_cst_list_String> archived;
+}
+
+final class wire_cst_appreciation_edge extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> from;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> to;
+
+  @ffi.Uint32()
+  external int count;
+}
+
+final class wire_cst_list_appreciation_edge extends ffi.Struct {
+  external ffi.Pointer<wire_cst_appreciation_edge> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_due_pulse_prompt extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> prompt;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> message;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> objective_id;
+}
+
+final class wire_cst_list_due_pulse_prompt extends ffi.Struct {
+  external ffi.Pointer<wire_cst_due_pulse_prompt> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_member_interaction_stats extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> email;
+
+  @ffi.Uint32()
+  external int kudos_given;
+
+  @ffi.Uint32()
+  external int kudos_received;
+
+  @ffi.Uint32()
+  external int feedback_given;
+
+  @ffi.Uint32()
+  external int feedback_received;
+
+  @ffi.Uint32()
+  external int total_sent;
+
+  @ffi.Uint32()
+  external int total_received;
+}
+
+final class wire_cst_list_member_interaction_stats extends ffi.Struct {
+  external ffi.Pointer<wire_cst_member_interaction_stats> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_principle_recognition extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> id;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> title;
+
+  @ffi.Uint32()
+  external int count;
+}
+
+final class wire_cst_list_principle_recognition extends ffi.Struct {
+  external ffi.Pointer<wire_cst_principle_recognition> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_team_event_field extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> key;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> value;
+}
+
+final class wire_cst_list_team_event_field extends ffi.Struct {
+  external ffi.Pointer<wire_cst_team_event_field> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_team_event extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> id;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> name;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> text;
+
+  external ffi.Pointer<wire_cst_list_team_event_field> fields;
+}
+
+final class wire_cst_list_team_event extends ffi.Struct {
+  external ffi.Pointer<wire_cst_team_event> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_week_activity extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> week_start;
+
+  @ffi.Uint32()
+  external int count;
+}
+
+final class wire_cst_list_week_activity extends ffi.Struct {
+  external ffi.Pointer<wire_cst_week_activity> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_pincode_check extends ffi.Struct {
+  @ffi.Bool()
+  external bool verified;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> email;
+
+  @ffi.Uint64()
+  external int retry_after_secs;
 }
@@ -0,0 +1,361 @@
+//! Team analytics
+//!
+//! Aggregates stored interactions into a who-appreciates-whom graph,
+//! per-member counts and activity over time, so the TUI, publish and the
+//! FFI share one walk over the team storage.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use chrono_tz::Tz;
+
+use crate::time::week_start;
+use crate::{normalize_email, same_email, Interaction, InteractionKind, Result, TeamStorage};
+
+/// Appreciations sent from one member to another
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Edge {
+    /// Who gave the appreciation
+    pub from: String,
+    /// Who received it
+    pub to: String,
+    /// How many times
+    pub count: usize,
+}
+
+/// Interactions a member sent and received, by kind
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MemberStats {
+    /// The member's email
+    pub email: String,
+    /// Interactions logged by the member
+    pub sent: BTreeMap<InteractionKind, usize>,
+    /// Interactions the member was part of
+    pub received: BTreeMap<InteractionKind, usize>,
+}
+
+impl MemberStats {
+    /// Number of interactions of a kind the member sent
+    pub fn sent_of(&self, kind: InteractionKind) -> usize {
+        self.sent.get(&kind).copied().unwrap_or_default()
+    }
+
+    /// Number of interactions of a kind the member received
+    pub fn received_of(&self, kind: InteractionKind) -> usize {
+        self.received.get(&kind).copied().unwrap_or_default()
+    }
+
+    /// Number of interactions the member sent
+    pub fn total_sent(&self) -> usize {
+        self.sent.values().sum()
+    }
+
+    /// Number of interactions the member received
+    pub fn total_received(&self) -> usize {
+        self.received.values().sum()
+    }
+}
+
+/// Number of interactions in one week
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WeekActivity {
+    /// The Monday the week starts on
+    pub week: NaiveDate,
+    /// Interactions in that week
+    pub count: usize,
+}
+
+/// Aggregates over a set of interactions
+#[derive(Debug, Clone, Default)]
+pub struct Analytics {
+    interactions: Vec<Interaction>,
+}
+
+impl Analytics {
+    /// Build analytics over interactions, counting each interaction id once
+    pub fn new(interactions: impl IntoIterator<Item = Interaction>) -> Self {
+        let mut seen = BTreeSet::new();
+        let interactions = interactions
+            .into_iter()
+            .filter(|i| seen.insert(i.id.clone()))
+            .collect();
+        Self { interactions }
+    }
+
+    /// Build analytics over everything the team can see: kudos and feedback
+    /// in member directories and shared team interactions
+    pub fn load(storage: &TeamStorage) -> Result<Self> {
+        let mut interactions = storage.load_team_kudos()?;
+        interactions.extend(storage.load_team_feedback()?);
+        interactions.extend(storage.load_interactions(true)?);
+        Ok(Self::new(interactions))
+    }
+
+    /// The interactions the analytics are built from
+    pub fn interactions(&self) -> &[Interaction] {
+        &self.interactions
+    }
+
+    /// Who appreciates whom, most frequent first
+    pub fn appreciation_graph(&self) -> Vec<Edge> {
+        let mut counts: BTreeMap<(String, String), usize> = BTreeMap::new();
+        for interaction in self.of_kind(InteractionKind::Appreciation) {
+            let from = normalize_email(&interaction.from);
+            for to in &interaction.with {
+                let to = normalize_email(to);
+                if to != from {
+                    *counts.entry((from.clone(), to)).or_default() += 1;
+                }
+            }
+        }
+
+        let mut edges: Vec<Edge> = counts
+            .into_iter()
+            .map(|((from, to), count)| Edge { from, to, count })
+            .collect();
+        edges.sort_by_key(|e| std::cmp::Reverse(e.count));
+        edges
+    }
+
+    /// What one member sent and received
+    pub fn member_stats(&self, email: &str) -> MemberStats {
+        let mut stats = MemberStats {
+            email: normalize_email(email),
+            ..MemberStats::default()
+        };
+        for interaction in &self.interactions {
+            if same_email(&interaction.from, email) {
+                *stats.sent.entry(interaction.kind).or_default() += 1;
+            }
+            if interaction.with.iter().any(|w| same_email(w, email)) {
+                *stats.received.entry(interaction.kind).or_default() += 1;
+            }
+        }
+        stats
+    }
+
+    /// Stats for everyone who sent or received an interaction, by email
+    pub fn all_member_stats(&self) -> Vec<MemberStats> {
+        let emails: BTreeSet<String> = self
+            .interactions
+            .iter()
+            .flat_map(|i| std::iter::once(&i.from).chain(i.with.iter()))
+            .map(|email| normalize_email(email))
+            .collect();
+        emails
+            .iter()
+            .map(|email| self.member_stats(email))
+            .collect()
+    }
+
+    /// Share of appreciating pairs where the appreciation goes both ways
+    ///
+    /// `None` when nobody has appreciated anyone yet.
+    pub fn reciprocity(&self) -> Option<f32> {
+        let pairs: BTreeSet<(String, String)> = self
+            .appreciation_graph()
+            .into_iter()
+            .map(|e| (e.from, e.to))
+            .collect();
+        if pairs.is_empty() {
+            return None;
+        }
+        let mutual = pairs
+            .iter()
+            .filter(|(from, to)| pairs.contains(&(to.clone(), from.clone())))
+            .count();
+        Some(mutual as f32 / pairs.len() as f32)
+    }
+
+    /// Members who received no appreciation in the last `weeks` weeks
+    pub fn unrecognised(&self, members: &[String], weeks: u32, now: DateTime<Utc>) -> Vec<String> {
+        let since = now - Duration::weeks(weeks as i64);
+        members
+            .iter()
+            .filter(|member| {
+                !self.of_kind(InteractionKind::Appreciation).any(|i| {
+                    i.timestamp >= since
+                        && !same_email(&i.from, member)
+                        && i.with.iter().any(|w| same_email(w, member))
+                })
+            })
+            .cloned()
+            .collect()
+    }
+
+    /// Interactions per week for the last `weeks` weeks in a timezone,
+    /// oldest first and ending with the week containing `now`
+    pub fn activity(&self, weeks: usize, now: DateTime<Utc>, tz: Tz) -> Vec<WeekActivity> {
+        let current = week_start(now, tz);
+        let mut activity: Vec<WeekActivity> = (0..weeks)
+            .rev()
+            .map(|ago| WeekActivity {
+                week: current - Duration::weeks(ago as i64),
+                count: 0,
+            })
+            .collect();
+        for interaction in &self.interactions {
+            let week = week_start(interaction.timestamp, tz);
+            if let Some(bucket) = activity.iter_mut().find(|a| a.week == week) {
+                bucket.count += 1;
+            }
+        }
+        activity
+    }
+
+    fn of_kind(&self, kind: InteractionKind) -> impl Iterator<Item = &Interaction> {
+        self.interactions.iter().filter(move |i| i.kind == kind)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Member, Team};
+    use tempfile::TempDir;
+
+    fn at(interaction: Interaction, timestamp: &str) -> Interaction {
+        Interaction {
+            timestamp: timestamp.parse().unwrap(),
+            ..interaction
+        }
+    }
+
+    fn kudos(from: &str, to: &[&str], timestamp: &str) -> Interaction {
+        let with = to.iter().map(|t| t.to_string()).collect();
+        let mut interaction = at(Interaction::appreciation(from, with, "Thanks"), timestamp);
+        // Ids come from the clock, so make them unique per test interaction
+        interaction.id = format!("{}-{}-{}", from, to.join("+"), timestamp);
+        interaction
+    }
+
+    fn sample() -> Analytics {
+        Analytics::new(vec![
+            kudos("alice@x.com", &["bob@x.com"], "2026-03-02T10:00:00Z"),
+            kudos("alice@x.com", &["bob@x.com"], "2026-03-09T10:00:00Z"),
+            kudos("bob@x.com", &["alice@x.com"], "2026-03-10T10:00:00Z"),
+            kudos(
+                "carol@x.com",
+                &["alice@x.com", "bob@x.com"],
+                "2026-03-11T10:00:00Z",
+            ),
+            at(
+                Interaction::feedback("bob@x.com", vec!["carol@x.com".to_string()], "Hmm"),
+                "2026-03-12T10:00:00Z",
+            ),
+        ])
+    }
+
+    #[test]
+    fn test_duplicate_ids_count_once() {
+        let k = kudos("alice@x.com", &["bob@x.com"], "2026-03-02T10:00:00Z");
+        let analytics = Analytics::new(vec![k.clone(), k]);
+        assert_eq!(analytics.interactions().len(), 1);
+    }
+
+    #[test]
+    fn test_appreciation_graph() {
+        let graph = sample().appreciation_graph();
+        assert_eq!(
+            graph[0],
+            Edge {
+                from: "alice@x.com".to_string(),
+                to: "bob@x.com".to_string(),
+                count: 2
+            }
+        );
+        assert_eq!(graph.len(), 4);
+    }
+
+    #[test]
+    fn test_member_stats() {
+        let analytics = sample();
+        let bob = analytics.member_stats("Bob@X.com");
+        assert_eq!(bob.sent_of(InteractionKind::Appreciation), 1);
+        assert_eq!(bob.sent_of(InteractionKind::Feedback), 1);
+        assert_eq!(bob.received_of(InteractionKind::Appreciation), 3);
+        assert_eq!(bob.total_sent(), 2);
+
+        let everyone: Vec<String> = analytics
+            .all_member_stats()
+            .into_iter()
+            .map(|s| s.email)
+            .collect();
+        assert_eq!(everyone, vec!["alice@x.com", "bob@x.com", "carol@x.com"]);
+    }
+
+    #[test]
+    fn test_reciprocity() {
+        // alice<->bob is mutual; carol->alice and carol->bob are not
+        assert_eq!(sample().reciprocity(), Some(0.5));
+        assert_eq!(Analytics::default().reciprocity(), None);
+    }
+
+    #[test]
+    fn test_unrecognised() {
+        let members: Vec<String> = ["alice@x.com", "bob@x.com", "carol@x.com", "dave@x.com"]
+            .iter()
+            .map(|m| m.to_string())
+            .collect();
+        let now: DateTime<Utc> = "2026-03-14T10:00:00Z".parse().unwrap();
+
+        assert_eq!(
+            sample().unrecognised(&members, 2, now),
+            vec!["carol@x.com", "dave@x.com"]
+        );
+        // A week later, the last kudos are more than a week old
+        let later: DateTime<Utc> = "2026-03-20T10:00:00Z".parse().unwrap();
+        assert_eq!(sample().unrecognised(&members, 1, later), members);
+    }
+
+    #[test]
+    fn test_activity() {
+        let now: DateTime<Utc> = "2026-03-14T10:00:00Z".parse().unwrap();
+        let activity = sample().activity(3, now, Tz::UTC);
+        let counts: Vec<(String, usize)> = activity
+            .iter()
+            .map(|a| (a.week.to_string(), a.count))
+            .collect();
+        assert_eq!(
+            counts,
+            vec![
+                ("2026-02-23".to_string(), 0),
+                ("2026-03-02".to_string(), 1),
+                ("2026-03-09".to_string(), 4),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_load_from_storage() {
+        let temp = TempDir::new().unwrap();
+        let storage = TeamStorage::new(temp.path());
+        let team = Team::new("T")
+            .add_leader("alice@x.com")
+            .add_member("bob@x.com");
+        storage
+            .initialize_team(
+                &team,
+                &crate::TeamConfig::new(),
+                &Member::new("alice@x.com"),
+                "1234",
+            )
+            .unwrap();
+        storage.save_member(&Member::new("bob@x.com")).unwrap();
+
+        let shared = kudos("alice@x.com", &["bob@x.com"], "2026-03-02T10:00:00Z");
+        storage.save_kudos(&shared.shared()).unwrap();
+        let feedback = Interaction::feedback("bob@x.com", vec!["alice@x.com".to_string()], "Hmm");
+        storage.save_feedback(&feedback).unwrap();
+
+        let analytics = Analytics::load(&storage).unwrap();
+        // The shared kudos is both in bob's directory and the team log
+        assert_eq!(analytics.interactions().len(), 2);
+        assert_eq!(
+            analytics
+                .member_stats("alice@x.com")
+                .received_of(InteractionKind::Feedback),
+            1
+        );
+    }
+}
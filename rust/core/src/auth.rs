@@ -3,6 +3,7 @@
 //! Handles pincode-based authentication for team members.
 //! The pincode is hashed with a salt and stored in the member's profile.
 
+use chrono::{DateTime, Duration, Utc};
 use rand::Rng;
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
@@ -12,6 +13,24 @@ use crate::{Error, Result};
 /// Salt length in bytes
 const SALT_LENGTH: usize = 16;
 
+/// How long a pincode reset token stays valid
+pub const RESET_TOKEN_VALIDITY: Duration = Duration::hours(24);
+
+/// Failed pincode attempts allowed before backoff starts
+pub const FREE_ATTEMPTS: u32 = 3;
+
+/// Delay after the first throttled attempt, doubled on each further failure
+const BACKOFF_BASE: Duration = Duration::seconds(5);
+
+/// Failed attempts after which the member is locked out
+pub const LOCKOUT_ATTEMPTS: u32 = 10;
+
+/// How long a lockout lasts
+pub const LOCKOUT_DURATION: Duration = Duration::minutes(15);
+
+/// Characters used for reset tokens (no 0/O or 1/I to avoid confusion)
+const TOKEN_ALPHABET: &[u8] = b"ABCDEFGHJKLMNPQRSTUVWXYZ23456789";
+
 /// Credentials stored in a member's profile
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct Credentials {
@@ -119,6 +138,126 @@ impl MemberCredentials {
     }
 }
 
+/// A one-time token a leader issues so a member can set a new pincode
+///
+/// Stored in .team/members/{email}/reset.yaml. Only the hash is stored; the
+/// leader passes the token itself to the member out of band.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ResetToken {
+    /// The member whose pincode may be reset
+    pub email: String,
+    /// The leader who issued the token
+    pub issued_by: String,
+    /// Salt used for hashing (hex encoded)
+    pub salt: String,
+    /// Hashed token (hex encoded)
+    pub token_hash: String,
+    /// When the token stops being valid
+    pub expires_at: DateTime<Utc>,
+}
+
+impl ResetToken {
+    /// Issue a new token, returning it together with its plaintext value
+    pub fn issue(email: impl Into<String>, issued_by: impl Into<String>) -> (Self, String) {
+        let mut rng = rand::thread_rng();
+        let raw: String = (0..8)
+            .map(|_| TOKEN_ALPHABET[rng.gen_range(0..TOKEN_ALPHABET.len())] as char)
+            .collect();
+        let token = format!("{}-{}", &raw[..4], &raw[4..]);
+
+        let salt = generate_salt();
+        let reset = Self {
+            email: email.into(),
+            issued_by: issued_by.into(),
+            salt: hex::encode(&salt),
+            token_hash: hash_pincode(&normalize_token(&token), &salt),
+            expires_at: Utc::now() + RESET_TOKEN_VALIDITY,
+        };
+        (reset, token)
+    }
+
+    /// Check whether the token has expired
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        now >= self.expires_at
+    }
+
+    /// Verify a token as typed by the member
+    ///
+    /// Case and dashes are ignored so the token is easy to read out loud.
+    pub fn verify(&self, token: &str, now: DateTime<Utc>) -> bool {
+        if self.is_expired(now) {
+            return false;
+        }
+        let salt = match hex::decode(&self.salt) {
+            Ok(s) => s,
+            Err(_) => return false,
+        };
+        constant_time_eq(
+            &hash_pincode(&normalize_token(token), &salt),
+            &self.token_hash,
+        )
+    }
+}
+
+/// Failed pincode attempts for one member, stored locally in .personal/
+///
+/// After [`FREE_ATTEMPTS`] failures each further attempt has to wait an
+/// exponentially growing delay, and after [`LOCKOUT_ATTEMPTS`] the member is
+/// locked out for [`LOCKOUT_DURATION`]. A successful attempt clears the record.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct PincodeAttempts {
+    /// Consecutive failed attempts
+    pub failures: u32,
+    /// When the last attempt failed
+    #[serde(default)]
+    pub last_failure: Option<DateTime<Utc>>,
+}
+
+impl PincodeAttempts {
+    /// How long to wait after the last failure before trying again
+    pub fn delay(&self) -> Duration {
+        if self.failures >= LOCKOUT_ATTEMPTS {
+            LOCKOUT_DURATION
+        } else if self.failures >= FREE_ATTEMPTS {
+            let doublings = self.failures - FREE_ATTEMPTS;
+            (BACKOFF_BASE * 2i32.pow(doublings)).min(LOCKOUT_DURATION)
+        } else {
+            Duration::zero()
+        }
+    }
+
+    /// Time left before another attempt is allowed, if any
+    pub fn retry_after(&self, now: DateTime<Utc>) -> Option<Duration> {
+        let last_failure = self.last_failure?;
+        let remaining = last_failure + self.delay() - now;
+        (remaining > Duration::zero()).then_some(remaining)
+    }
+
+    /// Whether the member is in the long lockout rather than a short backoff
+    pub fn is_locked_out(&self) -> bool {
+        self.failures >= LOCKOUT_ATTEMPTS
+    }
+
+    /// Record a failed attempt
+    pub fn record_failure(&mut self, now: DateTime<Utc>) {
+        // A lockout that has run out starts the count over
+        if self.is_locked_out() && self.retry_after(now).is_none() {
+            self.failures = 0;
+        }
+        self.failures += 1;
+        self.last_failure = Some(now);
+    }
+}
+
+/// Strip formatting from a typed reset token
+fn normalize_token(token: &str) -> String {
+    token
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .map(|c| c.to_ascii_uppercase())
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -178,6 +317,46 @@ mod tests {
         assert!(parsed.verify("testpin"));
     }
 
+    #[test]
+    fn test_reset_token() {
+        let (reset, token) = ResetToken::issue("user@example.com", "leader@example.com");
+        let now = Utc::now();
+
+        assert_eq!(token.len(), 9);
+        assert!(reset.verify(&token, now));
+        assert!(reset.verify(&token.to_lowercase().replace('-', ""), now));
+        assert!(!reset.verify("AAAA-BBBB", now));
+
+        // Expired tokens are rejected
+        assert!(!reset.verify(&token, reset.expires_at));
+    }
+
+    #[test]
+    fn test_pincode_attempts_backoff() {
+        let now = Utc::now();
+        let mut attempts = PincodeAttempts::default();
+
+        for _ in 0..FREE_ATTEMPTS {
+            attempts.record_failure(now);
+        }
+        assert_eq!(attempts.retry_after(now), Some(Duration::seconds(5)));
+
+        attempts.record_failure(now);
+        assert_eq!(attempts.retry_after(now), Some(Duration::seconds(10)));
+        assert_eq!(attempts.retry_after(now + Duration::seconds(10)), None);
+
+        while !attempts.is_locked_out() {
+            attempts.record_failure(now);
+        }
+        assert_eq!(attempts.retry_after(now), Some(LOCKOUT_DURATION));
+
+        // Once the lockout has passed, counting starts over
+        let later = now + LOCKOUT_DURATION;
+        attempts.record_failure(later);
+        assert_eq!(attempts.failures, 1);
+        assert_eq!(attempts.retry_after(later), None);
+    }
+
     #[test]
     fn test_different_salts_produce_different_hashes() {
         let creds1 = Credentials::new("samepin").unwrap();
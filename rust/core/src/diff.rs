@@ -0,0 +1,225 @@
+//! Field-level differences between two versions of a YAML file
+//!
+//! Compares the parsed documents rather than their lines, so reordered keys
+//! or requoted strings are not changes, and a list item is followed by its
+//! id instead of its position. Lists of records are matched the same way
+//! [`merge`](crate::merge) matches them.
+
+use std::fmt;
+
+use serde_yaml::{Mapping, Value};
+
+use crate::{Error, Result};
+
+/// Fields that identify an item in a list of mappings, in order of preference
+const ITEM_KEYS: &[&str] = &["id", "email", "description", "title"];
+
+/// One field that differs
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldChange {
+    /// Where the field is, e.g. `members` or `key_results[Beta].progress`
+    pub path: String,
+    /// The old value, `None` if the field or list item was added
+    pub old: Option<String>,
+    /// The new value, `None` if the field or list item was removed
+    pub new: Option<String>,
+}
+
+impl fmt::Display for FieldChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (&self.old, &self.new) {
+            (Some(old), Some(new)) => write!(f, "{}: {} → {}", self.path, old, new),
+            (None, Some(new)) => write!(f, "{}: + {}", self.path, new),
+            (Some(old), None) => write!(f, "{}: - {}", self.path, old),
+            (None, None) => write!(f, "{}", self.path),
+        }
+    }
+}
+
+/// The fields that differ between two versions of a YAML document
+///
+/// An empty version stands for a file that does not exist.
+pub fn diff(old: &str, new: &str) -> Result<Vec<FieldChange>> {
+    let parse = |content: &str| {
+        serde_yaml::from_str::<Value>(content).map_err(|e| Error::InvalidInput(e.to_string()))
+    };
+    let old = parse(old)?;
+    let new = parse(new)?;
+    let mut changes = Vec::new();
+    diff_values(&old, &new, "", &mut changes);
+    Ok(changes)
+}
+
+/// The field that identifies every item of some lists of mappings, if any
+pub(crate) fn item_key<'a>(items: impl Iterator<Item = &'a Value> + Clone) -> Option<&'static str> {
+    ITEM_KEYS.iter().copied().find(|key| {
+        items.clone().all(|v| {
+            matches!(v, Value::Mapping(m) if m.get(*key).is_some_and(|k| {
+                matches!(k, Value::String(_) | Value::Number(_) | Value::Bool(_))
+            }))
+        })
+    })
+}
+
+/// Whether a list holds plain values only, so it can be treated as a set
+pub(crate) fn is_set<'a>(mut items: impl Iterator<Item = &'a Value>) -> bool {
+    items.all(|v| !matches!(v, Value::Mapping(_) | Value::Sequence(_)))
+}
+
+/// How a list item is named in a path, e.g. `[Beta]`
+pub(crate) fn item_path(path: &str, id: &Value) -> String {
+    format!("{}[{}]", path, render(id))
+}
+
+/// A field's path below `path`
+pub(crate) fn child_path(path: &str, key: &Value) -> String {
+    let key = render(key);
+    if path.is_empty() {
+        key
+    } else {
+        format!("{}.{}", path, key)
+    }
+}
+
+/// A value on one line: scalars as they are, anything else as JSON
+fn render(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        Value::Null => "null".to_string(),
+        Value::Bool(b) => b.to_string(),
+        Value::Number(n) => n.to_string(),
+        other => serde_json::to_string(other).unwrap_or_default(),
+    }
+}
+
+fn diff_values(old: &Value, new: &Value, path: &str, changes: &mut Vec<FieldChange>) {
+    if old == new {
+        return;
+    }
+    match (old, new) {
+        (Value::Mapping(old), Value::Mapping(new)) => diff_mappings(old, new, path, changes),
+        // A whole file added or removed shows its fields
+        (Value::Null, Value::Mapping(new)) if path.is_empty() => {
+            diff_mappings(&Mapping::new(), new, path, changes)
+        }
+        (Value::Mapping(old), Value::Null) if path.is_empty() => {
+            diff_mappings(old, &Mapping::new(), path, changes)
+        }
+        (Value::Sequence(old), Value::Sequence(new)) => diff_sequences(old, new, path, changes),
+        // Each value of a set added or removed as a whole is a change of its own
+        (Value::Null, Value::Sequence(new)) if is_set(new.iter()) => {
+            diff_sequences(&[], new, path, changes)
+        }
+        (Value::Sequence(old), Value::Null) if is_set(old.iter()) => {
+            diff_sequences(old, &[], path, changes)
+        }
+        (Value::Null, new) => changes.push(FieldChange {
+            path: path.to_string(),
+            old: None,
+            new: Some(render(new)),
+        }),
+        (old, Value::Null) => changes.push(FieldChange {
+            path: path.to_string(),
+            old: Some(render(old)),
+            new: None,
+        }),
+        (old, new) => changes.push(FieldChange {
+            path: path.to_string(),
+            old: Some(render(old)),
+            new: Some(render(new)),
+        }),
+    }
+}
+
+fn diff_mappings(old: &Mapping, new: &Mapping, path: &str, changes: &mut Vec<FieldChange>) {
+    let null = Value::Null;
+    let keys = old
+        .keys()
+        .chain(new.keys().filter(|k| !old.contains_key(*k)));
+    for key in keys {
+        let old = old.get(key).unwrap_or(&null);
+        let new = new.get(key).unwrap_or(&null);
+        diff_values(old, new, &child_path(path, key), changes);
+    }
+}
+
+fn diff_sequences(old: &[Value], new: &[Value], path: &str, changes: &mut Vec<FieldChange>) {
+    let all = || old.iter().chain(new);
+
+    if is_set(all()) {
+        for item in old.iter().filter(|v| !new.contains(v)) {
+            changes.push(FieldChange {
+                path: path.to_string(),
+                old: Some(render(item)),
+                new: None,
+            });
+        }
+        for item in new.iter().filter(|v| !old.contains(v)) {
+            changes.push(FieldChange {
+                path: path.to_string(),
+                old: None,
+                new: Some(render(item)),
+            });
+        }
+        return;
+    }
+
+    let Some(key) = item_key(all()) else {
+        changes.push(FieldChange {
+            path: path.to_string(),
+            old: Some(render(&Value::Sequence(old.to_vec()))),
+            new: Some(render(&Value::Sequence(new.to_vec()))),
+        });
+        return;
+    };
+    let id = |v: &Value| v.get(key).cloned().unwrap_or(Value::Null);
+    let null = Value::Null;
+    for item in old {
+        let k = id(item);
+        let other = new.iter().find(|v| id(v) == k).unwrap_or(&null);
+        diff_values(item, other, &item_path(path, &k), changes);
+    }
+    for item in new {
+        let k = id(item);
+        if !old.iter().any(|v| id(v) == k) {
+            diff_values(&null, item, &item_path(path, &k), changes);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diff_fields() {
+        let old = "name: T\nleaders: [a@x.com]\nmembers: [b@x.com]\nvision: Grow\n";
+        let new = "members: [b@x.com, c@x.com]\nname: P\nleaders: [a@x.com]\n";
+        let changes: Vec<String> = diff(old, new)
+            .unwrap()
+            .iter()
+            .map(|c| c.to_string())
+            .collect();
+        assert_eq!(
+            changes,
+            vec!["name: T → P", "members: + c@x.com", "vision: - Grow"]
+        );
+    }
+
+    #[test]
+    fn test_diff_records_by_key() {
+        let old = "key_results:\n- description: Beta\n  progress: 0.5\n- description: Launch\n";
+        let new = "key_results:\n- description: Launch\n- description: Beta\n  progress: 0.75\n";
+        let changes = diff(old, new).unwrap();
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].path, "key_results[Beta].progress");
+
+        // A new file shows every field as added
+        let created: Vec<String> = diff("", "title: Ship\nowners: [a, b]\n")
+            .unwrap()
+            .iter()
+            .map(|c| c.to_string())
+            .collect();
+        assert_eq!(created, vec!["title: + Ship", "owners: + a", "owners: + b"]);
+    }
+}
@@ -14,6 +14,12 @@ pub enum Error {
     #[error("YAML parsing error: {0}")]
     Yaml(#[from] serde_yaml::Error),
 
+    #[error("JSON error: {0}")]
+    Json(#[from] serde_json::Error),
+
+    #[error("CSV error: {0}")]
+    Csv(#[from] csv::Error),
+
     #[error("Team not found: {0}")]
     TeamNotFound(String),
 
@@ -23,6 +29,9 @@ pub enum Error {
     #[error("Invalid configuration: {0}")]
     InvalidConfig(String),
 
+    #[error("Invalid input: {0}")]
+    InvalidInput(String),
+
     #[error("Storage error: {0}")]
     Storage(String),
 
@@ -31,4 +40,31 @@ pub enum Error {
 
     #[error("Credentials not found for: {0}")]
     CredentialsNotFound(String),
+
+    #[error("Unknown timezone: {0} (expected an IANA name like Europe/Amsterdam)")]
+    InvalidTimezone(String),
+
+    #[error("Too many failed attempts, try again in {}", format_wait(*retry_after_secs))]
+    TooManyAttempts { retry_after_secs: u64 },
+
+    #[error("Permission denied: {0}")]
+    PermissionDenied(String),
+
+    #[error("Git error: {0}")]
+    Git(String),
+
+    #[error("Webhook error: {0}")]
+    Webhook(String),
+
+    #[error("Hook failed: {0}")]
+    Hook(String),
+}
+
+/// Format a wait time for error messages, e.g. "45s" or "3m 20s"
+fn format_wait(secs: u64) -> String {
+    match (secs / 60, secs % 60) {
+        (0, s) => format!("{}s", s),
+        (m, 0) => format!("{}m", m),
+        (m, s) => format!("{}m {}s", m, s),
+    }
 }
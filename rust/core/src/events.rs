@@ -0,0 +1,715 @@
+//! Team events
+//!
+//! [`AuthorizedStorage`](crate::AuthorizedStorage) publishes an [`Event`]
+//! on an [`EventBus`] after each successful change. Each event gets its own
+//! id when it is created, so doing the same thing twice is two events. Subscribers react to it:
+//! the team's webhooks and shell hooks from `.team/config.yaml`, or anything
+//! the TUI or FFI subscribes. Private changes are never sent to webhooks or
+//! shell hooks.
+
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use chrono::{DateTime, Utc};
+
+use crate::outbox;
+use crate::webhook::{self, Notification};
+use crate::{
+    CustomWebhook, Error, FeedbackRequest, Interaction, InteractionKind, Manifesto, Member,
+    Objective, OkrVisibility, Result, ShellHook, TeamStorage,
+};
+
+/// Something that happened in the team, with an id of its own
+#[derive(Debug, Clone, PartialEq)]
+pub struct Event {
+    /// Unique id, assigned when the event is created
+    pub id: String,
+    /// When the event was created
+    pub created: DateTime<Utc>,
+    /// What changed
+    pub change: Change,
+}
+
+impl Event {
+    /// Every event name, as used in webhook and shell hook filters
+    pub const NAMES: [&'static str; 9] = Change::NAMES;
+
+    /// A new event for a change, with a fresh id
+    pub fn new(change: Change) -> Self {
+        let created = Utc::now();
+        Self {
+            id: new_event_id(created),
+            created,
+            change,
+        }
+    }
+
+    /// The event name, e.g. "interaction_created"
+    pub fn name(&self) -> &'static str {
+        self.change.name()
+    }
+
+    /// Whether the whole team may see what changed
+    pub fn is_shared(&self) -> bool {
+        self.change.is_shared()
+    }
+
+    /// The event as a webhook notification, with the event's id as
+    /// `event_id`
+    pub fn notification(&self) -> Notification {
+        self.change.notification().with_id(&self.id)
+    }
+}
+
+impl From<Change> for Event {
+    fn from(change: Change) -> Self {
+        Self::new(change)
+    }
+}
+
+/// An id made of the creation time and a counter, so two events created in
+/// the same instant still differ
+fn new_event_id(created: DateTime<Utc>) -> String {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    format!(
+        "{}-{:x}",
+        created.format("%Y%m%dT%H%M%S%.9fZ"),
+        COUNTER.fetch_add(1, Ordering::Relaxed)
+    )
+}
+
+/// Something that changed in the team
+#[derive(Debug, Clone, PartialEq)]
+pub enum Change {
+    /// An interaction was logged
+    InteractionCreated(Interaction),
+    /// A member asked a teammate for feedback
+    FeedbackRequested(FeedbackRequest),
+    /// A member joined the team
+    MemberAdded(Member),
+    /// A member was promoted to leader or demoted to member
+    MemberRoleChanged {
+        /// The member's email
+        email: String,
+        /// Whether they are a leader now
+        leader: bool,
+    },
+    /// A member who left was archived
+    MemberArchived(String),
+    /// A member was removed
+    MemberRemoved(String),
+    /// An objective was created
+    ObjectiveCreated(Objective),
+    /// An objective's progress changed
+    ObjectiveProgressed {
+        /// The objective as saved
+        objective: Objective,
+        /// Overall progress before the change, 0.0 to 1.0
+        previous: f32,
+    },
+    /// The manifesto was changed
+    ManifestoChanged(Manifesto),
+}
+
+impl Change {
+    /// Every event name, as used in webhook and shell hook filters
+    pub const NAMES: [&'static str; 9] = [
+        "interaction_created",
+        "feedback_requested",
+        "member_added",
+        "member_role_changed",
+        "member_archived",
+        "member_removed",
+        "objective_created",
+        "objective_progressed",
+        "manifesto_changed",
+    ];
+
+    /// The event name, e.g. "interaction_created"
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::InteractionCreated(_) => "interaction_created",
+            Self::FeedbackRequested(_) => "feedback_requested",
+            Self::MemberAdded(_) => "member_added",
+            Self::MemberRoleChanged { .. } => "member_role_changed",
+            Self::MemberArchived(_) => "member_archived",
+            Self::MemberRemoved(_) => "member_removed",
+            Self::ObjectiveCreated(_) => "objective_created",
+            Self::ObjectiveProgressed { .. } => "objective_progressed",
+            Self::ManifestoChanged(_) => "manifesto_changed",
+        }
+    }
+
+    /// Whether the whole team may see what changed
+    ///
+    /// Private interactions and objectives, and feedback requests between
+    /// two people, are not.
+    pub fn is_shared(&self) -> bool {
+        match self {
+            Self::InteractionCreated(interaction) => interaction.shared,
+            Self::FeedbackRequested(_) => false,
+            Self::ObjectiveCreated(objective) | Self::ObjectiveProgressed { objective, .. } => {
+                objective.visibility == OkrVisibility::Shared
+            }
+            _ => true,
+        }
+    }
+
+    /// The change as a webhook notification, without an event id
+    pub fn notification(&self) -> Notification {
+        match self {
+            Self::InteractionCreated(i) => {
+                let with = i.with.join(", ");
+                let text = match i.kind {
+                    InteractionKind::Appreciation => {
+                        format!("{} gave kudos to {}: {}", i.from, with, i.note)
+                    }
+                    InteractionKind::Feedback => format!("{} gave feedback to {}", i.from, with),
+                    kind => format!(
+                        "{} logged a {} with {}",
+                        i.from,
+                        kind.label().to_lowercase(),
+                        with
+                    ),
+                };
+                let kind = serde_yaml::to_string(&i.kind).unwrap_or_default();
+                Notification::new(self.name(), text)
+                    .with("id", &i.id)
+                    .with("kind", kind.trim())
+                    .with("from", &i.from)
+                    .with("with", with)
+                    .with("note", &i.note)
+                    .with("principles", i.principles.join(", "))
+                    .with("timestamp", i.timestamp.to_rfc3339())
+            }
+            Self::FeedbackRequested(r) => Notification::new(
+                self.name(),
+                format!("{} asked {} for feedback", r.from, r.to),
+            )
+            .with("id", &r.id)
+            .with("from", &r.from)
+            .with("to", &r.to)
+            .with("topic", r.topic.as_deref().unwrap_or_default()),
+            Self::MemberAdded(member) => Notification::new(
+                self.name(),
+                format!("{} joined the team", member.display_name()),
+            )
+            .with("member", &member.email)
+            .with("name", member.name.as_deref().unwrap_or_default()),
+            Self::MemberRoleChanged { email, leader } => {
+                let role = if *leader { "leader" } else { "member" };
+                Notification::new(self.name(), format!("{} is now a {}", email, role))
+                    .with("member", email)
+                    .with("role", role)
+            }
+            Self::MemberArchived(email) => {
+                Notification::new(self.name(), format!("{} left the team", email))
+                    .with("member", email)
+            }
+            Self::MemberRemoved(email) => {
+                Notification::new(self.name(), format!("{} was removed from the team", email))
+                    .with("member", email)
+            }
+            Self::ObjectiveCreated(o) => objective_fields(
+                Notification::new(self.name(), format!("New objective: {}", o.title)),
+                o,
+            ),
+            Self::ObjectiveProgressed {
+                objective,
+                previous,
+            } => objective_fields(
+                Notification::new(
+                    self.name(),
+                    format!(
+                        "{} is now {:.0}% done (was {:.0}%)",
+                        objective.title,
+                        objective.overall_progress() * 100.0,
+                        previous * 100.0
+                    ),
+                ),
+                objective,
+            )
+            .with("previous", format!("{:.0}", previous * 100.0)),
+            Self::ManifestoChanged(manifesto) => {
+                Notification::new(self.name(), "The team manifesto was updated".to_string())
+                    .with("principles", manifesto.principles.len().to_string())
+            }
+        }
+    }
+}
+
+fn objective_fields(notification: Notification, objective: &Objective) -> Notification {
+    notification
+        .with("id", &objective.id)
+        .with("title", &objective.title)
+        .with("owner", objective.owner.as_deref().unwrap_or_default())
+        .with("quarter", objective.quarter.as_deref().unwrap_or_default())
+        .with(
+            "progress",
+            format!("{:.0}", objective.overall_progress() * 100.0),
+        )
+}
+
+/// Something that reacts to events
+pub trait Subscriber {
+    /// Handle an event; errors are collected by the bus, not propagated
+    fn notify(&self, event: &Event) -> Result<()>;
+}
+
+impl<F: Fn(&Event) -> Result<()>> Subscriber for F {
+    fn notify(&self, event: &Event) -> Result<()> {
+        self(event)
+    }
+}
+
+/// Delivers events to subscribers
+///
+/// A failing subscriber does not stop the others or undo the change that
+/// caused the event; its error is kept until [`EventBus::take_failures`].
+#[derive(Default)]
+pub struct EventBus {
+    subscribers: Vec<Box<dyn Subscriber>>,
+    failures: RefCell<Vec<Error>>,
+}
+
+impl std::fmt::Debug for EventBus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EventBus")
+            .field("subscribers", &self.subscribers.len())
+            .finish()
+    }
+}
+
+impl EventBus {
+    /// Create a bus without subscribers
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A bus with the team's webhooks and shell hooks subscribed
+    pub fn for_team(storage: &TeamStorage) -> Result<Self> {
+        Self::team_bus(storage, true)
+    }
+
+    /// Like [`EventBus::for_team`], but webhook notifications are only
+    /// queued in the outbox
+    ///
+    /// Publishing then never waits for the network; the caller flushes the
+    /// outbox, for example with [`outbox::flush`] on a background thread.
+    pub fn for_team_queued(storage: &TeamStorage) -> Result<Self> {
+        Self::team_bus(storage, false)
+    }
+
+    fn team_bus(storage: &TeamStorage, flush: bool) -> Result<Self> {
+        let mut bus = Self::new();
+        if let Some(config) = storage.load_config()? {
+            if let Some(webhooks) = &config.webhooks {
+                let subscriber = WebhookSubscriber::new(webhook::endpoints(webhooks))
+                    .with_outbox(storage.root());
+                bus.subscribe(if flush {
+                    subscriber
+                } else {
+                    subscriber.queue_only()
+                });
+            }
+            if !config.hooks.is_empty() {
+                bus.subscribe(ShellSubscriber::new(storage.root(), config.hooks));
+            }
+        }
+        Ok(bus)
+    }
+
+    /// Add a subscriber
+    pub fn subscribe(&mut self, subscriber: impl Subscriber + 'static) {
+        self.subscribers.push(Box::new(subscriber));
+    }
+
+    /// Add a subscriber (builder pattern)
+    pub fn with(mut self, subscriber: impl Subscriber + 'static) -> Self {
+        self.subscribe(subscriber);
+        self
+    }
+
+    /// Deliver an event to every subscriber
+    pub fn publish(&self, event: &Event) {
+        for subscriber in &self.subscribers {
+            if let Err(e) = subscriber.notify(event) {
+                self.failures.borrow_mut().push(e);
+            }
+        }
+    }
+
+    /// Errors from subscribers since the last call
+    pub fn take_failures(&self) -> Vec<Error> {
+        self.failures.take()
+    }
+}
+
+/// Posts shared events to webhooks
+#[derive(Debug, Clone)]
+pub struct WebhookSubscriber {
+    hooks: Vec<CustomWebhook>,
+    outbox: Option<PathBuf>,
+    flush: bool,
+}
+
+impl WebhookSubscriber {
+    /// Post to these webhooks
+    pub fn new(hooks: Vec<CustomWebhook>) -> Self {
+        Self {
+            hooks,
+            outbox: None,
+            flush: true,
+        }
+    }
+
+    /// Queue notifications in the outbox of the team at `root` and retry the
+    /// ones that failed before (builder pattern)
+    pub fn with_outbox(mut self, root: impl Into<PathBuf>) -> Self {
+        self.outbox = Some(root.into());
+        self
+    }
+
+    /// Only queue notifications in the outbox, leaving the flush to the
+    /// caller (builder pattern)
+    pub fn queue_only(mut self) -> Self {
+        self.flush = false;
+        self
+    }
+}
+
+impl Subscriber for WebhookSubscriber {
+    fn notify(&self, event: &Event) -> Result<()> {
+        if !event.is_shared() {
+            return Ok(());
+        }
+        let Some(root) = &self.outbox else {
+            let errors = webhook::broadcast(&self.hooks, &event.notification());
+            return join_errors(errors, Error::Webhook);
+        };
+
+        let storage = TeamStorage::new(root);
+        let now = Utc::now();
+        outbox::enqueue(&storage, &self.hooks, &event.notification(), now)?;
+        if !self.flush {
+            return Ok(());
+        }
+        let flush = outbox::flush(&storage, now, false)?;
+        join_errors(flush.failures, Error::Webhook)
+    }
+}
+
+/// Runs shell hooks for shared events
+///
+/// Only hooks approved on this machine run; the others fail with an
+/// [`Error::Hook`] saying so.
+#[derive(Debug, Clone)]
+pub struct ShellSubscriber {
+    root: PathBuf,
+    hooks: Vec<ShellHook>,
+}
+
+impl ShellSubscriber {
+    /// Run these hooks in the team's root directory
+    pub fn new(root: impl Into<PathBuf>, hooks: Vec<ShellHook>) -> Self {
+        Self {
+            root: root.into(),
+            hooks,
+        }
+    }
+}
+
+impl Subscriber for ShellSubscriber {
+    fn notify(&self, event: &Event) -> Result<()> {
+        if !event.is_shared() {
+            return Ok(());
+        }
+        let notification = event.notification();
+        let approved = TeamStorage::new(&self.root).load_approved_hooks()?;
+        let errors = self
+            .hooks
+            .iter()
+            .filter(|hook| hook.wants(event.name()))
+            .filter_map(|hook| {
+                if !approved.contains(&hook.digest()) {
+                    return Some(Error::Hook(format!(
+                        "'{}' is not approved on this machine, run 'interactions hooks approve'",
+                        hook.run
+                    )));
+                }
+                run_hook(&self.root, hook, &notification.fields).err()
+            })
+            .collect();
+        join_errors(errors, Error::Hook)
+    }
+}
+
+fn run_hook(root: &Path, hook: &ShellHook, fields: &BTreeMap<String, String>) -> Result<()> {
+    let mut command = if cfg!(windows) {
+        let mut command = Command::new("cmd");
+        command.arg("/C");
+        command
+    } else {
+        let mut command = Command::new("sh");
+        command.arg("-c");
+        command
+    };
+    command
+        .arg(&hook.run)
+        .current_dir(root)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null());
+    for (key, value) in fields {
+        command.env(format!("INTERACTIONS_{}", key.to_uppercase()), value);
+    }
+
+    let fail = |message: String| Error::Hook(format!("'{}': {}", hook.run, message));
+    let mut child = command.spawn().map_err(|e| fail(e.to_string()))?;
+    if let Some(mut stdin) = child.stdin.take() {
+        // A hook that ignores its input may exit before reading it
+        let _ = stdin.write_all(serde_json::to_string(fields)?.as_bytes());
+    }
+    let status = child.wait().map_err(|e| fail(e.to_string()))?;
+    if !status.success() {
+        return Err(fail(format!("exited with {}", status)));
+    }
+    Ok(())
+}
+
+/// Fold several subscriber errors into one
+fn join_errors(errors: Vec<Error>, wrap: fn(String) -> Error) -> Result<()> {
+    match errors.len() {
+        0 => Ok(()),
+        1 => Err(errors.into_iter().next().expect("one error")),
+        _ => Err(wrap(
+            errors
+                .iter()
+                .map(|e| e.to_string())
+                .collect::<Vec<_>>()
+                .join("; "),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AuthorizedStorage, KeyResult, Team, TeamConfig};
+    use std::rc::Rc;
+    use tempfile::TempDir;
+
+    fn storage_with_team() -> (TempDir, TeamStorage) {
+        let temp = TempDir::new().unwrap();
+        let storage = TeamStorage::new(temp.path());
+        let team = Team::new("T")
+            .add_leader("alice@x.com")
+            .add_member("bob@x.com");
+        storage
+            .initialize_team(
+                &team,
+                &TeamConfig::new(),
+                &Member::new("alice@x.com"),
+                "1234",
+            )
+            .unwrap();
+        (temp, storage)
+    }
+
+    /// A bus recording the names of the events it sees
+    fn recording_bus() -> (EventBus, Rc<RefCell<Vec<String>>>) {
+        let seen = Rc::new(RefCell::new(Vec::new()));
+        let log = seen.clone();
+        let bus = EventBus::new().with(move |event: &Event| {
+            log.borrow_mut().push(event.name().to_string());
+            Ok(())
+        });
+        (bus, seen)
+    }
+
+    #[test]
+    fn test_storage_publishes_events() {
+        let (_temp, storage) = storage_with_team();
+        let (bus, seen) = recording_bus();
+        let alice = AuthorizedStorage::new(&storage, Some("alice@x.com")).with_events(&bus);
+
+        let kudos = Interaction::appreciation("alice@x.com", vec!["bob@x.com".to_string()], "Hi");
+        alice.save_kudos(&kudos).unwrap();
+        alice.add_member(&Member::new("carol@x.com")).unwrap();
+        alice.promote_member("carol@x.com").unwrap();
+
+        let mut objective = Objective::new("Ship").with_owner("alice@x.com");
+        objective.add_key_result(KeyResult::new("Release"));
+        alice.save_objective(&objective).unwrap();
+        // Saving without a change in progress is not an event
+        alice.save_objective(&objective).unwrap();
+        objective.set_progress(0, 0.5);
+        alice.save_objective(&objective).unwrap();
+
+        // Refused changes are not published
+        let bob = AuthorizedStorage::new(&storage, Some("bob@x.com")).with_events(&bus);
+        assert!(bob.remove_member("carol@x.com").is_err());
+
+        assert_eq!(
+            *seen.borrow(),
+            vec![
+                "interaction_created",
+                "member_added",
+                "member_role_changed",
+                "objective_created",
+                "objective_progressed",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_failures_are_collected() {
+        let bus = EventBus::new()
+            .with(|_: &Event| Err(Error::Hook("boom".to_string())))
+            .with(|_: &Event| Ok(()));
+        bus.publish(&Event::new(Change::MemberRemoved("bob@x.com".to_string())));
+        assert_eq!(bus.take_failures().len(), 1);
+        assert!(bus.take_failures().is_empty());
+    }
+
+    #[test]
+    fn test_notification() {
+        let kudos = Interaction::appreciation("alice@x.com", vec!["bob@x.com".to_string()], "Hi");
+        let notification = Event::new(Change::InteractionCreated(kudos.clone())).notification();
+        assert_eq!(notification.event(), "interaction_created");
+        assert_eq!(
+            notification.text(),
+            "alice@x.com gave kudos to bob@x.com: Hi"
+        );
+        assert_eq!(notification.fields["kind"], "appreciation");
+
+        assert!(!Event::new(Change::InteractionCreated(kudos.clone())).is_shared());
+        assert!(Event::new(Change::InteractionCreated(kudos.shared())).is_shared());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_shell_hooks() {
+        let temp = TempDir::new().unwrap();
+        let subscriber = ShellSubscriber::new(
+            temp.path(),
+            vec![
+                ShellHook::new(
+                    &["member_added"],
+                    "echo \"$INTERACTIONS_MEMBER\" > added.txt && cat > payload.json",
+                ),
+                ShellHook::new(&["member_removed"], "exit 3"),
+            ],
+        );
+        let storage = TeamStorage::new(temp.path());
+        for hook in &subscriber.hooks {
+            storage.approve_hook(hook).unwrap();
+        }
+
+        subscriber
+            .notify(&Event::new(Change::MemberAdded(Member::new("carol@x.com"))))
+            .unwrap();
+        let added = std::fs::read_to_string(temp.path().join("added.txt")).unwrap();
+        assert_eq!(added.trim(), "carol@x.com");
+        let payload: BTreeMap<String, String> = serde_json::from_str(
+            &std::fs::read_to_string(temp.path().join("payload.json")).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(payload["event"], "member_added");
+
+        let error = subscriber
+            .notify(&Event::new(Change::MemberRemoved(
+                "carol@x.com".to_string(),
+            )))
+            .unwrap_err();
+        assert!(matches!(error, Error::Hook(_)));
+
+        // Private events never reach shell hooks
+        let private = Interaction::appreciation("a@x.com", vec![], "Hi");
+        let all = ShellSubscriber::new(temp.path(), vec![ShellHook::new(&[], "exit 1")]);
+        assert!(all
+            .notify(&Event::new(Change::InteractionCreated(private)))
+            .is_ok());
+    }
+
+    #[test]
+    fn test_same_change_twice_is_delivered_twice() {
+        let (temp, storage) = storage_with_team();
+        let bus = EventBus::new().with(
+            WebhookSubscriber::new(vec![CustomWebhook::new("https://hooks.example.com")])
+                .with_outbox(temp.path())
+                .queue_only(),
+        );
+        let alice = AuthorizedStorage::new(&storage, Some("alice@x.com")).with_events(&bus);
+        alice.promote_member("bob@x.com").unwrap();
+        alice.demote_member("bob@x.com").unwrap();
+        alice.promote_member("bob@x.com").unwrap();
+
+        let promotions = RefCell::new(Vec::new());
+        let flush = outbox::flush_with(&storage, Utc::now(), false, |_, notification| {
+            if notification.fields["role"] == "leader" {
+                promotions.borrow_mut().push(notification.id());
+            }
+            Ok(())
+        })
+        .unwrap();
+        assert_eq!(flush.delivered, 3);
+        let promotions = promotions.into_inner();
+        assert_eq!(promotions.len(), 2);
+        assert_ne!(promotions[0], promotions[1]);
+    }
+
+    #[test]
+    fn test_event_ids_are_unique() {
+        let change = Change::MemberRemoved("bob@x.com".to_string());
+        let first = Event::new(change.clone());
+        let second = Event::new(change);
+        assert_ne!(first.id, second.id);
+        assert_eq!(first.notification().id(), first.id);
+    }
+
+    #[test]
+    fn test_queue_only_leaves_delivery_to_the_caller() {
+        let temp = TempDir::new().unwrap();
+        let storage = TeamStorage::new(temp.path());
+        // Nothing listens here, so a flush would fail
+        let subscriber = WebhookSubscriber::new(vec![CustomWebhook::new("http://127.0.0.1:9")])
+            .with_outbox(temp.path())
+            .queue_only();
+
+        subscriber
+            .notify(&Event::new(Change::MemberRemoved(
+                "carol@x.com".to_string(),
+            )))
+            .unwrap();
+        let queued = storage.load_outbox().unwrap();
+        assert_eq!(queued.len(), 1);
+        assert_eq!(queued[0].attempts, 0);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_shell_hooks_need_approval() {
+        let temp = TempDir::new().unwrap();
+        let storage = TeamStorage::new(temp.path());
+        let hook = ShellHook::new(&[], "touch ran.txt");
+        let subscriber = ShellSubscriber::new(temp.path(), vec![hook.clone()]);
+        let event = Event::new(Change::MemberRemoved("carol@x.com".to_string()));
+
+        let error = subscriber.notify(&event).unwrap_err();
+        assert!(error.to_string().contains("not approved"));
+        assert!(!temp.path().join("ran.txt").exists());
+
+        storage.approve_hook(&hook).unwrap();
+        assert!(storage.is_hook_approved(&hook).unwrap());
+        subscriber.notify(&event).unwrap();
+        assert!(temp.path().join("ran.txt").exists());
+
+        // A changed command needs its own approval
+        let changed = ShellHook::new(&[], "touch ran.txt && rm -rf /tmp/nothing");
+        assert!(!storage.is_hook_approved(&changed).unwrap());
+    }
+}
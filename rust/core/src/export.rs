@@ -0,0 +1,459 @@
+//! Export
+//!
+//! Serialises interactions and objectives to CSV, JSON or Markdown.
+//! Private items (interactions not shared with the team and private
+//! objectives) are only exported for the authenticated member they belong to.
+
+use std::str::FromStr;
+
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::Serialize;
+
+use crate::{
+    same_email, AuthorizedStorage, Error, Interaction, InteractionKind, Objective, OkrVisibility,
+    Result,
+};
+
+/// Output format of an export
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Csv,
+    Json,
+    Markdown,
+}
+
+impl FromStr for ExportFormat {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "csv" => Ok(Self::Csv),
+            "json" => Ok(Self::Json),
+            "md" | "markdown" => Ok(Self::Markdown),
+            other => Err(Error::InvalidInput(format!(
+                "Unknown export format '{}' (expected csv, json or md)",
+                other
+            ))),
+        }
+    }
+}
+
+/// What to include in an export
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExportFilter {
+    /// Interaction kinds to include, all kinds if `None`
+    pub kinds: Option<Vec<InteractionKind>>,
+    /// Whether to include objectives
+    pub objectives: bool,
+    /// Only interactions at or after this time (objectives are not dated)
+    pub since: Option<DateTime<Utc>>,
+}
+
+impl Default for ExportFilter {
+    fn default() -> Self {
+        Self {
+            kinds: None,
+            objectives: true,
+            since: None,
+        }
+    }
+}
+
+impl ExportFilter {
+    /// Include everything
+    pub fn all() -> Self {
+        Self::default()
+    }
+
+    /// Restrict to comma-separated kinds, e.g. "appreciation,feedback,okr"
+    ///
+    /// Interaction kinds use their YAML names; `kudos` is accepted for
+    /// appreciation and `okr`/`objectives` selects objectives.
+    pub fn with_kinds(mut self, names: &str) -> Result<Self> {
+        let mut kinds = Vec::new();
+        let mut objectives = false;
+        for name in names.split(',').map(str::trim).filter(|n| !n.is_empty()) {
+            match name.to_lowercase().as_str() {
+                "okr" | "okrs" | "objective" | "objectives" => objectives = true,
+                "kudos" => kinds.push(InteractionKind::Appreciation),
+                other => {
+                    let kind = serde_yaml::from_str::<InteractionKind>(other)
+                        .map_err(|_| Error::InvalidInput(format!("Unknown kind '{}'", name)))?;
+                    kinds.push(kind);
+                }
+            }
+        }
+        self.kinds = Some(kinds);
+        self.objectives = objectives;
+        Ok(self)
+    }
+
+    /// Only include interactions at or after a time
+    pub fn since(mut self, since: DateTime<Utc>) -> Self {
+        self.since = Some(since);
+        self
+    }
+
+    fn includes(&self, interaction: &Interaction) -> bool {
+        self.kinds
+            .as_ref()
+            .is_none_or(|kinds| kinds.contains(&interaction.kind))
+            && self
+                .since
+                .is_none_or(|since| interaction.timestamp >= since)
+    }
+}
+
+/// Parse a `--since` value: a date (midnight UTC) or an RFC 3339 timestamp
+pub fn parse_since(value: &str) -> Result<DateTime<Utc>> {
+    if let Ok(date) = NaiveDate::parse_from_str(value, "%Y-%m-%d") {
+        return Ok(date.and_hms_opt(0, 0, 0).expect("valid time").and_utc());
+    }
+    DateTime::parse_from_rfc3339(value)
+        .map(|t| t.with_timezone(&Utc))
+        .map_err(|_| {
+            Error::InvalidInput(format!(
+                "Invalid date '{}' (expected YYYY-MM-DD or an RFC 3339 timestamp)",
+                value
+            ))
+        })
+}
+
+/// Interactions and objectives selected for export
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct Export {
+    /// Interactions, oldest first
+    pub interactions: Vec<Interaction>,
+    /// Objectives, by title
+    pub objectives: Vec<Objective>,
+}
+
+impl Export {
+    /// Collect what the session's user may see, filtered
+    ///
+    /// Without an authenticated user only shared items are exported.
+    pub fn collect(session: &AuthorizedStorage, filter: &ExportFilter) -> Result<Self> {
+        let storage = session.storage();
+        let user = session.user();
+
+        let mut interactions = storage.load_team_kudos()?;
+        interactions.extend(storage.load_team_feedback()?);
+        interactions.extend(storage.load_interactions(true)?);
+        if user.is_some() {
+            interactions.extend(storage.load_interactions(false)?);
+            interactions.extend(storage.load_sent_kudos()?);
+            interactions.extend(storage.load_sent_feedback()?);
+        }
+
+        let mut seen = std::collections::BTreeSet::new();
+        let mut interactions: Vec<Interaction> = interactions
+            .into_iter()
+            .filter(|i| seen.insert(i.id.clone()))
+            .filter(|i| i.shared || user.is_some_and(|u| is_party(i, u)))
+            .filter(|i| filter.includes(i))
+            .collect();
+        interactions.sort_by_key(|i| i.timestamp);
+
+        let mut objectives = Vec::new();
+        if filter.objectives {
+            objectives = storage.load_objectives(true)?;
+            if user.is_some() {
+                objectives.extend(storage.load_objectives(false)?);
+            }
+            objectives.retain(|o| {
+                o.visibility == OkrVisibility::Shared || user.is_some_and(|u| owns(o, u))
+            });
+            objectives.sort_by_key(|o| o.title.to_lowercase());
+        }
+
+        Ok(Self {
+            interactions,
+            objectives,
+        })
+    }
+
+    /// Render in the given format
+    pub fn render(&self, format: ExportFormat) -> Result<String> {
+        match format {
+            ExportFormat::Json => Ok(serde_json::to_string_pretty(self)? + "\n"),
+            ExportFormat::Csv => self.to_csv(),
+            ExportFormat::Markdown => Ok(self.to_markdown()),
+        }
+    }
+
+    /// One row per item; interaction and objective columns are left empty
+    /// for the other record type
+    fn to_csv(&self) -> Result<String> {
+        let mut writer = csv::Writer::from_writer(Vec::new());
+        writer.write_record([
+            "record",
+            "id",
+            "kind",
+            "timestamp",
+            "from",
+            "with",
+            "note",
+            "shared",
+            "principles",
+            "title",
+            "owner",
+            "quarter",
+            "visibility",
+            "progress",
+        ])?;
+        for i in &self.interactions {
+            writer.write_record([
+                "interaction",
+                &i.id,
+                kind_name(i.kind),
+                &i.timestamp.to_rfc3339(),
+                &i.from,
+                &i.with.join(";"),
+                &i.note,
+                &i.shared.to_string(),
+                &i.principles.join(";"),
+                "",
+                "",
+                "",
+                "",
+                "",
+            ])?;
+        }
+        for o in &self.objectives {
+            writer.write_record([
+                "objective",
+                &o.id,
+                "",
+                "",
+                "",
+                "",
+                o.description.as_deref().unwrap_or_default(),
+                &(o.visibility == OkrVisibility::Shared).to_string(),
+                &o.principles.join(";"),
+                &o.title,
+                o.owner.as_deref().unwrap_or_default(),
+                o.quarter.as_deref().unwrap_or_default(),
+                visibility_name(o.visibility),
+                &format!("{:.2}", o.overall_progress()),
+            ])?;
+        }
+        let bytes = writer
+            .into_inner()
+            .map_err(|e| Error::Storage(e.to_string()))?;
+        String::from_utf8(bytes).map_err(|e| Error::Storage(e.to_string()))
+    }
+
+    fn to_markdown(&self) -> String {
+        let mut out = String::from("# Export\n");
+
+        out.push_str("\n## Interactions\n\n");
+        if self.interactions.is_empty() {
+            out.push_str("None.\n");
+        } else {
+            out.push_str("| When (UTC) | Kind | From | With | Note |\n|---|---|---|---|---|\n");
+            for i in &self.interactions {
+                out.push_str(&format!(
+                    "| {} | {} | {} | {} | {} |\n",
+                    i.timestamp.format("%Y-%m-%d %H:%M"),
+                    i.kind.label(),
+                    cell(&i.from),
+                    cell(&i.with.join(", ")),
+                    cell(&i.note),
+                ));
+            }
+        }
+
+        out.push_str("\n## Objectives\n\n");
+        if self.objectives.is_empty() {
+            out.push_str("None.\n");
+        } else {
+            out.push_str("| Objective | Owner | Quarter | Progress |\n|---|---|---|---|\n");
+            for o in &self.objectives {
+                out.push_str(&format!(
+                    "| {} | {} | {} | {:.0}% |\n",
+                    cell(&o.title),
+                    cell(o.owner.as_deref().unwrap_or("team")),
+                    cell(o.quarter.as_deref().unwrap_or_default()),
+                    o.overall_progress() * 100.0,
+                ));
+            }
+        }
+        out
+    }
+}
+
+/// Whether a user wrote or received an interaction
+fn is_party(interaction: &Interaction, user: &str) -> bool {
+    same_email(&interaction.from, user) || interaction.with.iter().any(|w| same_email(w, user))
+}
+
+/// Whether a user owns an objective
+///
+/// Private objectives without an owner live in the local .personal/
+/// directory and belong to whoever is logged in on this checkout.
+fn owns(objective: &Objective, user: &str) -> bool {
+    objective
+        .owner
+        .as_deref()
+        .is_none_or(|o| same_email(o, user))
+}
+
+fn kind_name(kind: InteractionKind) -> &'static str {
+    match kind {
+        InteractionKind::Appreciation => "appreciation",
+        InteractionKind::Feedback => "feedback",
+        InteractionKind::Apology => "apology",
+        InteractionKind::CheckIn => "check_in",
+        InteractionKind::Retrospective => "retrospective",
+    }
+}
+
+fn visibility_name(visibility: OkrVisibility) -> &'static str {
+    match visibility {
+        OkrVisibility::Private => "private",
+        OkrVisibility::Shared => "shared",
+    }
+}
+
+/// Escape text for a markdown table cell
+fn cell(text: &str) -> String {
+    text.replace('|', "\\|").replace('\n', " ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Member, Team, TeamConfig, TeamStorage};
+    use tempfile::TempDir;
+
+    fn storage() -> (TempDir, TeamStorage) {
+        let temp = TempDir::new().unwrap();
+        let storage = TeamStorage::new(temp.path());
+        let team = Team::new("T")
+            .add_leader("alice@x.com")
+            .add_member("bob@x.com")
+            .add_member("carol@x.com");
+        storage
+            .initialize_team(
+                &team,
+                &TeamConfig::new(),
+                &Member::new("alice@x.com"),
+                "1234",
+            )
+            .unwrap();
+
+        let mut shared =
+            Interaction::appreciation("alice@x.com", vec!["bob@x.com".into()], "Hi").shared();
+        shared.id = "shared".into();
+        shared.timestamp = "2026-01-05T09:00:00Z".parse().unwrap();
+        storage.save_kudos(&shared).unwrap();
+
+        let mut private = Interaction::feedback("bob@x.com", vec!["carol@x.com".into()], "Psst");
+        private.id = "private".into();
+        private.timestamp = "2026-02-05T09:00:00Z".parse().unwrap();
+        storage.save_feedback(&private).unwrap();
+
+        storage
+            .save_objective(&Objective::new("Team goal").with_visibility(OkrVisibility::Shared))
+            .unwrap();
+        storage
+            .save_objective(&Objective::new("Carol's goal").with_owner("carol@x.com"))
+            .unwrap();
+        (temp, storage)
+    }
+
+    fn ids(export: &Export) -> Vec<&str> {
+        export.interactions.iter().map(|i| i.id.as_str()).collect()
+    }
+
+    #[test]
+    fn test_private_items_need_their_owner() {
+        let (_temp, storage) = storage();
+
+        let anonymous = Export::collect(
+            &AuthorizedStorage::new(&storage, None),
+            &ExportFilter::all(),
+        )
+        .unwrap();
+        assert_eq!(ids(&anonymous), vec!["shared"]);
+        assert_eq!(anonymous.objectives.len(), 1);
+
+        let alice = Export::collect(
+            &AuthorizedStorage::new(&storage, Some("alice@x.com")),
+            &ExportFilter::all(),
+        )
+        .unwrap();
+        assert_eq!(ids(&alice), vec!["shared"]);
+        assert_eq!(alice.objectives.len(), 1);
+
+        let carol = Export::collect(
+            &AuthorizedStorage::new(&storage, Some("carol@x.com")),
+            &ExportFilter::all(),
+        )
+        .unwrap();
+        assert_eq!(ids(&carol), vec!["shared", "private"]);
+        assert_eq!(carol.objectives.len(), 2);
+    }
+
+    #[test]
+    fn test_filter_by_kind_and_date() {
+        let (_temp, storage) = storage();
+        let session = AuthorizedStorage::new(&storage, Some("carol@x.com"));
+
+        let feedback = ExportFilter::all().with_kinds("feedback").unwrap();
+        let export = Export::collect(&session, &feedback).unwrap();
+        assert_eq!(ids(&export), vec!["private"]);
+        assert!(export.objectives.is_empty());
+
+        let recent = ExportFilter::all().since(parse_since("2026-02-01").unwrap());
+        assert_eq!(
+            ids(&Export::collect(&session, &recent).unwrap()),
+            vec!["private"]
+        );
+
+        let okrs = ExportFilter::all().with_kinds("okr").unwrap();
+        let export = Export::collect(&session, &okrs).unwrap();
+        assert!(export.interactions.is_empty());
+        assert_eq!(export.objectives.len(), 2);
+
+        assert!(ExportFilter::all().with_kinds("gossip").is_err());
+        assert!(parse_since("last week").is_err());
+    }
+
+    #[test]
+    fn test_render_formats() {
+        let (_temp, storage) = storage();
+        let export = Export::collect(
+            &AuthorizedStorage::new(&storage, None),
+            &ExportFilter::all(),
+        )
+        .unwrap();
+
+        let csv = export.render(ExportFormat::Csv).unwrap();
+        let mut lines = csv.lines();
+        assert!(lines
+            .next()
+            .unwrap()
+            .starts_with("record,id,kind,timestamp"));
+        assert!(lines
+            .next()
+            .unwrap()
+            .starts_with("interaction,shared,appreciation,2026-01-05T09:00:00+00:00,alice@x.com,bob@x.com,Hi,true"));
+        assert!(lines.next().unwrap().starts_with("objective,"));
+
+        let json: serde_json::Value =
+            serde_json::from_str(&export.render(ExportFormat::Json).unwrap()).unwrap();
+        assert_eq!(json["interactions"][0]["kind"], "appreciation");
+        assert_eq!(json["objectives"][0]["title"], "Team goal");
+
+        let md = export.render(ExportFormat::Markdown).unwrap();
+        assert!(md.contains("| 2026-01-05 09:00 | Appreciation | alice@x.com | bob@x.com | Hi |"));
+        assert!(md.contains("| Team goal | team |  | 0% |"));
+
+        assert_eq!(
+            "md".parse::<ExportFormat>().unwrap(),
+            ExportFormat::Markdown
+        );
+        assert!("xml".parse::<ExportFormat>().is_err());
+    }
+}
@@ -0,0 +1,241 @@
+//! Thin wrapper around the git command line
+//!
+//! `.team/` lives in a git repository, so git already knows who changed
+//! what. These helpers shell out to `git` rather than linking a git library.
+
+use crate::{Error, Result};
+use chrono::{DateTime, Utc};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Name of the merge driver for .team/ YAML files in .gitattributes
+pub const MERGE_DRIVER: &str = "interactions";
+
+/// Marks a pre-commit hook written by [`install_pre_commit_hook`]
+const HOOK_MARKER: &str = "# interactions pre-commit hook";
+
+/// A commit with the author information needed for permission checks
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Commit {
+    /// Full commit hash
+    pub hash: String,
+    /// Author email as recorded in the commit
+    pub author_email: String,
+}
+
+/// A commit that touched some files, as shown by [`log`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogEntry {
+    /// Full commit hash
+    pub hash: String,
+    /// Author name as recorded in the commit
+    pub author_name: String,
+    /// Author email as recorded in the commit
+    pub author_email: String,
+    /// When the change was authored
+    pub date: DateTime<Utc>,
+    /// First line of the commit message
+    pub subject: String,
+    /// The files it changed among those asked for, relative to the repository
+    pub files: Vec<String>,
+}
+
+/// Run a git command in the given repository and return its stdout
+pub fn run(repo: &Path, args: &[&str]) -> Result<String> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(repo)
+        .args(args)
+        .output()
+        .map_err(|e| Error::Git(format!("failed to run git: {}", e)))?;
+
+    if !output.status.success() {
+        return Err(Error::Git(
+            String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        ));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// List the commits in a revision range, oldest first
+pub fn commits(repo: &Path, range: &str) -> Result<Vec<Commit>> {
+    let output = run(repo, &["log", "--reverse", "--format=%H%x09%ae", range])?;
+    Ok(output
+        .lines()
+        .filter_map(|line| line.split_once('\t'))
+        .map(|(hash, email)| Commit {
+            hash: hash.to_string(),
+            author_email: email.to_string(),
+        })
+        .collect())
+}
+
+/// List the commits that changed any of `paths`, newest first
+///
+/// Merge commits are left out; the commits they merge are listed instead.
+pub fn log(repo: &Path, paths: &[&str], limit: Option<usize>) -> Result<Vec<LogEntry>> {
+    let mut args = vec![
+        "log".to_string(),
+        "--no-merges".to_string(),
+        "--name-only".to_string(),
+        "--format=%x1e%H%x09%an%x09%ae%x09%aI%x09%s".to_string(),
+    ];
+    if let Some(limit) = limit {
+        args.push(format!("--max-count={}", limit));
+    }
+    args.push("--".to_string());
+    args.extend(paths.iter().map(|p| p.to_string()));
+    let args: Vec<&str> = args.iter().map(|a| a.as_str()).collect();
+
+    let output = match run(repo, &args) {
+        Ok(output) => output,
+        // A repository without commits has no history yet
+        Err(Error::Git(message)) if message.contains("does not have any commits") => {
+            return Ok(Vec::new())
+        }
+        Err(e) => return Err(e),
+    };
+    let mut entries = Vec::new();
+    for record in output.split('\x1e').filter(|r| !r.trim().is_empty()) {
+        let mut lines = record.lines();
+        let header: Vec<&str> = lines.next().unwrap_or_default().splitn(5, '\t').collect();
+        let [hash, name, email, date, subject] = header[..] else {
+            continue;
+        };
+        let Ok(date) = DateTime::parse_from_rfc3339(date) else {
+            continue;
+        };
+        entries.push(LogEntry {
+            hash: hash.to_string(),
+            author_name: name.to_string(),
+            author_email: email.to_string(),
+            date: date.with_timezone(&Utc),
+            subject: subject.to_string(),
+            files: lines
+                .filter(|l| !l.is_empty())
+                .map(|l| l.to_string())
+                .collect(),
+        });
+    }
+    Ok(entries)
+}
+
+/// List the paths changed by a commit
+pub fn changed_files(repo: &Path, hash: &str) -> Result<Vec<String>> {
+    let output = run(
+        repo,
+        &[
+            "diff-tree",
+            "--root",
+            "--no-commit-id",
+            "--name-only",
+            "-r",
+            hash,
+        ],
+    )?;
+    Ok(output.lines().map(|l| l.to_string()).collect())
+}
+
+/// Read a file as it was at a given revision, `None` if it did not exist
+pub fn show_file(repo: &Path, rev: &str, path: &str) -> Result<Option<String>> {
+    match run(repo, &["show", &format!("{}:{}", rev, path)]) {
+        Ok(content) => Ok(Some(content)),
+        Err(Error::Git(_)) => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+/// List the paths staged for the next commit, leaving out deletions
+pub fn staged_files(repo: &Path) -> Result<Vec<String>> {
+    let output = run(
+        repo,
+        &["diff", "--cached", "--name-only", "--diff-filter=d", "-z"],
+    )?;
+    Ok(output
+        .split('\0')
+        .filter(|p| !p.is_empty())
+        .map(|p| p.to_string())
+        .collect())
+}
+
+/// Install a pre-commit hook that runs `command lint --staged`
+///
+/// A pre-commit hook that was not installed by us is only replaced with
+/// `force`. Returns the path of the hook.
+pub fn install_pre_commit_hook(repo: &Path, command: &str, force: bool) -> Result<PathBuf> {
+    let hooks = PathBuf::from(run(repo, &["rev-parse", "--git-path", "hooks"])?.trim());
+    let hooks = if hooks.is_absolute() {
+        hooks
+    } else {
+        repo.join(hooks)
+    };
+    let path = hooks.join("pre-commit");
+
+    if let Ok(existing) = std::fs::read_to_string(&path) {
+        if !force && !existing.contains(HOOK_MARKER) {
+            return Err(Error::Git(format!(
+                "{} already exists; use --force to replace it",
+                path.display()
+            )));
+        }
+    }
+
+    let script = format!(
+        "#!/bin/sh\n\
+         {}\n\
+         # Blocks .personal/ and checks staged .team/ files. Reinstall with\n\
+         # 'interactions hooks install'; skip once with 'git commit --no-verify'.\n\
+         exec {} lint --staged\n",
+        HOOK_MARKER,
+        shell_quote(command)
+    );
+    std::fs::create_dir_all(&hooks)?;
+    std::fs::write(&path, script)?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o755))?;
+    }
+    Ok(path)
+}
+
+/// Tell git how to run the `interactions` merge driver in this clone
+///
+/// Merge drivers are configured in `.git/config`, which is not shared, so
+/// every clone needs this once.
+pub fn configure_merge_driver(repo: &Path, command: &str) -> Result<()> {
+    let name = format!("merge.{}.name", MERGE_DRIVER);
+    let driver = format!("merge.{}.driver", MERGE_DRIVER);
+    run(repo, &["config", &name, "interactions team data"])?;
+    run(
+        repo,
+        &[
+            "config",
+            &driver,
+            &format!("{} merge-driver %O %A %B %P", shell_quote(command)),
+        ],
+    )?;
+    Ok(())
+}
+
+/// Merge two files as text, leaving conflict markers in `current`
+///
+/// Returns whether the merge was clean.
+pub fn merge_file(current: &Path, base: &Path, other: &Path) -> Result<bool> {
+    let status = Command::new("git")
+        .args(["merge-file", "-L", "ours", "-L", "base", "-L", "theirs"])
+        .args([current, base, other])
+        .status()
+        .map_err(|e| Error::Git(format!("failed to run git: {}", e)))?;
+    match status.code() {
+        Some(0) => Ok(true),
+        // The number of conflicts
+        Some(code) if code > 0 => Ok(false),
+        _ => Err(Error::Git(format!("git merge-file failed: {}", status))),
+    }
+}
+
+/// Quote a value for `sh`
+fn shell_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', r"'\''"))
+}
@@ -0,0 +1,394 @@
+//! Who changed what in .team/
+//!
+//! `.team/` lives in a git repository, so every change to the manifesto,
+//! the team's roles or an objective is already recorded along with its
+//! author. This module reads that log for the files of interest, maps the
+//! commit authors to team members and compares each version of a file with
+//! the one before it, field by field.
+//!
+//! Like [`lint::lint_commits`](crate::lint::lint_commits), it expects the
+//! team directory to sit at the root of the repository.
+
+use std::fmt;
+
+use crate::diff::{self, FieldChange};
+use crate::git::{self, LogEntry};
+use crate::lint::{self, FileKind};
+use crate::{
+    normalize_email, same_email, Error, FeedbackRequest, Interaction, Member, Result, TeamStorage,
+};
+
+/// The files whose history is shown
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Scope {
+    /// Everything in .team/
+    Team,
+    /// One file or directory, relative to the team root
+    Path(String),
+    /// A member's profile and received interactions, and the changes to
+    /// their role
+    Member(String),
+    /// A team objective, by id
+    Objective(String),
+}
+
+impl Scope {
+    /// Work out what a command line argument refers to: a path, a member's
+    /// email or alias, or the id, id prefix or title of a team objective
+    pub fn parse(storage: &TeamStorage, arg: Option<&str>) -> Result<Self> {
+        let Some(arg) = arg.map(str::trim).filter(|a| !a.is_empty()) else {
+            return Ok(Self::Team);
+        };
+        let path = arg.trim_start_matches("./").replace('\\', "/");
+        if path.starts_with(".team") || storage.root().join(&path).exists() {
+            return Ok(Self::Path(path.trim_end_matches('/').to_string()));
+        }
+        if arg.contains('@') {
+            return Ok(Self::Member(storage.resolve_email(arg)?));
+        }
+
+        let objectives = storage.load_objectives(true)?;
+        let found = objectives
+            .iter()
+            .find(|o| o.id == arg || o.title.eq_ignore_ascii_case(arg))
+            .or_else(|| {
+                let mut matches = objectives.iter().filter(|o| o.id.starts_with(arg));
+                matches.next().filter(|_| matches.next().is_none())
+            });
+        match found {
+            Some(objective) => Ok(Self::Objective(objective.id.clone())),
+            None => Err(Error::InvalidInput(format!(
+                "No file, member or team objective matches '{}'",
+                arg
+            ))),
+        }
+    }
+
+    /// The paths to read the log of, relative to the team root
+    pub fn paths(&self, storage: &TeamStorage) -> Vec<String> {
+        let relative = |path: std::path::PathBuf| {
+            path.strip_prefix(storage.root())
+                .unwrap_or(&path)
+                .to_string_lossy()
+                .replace('\\', "/")
+        };
+        match self {
+            Self::Team => vec![".team".to_string()],
+            Self::Path(path) => vec![path.clone()],
+            Self::Member(email) => vec![
+                relative(storage.member_dir(email)),
+                ".team/config.yaml".to_string(),
+            ],
+            Self::Objective(id) => {
+                vec![relative(
+                    storage.okrs_dir(true).join(format!("{}.yaml", id)),
+                )]
+            }
+        }
+    }
+
+    /// Whether a change to a file is about what the scope is about
+    ///
+    /// For a member, changes to the team file only count when they add or
+    /// remove the member, e.g. from the leaders.
+    fn wants(&self, path: &str, change: &FieldChange) -> bool {
+        match self {
+            Self::Member(email) if path == ".team/config.yaml" => [&change.old, &change.new]
+                .into_iter()
+                .flatten()
+                .any(|value| normalize_email(value) == *email),
+            _ => true,
+        }
+    }
+}
+
+impl fmt::Display for Scope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Team => write!(f, "the team"),
+            Self::Path(path) => write!(f, "{}", path),
+            Self::Member(email) => write!(f, "{}", email),
+            Self::Objective(id) => write!(f, "objective {}", id),
+        }
+    }
+}
+
+/// How one file changed in a commit
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileChanges {
+    /// Path relative to the team root
+    pub path: String,
+    /// The fields that changed; empty when only formatting changed
+    pub changes: Vec<FieldChange>,
+}
+
+/// One commit in the history
+#[derive(Debug, Clone)]
+pub struct HistoryEntry {
+    /// The commit
+    pub commit: LogEntry,
+    /// The member who authored it, if their email or an alias is known
+    pub author: Option<Member>,
+    /// The files it changed
+    pub files: Vec<FileChanges>,
+}
+
+impl HistoryEntry {
+    /// Who made the change: the member's display name, or the commit's
+    /// author name for people who are not members
+    pub fn author_name(&self) -> &str {
+        match &self.author {
+            Some(member) => member.display_name(),
+            None => &self.commit.author_name,
+        }
+    }
+}
+
+/// The history of a scope, newest change first
+///
+/// Reads at most `limit` commits. Files that do not parse as YAML are
+/// listed without field changes. Credentials and reset tokens are never
+/// listed.
+pub fn history(
+    storage: &TeamStorage,
+    scope: &Scope,
+    limit: Option<usize>,
+) -> Result<Vec<HistoryEntry>> {
+    read_history(storage, scope, limit, false, None)
+}
+
+/// The history of a scope as a member may see it
+///
+/// Like [`history`], but private interactions and feedback requests are
+/// left out unless `viewer` took part in them; without a viewer, only
+/// shared ones are listed.
+pub fn history_for(
+    storage: &TeamStorage,
+    scope: &Scope,
+    limit: Option<usize>,
+    viewer: Option<&str>,
+) -> Result<Vec<HistoryEntry>> {
+    read_history(storage, scope, limit, true, viewer)
+}
+
+/// Whether a file's changes may be listed
+///
+/// `content` is the file after the commit, or before it if the commit
+/// deleted it. Private interactions and requests are only checked when
+/// `private` is set.
+fn readable(path: &str, content: &str, private: bool, viewer: Option<&str>) -> bool {
+    let is_viewer = |email: &str| viewer.is_some_and(|viewer| same_email(viewer, email));
+    match lint::classify(path) {
+        Some(FileKind::Credentials(_) | FileKind::ResetToken(_)) => false,
+        Some(FileKind::Interaction) if private => {
+            match serde_yaml::from_str::<Interaction>(content) {
+                Ok(i) => i.shared || is_viewer(&i.from) || i.with.iter().any(|e| is_viewer(e)),
+                Err(_) => false,
+            }
+        }
+        Some(FileKind::FeedbackRequest) if private => {
+            match serde_yaml::from_str::<FeedbackRequest>(content) {
+                Ok(r) => is_viewer(&r.from) || is_viewer(&r.to),
+                Err(_) => false,
+            }
+        }
+        _ => true,
+    }
+}
+
+fn read_history(
+    storage: &TeamStorage,
+    scope: &Scope,
+    limit: Option<usize>,
+    private: bool,
+    viewer: Option<&str>,
+) -> Result<Vec<HistoryEntry>> {
+    let repo = storage.root();
+    let paths = scope.paths(storage);
+    let paths: Vec<&str> = paths.iter().map(|p| p.as_str()).collect();
+    let members = storage.load_members()?;
+
+    let mut entries = Vec::new();
+    for commit in git::log(repo, &paths, limit)? {
+        let parent = format!("{}^", commit.hash);
+        let mut files = Vec::new();
+        for path in commit.files.iter().filter(|p| p.ends_with(".yaml")) {
+            let before = git::show_file(repo, &parent, path)?.unwrap_or_default();
+            let after = git::show_file(repo, &commit.hash, path)?.unwrap_or_default();
+            let content = if after.is_empty() { &before } else { &after };
+            if !readable(path, content, private, viewer) {
+                continue;
+            }
+            let changes: Vec<FieldChange> = diff::diff(&before, &after)
+                .unwrap_or_default()
+                .into_iter()
+                .filter(|change| scope.wants(path, change))
+                .collect();
+            // The team file only matters to a member where it names them
+            if changes.is_empty()
+                && matches!(scope, Scope::Member(_))
+                && path == ".team/config.yaml"
+            {
+                continue;
+            }
+            files.push(FileChanges {
+                path: path.clone(),
+                changes,
+            });
+        }
+        if files.is_empty() {
+            continue;
+        }
+        let author = members
+            .iter()
+            .find(|m| m.has_email(&commit.author_email))
+            .cloned();
+        entries.push(HistoryEntry {
+            commit,
+            author,
+            files,
+        });
+    }
+    Ok(entries)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{KeyResult, Objective, OkrVisibility, Team};
+    use std::path::Path;
+    use std::process::Command;
+    use tempfile::TempDir;
+
+    fn git(dir: &Path, args: &[&str]) {
+        let status = Command::new("git")
+            .arg("-C")
+            .arg(dir)
+            .args(args)
+            .status()
+            .unwrap();
+        assert!(status.success());
+    }
+
+    fn commit_as(dir: &Path, email: &str, message: &str) {
+        git(dir, &["config", "user.email", email]);
+        git(dir, &["add", "-A"]);
+        git(dir, &["commit", "-qm", message]);
+    }
+
+    #[test]
+    fn test_history() {
+        let temp = TempDir::new().unwrap();
+        let dir = temp.path();
+        git(dir, &["init", "-q"]);
+        git(dir, &["config", "user.name", "Someone"]);
+        let storage = TeamStorage::new(dir);
+        assert!(history(&storage, &Scope::Team, None).unwrap().is_empty());
+
+        storage.initialize().unwrap();
+        let team = Team::new("T")
+            .add_leader("lead@x.com")
+            .add_member("b@x.com");
+        storage.save_team(&team).unwrap();
+        storage
+            .save_member(
+                &Member::new("lead@x.com")
+                    .with_name("Lea")
+                    .with_alias("lea@home.com"),
+            )
+            .unwrap();
+        storage.save_member(&Member::new("b@x.com")).unwrap();
+        let mut goal = Objective::new("Ship v2").with_visibility(OkrVisibility::Shared);
+        goal.add_key_result(KeyResult::new("Beta"));
+        storage.save_objective(&goal).unwrap();
+        commit_as(dir, "lead@x.com", "Create team");
+
+        let mut promoted = team.clone();
+        promoted.promote("b@x.com");
+        storage.save_team(&promoted).unwrap();
+        goal.key_results[0].set_progress(0.5);
+        storage.save_objective(&goal).unwrap();
+        commit_as(dir, "lea@home.com", "Promote b, move Beta");
+
+        let entries = history(&storage, &Scope::Team, None).unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].commit.subject, "Promote b, move Beta");
+        // The alias maps to the member
+        assert_eq!(entries[0].author_name(), "Lea");
+
+        let scope = Scope::parse(&storage, Some("Ship v2")).unwrap();
+        assert_eq!(scope, Scope::Objective(goal.id.clone()));
+        let entries = history(&storage, &scope, None).unwrap();
+        assert_eq!(entries.len(), 2);
+        let changes: Vec<String> = entries[0].files[0]
+            .changes
+            .iter()
+            .map(|c| c.to_string())
+            .collect();
+        assert_eq!(changes, vec!["key_results[Beta].progress: 0.0 → 0.5"]);
+
+        // A member's history shows their role change but not other team edits
+        let scope = Scope::parse(&storage, Some("B@x.com")).unwrap();
+        let entries = history(&storage, &scope, Some(1)).unwrap();
+        let changes: Vec<String> = entries[0].files[0]
+            .changes
+            .iter()
+            .map(|c| c.to_string())
+            .collect();
+        assert_eq!(changes, vec!["leaders: + b@x.com", "members: - b@x.com"]);
+
+        assert!(Scope::parse(&storage, Some("nothing")).is_err());
+    }
+
+    #[test]
+    fn test_history_for_hides_private_files() {
+        let temp = TempDir::new().unwrap();
+        let dir = temp.path();
+        git(dir, &["init", "-q"]);
+        git(dir, &["config", "user.name", "Someone"]);
+        let storage = TeamStorage::new(dir);
+        storage.initialize().unwrap();
+        let team = Team::new("T")
+            .add_leader("lead@x.com")
+            .add_member("b@x.com")
+            .add_member("c@x.com");
+        storage.save_team(&team).unwrap();
+        for email in ["lead@x.com", "b@x.com", "c@x.com"] {
+            storage.save_member(&Member::new(email)).unwrap();
+        }
+        commit_as(dir, "lead@x.com", "Create team");
+
+        let private = Interaction::appreciation("lead@x.com", vec!["b@x.com".to_string()], "Hi");
+        storage.save_kudos(&private).unwrap();
+        storage
+            .save_feedback_request(&FeedbackRequest::new("b@x.com", "lead@x.com"))
+            .unwrap();
+        storage
+            .save_credentials(&crate::MemberCredentials::new("c@x.com", "1234").unwrap())
+            .unwrap();
+        storage.issue_reset_token("b@x.com", "lead@x.com").unwrap();
+        commit_as(dir, "lead@x.com", "Private things");
+
+        let paths = |viewer: Option<&str>| -> Vec<String> {
+            history_for(&storage, &Scope::Team, Some(1), viewer)
+                .unwrap()
+                .iter()
+                .flat_map(|e| e.files.iter().map(|f| f.path.clone()))
+                .collect()
+        };
+        let kudos = format!(".team/members/b@x.com/kudos/{}.yaml", private.id);
+        assert!(paths(Some("B@x.com")).contains(&kudos));
+        assert_eq!(paths(Some("lead@x.com")).len(), 2);
+        // Neither the kudos nor the request is theirs
+        assert!(paths(Some("c@x.com")).is_empty());
+        assert!(paths(None).is_empty());
+
+        // Credentials and reset tokens are left out even without a viewer
+        let all = history(&storage, &Scope::Team, Some(1)).unwrap();
+        let files: Vec<&str> = all[0].files.iter().map(|f| f.path.as_str()).collect();
+        assert_eq!(files.len(), 2);
+        assert!(files
+            .iter()
+            .all(|f| !f.ends_with("credentials.yaml") && !f.ends_with("reset.yaml")));
+    }
+}
@@ -0,0 +1,601 @@
+//! Import
+//!
+//! Turns appreciation history kept elsewhere, in a Slack export or a CSV
+//! tracker, into interactions. People are mapped to member emails with a
+//! mapping file, falling back to matching team members by email, alias or
+//! name. Planning an import writes nothing, so it doubles as a dry run.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use chrono::{DateTime, NaiveDate, NaiveDateTime, TimeZone, Utc};
+use regex::Regex;
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+
+use crate::{recipients, Error, Interaction, InteractionKind, Member, Result};
+
+/// Slack messages that count as kudos when the mapping sets no pattern
+pub const DEFAULT_KUDOS_PATTERN: &str =
+    r"(?i)\b(kudos|thanks|thank you|shout-?out)\b|:tada:|:clap:|:raised_hands:";
+
+/// How people in the source map to member emails
+///
+/// ```yaml
+/// users:
+///   U024BE7LH: alice@example.com
+///   Bob de Vries: bob@example.com
+/// kudos_pattern: "(?i)kudos|:tada:"
+/// ```
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ImportMapping {
+    /// Slack user ids, Slack names or CSV names, to member emails
+    #[serde(default)]
+    pub users: BTreeMap<String, String>,
+
+    /// Regular expression for Slack messages that count as kudos
+    #[serde(default)]
+    pub kudos_pattern: Option<String>,
+}
+
+impl ImportMapping {
+    /// Load a mapping file
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)?;
+        Ok(serde_yaml::from_str(&content)?)
+    }
+
+    /// Check that every mapped email belongs to a team member
+    ///
+    /// A typo in the mapping would otherwise file history under an email
+    /// nobody logs in with.
+    pub fn validate(&self, members: &[Member]) -> Result<()> {
+        let unknown: Vec<&str> = self
+            .users
+            .values()
+            .filter(|email| !members.iter().any(|m| m.has_email(email)))
+            .map(String::as_str)
+            .collect();
+        if unknown.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::InvalidInput(format!(
+                "Mapping refers to emails that are not team members: {}",
+                unknown.join(", ")
+            )))
+        }
+    }
+
+    /// The email mapped to a source name, ignoring case
+    fn lookup(&self, key: &str) -> Option<&str> {
+        self.users
+            .iter()
+            .find(|(k, _)| k.eq_ignore_ascii_case(key.trim()))
+            .map(|(_, email)| email.as_str())
+    }
+
+    /// Map a source name to a member email
+    fn resolve(&self, key: &str, members: &[Member]) -> Option<String> {
+        if let Some(email) = self.lookup(key) {
+            return Some(email.to_string());
+        }
+        recipients::resolve(members, key).map(|m| m.email.clone())
+    }
+
+    fn kudos_regex(&self) -> Result<Regex> {
+        let pattern = self
+            .kudos_pattern
+            .as_deref()
+            .unwrap_or(DEFAULT_KUDOS_PATTERN);
+        Regex::new(pattern)
+            .map_err(|e| Error::InvalidInput(format!("Invalid kudos pattern: {}", e)))
+    }
+}
+
+/// A source record that will not be imported
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Skipped {
+    /// Where the record came from, e.g. "general/2021-03-01.json" or "row 4"
+    pub source: String,
+    /// Why it is skipped
+    pub reason: String,
+}
+
+/// What an import would create
+#[derive(Debug, Clone, Default)]
+pub struct ImportPlan {
+    /// Interactions to create
+    pub interactions: Vec<Interaction>,
+    /// Records that could not be mapped
+    pub skipped: Vec<Skipped>,
+}
+
+impl ImportPlan {
+    /// A human-readable preview for dry runs
+    pub fn preview(&self) -> String {
+        let mut out = format!("{} interaction(s) to import\n", self.interactions.len());
+        for i in &self.interactions {
+            out.push_str(&format!(
+                "  {} {} from {} to {}: {}\n",
+                i.timestamp.format("%Y-%m-%d %H:%M"),
+                i.kind.label(),
+                i.from,
+                i.with.join(", "),
+                truncate(&i.note.replace('\n', " "), 60),
+            ));
+        }
+        if !self.skipped.is_empty() {
+            out.push_str(&format!("{} record(s) skipped\n", self.skipped.len()));
+            for s in &self.skipped {
+                out.push_str(&format!("  {}: {}\n", s.source, s.reason));
+            }
+        }
+        out
+    }
+}
+
+/// Plan an import of kudos from a Slack export
+///
+/// `path` is the unzipped export directory (channel folders of daily JSON
+/// files, with users.json at the top) or a single channel JSON file.
+/// Messages matching the kudos pattern that mention someone become shared
+/// appreciations from the poster to everyone mentioned.
+pub fn plan_slack(path: &Path, mapping: &ImportMapping, members: &[Member]) -> Result<ImportPlan> {
+    mapping.validate(members)?;
+    let pattern = mapping.kudos_regex()?;
+    let slack_users = read_slack_users(path)?;
+    let mention = Regex::new(r"<@([A-Z0-9]+)(?:\|[^>]*)?>").expect("valid regex");
+
+    let mut files = Vec::new();
+    collect_json_files(path, &mut files)?;
+    files.sort();
+
+    let mut plan = ImportPlan::default();
+    for file in files {
+        let source = file
+            .strip_prefix(path)
+            .ok()
+            .filter(|p| !p.as_os_str().is_empty())
+            .unwrap_or(&file)
+            .display()
+            .to_string();
+        let content = std::fs::read_to_string(&file)?;
+        let Ok(serde_json::Value::Array(messages)) = serde_json::from_str(&content) else {
+            continue;
+        };
+
+        for message in messages.iter().filter_map(|m| m.as_object()) {
+            let text = message.get("text").and_then(|t| t.as_str()).unwrap_or("");
+            let (Some(user), Some(ts)) = (
+                message.get("user").and_then(|u| u.as_str()),
+                message.get("ts").and_then(|t| t.as_str()),
+            ) else {
+                continue;
+            };
+            if !pattern.is_match(text) {
+                continue;
+            }
+            let mentioned: Vec<&str> = mention
+                .captures_iter(text)
+                .map(|c| c.get(1).expect("group").as_str())
+                .filter(|id| *id != user)
+                .collect();
+            if mentioned.is_empty() {
+                continue;
+            }
+
+            let record = format!("{} at {}", source, ts);
+            let resolve = |id: &str| -> Option<String> {
+                let slack = slack_users.get(id);
+                mapping
+                    .lookup(id)
+                    .map(str::to_string)
+                    .or_else(|| slack.and_then(|u| u.email.clone()))
+                    .or_else(|| slack.and_then(|u| mapping.resolve(&u.name, members)))
+                    .or_else(|| {
+                        slack
+                            .and_then(|u| u.real_name.as_deref())
+                            .and_then(|n| mapping.resolve(n, members))
+                    })
+            };
+
+            let Some(from) = resolve(user) else {
+                plan.skipped.push(unmapped(record, user, &slack_users));
+                continue;
+            };
+            let mut with = Vec::new();
+            let mut missing = None;
+            for id in &mentioned {
+                match resolve(id) {
+                    Some(email) if !with.contains(&email) => with.push(email),
+                    Some(_) => {}
+                    None => missing = Some(*id),
+                }
+            }
+            if let Some(id) = missing {
+                plan.skipped.push(unmapped(record, id, &slack_users));
+                continue;
+            }
+            let Some(timestamp) = parse_slack_ts(ts) else {
+                plan.skipped.push(Skipped {
+                    source: record,
+                    reason: format!("unreadable timestamp '{}'", ts),
+                });
+                continue;
+            };
+
+            // Show mentions as names rather than Slack ids
+            let note = mention.replace_all(text, |c: &regex::Captures| {
+                let id = &c[1];
+                match slack_users.get(id) {
+                    Some(u) => format!("@{}", u.real_name.as_deref().unwrap_or(&u.name)),
+                    None => format!("@{}", id),
+                }
+            });
+
+            let mut interaction = Interaction::appreciation(from, with, note.trim()).shared();
+            interaction.id = import_id("slack", &[&source, ts]);
+            interaction.timestamp = timestamp;
+            interaction.imported_from = Some("slack".to_string());
+            plan.interactions.push(interaction);
+        }
+    }
+    plan.interactions.sort_by_key(|i| i.timestamp);
+    Ok(plan)
+}
+
+/// Plan an import from a CSV tracker
+///
+/// The header names the columns, in any order and case:
+/// - `date` (or `timestamp`, `when`): RFC 3339, `YYYY-MM-DD HH:MM` or `YYYY-MM-DD`, in UTC
+/// - `from` (or `giver`, `sender`)
+/// - `to` (or `with`, `recipient`, `recipients`): several separated by `;` or `,`
+/// - `note` (or `message`, `text`, `reason`)
+/// - optional `kind` (appreciation by default) and `shared` (true by default)
+pub fn plan_csv(content: &str, mapping: &ImportMapping, members: &[Member]) -> Result<ImportPlan> {
+    mapping.validate(members)?;
+    let mut reader = csv::ReaderBuilder::new()
+        .trim(csv::Trim::All)
+        .from_reader(content.as_bytes());
+    let headers: Vec<String> = reader.headers()?.iter().map(|h| h.to_lowercase()).collect();
+    let column = |names: &[&str]| headers.iter().position(|h| names.contains(&h.as_str()));
+    let required = |names: &[&str]| {
+        column(names)
+            .ok_or_else(|| Error::InvalidInput(format!("CSV needs a '{}' column", names[0])))
+    };
+    let date_col = required(&["date", "timestamp", "when"])?;
+    let from_col = required(&["from", "giver", "sender"])?;
+    let to_col = required(&["to", "with", "recipient", "recipients"])?;
+    let note_col = required(&["note", "message", "text", "reason"])?;
+    let kind_col = column(&["kind", "type"]);
+    let shared_col = column(&["shared", "public"]);
+
+    let mut plan = ImportPlan::default();
+    for (index, row) in reader.records().enumerate() {
+        // Row 1 is the header
+        let source = format!("row {}", index + 2);
+        let row = row?;
+        let field = |col: usize| row.get(col).unwrap_or("").to_string();
+        let skip = |reason: String| Skipped {
+            source: source.clone(),
+            reason,
+        };
+
+        let Some(timestamp) = parse_date(&field(date_col)) else {
+            plan.skipped
+                .push(skip(format!("unreadable date '{}'", field(date_col))));
+            continue;
+        };
+        let kind = match kind_col.map(field).filter(|k| !k.is_empty()) {
+            None => InteractionKind::Appreciation,
+            Some(k) if k.eq_ignore_ascii_case("kudos") => InteractionKind::Appreciation,
+            Some(k) => match serde_yaml::from_str::<InteractionKind>(&k.to_lowercase()) {
+                Ok(kind) => kind,
+                Err(_) => {
+                    plan.skipped.push(skip(format!("unknown kind '{}'", k)));
+                    continue;
+                }
+            },
+        };
+        let shared = shared_col
+            .map(field)
+            .is_none_or(|s| !matches!(s.to_lowercase().as_str(), "false" | "no" | "n" | "0"));
+
+        let Some(from) = mapping.resolve(&field(from_col), members) else {
+            plan.skipped
+                .push(skip(format!("no member for '{}'", field(from_col))));
+            continue;
+        };
+        let names = field(to_col);
+        let mut with = Vec::new();
+        let mut missing = None;
+        for name in names
+            .split([';', ','])
+            .map(str::trim)
+            .filter(|n| !n.is_empty())
+        {
+            match mapping.resolve(name, members) {
+                Some(email) if !with.contains(&email) => with.push(email),
+                Some(_) => {}
+                None => missing = Some(name.to_string()),
+            }
+        }
+        if let Some(name) = missing {
+            plan.skipped.push(skip(format!("no member for '{}'", name)));
+            continue;
+        }
+        if with.is_empty() {
+            plan.skipped.push(skip("no recipient".to_string()));
+            continue;
+        }
+
+        let mut interaction = Interaction::new(kind, from, with, field(note_col));
+        interaction.id = import_id(
+            "csv",
+            &[&field(date_col), &field(from_col), &names, &field(note_col)],
+        );
+        interaction.timestamp = timestamp;
+        interaction.shared = shared;
+        interaction.imported_from = Some("csv".to_string());
+        plan.interactions.push(interaction);
+    }
+    plan.interactions.sort_by_key(|i| i.timestamp);
+    Ok(plan)
+}
+
+/// A Slack user from users.json
+#[derive(Debug, Clone, Default)]
+struct SlackUser {
+    name: String,
+    real_name: Option<String>,
+    email: Option<String>,
+}
+
+/// Read users.json next to (or at the top of) a Slack export, if present
+fn read_slack_users(path: &Path) -> Result<BTreeMap<String, SlackUser>> {
+    let dir = if path.is_dir() {
+        path
+    } else {
+        path.parent()
+            .and_then(Path::parent)
+            .unwrap_or(Path::new("."))
+    };
+    let file = dir.join("users.json");
+    if !file.exists() {
+        return Ok(BTreeMap::new());
+    }
+    let users: Vec<serde_json::Value> = serde_json::from_str(&std::fs::read_to_string(file)?)?;
+    let text = |v: &serde_json::Value, key: &str| {
+        v.get(key)
+            .and_then(|s| s.as_str())
+            .filter(|s| !s.is_empty())
+            .map(str::to_string)
+    };
+    Ok(users
+        .iter()
+        .filter_map(|u| {
+            let profile = u.get("profile").cloned().unwrap_or_default();
+            Some((
+                text(u, "id")?,
+                SlackUser {
+                    name: text(u, "name").unwrap_or_default(),
+                    real_name: text(u, "real_name").or_else(|| text(&profile, "real_name")),
+                    email: text(&profile, "email"),
+                },
+            ))
+        })
+        .collect())
+}
+
+/// Channel message files in a Slack export, or the file itself
+fn collect_json_files(path: &Path, files: &mut Vec<std::path::PathBuf>) -> Result<()> {
+    if path.is_file() {
+        files.push(path.to_path_buf());
+        return Ok(());
+    }
+    for entry in std::fs::read_dir(path)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect_json_files(&path, files)?;
+        } else if path.extension().and_then(|e| e.to_str()) == Some("json") {
+            // Workspace metadata sits next to the channel folders
+            let top_level = matches!(
+                path.file_name().and_then(|n| n.to_str()),
+                Some(
+                    "users.json"
+                        | "channels.json"
+                        | "groups.json"
+                        | "dms.json"
+                        | "mpims.json"
+                        | "integration_logs.json"
+                        | "canvases.json"
+                )
+            );
+            if !top_level {
+                files.push(path);
+            }
+        }
+    }
+    Ok(())
+}
+
+fn unmapped(source: String, id: &str, users: &BTreeMap<String, SlackUser>) -> Skipped {
+    let who = match users.get(id) {
+        Some(u) => format!("{} ({})", id, u.real_name.as_deref().unwrap_or(&u.name)),
+        None => id.to_string(),
+    };
+    Skipped {
+        source,
+        reason: format!("no member for Slack user {}", who),
+    }
+}
+
+/// Parse a Slack timestamp such as "1612345678.000200"
+fn parse_slack_ts(ts: &str) -> Option<DateTime<Utc>> {
+    let (secs, micros) = ts.split_once('.').unwrap_or((ts, "0"));
+    let micros: u32 = format!("{:0<6}", micros).get(..6)?.parse().ok()?;
+    Utc.timestamp_opt(secs.parse().ok()?, micros * 1000)
+        .single()
+}
+
+/// Parse a CSV date: RFC 3339, "YYYY-MM-DD HH:MM[:SS]" or "YYYY-MM-DD", in UTC
+fn parse_date(value: &str) -> Option<DateTime<Utc>> {
+    if let Ok(t) = DateTime::parse_from_rfc3339(value) {
+        return Some(t.with_timezone(&Utc));
+    }
+    for format in ["%Y-%m-%d %H:%M:%S", "%Y-%m-%d %H:%M"] {
+        if let Ok(t) = NaiveDateTime::parse_from_str(value, format) {
+            return Some(t.and_utc());
+        }
+    }
+    NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .ok()
+        .and_then(|d| d.and_hms_opt(0, 0, 0))
+        .map(|t| t.and_utc())
+}
+
+/// A stable id derived from the source record, so re-imports are skipped
+fn import_id(prefix: &str, parts: &[&str]) -> String {
+    let mut hasher = Sha256::new();
+    for part in parts {
+        hasher.update(part.as_bytes());
+        hasher.update([0]);
+    }
+    format!("{}-{}", prefix, &hex::encode(hasher.finalize())[..16])
+}
+
+fn truncate(text: &str, max: usize) -> String {
+    if text.chars().count() <= max {
+        text.to_string()
+    } else {
+        let cut: String = text.chars().take(max.saturating_sub(1)).collect();
+        format!("{}…", cut)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AuthorizedStorage, Team, TeamConfig, TeamStorage};
+    use tempfile::TempDir;
+
+    fn members() -> Vec<Member> {
+        vec![
+            Member::new("alice@example.com").with_name("Alice Jansen"),
+            Member::new("bob@example.com").with_name("Bob de Vries"),
+        ]
+    }
+
+    fn slack_export(dir: &Path) {
+        std::fs::write(
+            dir.join("users.json"),
+            r#"[
+                {"id": "UALICE", "name": "alice", "profile": {"email": "alice@example.com"}},
+                {"id": "UBOB", "name": "bobby", "real_name": "Bob de Vries", "profile": {}},
+                {"id": "UCAROL", "name": "carol", "profile": {}}
+            ]"#,
+        )
+        .unwrap();
+        std::fs::create_dir(dir.join("kudos")).unwrap();
+        std::fs::write(
+            dir.join("kudos/2021-03-01.json"),
+            r#"[
+                {"type": "message", "user": "UALICE", "ts": "1614600000.000100",
+                 "text": "Kudos to <@UBOB> for the release :tada:"},
+                {"type": "message", "user": "UBOB", "ts": "1614600100.000200",
+                 "text": "lunch anyone?"},
+                {"type": "message", "user": "UBOB", "ts": "1614600200.000300",
+                 "text": "thanks <@UCAROL|carol>!"}
+            ]"#,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_plan_slack() {
+        let temp = TempDir::new().unwrap();
+        slack_export(temp.path());
+
+        let plan = plan_slack(temp.path(), &ImportMapping::default(), &members()).unwrap();
+        assert_eq!(plan.interactions.len(), 1);
+        let kudos = &plan.interactions[0];
+        assert_eq!(kudos.from, "alice@example.com");
+        // Bob has no email in Slack but his real name matches a member
+        assert_eq!(kudos.with, vec!["bob@example.com"]);
+        assert_eq!(kudos.note, "Kudos to @Bob de Vries for the release :tada:");
+        assert_eq!(
+            kudos.timestamp.to_rfc3339(),
+            "2021-03-01T12:00:00.000100+00:00"
+        );
+        assert!(kudos.shared);
+
+        // Carol is not a member and not mapped
+        assert_eq!(plan.skipped.len(), 1);
+        assert!(plan.skipped[0].reason.contains("UCAROL"));
+
+        // A mapping and a custom pattern change what is imported
+        let mapping: ImportMapping = serde_yaml::from_str(
+            "users:\n  UCAROL: alice@example.com\nkudos_pattern: \"(?i)thanks\"\n",
+        )
+        .unwrap();
+        let plan = plan_slack(temp.path(), &mapping, &members()).unwrap();
+        assert_eq!(plan.interactions.len(), 1);
+        assert_eq!(plan.interactions[0].from, "bob@example.com");
+        assert_eq!(plan.interactions[0].with, vec!["alice@example.com"]);
+    }
+
+    #[test]
+    fn test_plan_csv() {
+        let csv = "Date,From,To,Message,Shared\n\
+                   2020-05-04,Alice Jansen,bob@example.com; Alice Jansen,Great demo,yes\n\
+                   2020-05-05 14:30,bob,alice@example.com,Thanks!,no\n\
+                   yesterday,Alice Jansen,bob@example.com,Hmm,\n";
+        let mapping: ImportMapping =
+            serde_yaml::from_str("users:\n  bob: bob@example.com\n").unwrap();
+
+        let plan = plan_csv(csv, &mapping, &members()).unwrap();
+        assert_eq!(plan.interactions.len(), 2);
+        let first = &plan.interactions[0];
+        assert_eq!(first.from, "alice@example.com");
+        assert_eq!(first.with, vec!["bob@example.com", "alice@example.com"]);
+        assert_eq!(first.timestamp.to_rfc3339(), "2020-05-04T00:00:00+00:00");
+        assert!(first.shared);
+        assert!(!plan.interactions[1].shared);
+        assert_eq!(plan.skipped[0].source, "row 4");
+
+        assert!(plan_csv("when,who\n", &mapping, &members()).is_err());
+
+        // Mapping to someone who is not on the team is a mistake, not a skip
+        let typo: ImportMapping = serde_yaml::from_str("users:\n  bob: bob@exmaple.com\n").unwrap();
+        let result = plan_csv(csv, &typo, &members());
+        assert!(matches!(result, Err(Error::InvalidInput(m)) if m.contains("bob@exmaple.com")));
+        assert!(plan.preview().contains("2 interaction(s) to import"));
+    }
+
+    #[test]
+    fn test_import_is_idempotent_and_leader_only() {
+        let temp = TempDir::new().unwrap();
+        let storage = TeamStorage::new(temp.path());
+        let team = Team::new("T")
+            .add_leader("alice@example.com")
+            .add_member("bob@example.com");
+        storage
+            .initialize_team(&team, &TeamConfig::new(), &members()[0], "1234")
+            .unwrap();
+
+        let csv = "date,from,to,note\n2020-05-04,bob@example.com,alice@example.com,Thanks\n";
+        let plan = plan_csv(csv, &ImportMapping::default(), &members()).unwrap();
+
+        let member = AuthorizedStorage::new(&storage, Some("bob@example.com"));
+        assert!(member.import_interactions(&plan.interactions).is_err());
+
+        let leader = AuthorizedStorage::new(&storage, Some("alice@example.com"));
+        assert_eq!(leader.import_interactions(&plan.interactions).unwrap(), 1);
+        assert_eq!(leader.import_interactions(&plan.interactions).unwrap(), 0);
+
+        let received = storage.load_received_kudos("alice@example.com").unwrap();
+        assert_eq!(received.len(), 1);
+        assert_eq!(received[0].imported_from.as_deref(), Some("csv"));
+        assert!(storage.load_sent_kudos().unwrap().is_empty());
+    }
+}
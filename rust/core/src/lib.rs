@@ -3,12 +3,32 @@
 //! This library provides the domain models and business logic for
 //! personal and team development goals across organizations and communities.
 
+pub mod analytics;
 pub mod auth;
+pub mod diff;
 pub mod error;
+pub mod events;
+pub mod export;
+pub mod git;
+pub mod history;
+pub mod import;
+pub mod lint;
+pub mod merge;
 pub mod models;
+pub mod outbox;
+pub mod permissions;
+pub mod publish;
+pub mod pulse;
+pub mod recipients;
+pub mod secrets;
 pub mod storage;
+pub mod sync;
+pub mod time;
+pub mod webhook;
 
-pub use auth::{Credentials, MemberCredentials};
+pub use auth::{Credentials, MemberCredentials, PincodeAttempts, ResetToken};
 pub use error::{Error, Result};
+pub use events::{Change, Event, EventBus};
 pub use models::*;
-pub use storage::TeamStorage;
+pub use permissions::{Action, Role};
+pub use storage::{AuthorizedStorage, TeamStorage};
@@ -0,0 +1,576 @@
+//! Validation of the .team/ structure
+//!
+//! Used by `interactions lint` on pull requests: every YAML file must parse
+//! into its model, hold no literal credentials, and every commit must
+//! respect the same role-based permissions that
+//! [`AuthorizedStorage`](crate::AuthorizedStorage) enforces.
+
+use crate::auth::{MemberCredentials, ResetToken};
+use crate::permissions::{authorize, Action, Role};
+use crate::secrets;
+use crate::sync;
+use crate::{
+    git, normalize_email, same_email, FeedbackRequest, Interaction, Manifesto, Member, Objective,
+    Result, Team, TeamStorage,
+};
+use serde::de::DeserializeOwned;
+use std::fmt;
+use std::path::Path;
+
+/// A problem found while linting
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LintIssue {
+    /// Path of the offending file, relative to the repository root
+    pub path: String,
+    /// What is wrong
+    pub message: String,
+}
+
+impl LintIssue {
+    fn new(path: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for LintIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.path, self.message)
+    }
+}
+
+/// The kind of file a path under .team/ holds
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum FileKind<'a> {
+    Team,
+    Manifesto,
+    Vision,
+    Profile(&'a str),
+    Credentials(&'a str),
+    ResetToken(&'a str),
+    Interaction,
+    FeedbackRequest,
+    Objective,
+    Other,
+}
+
+/// Classify a repository-relative path, `None` if it is outside .team/
+pub(crate) fn classify(path: &str) -> Option<FileKind<'_>> {
+    let rest = path.strip_prefix(".team/")?;
+    let parts: Vec<&str> = rest.split('/').collect();
+    let kind = match parts.as_slice() {
+        ["config.yaml"] => FileKind::Team,
+        ["manifesto.yaml"] => FileKind::Manifesto,
+        ["vision.yaml"] => FileKind::Vision,
+        ["members", email, "profile.yaml"] => FileKind::Profile(email),
+        ["members", email, "credentials.yaml"] => FileKind::Credentials(email),
+        ["members", email, "reset.yaml"] => FileKind::ResetToken(email),
+        ["members", _, "kudos" | "feedback", _] => FileKind::Interaction,
+        ["members", _, "requests", _] => FileKind::FeedbackRequest,
+        ["team", "interactions", _] => FileKind::Interaction,
+        ["team", "okrs", _] => FileKind::Objective,
+        _ => FileKind::Other,
+    };
+    Some(kind)
+}
+
+/// Validate that every YAML file under .team/ parses into its model
+pub fn lint_storage(storage: &TeamStorage) -> Result<Vec<LintIssue>> {
+    let mut issues = Vec::new();
+    let root = storage.team_dir();
+    if !root.exists() {
+        issues.push(LintIssue::new(".team", "Team directory not found"));
+        return Ok(issues);
+    }
+
+    let mut files = Vec::new();
+    collect_yaml_files(&root, &mut files)?;
+    files.sort();
+
+    for file in files {
+        let relative = match file.strip_prefix(root.parent().unwrap_or(&root)) {
+            Ok(p) => p.to_string_lossy().replace('\\', "/"),
+            Err(_) => continue,
+        };
+        if let Some(original) = file
+            .file_name()
+            .and_then(|name| name.to_str())
+            .and_then(sync::original_name)
+        {
+            issues.push(LintIssue::new(
+                &relative,
+                format!(
+                    "Sync conflict copy of {}; resolve with 'interactions conflicts'",
+                    original
+                ),
+            ));
+            continue;
+        }
+        let content = std::fs::read_to_string(&file)?;
+        issues.extend(lint_file(&relative, &content));
+    }
+
+    // One person should have one directory; other addresses go in aliases
+    let mut profiles = Vec::new();
+    for email in storage.list_members()? {
+        if email != normalize_email(&email) {
+            issues.push(LintIssue::new(
+                format!(".team/members/{}", email),
+                "Member directory name is not a normalised (lowercase) email",
+            ));
+        }
+        if let Ok(Some(member)) = storage.load_member(&email) {
+            profiles.push(member);
+        }
+    }
+    for member in &profiles {
+        for other in &profiles {
+            if !same_email(&member.email, &other.email) && member.aliases.contains(&other.email) {
+                issues.push(LintIssue::new(
+                    format!(".team/members/{}", other.email),
+                    format!(
+                        "{} is an alias of {}; run 'interactions merge-members {} {}'",
+                        other.email, member.email, other.email, member.email
+                    ),
+                ));
+            }
+        }
+    }
+
+    if let Ok(Some(team)) = storage.load_team() {
+        for email in team.leaders.iter().chain(team.members.iter()) {
+            if !storage.member_dir(email).join("profile.yaml").exists() {
+                issues.push(LintIssue::new(
+                    ".team/config.yaml",
+                    format!("{} is listed in the team but has no profile", email),
+                ));
+            }
+        }
+    }
+
+    Ok(issues)
+}
+
+/// Validate a single file's content against the model its path implies
+pub fn lint_file(path: &str, content: &str) -> Vec<LintIssue> {
+    let Some(kind) = classify(path) else {
+        return Vec::new();
+    };
+
+    let parsed = match kind {
+        FileKind::Team => parse::<Team>(content),
+        FileKind::Profile(email) => match serde_yaml::from_str::<Member>(content) {
+            Ok(member) if !same_email(&member.email, email) => Err(format!(
+                "Profile email {} does not match its directory {}",
+                member.email, email
+            )),
+            Ok(member)
+                if member
+                    .timezone
+                    .as_deref()
+                    .is_some_and(|tz| crate::time::parse_timezone(tz).is_err()) =>
+            {
+                Err(format!(
+                    "Unknown timezone {} (expected an IANA name like Europe/Amsterdam)",
+                    member.timezone.unwrap_or_default()
+                ))
+            }
+            Ok(_) => Ok(()),
+            Err(e) => Err(e.to_string()),
+        },
+        FileKind::Credentials(_) => parse::<MemberCredentials>(content),
+        FileKind::ResetToken(_) => parse::<ResetToken>(content),
+        FileKind::Interaction => parse::<Interaction>(content),
+        FileKind::FeedbackRequest => parse::<FeedbackRequest>(content),
+        FileKind::Objective => parse::<Objective>(content),
+        FileKind::Manifesto => Manifesto::parse(content)
+            .and_then(|m| m.validate())
+            .map_err(|e| e.to_string()),
+        FileKind::Vision | FileKind::Other => Ok(()),
+    };
+
+    let mut issues = match parsed {
+        Ok(()) => Vec::new(),
+        Err(message) => vec![LintIssue::new(path, message)],
+    };
+
+    // Everything under .team/ is committed, so credentials must be references
+    for (key, reason) in secrets::find_literal_secrets(content) {
+        issues.push(LintIssue::new(
+            path,
+            format!(
+                "{} {}; use an env: or file: reference instead of committing it",
+                key, reason
+            ),
+        ));
+    }
+    issues
+}
+
+/// Check that an author was allowed to change a file
+///
+/// `content` is the new content of the file, or `None` if it was deleted.
+pub fn check_change(
+    team: &Team,
+    author: &str,
+    path: &str,
+    content: Option<&str>,
+) -> Option<LintIssue> {
+    let kind = classify(path)?;
+    let user = Some(author);
+
+    let result = match kind {
+        FileKind::Team => authorize(team, user, Action::EditTeam),
+        FileKind::Manifesto => authorize(team, user, Action::EditManifesto),
+        FileKind::Vision => authorize(team, user, Action::EditVision),
+        FileKind::Profile(email) => authorize(team, user, Action::EditMember(email)),
+        // Leaders delete both when removing a member; a member deletes their
+        // own reset token when redeeming it
+        FileKind::Credentials(email) | FileKind::ResetToken(email) if content.is_none() => {
+            authorize(team, user, Action::SetCredentials(email))
+                .or_else(|_| authorize(team, user, Action::EditTeam))
+        }
+        FileKind::Credentials(email) => authorize(team, user, Action::SetCredentials(email)),
+        FileKind::ResetToken(_) => authorize(team, user, Action::EditTeam),
+        FileKind::Interaction => match content.map(serde_yaml::from_str::<Interaction>) {
+            // Imported history is committed by a leader on everyone's behalf
+            Some(Ok(interaction)) if interaction.imported_from.is_some() => {
+                authorize(team, user, Action::EditTeam)
+            }
+            Some(Ok(interaction)) => {
+                authorize(team, user, Action::AuthorInteraction(&interaction.from))
+            }
+            // Deleting or corrupting an interaction is a team-level change
+            _ => authorize(team, user, Action::EditTeam),
+        },
+        FileKind::FeedbackRequest => match content.map(serde_yaml::from_str::<FeedbackRequest>) {
+            Some(Ok(request)) => authorize(team, user, Action::AuthorInteraction(&request.from)),
+            // Any member may clear a request once it is handled
+            None => authorize(team, user, Action::AuthorInteraction(author)),
+            Some(Err(_)) => authorize(team, user, Action::EditTeam),
+        },
+        FileKind::Objective => match content.map(serde_yaml::from_str::<Objective>) {
+            Some(Ok(objective)) => authorize(
+                team,
+                user,
+                Action::EditObjective(objective.owner.as_deref()),
+            ),
+            _ => authorize(team, user, Action::EditObjective(None)),
+        },
+        FileKind::Other => {
+            if Role::of(team, user) == Role::Observer {
+                return Some(LintIssue::new(
+                    path,
+                    format!("{} is not a member of the team", author),
+                ));
+            }
+            Ok(())
+        }
+    };
+
+    result.err().map(|e| LintIssue::new(path, e.to_string()))
+}
+
+/// Check the files staged for the next commit, for the pre-commit hook
+///
+/// Anything under a `.personal/` directory is refused outright; staged
+/// `.team/` files are validated like [`lint_file`] does.
+pub fn lint_staged(repo: &Path) -> Result<Vec<LintIssue>> {
+    let mut issues = Vec::new();
+    for path in git::staged_files(repo)? {
+        if path.starts_with(".personal/") || path.contains("/.personal/") {
+            issues.push(LintIssue::new(
+                &path,
+                format!(
+                    "Personal data must never be committed; run 'git rm --cached {}'",
+                    path
+                ),
+            ));
+            continue;
+        }
+        if classify(&path).is_none() {
+            continue;
+        }
+        if let Some(content) = git::show_file(repo, "", &path)? {
+            issues.extend(lint_file(&path, &content));
+        }
+    }
+    Ok(issues)
+}
+
+/// Check every commit in a git revision range against the team permissions
+///
+/// Roles are taken from the team as it was before each commit, so nobody can
+/// promote themselves and use the new role in the same commit.
+pub fn lint_commits(repo: &Path, range: &str) -> Result<Vec<LintIssue>> {
+    let mut issues = Vec::new();
+
+    for commit in git::commits(repo, range)? {
+        let parent = format!("{}^", commit.hash);
+        let team_yaml = match git::show_file(repo, &parent, ".team/config.yaml")? {
+            Some(yaml) => Some(yaml),
+            None => git::show_file(repo, &commit.hash, ".team/config.yaml")?,
+        };
+        let Some(team) = team_yaml.and_then(|y| serde_yaml::from_str::<Team>(&y).ok()) else {
+            continue;
+        };
+
+        for path in git::changed_files(repo, &commit.hash)? {
+            let content = git::show_file(repo, &commit.hash, &path)?;
+            if let Some(mut issue) =
+                check_change(&team, &commit.author_email, &path, content.as_deref())
+            {
+                issue.message = format!("{} (commit {})", issue.message, &commit.hash[..8]);
+                issues.push(issue);
+            }
+        }
+    }
+
+    Ok(issues)
+}
+
+fn parse<T: DeserializeOwned>(content: &str) -> std::result::Result<(), String> {
+    serde_yaml::from_str::<T>(content)
+        .map(|_| ())
+        .map_err(|e| e.to_string())
+}
+
+fn collect_yaml_files(dir: &Path, files: &mut Vec<std::path::PathBuf>) -> Result<()> {
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect_yaml_files(&path, files)?;
+        } else if path.extension().and_then(|s| s.to_str()) == Some("yaml") {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TeamConfig;
+    use std::process::Command;
+    use tempfile::TempDir;
+
+    fn team() -> Team {
+        Team::new("Engineering")
+            .add_leader("leader@example.com")
+            .add_member("member@example.com")
+    }
+
+    #[test]
+    fn test_lint_valid_storage() {
+        let temp = TempDir::new().unwrap();
+        let storage = TeamStorage::new(temp.path());
+        let leader = Member::new("leader@example.com");
+        storage
+            .initialize_team(
+                &Team::new("Team").add_leader("leader@example.com"),
+                &TeamConfig::with_defaults(),
+                &leader,
+                "leaderpin",
+            )
+            .unwrap();
+
+        assert!(lint_storage(&storage).unwrap().is_empty());
+
+        let config = storage.team_dir().join("config.yaml");
+        std::fs::copy(&config, storage.team_dir().join("config-conflict-PC.yaml")).unwrap();
+        let issues = lint_storage(&storage).unwrap();
+        assert_eq!(issues.len(), 1);
+        assert!(issues[0]
+            .message
+            .contains("Sync conflict copy of config.yaml"));
+    }
+
+    #[test]
+    fn test_lint_manifesto() {
+        let legacy = "# Our manifesto\n\nWe value collaboration.";
+        assert!(lint_file(".team/manifesto.yaml", legacy).is_empty());
+
+        let duplicate =
+            "principles:\n  - id: kind\n    title: Be kind\n  - id: kind\n    title: Be nice\n";
+        let issues = lint_file(".team/manifesto.yaml", duplicate);
+        assert_eq!(issues.len(), 1);
+        assert!(issues[0].message.contains("Duplicate principle id 'kind'"));
+    }
+
+    #[test]
+    fn test_lint_reports_broken_files() {
+        let temp = TempDir::new().unwrap();
+        let storage = TeamStorage::new(temp.path());
+        storage.initialize().unwrap();
+        storage.save_team(&team()).unwrap();
+        storage
+            .save_member(&Member::new("leader@example.com"))
+            .unwrap();
+        std::fs::write(
+            storage.interactions_dir(true).join("broken.yaml"),
+            "kind: [not valid",
+        )
+        .unwrap();
+
+        let issues = lint_storage(&storage).unwrap();
+        assert!(issues
+            .iter()
+            .any(|i| i.path == ".team/team/interactions/broken.yaml"));
+        assert!(issues
+            .iter()
+            .any(|i| i.message.contains("member@example.com")));
+    }
+
+    #[test]
+    fn test_lint_flags_literal_secrets() {
+        let config = "name: T\nwebhooks:\n  slack: https://hooks.slack.com/services/T0/B0/xyz\n";
+        let issues = lint_file(".team/config.yaml", config);
+        assert_eq!(issues.len(), 1);
+        assert!(issues[0].message.starts_with("webhooks.slack looks like"));
+
+        let referenced = "name: T\nwebhooks:\n  slack: env:INTERACTIONS_SLACK_WEBHOOK\n";
+        assert!(lint_file(".team/config.yaml", referenced).is_empty());
+    }
+
+    #[test]
+    fn test_lint_profile_email_mismatch() {
+        let issues = lint_file(
+            ".team/members/alice@example.com/profile.yaml",
+            "email: bob@example.com\n",
+        );
+        assert_eq!(issues.len(), 1);
+    }
+
+    #[test]
+    fn test_check_change() {
+        let team = team();
+        let kudos = serde_yaml::to_string(&Interaction::appreciation(
+            "member@example.com",
+            vec!["leader@example.com".to_string()],
+            "Thanks!",
+        ))
+        .unwrap();
+        let kudos_path = ".team/members/leader@example.com/kudos/1.yaml";
+
+        assert!(check_change(&team, "leader@example.com", ".team/config.yaml", Some("")).is_none());
+        assert!(check_change(&team, "member@example.com", ".team/config.yaml", Some("")).is_some());
+        assert!(check_change(&team, "member@example.com", kudos_path, Some(&kudos)).is_none());
+        assert!(check_change(&team, "leader@example.com", kudos_path, Some(&kudos)).is_some());
+        assert!(check_change(
+            &team,
+            "member@example.com",
+            ".team/members/member@example.com/profile.yaml",
+            Some("")
+        )
+        .is_none());
+
+        let creds_path = ".team/members/member@example.com/credentials.yaml";
+        assert!(check_change(&team, "member@example.com", creds_path, Some("")).is_none());
+        assert!(check_change(&team, "leader@example.com", creds_path, Some("")).is_some());
+        assert!(check_change(&team, "leader@example.com", creds_path, None).is_none());
+        assert!(check_change(&team, "other@example.com", creds_path, None).is_some());
+
+        let reset_path = ".team/members/member@example.com/reset.yaml";
+        assert!(check_change(&team, "leader@example.com", reset_path, Some("")).is_none());
+        assert!(check_change(&team, "member@example.com", reset_path, Some("")).is_some());
+        assert!(check_change(&team, "member@example.com", reset_path, None).is_none());
+        assert!(check_change(&team, "leader@example.com", reset_path, None).is_none());
+        assert!(check_change(
+            &team,
+            "other@example.com",
+            ".team/members/leader@example.com/reset.yaml",
+            None
+        )
+        .is_some());
+
+        assert!(check_change(&team, "stranger@example.com", ".team/drafts/x.yaml", None).is_some());
+        assert!(check_change(&team, "stranger@example.com", "README.md", None).is_none());
+    }
+
+    fn git(dir: &Path, args: &[&str]) {
+        let status = Command::new("git")
+            .arg("-C")
+            .arg(dir)
+            .args(args)
+            .status()
+            .unwrap();
+        assert!(status.success());
+    }
+
+    #[test]
+    fn test_lint_commits() {
+        let temp = TempDir::new().unwrap();
+        let dir = temp.path();
+        git(dir, &["init", "-q"]);
+        git(dir, &["config", "user.name", "Leader"]);
+        git(dir, &["config", "user.email", "leader@example.com"]);
+
+        let storage = TeamStorage::new(dir);
+        storage.initialize().unwrap();
+        storage.save_team(&team()).unwrap();
+        git(dir, &["add", "-A"]);
+        git(dir, &["commit", "-qm", "Create team"]);
+
+        // A member tries to promote themselves
+        git(dir, &["config", "user.email", "member@example.com"]);
+        let mut promoted = team();
+        promoted.promote("member@example.com");
+        storage.save_team(&promoted).unwrap();
+        git(dir, &["commit", "-qam", "Promote myself"]);
+
+        let issues = lint_commits(dir, "HEAD~1..HEAD").unwrap();
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].path, ".team/config.yaml");
+
+        // The leader's root commit is allowed
+        assert!(lint_commits(dir, "HEAD~1").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_lint_staged() {
+        let temp = TempDir::new().unwrap();
+        let dir = temp.path();
+        git(dir, &["init", "-q"]);
+
+        let storage = TeamStorage::new(dir);
+        storage.initialize().unwrap();
+        storage.save_team(&team()).unwrap();
+        std::fs::write(storage.personal_dir().join("journal/today.yaml"), "x").unwrap();
+        git(dir, &["add", "-A"]);
+        assert!(lint_staged(dir).unwrap().is_empty());
+
+        std::fs::write(
+            storage.interactions_dir(true).join("broken.yaml"),
+            "kind: [not valid",
+        )
+        .unwrap();
+
+        // .gitignore keeps .personal/ out, but a forced add gets past it
+        git(dir, &["add", "-f", ".personal/journal/today.yaml"]);
+        git(dir, &["add", ".team/team/interactions/broken.yaml"]);
+        let paths: Vec<String> = lint_staged(dir)
+            .unwrap()
+            .into_iter()
+            .map(|i| i.path)
+            .collect();
+        assert_eq!(
+            paths,
+            vec![
+                ".personal/journal/today.yaml",
+                ".team/team/interactions/broken.yaml"
+            ]
+        );
+
+        let hook = git::install_pre_commit_hook(dir, "interactions", false).unwrap();
+        assert!(std::fs::read_to_string(&hook)
+            .unwrap()
+            .contains("exec 'interactions' lint --staged"));
+        // Our own hook can be reinstalled, somebody else's is kept
+        assert!(git::install_pre_commit_hook(dir, "interactions", false).is_ok());
+        std::fs::write(&hook, "#!/bin/sh\nexit 0\n").unwrap();
+        assert!(git::install_pre_commit_hook(dir, "interactions", false).is_err());
+        assert!(git::install_pre_commit_hook(dir, "interactions", true).is_ok());
+    }
+}
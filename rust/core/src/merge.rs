@@ -0,0 +1,299 @@
+//! Three-way merge of .team/ YAML files
+//!
+//! Used by `interactions merge-driver`, which git calls instead of its line
+//! based merge for the files `.gitattributes` assigns to it. Two members who
+//! add different people to the team, or update different key results of an
+//! objective, change different lines of the same list; git sees a conflict,
+//! but the models merge cleanly.
+//!
+//! Mappings are merged field by field. Lists of mappings are matched by
+//! `id`, `email`, `description` or `title`, whichever all their items have,
+//! and merged item by item; lists of plain values (leaders, members,
+//! aliases, ...) are merged as sets. Only a field both sides changed in
+//! different ways is a conflict. The result must still lint as the model
+//! its path holds.
+
+use serde_yaml::{Mapping, Value};
+
+use crate::diff::{child_path, is_set, item_key, item_path};
+use crate::lint;
+use crate::{Error, Result};
+
+/// The outcome of a merge
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Merge {
+    /// The merged file; conflicting fields keep our value
+    pub content: String,
+    /// Fields both sides changed differently, e.g. `key_results[Ship v2].progress`
+    pub conflicts: Vec<String>,
+}
+
+impl Merge {
+    /// Whether the merge needs no help
+    pub fn is_clean(&self) -> bool {
+        self.conflicts.is_empty()
+    }
+}
+
+/// Merge our and their version of the file at `path` with their common
+/// ancestor
+///
+/// `base` is empty when both sides added the file. Fails if a version is not
+/// valid YAML.
+pub fn merge(path: &str, base: &str, ours: &str, theirs: &str) -> Result<Merge> {
+    let parse = |content: &str, side: &str| {
+        serde_yaml::from_str::<Value>(content)
+            .map_err(|e| Error::InvalidInput(format!("{} version of {}: {}", side, path, e)))
+    };
+    let base = match parse(base, "Common")? {
+        Value::Null => None,
+        value => Some(value),
+    };
+    let ours_value = parse(ours, "Our")?;
+    let theirs_value = parse(theirs, "Their")?;
+
+    let mut conflicts = Vec::new();
+    let merged = merge_value(
+        base.as_ref(),
+        Some(&ours_value),
+        Some(&theirs_value),
+        "",
+        &mut conflicts,
+    )
+    .unwrap_or(Value::Null);
+    let content = serde_yaml::to_string(&merged)?;
+
+    // Merging two valid files can still break a rule; report what is new
+    let known: Vec<String> = lint::lint_file(path, ours)
+        .into_iter()
+        .map(|i| i.message)
+        .collect();
+    for issue in lint::lint_file(path, &content) {
+        if !known.contains(&issue.message) {
+            conflicts.push(format!("merged file is invalid: {}", issue.message));
+        }
+    }
+
+    Ok(Merge { content, conflicts })
+}
+
+fn merge_value(
+    base: Option<&Value>,
+    ours: Option<&Value>,
+    theirs: Option<&Value>,
+    path: &str,
+    conflicts: &mut Vec<String>,
+) -> Option<Value> {
+    if ours == theirs || base == theirs {
+        return ours.cloned();
+    }
+    if base == ours {
+        return theirs.cloned();
+    }
+
+    match (ours, theirs) {
+        (Some(Value::Mapping(o)), Some(Value::Mapping(t))) => {
+            let empty = Mapping::new();
+            let b = match base {
+                Some(Value::Mapping(b)) => b,
+                _ => &empty,
+            };
+            Some(Value::Mapping(merge_mapping(b, o, t, path, conflicts)))
+        }
+        (Some(Value::Sequence(o)), Some(Value::Sequence(t))) => {
+            let b = match base {
+                Some(Value::Sequence(b)) => b.as_slice(),
+                _ => &[],
+            };
+            match merge_sequence(b, o, t, path, conflicts) {
+                Some(items) => Some(Value::Sequence(items)),
+                None => {
+                    conflicts.push(label(path));
+                    ours.cloned()
+                }
+            }
+        }
+        _ => {
+            conflicts.push(label(path));
+            ours.cloned()
+        }
+    }
+}
+
+fn merge_mapping(
+    base: &Mapping,
+    ours: &Mapping,
+    theirs: &Mapping,
+    path: &str,
+    conflicts: &mut Vec<String>,
+) -> Mapping {
+    let mut merged = Mapping::new();
+    let keys = ours
+        .keys()
+        .chain(theirs.keys().filter(|k| !ours.contains_key(*k)))
+        .chain(
+            base.keys()
+                .filter(|k| !ours.contains_key(*k) && !theirs.contains_key(*k)),
+        );
+    for key in keys {
+        let value = merge_value(
+            base.get(key),
+            ours.get(key),
+            theirs.get(key),
+            &child_path(path, key),
+            conflicts,
+        );
+        if let Some(value) = value {
+            merged.insert(key.clone(), value);
+        }
+    }
+    merged
+}
+
+/// Merge two lists, `None` if their items cannot be matched up
+fn merge_sequence(
+    base: &[Value],
+    ours: &[Value],
+    theirs: &[Value],
+    path: &str,
+    conflicts: &mut Vec<String>,
+) -> Option<Vec<Value>> {
+    let all = || base.iter().chain(ours).chain(theirs);
+
+    if is_set(all()) {
+        // A set: keep what either side added, drop what either side removed
+        let removed = |v: &Value| base.contains(v) && !(ours.contains(v) && theirs.contains(v));
+        let mut merged: Vec<Value> = ours.iter().filter(|v| !removed(v)).cloned().collect();
+        for item in theirs {
+            if !base.contains(item) && !merged.contains(item) {
+                merged.push(item.clone());
+            }
+        }
+        return Some(merged);
+    }
+
+    let key = item_key(all())?;
+    let id = |v: &Value| v.get(key).cloned().unwrap_or(Value::Null);
+    let find = |items: &[Value], k: &Value| items.iter().find(|v| id(v) == *k).cloned();
+    let item_path = |k: &Value| item_path(path, k);
+
+    let mut merged = Vec::new();
+    for item in ours {
+        let k = id(item);
+        let b = find(base, &k);
+        let t = find(theirs, &k);
+        if let (Some(b), None) = (&b, &t) {
+            // They deleted it; fine unless we changed it
+            if b != item {
+                conflicts.push(item_path(&k));
+                merged.push(item.clone());
+            }
+            continue;
+        }
+        let path = item_path(&k);
+        if let Some(value) = merge_value(b.as_ref(), Some(item), t.as_ref(), &path, conflicts) {
+            merged.push(value);
+        }
+    }
+    for item in theirs {
+        let k = id(item);
+        if find(ours, &k).is_some() {
+            continue;
+        }
+        match find(base, &k) {
+            // They added it
+            None => merged.push(item.clone()),
+            // We deleted it; fine unless they changed it
+            Some(b) if b == *item => {}
+            Some(_) => {
+                conflicts.push(item_path(&k));
+                merged.push(item.clone());
+            }
+        }
+    }
+    Some(merged)
+}
+
+fn label(path: &str) -> String {
+    if path.is_empty() {
+        "the whole file".to_string()
+    } else {
+        path.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{KeyResult, Objective, Team};
+
+    fn yaml<T: serde::Serialize>(value: &T) -> String {
+        serde_yaml::to_string(value).unwrap()
+    }
+
+    #[test]
+    fn test_merge_team_lists_as_sets() {
+        let base = Team::new("T").add_leader("a@x.com").add_member("b@x.com");
+        let mut ours = base.clone();
+        ours.push_member("c@x.com");
+        let mut theirs = base.clone();
+        theirs.promote("b@x.com");
+        theirs.push_member("d@x.com");
+
+        let merged = merge(
+            ".team/config.yaml",
+            &yaml(&base),
+            &yaml(&ours),
+            &yaml(&theirs),
+        )
+        .unwrap();
+        assert!(merged.is_clean(), "{:?}", merged.conflicts);
+        let team: Team = serde_yaml::from_str(&merged.content).unwrap();
+        assert_eq!(team.leaders, vec!["a@x.com", "b@x.com"]);
+        assert_eq!(team.members, vec!["c@x.com", "d@x.com"]);
+    }
+
+    #[test]
+    fn test_merge_key_results_by_description() {
+        let mut base = Objective::new("Ship");
+        base.add_key_result(KeyResult::new("Beta"));
+        base.add_key_result(KeyResult::new("Launch"));
+        let mut ours = base.clone();
+        ours.key_results[0].set_progress(0.5);
+        let mut theirs = base.clone();
+        theirs.key_results[1].set_progress(0.25);
+        theirs.add_key_result(KeyResult::new("Docs"));
+
+        let path = ".team/team/okrs/ship.yaml";
+        let merged = merge(path, &yaml(&base), &yaml(&ours), &yaml(&theirs)).unwrap();
+        assert!(merged.is_clean(), "{:?}", merged.conflicts);
+        let objective: Objective = serde_yaml::from_str(&merged.content).unwrap();
+        let progress: Vec<f32> = objective.key_results.iter().map(|k| k.progress).collect();
+        assert_eq!(progress, vec![0.5, 0.25, 0.0]);
+
+        // Both moving the same key result is a real conflict
+        let mut other = base.clone();
+        other.key_results[0].set_progress(0.75);
+        let merged = merge(path, &yaml(&base), &yaml(&ours), &yaml(&other)).unwrap();
+        assert_eq!(merged.conflicts, vec!["key_results[Beta].progress"]);
+    }
+
+    #[test]
+    fn test_merge_deletions() {
+        let base = "principles:\n  - id: a\n    title: A\n  - id: b\n    title: B\n";
+        let ours = "principles:\n  - id: a\n    title: A\n";
+        let theirs = "principles:\n  - id: a\n    title: A\n  - id: b\n    title: Bee\n";
+        let merged = merge(".team/manifesto.yaml", base, ours, base).unwrap();
+        assert!(merged.is_clean());
+        assert!(!merged.content.contains("id: b"));
+
+        // We deleted what they changed
+        let merged = merge(".team/manifesto.yaml", base, ours, theirs).unwrap();
+        assert_eq!(merged.conflicts, vec!["principles[b]"]);
+    }
+
+    #[test]
+    fn test_invalid_yaml_fails() {
+        assert!(merge(".team/config.yaml", "", "name: [", "name: T").is_err());
+    }
+}
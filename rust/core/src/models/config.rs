@@ -2,7 +2,13 @@
 //!
 //! Represents the .team/config.yaml file structure.
 
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Duration, Months, Utc};
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use super::member::same_email;
 
 /// Team configuration stored in .team/config.yaml
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
@@ -22,6 +28,14 @@ pub struct TeamConfig {
     /// Backup configuration
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub backup: Option<BackupConfig>,
+
+    /// Pulse schedule; no prompts are due when unset
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pulse: Option<PulseConfig>,
+
+    /// Commands to run when something changes in the team
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub hooks: Vec<ShellHook>,
 }
 
 impl TeamConfig {
@@ -37,6 +51,8 @@ impl TeamConfig {
                 manifesto: Some("/MANIFESTO.md".to_string()),
                 vision: Some("/VISION.md".to_string()),
                 okrs: Some("/okrs/".to_string()),
+                interactions: Some("/INTERACTIONS.md".to_string()),
+                timezone: None,
             }),
             webhooks: None,
             linting: Some(LintingConfig {
@@ -46,6 +62,8 @@ impl TeamConfig {
             backup: Some(BackupConfig {
                 protected_branch: Some("main".to_string()),
             }),
+            pulse: Some(PulseConfig::default()),
+            hooks: Vec::new(),
         }
     }
 
@@ -66,6 +84,18 @@ impl TeamConfig {
         self.backup = Some(backup);
         self
     }
+
+    /// Set pulse configuration
+    pub fn with_pulse(mut self, pulse: PulseConfig) -> Self {
+        self.pulse = Some(pulse);
+        self
+    }
+
+    /// Add a shell hook
+    pub fn with_hook(mut self, hook: ShellHook) -> Self {
+        self.hooks.push(hook);
+        self
+    }
 }
 
 /// Configuration for publishing markdown files
@@ -82,22 +112,182 @@ pub struct PublishConfig {
     /// Path to publish OKRs
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub okrs: Option<String>,
+
+    /// Path to publish the log of shared interactions
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub interactions: Option<String>,
+
+    /// IANA timezone to show published timestamps in (UTC if unset)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
 }
 
 /// Configuration for webhook notifications
+///
+/// Webhook URLs are credentials and this file is committed, so they are
+/// usually [secret references](crate::secrets) such as
+/// `env:INTERACTIONS_SLACK_WEBHOOK`.
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
 pub struct WebhookConfig {
-    /// Discord webhook URL
+    /// Discord webhook URL, or a reference to it
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub discord: Option<String>,
 
-    /// Slack webhook URL
+    /// Slack webhook URL, or a reference to it
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub slack: Option<String>,
 
     /// Signal configuration
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub signal: Option<String>,
+
+    /// Webhooks for other services, such as Mattermost, Teams or in-house bots
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub custom: Vec<CustomWebhook>,
+}
+
+/// A webhook for any service that accepts HTTP posts
+///
+/// ```yaml
+/// webhooks:
+///   custom:
+///     - name: mattermost
+///       url: env:INTERACTIONS_MATTERMOST_WEBHOOK
+///       template: '{"text": "{{text}}"}'
+///       headers:
+///         X-Team: platform
+///       secret: file:~/.config/interactions/secrets.yaml#mattermost
+///       events: [interaction_created]
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+pub struct CustomWebhook {
+    /// Name used in messages about this webhook
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// URL to post to, or a reference to it
+    pub url: String,
+
+    /// Payload with `{{field}}` placeholders; the fields as a JSON object if unset
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub template: Option<String>,
+
+    /// Content type of the payload (application/json if unset)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_type: Option<String>,
+
+    /// Extra HTTP headers; only the `Authorization` value may be a reference
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub headers: BTreeMap<String, String>,
+
+    /// Key to sign payloads with HMAC-SHA256, or a reference to it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub secret: Option<String>,
+
+    /// Header carrying the signature (X-Interactions-Signature if unset)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signature_header: Option<String>,
+
+    /// Events to post, such as `interaction_created`; all of them if empty
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub events: Vec<String>,
+}
+
+impl CustomWebhook {
+    /// Create a webhook posting the fields as JSON to a URL
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Set the name
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Set the payload template
+    pub fn with_template(mut self, template: impl Into<String>) -> Self {
+        self.template = Some(template.into());
+        self
+    }
+
+    /// Add an HTTP header
+    pub fn with_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.insert(name.into(), value.into());
+        self
+    }
+
+    /// Sign payloads with a shared secret
+    pub fn with_secret(mut self, secret: impl Into<String>) -> Self {
+        self.secret = Some(secret.into());
+        self
+    }
+
+    /// Only post these events
+    pub fn with_events(mut self, events: &[&str]) -> Self {
+        self.events = events.iter().map(|e| e.to_string()).collect();
+        self
+    }
+
+    /// The name, or the URL without its path if the webhook has none
+    pub fn label(&self) -> String {
+        match &self.name {
+            Some(name) => name.clone(),
+            None => crate::secrets::redact_url(&self.url),
+        }
+    }
+
+    /// Whether the webhook wants to hear about an event
+    pub fn wants(&self, event: &str) -> bool {
+        self.events.is_empty() || self.events.iter().any(|e| e == event)
+    }
+}
+
+/// A command run when something changes in the team
+///
+/// ```yaml
+/// hooks:
+///   - on: [interaction_created]
+///     run: ./scripts/announce.sh
+/// ```
+///
+/// The command runs in the team's root directory. It gets the event's fields
+/// as `INTERACTIONS_<FIELD>` environment variables and as a JSON object on
+/// stdin. Anyone who can push to the team repository can change it, so it
+/// only runs on machines where it was approved, see
+/// [`TeamStorage::approve_hook`](crate::TeamStorage::approve_hook).
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+pub struct ShellHook {
+    /// Events to run for, such as `member_added`; all of them if empty
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub on: Vec<String>,
+
+    /// The command, run with `sh -c` (`cmd /C` on Windows)
+    pub run: String,
+}
+
+impl ShellHook {
+    /// Run a command for some events, or all of them if `on` is empty
+    pub fn new(on: &[&str], run: impl Into<String>) -> Self {
+        Self {
+            on: on.iter().map(|e| e.to_string()).collect(),
+            run: run.into(),
+        }
+    }
+
+    /// Whether the hook runs for an event
+    pub fn wants(&self, event: &str) -> bool {
+        self.on.is_empty() || self.on.iter().any(|e| e == event)
+    }
+
+    /// SHA-256 of the command, as hex; what an approval is keyed by, so a
+    /// changed command needs a new one
+    pub fn digest(&self) -> String {
+        hex::encode(Sha256::digest(self.run.as_bytes()))
+    }
 }
 
 /// Configuration for linting on PRs
@@ -120,6 +310,162 @@ pub struct BackupConfig {
     pub protected_branch: Option<String>,
 }
 
+/// Something the pulse regularly asks members to do
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum PulsePrompt {
+    /// Log a check-in
+    CheckIn,
+    /// Update progress on owned objectives
+    OkrUpdate,
+    /// Take part in a retrospective
+    Retro,
+}
+
+impl PulsePrompt {
+    /// Every prompt, in display order
+    pub const ALL: [PulsePrompt; 3] = [Self::CheckIn, Self::OkrUpdate, Self::Retro];
+
+    /// Get a human-readable label for this prompt
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::CheckIn => "Check-in",
+            Self::OkrUpdate => "OKR update",
+            Self::Retro => "Retrospective",
+        }
+    }
+
+    /// How often the prompt comes up when the team sets no cadence
+    pub fn default_cadence(&self) -> Cadence {
+        match self {
+            Self::CheckIn => Cadence::Weekly,
+            Self::OkrUpdate => Cadence::Monthly,
+            Self::Retro => Cadence::Quarterly,
+        }
+    }
+}
+
+/// How often a pulse prompt comes up
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Cadence {
+    /// Every 7 days
+    Weekly,
+    /// Every 14 days
+    Biweekly,
+    /// Every calendar month
+    Monthly,
+    /// Every three calendar months
+    Quarterly,
+    /// Never
+    Off,
+}
+
+impl Cadence {
+    /// When the next prompt is due after something was last done, `None` if never
+    pub fn next_after(&self, last: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        match self {
+            Self::Weekly => Some(last + Duration::weeks(1)),
+            Self::Biweekly => Some(last + Duration::weeks(2)),
+            Self::Monthly => last.checked_add_months(Months::new(1)),
+            Self::Quarterly => last.checked_add_months(Months::new(3)),
+            Self::Off => None,
+        }
+    }
+}
+
+/// Cadences per prompt; unset prompts fall back to the next level
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+pub struct PulseCadences {
+    /// How often to ask for a check-in
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub checkin: Option<Cadence>,
+
+    /// How often to ask for OKR progress
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub okr_update: Option<Cadence>,
+
+    /// How often to ask for a retrospective
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retro: Option<Cadence>,
+}
+
+impl PulseCadences {
+    /// The cadence set for a prompt, if any
+    pub fn get(&self, prompt: PulsePrompt) -> Option<Cadence> {
+        match prompt {
+            PulsePrompt::CheckIn => self.checkin,
+            PulsePrompt::OkrUpdate => self.okr_update,
+            PulsePrompt::Retro => self.retro,
+        }
+    }
+
+    /// Set the cadence for a prompt
+    pub fn set(&mut self, prompt: PulsePrompt, cadence: Cadence) {
+        let slot = match prompt {
+            PulsePrompt::CheckIn => &mut self.checkin,
+            PulsePrompt::OkrUpdate => &mut self.okr_update,
+            PulsePrompt::Retro => &mut self.retro,
+        };
+        *slot = Some(cadence);
+    }
+}
+
+/// Configuration for pulse prompts
+///
+/// ```yaml
+/// pulse:
+///   checkin: weekly
+///   okr_update: monthly
+///   retro: quarterly
+///   members:
+///     bob@example.com:
+///       checkin: biweekly
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+pub struct PulseConfig {
+    /// Team-wide cadences
+    #[serde(flatten)]
+    pub team: PulseCadences,
+
+    /// Per-member overrides, by email
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub members: BTreeMap<String, PulseCadences>,
+}
+
+impl PulseConfig {
+    /// Set a team-wide cadence (builder pattern)
+    pub fn with_cadence(mut self, prompt: PulsePrompt, cadence: Cadence) -> Self {
+        self.team.set(prompt, cadence);
+        self
+    }
+
+    /// Override a cadence for one member (builder pattern)
+    pub fn with_member_cadence(
+        mut self,
+        email: impl Into<String>,
+        prompt: PulsePrompt,
+        cadence: Cadence,
+    ) -> Self {
+        self.members
+            .entry(email.into())
+            .or_default()
+            .set(prompt, cadence);
+        self
+    }
+
+    /// The cadence of a prompt for a member: their override, else the
+    /// team's, else the prompt's default
+    pub fn cadence(&self, prompt: PulsePrompt, email: &str) -> Cadence {
+        self.members
+            .iter()
+            .filter(|(member, _)| same_email(member, email))
+            .find_map(|(_, cadences)| cadences.get(prompt))
+            .or_else(|| self.team.get(prompt))
+            .unwrap_or_else(|| prompt.default_cadence())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -152,6 +498,7 @@ mod tests {
         assert!(config.webhooks.is_none());
         assert!(config.linting.is_none());
         assert!(config.backup.is_none());
+        assert!(config.pulse.is_none());
     }
 
     #[test]
@@ -167,8 +514,7 @@ mod tests {
         let config = TeamConfig::new()
             .with_publish(PublishConfig {
                 manifesto: Some("/docs/MANIFESTO.md".to_string()),
-                vision: None,
-                okrs: None,
+                ..PublishConfig::default()
             })
             .with_linting(LintingConfig {
                 enabled: true,
@@ -186,10 +532,59 @@ mod tests {
             discord: Some("https://discord.com/webhook/123".to_string()),
             slack: None,
             signal: None,
+            custom: Vec::new(),
         };
 
         let yaml = serde_yaml::to_string(&webhooks).unwrap();
         assert!(yaml.contains("discord:"));
         assert!(!yaml.contains("slack:"));
+        assert!(!yaml.contains("custom:"));
+    }
+
+    #[test]
+    fn test_pulse_cadences() {
+        let pulse: PulseConfig = serde_yaml::from_str(
+            "checkin: biweekly\nmembers:\n  Bob@Example.com:\n    checkin: weekly\n    retro: off\n",
+        )
+        .unwrap();
+
+        assert_eq!(
+            pulse.cadence(PulsePrompt::CheckIn, "alice@example.com"),
+            Cadence::Biweekly
+        );
+        assert_eq!(
+            pulse.cadence(PulsePrompt::CheckIn, "bob@example.com"),
+            Cadence::Weekly
+        );
+        assert_eq!(
+            pulse.cadence(PulsePrompt::Retro, "bob@example.com"),
+            Cadence::Off
+        );
+        assert_eq!(
+            pulse.cadence(PulsePrompt::OkrUpdate, "bob@example.com"),
+            Cadence::Monthly
+        );
+
+        let built = PulseConfig::default()
+            .with_cadence(PulsePrompt::CheckIn, Cadence::Biweekly)
+            .with_member_cadence("Bob@Example.com", PulsePrompt::CheckIn, Cadence::Weekly)
+            .with_member_cadence("Bob@Example.com", PulsePrompt::Retro, Cadence::Off);
+        assert_eq!(pulse, built);
+        let yaml = serde_yaml::to_string(&built).unwrap();
+        assert_eq!(serde_yaml::from_str::<PulseConfig>(&yaml).unwrap(), built);
+    }
+
+    #[test]
+    fn test_cadence_next_after() {
+        let last: DateTime<Utc> = "2026-01-31T09:00:00Z".parse().unwrap();
+        assert_eq!(
+            Cadence::Monthly.next_after(last).unwrap().to_rfc3339(),
+            "2026-02-28T09:00:00+00:00"
+        );
+        assert_eq!(
+            Cadence::Weekly.next_after(last).unwrap().to_rfc3339(),
+            "2026-02-07T09:00:00+00:00"
+        );
+        assert_eq!(Cadence::Off.next_after(last), None);
     }
 }
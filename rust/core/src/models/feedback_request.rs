@@ -0,0 +1,95 @@
+//! Feedback request model
+//!
+//! A member asking a teammate for feedback. The request stays pending until
+//! the teammate sends feedback back.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::interaction::{Interaction, InteractionKind};
+use super::member::same_email;
+
+/// A request for feedback from one member to another
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct FeedbackRequest {
+    /// Unique identifier
+    pub id: String,
+
+    /// Who is asking for feedback
+    pub from: String,
+
+    /// Who is asked to give it
+    pub to: String,
+
+    /// What the feedback should be about
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub topic: Option<String>,
+
+    /// When the request was made
+    pub timestamp: DateTime<Utc>,
+}
+
+impl FeedbackRequest {
+    /// Create a new feedback request
+    pub fn new(from: impl Into<String>, to: impl Into<String>) -> Self {
+        Self {
+            id: generate_request_id(),
+            from: from.into(),
+            to: to.into(),
+            topic: None,
+            timestamp: Utc::now(),
+        }
+    }
+
+    /// Set the topic
+    pub fn with_topic(mut self, topic: impl Into<String>) -> Self {
+        self.topic = Some(topic.into());
+        self
+    }
+
+    /// Whether the feedback asked for has been given
+    pub fn is_answered_by(&self, interaction: &Interaction) -> bool {
+        interaction.kind == InteractionKind::Feedback
+            && interaction.timestamp >= self.timestamp
+            && same_email(&interaction.from, &self.to)
+            && interaction.with.iter().any(|w| same_email(w, &self.from))
+    }
+}
+
+/// Generate a simple unique ID for feedback requests
+fn generate_request_id() -> String {
+    use std::time::{SystemTime, UNIX_EPOCH};
+    let duration = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default();
+    format!("req-{:x}{:x}", duration.as_secs(), duration.subsec_nanos())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_answered_by() {
+        let request =
+            FeedbackRequest::new("alice@example.com", "bob@example.com").with_topic("My talk");
+
+        let reply = Interaction::feedback(
+            "bob@example.com",
+            vec!["alice@example.com".to_string()],
+            "Slow down a bit",
+        );
+        assert!(request.is_answered_by(&reply));
+
+        let mut earlier = reply.clone();
+        earlier.timestamp = request.timestamp - chrono::Duration::days(1);
+        assert!(!request.is_answered_by(&earlier));
+
+        let kudos = Interaction::appreciation(
+            "bob@example.com",
+            vec!["alice@example.com".to_string()],
+            "Great talk",
+        );
+        assert!(!request.is_answered_by(&kudos));
+    }
+}
@@ -6,7 +6,7 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 /// The kind of interaction
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[serde(rename_all = "snake_case")]
 pub enum InteractionKind {
     /// Kudos, recognition, appreciation
@@ -22,6 +22,15 @@ pub enum InteractionKind {
 }
 
 impl InteractionKind {
+    /// Every kind, in display order
+    pub const ALL: [InteractionKind; 5] = [
+        Self::Appreciation,
+        Self::Feedback,
+        Self::Apology,
+        Self::CheckIn,
+        Self::Retrospective,
+    ];
+
     /// Get a human-readable label for this kind
     pub fn label(&self) -> &'static str {
         match self {
@@ -58,6 +67,15 @@ pub struct Interaction {
     /// Whether this is shared with the team or private
     #[serde(default)]
     pub shared: bool,
+
+    /// Ids of the manifesto principles this interaction relates to
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub principles: Vec<String>,
+
+    /// Where an imported interaction came from (e.g. "slack"), `None` if
+    /// it was logged here
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub imported_from: Option<String>,
 }
 
 impl Interaction {
@@ -76,6 +94,8 @@ impl Interaction {
             note: note.into(),
             timestamp: Utc::now(),
             shared: false,
+            principles: Vec::new(),
+            imported_from: None,
         }
     }
 
@@ -85,6 +105,12 @@ impl Interaction {
         self
     }
 
+    /// Link this interaction to manifesto principles by id
+    pub fn with_principles(mut self, principles: Vec<String>) -> Self {
+        self.principles = principles;
+        self
+    }
+
     /// Create an appreciation interaction
     pub fn appreciation(
         from: impl Into<String>,
@@ -127,6 +153,7 @@ mod tests {
         assert_eq!(interaction.with, vec!["bob@example.com"]);
         assert_eq!(interaction.note, "Great work on the PR!");
         assert!(!interaction.shared);
+        assert!(interaction.principles.is_empty());
     }
 
     #[test]
@@ -0,0 +1,339 @@
+//! Manifesto model
+//!
+//! The manifesto is an ordered list of principles the team strives for.
+//! Each principle has a stable id so objectives and interactions can
+//! refer to it.
+
+use serde::{Deserialize, Serialize};
+
+use crate::{Error, Interaction, InteractionKind, Result};
+
+/// A single principle in the manifesto
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Principle {
+    /// Stable identifier used to reference the principle (e.g. "own-the-outcome")
+    pub id: String,
+
+    /// Short title
+    pub title: String,
+
+    /// What living this principle looks like
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+impl Principle {
+    /// Create a new principle with an id derived from the title
+    pub fn new(title: impl Into<String>) -> Self {
+        let title = title.into();
+        Self {
+            id: slugify(&title),
+            title,
+            description: None,
+        }
+    }
+
+    /// Set an explicit id
+    pub fn with_id(mut self, id: impl Into<String>) -> Self {
+        self.id = id.into();
+        self
+    }
+
+    /// Set the description
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+}
+
+/// The team's manifesto: an optional introduction and ordered principles
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[serde(from = "ManifestoRepr")]
+pub struct Manifesto {
+    /// Free text introducing the principles
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub preamble: Option<String>,
+
+    /// Principles in the order the team lists them
+    #[serde(default)]
+    pub principles: Vec<Principle>,
+}
+
+/// Manifestos used to be a plain string; accept both forms when loading
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ManifestoRepr {
+    Text(String),
+    Structured {
+        #[serde(default)]
+        preamble: Option<String>,
+        #[serde(default)]
+        principles: Vec<Principle>,
+    },
+}
+
+/// The structured form of manifesto.yaml
+///
+/// Unknown keys mean the file is not structured: a legacy manifesto such as
+/// `We value: collaboration.` is also a YAML mapping.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ManifestoFile {
+    #[serde(default)]
+    preamble: Option<String>,
+    #[serde(default)]
+    principles: Option<Vec<Principle>>,
+}
+
+impl From<ManifestoRepr> for Manifesto {
+    fn from(repr: ManifestoRepr) -> Self {
+        match repr {
+            ManifestoRepr::Text(text) => Manifesto::from_text(&text),
+            ManifestoRepr::Structured {
+                preamble,
+                principles,
+            } => Manifesto {
+                preamble,
+                principles,
+            },
+        }
+    }
+}
+
+impl From<&str> for Manifesto {
+    fn from(text: &str) -> Self {
+        Manifesto::from_text(text)
+    }
+}
+
+impl From<String> for Manifesto {
+    fn from(text: String) -> Self {
+        Manifesto::from_text(&text)
+    }
+}
+
+impl Manifesto {
+    /// Create an empty manifesto
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Wrap a legacy plain-text manifesto, keeping the text as the preamble
+    pub fn from_text(text: &str) -> Self {
+        let text = text.trim();
+        Self {
+            preamble: (!text.is_empty()).then(|| text.to_string()),
+            principles: Vec::new(),
+        }
+    }
+
+    /// Parse the contents of manifesto.yaml
+    ///
+    /// Only a mapping with a `preamble` or `principles` key and nothing else
+    /// is structured. Anything else is a legacy manifesto written as plain
+    /// text or markdown, and is kept whole as the preamble.
+    pub fn parse(content: &str) -> Result<Self> {
+        match serde_yaml::from_str::<ManifestoFile>(content) {
+            Ok(ManifestoFile {
+                preamble,
+                principles,
+            }) if preamble.is_some() || principles.is_some() => Ok(Self {
+                preamble,
+                principles: principles.unwrap_or_default(),
+            }),
+            _ => Ok(Self::from_text(content)),
+        }
+    }
+
+    /// Set the preamble
+    pub fn with_preamble(mut self, preamble: impl Into<String>) -> Self {
+        self.preamble = Some(preamble.into());
+        self
+    }
+
+    /// Add a principle (builder pattern)
+    pub fn with_principle(mut self, principle: Principle) -> Self {
+        self.push_principle(principle);
+        self
+    }
+
+    /// Add a principle, making its id unique if another principle already uses it
+    pub fn push_principle(&mut self, mut principle: Principle) {
+        if principle.id.is_empty() {
+            principle.id = "principle".to_string();
+        }
+        let base = principle.id.clone();
+        let mut n = 2;
+        while self.principle(&principle.id).is_some() {
+            principle.id = format!("{}-{}", base, n);
+            n += 1;
+        }
+        self.principles.push(principle);
+    }
+
+    /// Look up a principle by id
+    pub fn principle(&self, id: &str) -> Option<&Principle> {
+        self.principles.iter().find(|p| p.id == id)
+    }
+
+    /// Count how many appreciations named each principle, in manifesto order
+    ///
+    /// References to principles that are no longer in the manifesto are ignored.
+    pub fn recognition(&self, interactions: &[Interaction]) -> Vec<(&Principle, usize)> {
+        self.principles
+            .iter()
+            .map(|principle| {
+                let count = interactions
+                    .iter()
+                    .filter(|i| i.kind == InteractionKind::Appreciation)
+                    .filter(|i| i.principles.contains(&principle.id))
+                    .count();
+                (principle, count)
+            })
+            .collect()
+    }
+
+    /// Plain-text rendering: the preamble followed by numbered principles
+    pub fn to_text(&self) -> String {
+        let mut parts: Vec<String> = self.preamble.iter().cloned().collect();
+        for (i, principle) in self.principles.iter().enumerate() {
+            let mut line = format!("{}. {}", i + 1, principle.title);
+            if let Some(description) = &principle.description {
+                line.push_str(&format!(" - {}", description));
+            }
+            parts.push(line);
+        }
+        parts.join("\n\n")
+    }
+
+    /// Whether there is nothing in the manifesto
+    pub fn is_empty(&self) -> bool {
+        self.preamble.is_none() && self.principles.is_empty()
+    }
+
+    /// Check that every principle has a non-empty, unique id and a title
+    pub fn validate(&self) -> Result<()> {
+        for (i, principle) in self.principles.iter().enumerate() {
+            if principle.id.trim().is_empty() {
+                return Err(Error::InvalidConfig(format!(
+                    "Principle {} has no id",
+                    i + 1
+                )));
+            }
+            if principle.title.trim().is_empty() {
+                return Err(Error::InvalidConfig(format!(
+                    "Principle '{}' has no title",
+                    principle.id
+                )));
+            }
+            if self.principles[..i].iter().any(|p| p.id == principle.id) {
+                return Err(Error::InvalidConfig(format!(
+                    "Duplicate principle id '{}'",
+                    principle.id
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Turn a title into a lowercase, dash-separated id
+fn slugify(title: &str) -> String {
+    let mut slug = String::new();
+    for c in title.chars() {
+        if c.is_alphanumeric() {
+            slug.extend(c.to_lowercase());
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    slug.trim_end_matches('-').to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_principle_ids_from_titles() {
+        let manifesto = Manifesto::new()
+            .with_principle(Principle::new("Own the outcome!"))
+            .with_principle(Principle::new("Own the outcome"))
+            .with_principle(Principle::new("Be kind").with_id("kindness"));
+
+        let ids: Vec<&str> = manifesto.principles.iter().map(|p| p.id.as_str()).collect();
+        assert_eq!(
+            ids,
+            vec!["own-the-outcome", "own-the-outcome-2", "kindness"]
+        );
+        assert_eq!(manifesto.principle("kindness").unwrap().title, "Be kind");
+        assert_eq!(
+            manifesto.to_text(),
+            "1. Own the outcome!\n\n2. Own the outcome\n\n3. Be kind"
+        );
+        assert!(manifesto.validate().is_ok());
+    }
+
+    #[test]
+    fn test_recognition() {
+        let manifesto = Manifesto::new()
+            .with_principle(Principle::new("Ship small"))
+            .with_principle(Principle::new("Be kind"));
+        let kudos = |principles: &[&str]| {
+            Interaction::appreciation("a@example.com", vec![], "Thanks")
+                .with_principles(principles.iter().map(|p| p.to_string()).collect())
+        };
+        let interactions = vec![
+            kudos(&["be-kind"]),
+            kudos(&["be-kind", "ship-small"]),
+            kudos(&["retired-principle"]),
+            Interaction::feedback("a@example.com", vec![], "Hmm")
+                .with_principles(vec!["ship-small".to_string()]),
+        ];
+
+        let counts: Vec<(&str, usize)> = manifesto
+            .recognition(&interactions)
+            .into_iter()
+            .map(|(p, n)| (p.id.as_str(), n))
+            .collect();
+        assert_eq!(counts, vec![("ship-small", 1), ("be-kind", 2)]);
+    }
+
+    #[test]
+    fn test_validate_rejects_duplicates() {
+        let mut manifesto = Manifesto::new();
+        manifesto.principles.push(Principle::new("Be kind"));
+        manifesto.principles.push(Principle::new("Be kind"));
+        assert!(matches!(manifesto.validate(), Err(Error::InvalidConfig(_))));
+    }
+
+    #[test]
+    fn test_yaml_roundtrip_keeps_order() {
+        let manifesto = Manifesto::new()
+            .with_preamble("How we work")
+            .with_principle(Principle::new("Ship small").with_description("Merge daily"))
+            .with_principle(Principle::new("Ask early"));
+
+        let yaml = serde_yaml::to_string(&manifesto).unwrap();
+        let parsed: Manifesto = serde_yaml::from_str(&yaml).unwrap();
+        assert_eq!(manifesto, parsed);
+        assert_eq!(parsed.principles[0].id, "ship-small");
+    }
+
+    #[test]
+    fn test_legacy_text_loads() {
+        let parsed: Manifesto = serde_yaml::from_str("\"We value collaboration\"").unwrap();
+        assert_eq!(parsed.preamble.as_deref(), Some("We value collaboration"));
+        assert!(parsed.principles.is_empty());
+
+        // Plain text that happens to be a YAML mapping is still plain text
+        let parsed = Manifesto::parse("We value: collaboration.\n").unwrap();
+        assert_eq!(parsed.preamble.as_deref(), Some("We value: collaboration."));
+        assert!(parsed.principles.is_empty());
+
+        let parsed =
+            Manifesto::parse("preamble: Hello\nprinciples:\n  - id: a\n    title: A\n").unwrap();
+        assert_eq!(parsed.preamble.as_deref(), Some("Hello"));
+        assert_eq!(parsed.principles.len(), 1);
+    }
+}
@@ -21,6 +21,23 @@ pub struct Member {
     /// Timezone for pulse notifications
     #[serde(default)]
     pub timezone: Option<String>,
+
+    /// Other email addresses this member is known by
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub aliases: Vec<String>,
+}
+
+/// Normalise an email address for storage and lookup
+///
+/// Addresses are trimmed and lowercased, so `Alice@Example.com ` and
+/// `alice@example.com` refer to the same person.
+pub fn normalize_email(email: &str) -> String {
+    email.trim().to_lowercase()
+}
+
+/// Check whether two email addresses are the same after normalisation
+pub fn same_email(a: &str, b: &str) -> bool {
+    normalize_email(a) == normalize_email(b)
 }
 
 impl Member {
@@ -31,6 +48,7 @@ impl Member {
             name: None,
             bio: None,
             timezone: None,
+            aliases: Vec::new(),
         }
     }
 
@@ -52,6 +70,53 @@ impl Member {
         self
     }
 
+    /// Add an alias email address (builder pattern)
+    pub fn with_alias(mut self, email: impl AsRef<str>) -> Self {
+        self.add_alias(email.as_ref());
+        self
+    }
+
+    /// Add an alias email address, ignoring duplicates and the primary email
+    pub fn add_alias(&mut self, email: &str) {
+        if !self.has_email(email) {
+            self.aliases.push(normalize_email(email));
+        }
+    }
+
+    /// Check whether an email is this member's primary address or an alias
+    pub fn has_email(&self, email: &str) -> bool {
+        same_email(&self.email, email) || self.aliases.iter().any(|a| same_email(a, email))
+    }
+
+    /// Normalise the primary email and aliases
+    pub fn normalize(&mut self) {
+        self.email = normalize_email(&self.email);
+        let aliases = std::mem::take(&mut self.aliases);
+        for alias in aliases {
+            self.add_alias(&alias);
+        }
+    }
+
+    /// Fold another member's profile into this one
+    ///
+    /// Their emails become aliases, and any profile fields missing here are
+    /// taken from them.
+    pub fn absorb(&mut self, other: &Member) {
+        self.add_alias(&other.email);
+        for alias in &other.aliases {
+            self.add_alias(alias);
+        }
+        if self.name.is_none() {
+            self.name = other.name.clone();
+        }
+        if self.bio.is_none() {
+            self.bio = other.bio.clone();
+        }
+        if self.timezone.is_none() {
+            self.timezone = other.timezone.clone();
+        }
+    }
+
     /// Get the display name, falling back to email if not set
     pub fn display_name(&self) -> &str {
         self.name.as_deref().unwrap_or(&self.email)
@@ -90,6 +155,43 @@ mod tests {
         assert_eq!(member_with_name.display_name(), "Alice");
     }
 
+    #[test]
+    fn test_normalize_email() {
+        assert_eq!(normalize_email(" Alice@Example.COM "), "alice@example.com");
+        assert!(same_email("alice@example.com", "ALICE@example.com"));
+        assert!(!same_email("alice@example.com", "bob@example.com"));
+    }
+
+    #[test]
+    fn test_aliases() {
+        let mut member = Member::new("Alice@Example.com").with_alias("alice@home.example");
+        member.normalize();
+
+        assert_eq!(member.email, "alice@example.com");
+        assert!(member.has_email("ALICE@home.example"));
+        assert!(!member.has_email("bob@example.com"));
+
+        // Duplicates and the primary email are not added as aliases
+        member.add_alias("alice@example.com");
+        member.add_alias("Alice@Home.example");
+        assert_eq!(member.aliases.len(), 1);
+    }
+
+    #[test]
+    fn test_absorb() {
+        let mut primary = Member::new("alice@example.com");
+        let other = Member::new("alice@home.example")
+            .with_name("Alice")
+            .with_alias("ally@old.example");
+
+        primary.absorb(&other);
+        assert_eq!(primary.name, Some("Alice".to_string()));
+        assert_eq!(
+            primary.aliases,
+            vec!["alice@home.example", "ally@old.example"]
+        );
+    }
+
     #[test]
     fn test_member_serialization() {
         let member = Member::new("user@example.com")
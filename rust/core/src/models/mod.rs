@@ -1,13 +1,20 @@
 //! Domain models for interactions.work
 
 mod config;
+mod feedback_request;
 mod interaction;
+mod manifesto;
 mod member;
 mod okr;
 mod team;
 
-pub use config::{BackupConfig, LintingConfig, PublishConfig, TeamConfig, WebhookConfig};
+pub use config::{
+    BackupConfig, Cadence, CustomWebhook, LintingConfig, PublishConfig, PulseCadences, PulseConfig,
+    PulsePrompt, ShellHook, TeamConfig, WebhookConfig,
+};
+pub use feedback_request::FeedbackRequest;
 pub use interaction::{Interaction, InteractionKind};
-pub use member::Member;
+pub use manifesto::{Manifesto, Principle};
+pub use member::{normalize_email, same_email, Member};
 pub use okr::{KeyResult, Objective, OkrVisibility};
 pub use team::Team;
@@ -2,6 +2,7 @@
 //!
 //! Based on the OKR framework, tied to manifesto principles.
 
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 /// Visibility level for OKRs
@@ -80,6 +81,14 @@ pub struct Objective {
     /// Quarter (e.g., "2026-Q1")
     #[serde(default)]
     pub quarter: Option<String>,
+
+    /// Ids of the manifesto principles this objective serves
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub principles: Vec<String>,
+
+    /// When the objective or its progress was last changed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated: Option<DateTime<Utc>>,
 }
 
 impl Objective {
@@ -93,6 +102,8 @@ impl Objective {
             visibility: OkrVisibility::default(),
             owner: None,
             quarter: None,
+            principles: Vec::new(),
+            updated: Some(Utc::now()),
         }
     }
 
@@ -125,6 +136,29 @@ impl Objective {
         self
     }
 
+    /// Tie the objective to a manifesto principle by id
+    pub fn with_principle(mut self, id: impl Into<String>) -> Self {
+        self.principles.push(id.into());
+        self
+    }
+
+    /// Record that the objective was changed just now
+    pub fn touch(&mut self) {
+        self.updated = Some(Utc::now());
+    }
+
+    /// Set a key result's progress and record the update
+    ///
+    /// Returns false if there is no key result at `index`.
+    pub fn set_progress(&mut self, index: usize, progress: f32) -> bool {
+        let Some(kr) = self.key_results.get_mut(index) else {
+            return false;
+        };
+        kr.set_progress(progress);
+        self.touch();
+        true
+    }
+
     /// Calculate overall progress based on key results
     pub fn overall_progress(&self) -> f32 {
         if self.key_results.is_empty() {
@@ -5,6 +5,9 @@
 
 use serde::{Deserialize, Serialize};
 
+use super::manifesto::Manifesto;
+use super::member::{normalize_email, same_email};
+
 /// A team with its manifesto, vision, and members
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct Team {
@@ -13,7 +16,7 @@ pub struct Team {
 
     /// Behavior norms and cultural principles the team strives for
     #[serde(default)]
-    pub manifesto: Option<String>,
+    pub manifesto: Option<Manifesto>,
 
     /// What the team aims to achieve
     #[serde(default)]
@@ -26,6 +29,10 @@ pub struct Team {
     /// Email addresses of team members who commit to following manifesto principles
     #[serde(default)]
     pub members: Vec<String>,
+
+    /// Email addresses of former members, kept so their past interactions still resolve
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub archived: Vec<String>,
 }
 
 impl Team {
@@ -37,11 +44,12 @@ impl Team {
             vision: None,
             leaders: Vec::new(),
             members: Vec::new(),
+            archived: Vec::new(),
         }
     }
 
     /// Set the team manifesto
-    pub fn with_manifesto(mut self, manifesto: impl Into<String>) -> Self {
+    pub fn with_manifesto(mut self, manifesto: impl Into<Manifesto>) -> Self {
         self.manifesto = Some(manifesto.into());
         self
     }
@@ -54,34 +62,128 @@ impl Team {
 
     /// Add a leader to the team (builder pattern)
     pub fn add_leader(mut self, email: impl Into<String>) -> Self {
-        self.leaders.push(email.into());
+        self.leaders.push(normalize_email(&email.into()));
         self
     }
 
     /// Add a member to the team (builder pattern)
     pub fn add_member(mut self, email: impl Into<String>) -> Self {
-        self.members.push(email.into());
+        self.members.push(normalize_email(&email.into()));
         self
     }
 
-    /// Add a leader to the team (mutable)
+    /// Add a leader to the team (mutable), restoring them if archived
     pub fn push_leader(&mut self, email: impl Into<String>) {
-        self.leaders.push(email.into());
+        let email = normalize_email(&email.into());
+        self.archived.retain(|e| !same_email(e, &email));
+        self.leaders.push(email);
     }
 
-    /// Add a member to the team (mutable)
+    /// Add a member to the team (mutable), restoring them if archived
     pub fn push_member(&mut self, email: impl Into<String>) {
-        self.members.push(email.into());
+        let email = normalize_email(&email.into());
+        self.archived.retain(|e| !same_email(e, &email));
+        self.members.push(email);
     }
 
     /// Check if an email is a leader
     pub fn is_leader(&self, email: &str) -> bool {
-        self.leaders.iter().any(|e| e == email)
+        self.leaders.iter().any(|e| same_email(e, email))
     }
 
     /// Check if an email is a member (including leaders)
     pub fn is_member(&self, email: &str) -> bool {
-        self.members.iter().any(|e| e == email) || self.is_leader(email)
+        self.members.iter().any(|e| same_email(e, email)) || self.is_leader(email)
+    }
+
+    /// Check if an email belongs to a former (archived) member
+    pub fn is_archived(&self, email: &str) -> bool {
+        self.archived.iter().any(|e| same_email(e, email))
+    }
+
+    /// Normalise every email address in the team
+    pub fn normalize(&mut self) {
+        for list in [&mut self.leaders, &mut self.members, &mut self.archived] {
+            let mut seen: Vec<String> = Vec::new();
+            for email in list.drain(..) {
+                let email = normalize_email(&email);
+                if !seen.contains(&email) {
+                    seen.push(email);
+                }
+            }
+            *list = seen;
+        }
+    }
+
+    /// Remove someone from the team entirely, including the archive.
+    ///
+    /// Returns `true` if the email was found.
+    pub fn remove_member(&mut self, email: &str) -> bool {
+        let before = self.leaders.len() + self.members.len() + self.archived.len();
+        self.leaders.retain(|e| !same_email(e, email));
+        self.members.retain(|e| !same_email(e, email));
+        self.archived.retain(|e| !same_email(e, email));
+        before != self.leaders.len() + self.members.len() + self.archived.len()
+    }
+
+    /// Move an active leader or member to the archive.
+    ///
+    /// Returns `true` if the email was an active member.
+    pub fn archive_member(&mut self, email: &str) -> bool {
+        if !self.is_member(email) {
+            return false;
+        }
+        self.leaders.retain(|e| !same_email(e, email));
+        self.members.retain(|e| !same_email(e, email));
+        if !self.is_archived(email) {
+            self.archived.push(normalize_email(email));
+        }
+        true
+    }
+
+    /// Promote a member to leader.
+    ///
+    /// Returns `true` if the email was a (non-leader) member.
+    pub fn promote(&mut self, email: &str) -> bool {
+        let Some(pos) = self.members.iter().position(|e| same_email(e, email)) else {
+            return false;
+        };
+        let email = self.members.remove(pos);
+        self.leaders.push(email);
+        true
+    }
+
+    /// Demote a leader to member.
+    ///
+    /// Returns `true` if the email was a leader.
+    pub fn demote(&mut self, email: &str) -> bool {
+        let Some(pos) = self.leaders.iter().position(|e| same_email(e, email)) else {
+            return false;
+        };
+        let email = self.leaders.remove(pos);
+        self.members.push(email);
+        true
+    }
+
+    /// Fold one person's membership into another's, keeping the stronger role.
+    ///
+    /// Returns `true` if `from` was part of the team, active or archived.
+    pub fn merge_member(&mut self, from: &str, into: &str) -> bool {
+        let was_leader = self.is_leader(from);
+        let was_member = self.is_member(from);
+        if !self.remove_member(from) {
+            return false;
+        }
+
+        if was_leader && !self.is_leader(into) {
+            self.remove_member(into);
+            self.push_leader(into);
+        } else if was_member && !self.is_member(into) {
+            self.push_member(into);
+        } else if !self.is_member(into) && !self.is_archived(into) {
+            self.archived.push(normalize_email(into));
+        }
+        true
     }
 }
 
@@ -105,7 +207,10 @@ mod tests {
             .with_manifesto("We value collaboration")
             .with_vision("Build great software");
 
-        assert_eq!(team.manifesto, Some("We value collaboration".to_string()));
+        assert_eq!(
+            team.manifesto.unwrap().preamble.as_deref(),
+            Some("We value collaboration")
+        );
         assert_eq!(team.vision, Some("Build great software".to_string()));
     }
 
@@ -132,6 +237,78 @@ mod tests {
         assert!(team.is_member("member@example.com"));
     }
 
+    #[test]
+    fn test_membership_ignores_case() {
+        let mut team = Team::new("Engineering").add_leader("Leader@Example.com");
+        team.members.push("Member@Example.com".to_string());
+
+        assert!(team.is_leader("leader@example.com"));
+        assert!(team.is_member("MEMBER@example.com"));
+
+        team.normalize();
+        assert_eq!(team.members, vec!["member@example.com"]);
+    }
+
+    #[test]
+    fn test_merge_member() {
+        let mut team = Team::new("Engineering")
+            .add_leader("alice@work.example")
+            .add_member("alice@home.example")
+            .add_member("bob@example.com");
+
+        // The stronger role wins
+        assert!(team.merge_member("alice@work.example", "alice@home.example"));
+        assert!(team.is_leader("alice@home.example"));
+        assert!(!team.is_member("alice@work.example"));
+        assert_eq!(team.members, vec!["bob@example.com"]);
+
+        assert!(!team.merge_member("stranger@example.com", "bob@example.com"));
+    }
+
+    #[test]
+    fn test_promote_and_demote() {
+        let mut team = Team::new("Engineering")
+            .add_leader("leader@example.com")
+            .add_member("member@example.com");
+
+        assert!(team.promote("member@example.com"));
+        assert!(team.is_leader("member@example.com"));
+        assert!(!team.members.contains(&"member@example.com".to_string()));
+        assert!(!team.promote("member@example.com")); // Already a leader
+
+        assert!(team.demote("leader@example.com"));
+        assert!(!team.is_leader("leader@example.com"));
+        assert!(team.is_member("leader@example.com"));
+        assert!(!team.demote("stranger@example.com"));
+    }
+
+    #[test]
+    fn test_archive_and_remove() {
+        let mut team = Team::new("Engineering")
+            .add_leader("leader@example.com")
+            .add_member("member@example.com");
+
+        assert!(team.archive_member("member@example.com"));
+        assert!(!team.is_member("member@example.com"));
+        assert!(team.is_archived("member@example.com"));
+        assert!(!team.archive_member("member@example.com")); // No longer active
+
+        team.push_member("member@example.com");
+        assert!(team.is_member("member@example.com"));
+        assert!(!team.is_archived("member@example.com"));
+
+        assert!(team.remove_member("member@example.com"));
+        assert!(!team.is_member("member@example.com"));
+        assert!(!team.remove_member("member@example.com"));
+    }
+
+    #[test]
+    fn test_archived_skipped_when_empty() {
+        let team = Team::new("Engineering");
+        let yaml = serde_yaml::to_string(&team).unwrap();
+        assert!(!yaml.contains("archived"));
+    }
+
     #[test]
     fn test_team_serialization() {
         let team = Team::new("Engineering")
@@ -0,0 +1,383 @@
+//! Outbox for webhook notifications
+//!
+//! Notifications are stored in `.personal/outbox/` before they are sent, so
+//! one that cannot be delivered, for example while offline, is retried later
+//! with exponential backoff instead of being lost. Each entry is one event
+//! for one webhook; queueing the same event for the same webhook again is a
+//! no-op, so a notification is delivered at most once.
+
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::webhook::{self, Notification};
+use crate::{CustomWebhook, Error, Result, TeamStorage};
+
+/// Attempts after which an entry is only retried by a forced flush
+pub const MAX_ATTEMPTS: u32 = 10;
+
+/// Wait after the first failed attempt; doubled after each further one
+const FIRST_RETRY: Duration = Duration::seconds(30);
+
+/// Longest wait between two attempts
+const MAX_RETRY: Duration = Duration::hours(6);
+
+/// How long delivered entries are kept to recognise duplicates
+const KEEP_DELIVERED: Duration = Duration::days(7);
+
+/// Where an outbox entry stands
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// Waiting for its first or next attempt
+    Pending,
+    /// Gave up after [`MAX_ATTEMPTS`]
+    Failed,
+    /// Delivered
+    Delivered,
+}
+
+impl Status {
+    /// Human-readable label
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Pending => "pending",
+            Self::Failed => "failed",
+            Self::Delivered => "delivered",
+        }
+    }
+}
+
+/// A notification for one webhook
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct OutboxEntry {
+    /// Short id derived from the event id and the webhook URL
+    pub id: String,
+
+    /// Id of the event the notification is about
+    pub event_id: String,
+
+    /// The webhook to post to
+    pub hook: CustomWebhook,
+
+    /// The notification's fields
+    pub fields: BTreeMap<String, String>,
+
+    /// When the entry was queued
+    pub created: DateTime<Utc>,
+
+    /// Failed attempts so far
+    #[serde(default)]
+    pub attempts: u32,
+
+    /// Earliest moment of the next attempt
+    pub next_attempt: DateTime<Utc>,
+
+    /// Why the last attempt failed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_error: Option<String>,
+
+    /// When the notification was delivered
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub delivered: Option<DateTime<Utc>>,
+}
+
+impl OutboxEntry {
+    /// Queue a notification for a webhook
+    pub fn new(hook: &CustomWebhook, notification: &Notification, now: DateTime<Utc>) -> Self {
+        let event_id = notification.id();
+        Self {
+            id: entry_id(&event_id, hook),
+            event_id,
+            hook: hook.clone(),
+            fields: notification.fields.clone(),
+            created: now,
+            attempts: 0,
+            next_attempt: now,
+            last_error: None,
+            delivered: None,
+        }
+    }
+
+    /// The notification to send
+    pub fn notification(&self) -> Notification {
+        Notification {
+            fields: self.fields.clone(),
+        }
+    }
+
+    /// Where the entry stands
+    pub fn status(&self) -> Status {
+        if self.delivered.is_some() {
+            Status::Delivered
+        } else if self.attempts >= MAX_ATTEMPTS {
+            Status::Failed
+        } else {
+            Status::Pending
+        }
+    }
+
+    /// Whether a flush at `now` should try to deliver the entry
+    pub fn is_due(&self, now: DateTime<Utc>) -> bool {
+        self.status() == Status::Pending && self.next_attempt <= now
+    }
+
+    /// Record a failed attempt and schedule the next one
+    fn failed(&mut self, error: &Error, now: DateTime<Utc>) {
+        self.attempts += 1;
+        self.last_error = Some(error.to_string());
+        self.next_attempt = now + backoff(self.attempts);
+    }
+}
+
+/// Wait before the next attempt after `attempts` failed ones
+pub fn backoff(attempts: u32) -> Duration {
+    let doublings = attempts.saturating_sub(1).min(20);
+    (FIRST_RETRY * 2i32.pow(doublings)).min(MAX_RETRY)
+}
+
+fn entry_id(event_id: &str, hook: &CustomWebhook) -> String {
+    let digest = Sha256::digest(format!("{}\n{}", event_id, hook.url).as_bytes());
+    hex::encode(&digest[..6])
+}
+
+/// What a flush did
+#[derive(Debug, Default)]
+pub struct Flush {
+    /// Entries delivered
+    pub delivered: usize,
+    /// Why entries could not be delivered; they stay queued
+    pub failures: Vec<Error>,
+    /// Entries not tried because they are waiting for a retry or gave up
+    pub waiting: usize,
+}
+
+/// Queue a notification for every webhook that wants it
+///
+/// Returns how many entries were new; a webhook that already has the event
+/// queued or delivered is skipped.
+pub fn enqueue(
+    storage: &TeamStorage,
+    hooks: &[CustomWebhook],
+    notification: &Notification,
+    now: DateTime<Utc>,
+) -> Result<usize> {
+    let existing = storage.load_outbox()?;
+    let mut queued = 0;
+    for hook in hooks.iter().filter(|h| h.wants(notification.event())) {
+        let entry = OutboxEntry::new(hook, notification, now);
+        if existing.iter().any(|e| e.id == entry.id) {
+            continue;
+        }
+        storage.save_outbox_entry(&entry)?;
+        queued += 1;
+    }
+    Ok(queued)
+}
+
+/// Post the entries that are due
+///
+/// With `force`, every undelivered entry is tried, including ones waiting
+/// for a retry and ones that gave up. Delivered entries older than a week
+/// are cleaned up.
+pub fn flush(storage: &TeamStorage, now: DateTime<Utc>, force: bool) -> Result<Flush> {
+    flush_with(storage, now, force, webhook::send)
+}
+
+/// [`flush`], posting with `send`
+pub fn flush_with(
+    storage: &TeamStorage,
+    now: DateTime<Utc>,
+    force: bool,
+    send: impl Fn(&CustomWebhook, &Notification) -> Result<()>,
+) -> Result<Flush> {
+    let mut flush = Flush::default();
+    for mut entry in storage.load_outbox()? {
+        match entry.delivered {
+            Some(delivered) if delivered + KEEP_DELIVERED < now => {
+                storage.remove_outbox_entry(&entry.id)?;
+                continue;
+            }
+            Some(_) => continue,
+            None => {}
+        }
+        if !(force || entry.is_due(now)) {
+            flush.waiting += 1;
+            continue;
+        }
+
+        match send(&entry.hook, &entry.notification()) {
+            Ok(()) => {
+                entry.delivered = Some(now);
+                entry.last_error = None;
+                flush.delivered += 1;
+            }
+            Err(e) => {
+                entry.failed(&e, now);
+                flush.failures.push(e);
+            }
+        }
+        storage.save_outbox_entry(&entry)?;
+    }
+    Ok(flush)
+}
+
+/// Remove the entries whose id starts with `prefix`, returning how many
+pub fn discard(storage: &TeamStorage, prefix: &str) -> Result<usize> {
+    let mut removed = 0;
+    for entry in storage.load_outbox()? {
+        if entry.id.starts_with(prefix) && storage.remove_outbox_entry(&entry.id)? {
+            removed += 1;
+        }
+    }
+    Ok(removed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pulse::Pulse;
+    use crate::{Cadence, KeyResult, Objective, PulseConfig, PulsePrompt};
+    use std::cell::Cell;
+    use tempfile::TempDir;
+
+    fn hooks() -> Vec<CustomWebhook> {
+        vec![
+            CustomWebhook::new("https://a.example.com").with_name("a"),
+            CustomWebhook::new("https://b.example.com")
+                .with_name("b")
+                .with_events(&["member_added"]),
+        ]
+    }
+
+    fn offline(_: &CustomWebhook, _: &Notification) -> Result<()> {
+        Err(Error::Webhook("offline".to_string()))
+    }
+
+    #[test]
+    fn test_enqueue_deduplicates() {
+        let temp = TempDir::new().unwrap();
+        let storage = TeamStorage::new(temp.path());
+        let now = Utc::now();
+        let notification = Notification::new("interaction_created", "Hi").with_id("k1");
+
+        // Only the webhook that wants the event gets an entry
+        assert_eq!(enqueue(&storage, &hooks(), &notification, now).unwrap(), 1);
+        assert_eq!(enqueue(&storage, &hooks(), &notification, now).unwrap(), 0);
+
+        let other = Notification::new("interaction_created", "Hi").with_id("k2");
+        assert_eq!(enqueue(&storage, &hooks(), &other, now).unwrap(), 1);
+        assert_eq!(storage.load_outbox().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_enqueue_okr_updates_per_objective() {
+        let temp = TempDir::new().unwrap();
+        let storage = TeamStorage::new(temp.path());
+        let now: DateTime<Utc> = "2026-10-14T10:00:00Z".parse().unwrap();
+        let objectives = ["Ship", "Hire"]
+            .into_iter()
+            .map(|title| {
+                let mut o = Objective::new(title)
+                    .with_owner("alice@x.com")
+                    .with_quarter("2026-Q4");
+                o.add_key_result(KeyResult::new("Something"));
+                o.updated = Some("2026-09-01T00:00:00Z".parse().unwrap());
+                o
+            })
+            .collect();
+        let config = PulseConfig::default()
+            .with_cadence(PulsePrompt::CheckIn, Cadence::Off)
+            .with_cadence(PulsePrompt::Retro, Cadence::Off);
+        let due = Pulse::new(Some(config), Vec::new(), objectives).due_for("alice@x.com", now);
+        assert_eq!(due.len(), 2);
+
+        // Both stale objectives get a reminder, but only once each
+        for d in due.iter().chain(due.iter()) {
+            enqueue(&storage, &hooks()[..1], &Notification::pulse(d, now), now).unwrap();
+        }
+        assert_eq!(storage.load_outbox().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_retry_with_backoff() {
+        let temp = TempDir::new().unwrap();
+        let storage = TeamStorage::new(temp.path());
+        let now = Utc::now();
+        let notification = Notification::new("member_added", "Hi");
+        enqueue(&storage, &hooks(), &notification, now).unwrap();
+
+        let flushed = flush_with(&storage, now, false, offline).unwrap();
+        assert_eq!(flushed.failures.len(), 2);
+        let entry = &storage.load_outbox().unwrap()[0];
+        assert_eq!(entry.attempts, 1);
+        assert_eq!(entry.next_attempt, now + Duration::seconds(30));
+        assert_eq!(entry.last_error.as_deref(), Some("Webhook error: offline"));
+
+        // Not due yet, so nothing is sent
+        let sent = Cell::new(0);
+        let count = |_: &CustomWebhook, _: &Notification| {
+            sent.set(sent.get() + 1);
+            Ok(())
+        };
+        let flushed = flush_with(&storage, now, false, count).unwrap();
+        assert_eq!((sent.get(), flushed.waiting), (0, 2));
+
+        let later = now + Duration::minutes(1);
+        let flushed = flush_with(&storage, later, false, count).unwrap();
+        assert_eq!((sent.get(), flushed.delivered), (2, 2));
+        assert!(storage
+            .load_outbox()
+            .unwrap()
+            .iter()
+            .all(|e| e.status() == Status::Delivered));
+
+        // Delivered events are not queued again, and are cleaned up later
+        assert_eq!(
+            enqueue(&storage, &hooks(), &notification, later).unwrap(),
+            0
+        );
+        flush_with(&storage, later + Duration::days(8), false, count).unwrap();
+        assert!(storage.load_outbox().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_give_up_and_force() {
+        let temp = TempDir::new().unwrap();
+        let storage = TeamStorage::new(temp.path());
+        let mut now = Utc::now();
+        enqueue(
+            &storage,
+            &hooks()[..1],
+            &Notification::new("pulse", "Hi"),
+            now,
+        )
+        .unwrap();
+
+        for _ in 0..MAX_ATTEMPTS {
+            flush_with(&storage, now, false, offline).unwrap();
+            now += MAX_RETRY;
+        }
+        let entry = &storage.load_outbox().unwrap()[0];
+        assert_eq!(entry.status(), Status::Failed);
+        assert_eq!(
+            flush_with(&storage, now, false, offline).unwrap().waiting,
+            1
+        );
+
+        let flushed = flush_with(&storage, now, true, |_, _| Ok(())).unwrap();
+        assert_eq!(flushed.delivered, 1);
+
+        assert_eq!(discard(&storage, &entry.id[..4]).unwrap(), 1);
+        assert!(storage.load_outbox().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_backoff() {
+        assert_eq!(backoff(1), Duration::seconds(30));
+        assert_eq!(backoff(3), Duration::minutes(2));
+        assert_eq!(backoff(MAX_ATTEMPTS), Duration::seconds(30 * 512));
+        assert_eq!(backoff(12), MAX_RETRY);
+    }
+}
@@ -0,0 +1,193 @@
+//! Role-based permissions
+//!
+//! Leaders maintain the team itself (members, manifesto, vision and
+//! configuration). Members can only write their own profile and the
+//! interactions they authored. Anyone else is an observer with read-only
+//! access.
+
+use crate::{same_email, Error, Result, Team};
+
+/// A person's role within a team
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Role {
+    /// Sets purpose and maintains the team
+    Leader,
+    /// Takes part in the team
+    Member,
+    /// Can read shared team data but not change it
+    Observer,
+}
+
+impl Role {
+    /// Determine the role of a user, `None` meaning not authenticated
+    pub fn of(team: &Team, user: Option<&str>) -> Self {
+        match user {
+            Some(email) if team.is_leader(email) => Role::Leader,
+            Some(email) if team.is_member(email) => Role::Member,
+            _ => Role::Observer,
+        }
+    }
+
+    /// Get a human-readable label for this role
+    pub fn label(&self) -> &'static str {
+        match self {
+            Role::Leader => "Leader",
+            Role::Member => "Member",
+            Role::Observer => "Observer",
+        }
+    }
+}
+
+/// A change someone wants to make to team data
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action<'a> {
+    /// Change the team roster or name
+    EditTeam,
+    /// Change the manifesto
+    EditManifesto,
+    /// Change the vision
+    EditVision,
+    /// Change the team configuration
+    EditConfig,
+    /// Change the profile of the given member
+    EditMember(&'a str),
+    /// Set the pincode of the given member; leaders reset it with a token instead
+    SetCredentials(&'a str),
+    /// Write an interaction authored by the given member
+    AuthorInteraction(&'a str),
+    /// Change an objective, with its owner for personal OKRs
+    EditObjective(Option<&'a str>),
+}
+
+impl Action<'_> {
+    /// Describe the action for error messages
+    pub fn describe(&self) -> String {
+        match self {
+            Action::EditTeam => "edit the team".to_string(),
+            Action::EditManifesto => "edit the manifesto".to_string(),
+            Action::EditVision => "edit the vision".to_string(),
+            Action::EditConfig => "edit the team configuration".to_string(),
+            Action::EditMember(email) => format!("edit the profile of {}", email),
+            Action::SetCredentials(email) => format!("set the pincode of {}", email),
+            Action::AuthorInteraction(email) => {
+                format!("write interactions on behalf of {}", email)
+            }
+            Action::EditObjective(Some(owner)) => format!("edit objectives owned by {}", owner),
+            Action::EditObjective(None) => "edit team objectives".to_string(),
+        }
+    }
+}
+
+/// Check whether a user may perform an action
+///
+/// Returns [`Error::PermissionDenied`] when the user's role does not allow it.
+pub fn authorize(team: &Team, user: Option<&str>, action: Action) -> Result<()> {
+    let role = Role::of(team, user);
+    let is_self = |email: &str| user.is_some_and(|u| same_email(u, email));
+
+    let allowed = match (role, action) {
+        (Role::Observer, _) => false,
+        (_, Action::SetCredentials(email)) => is_self(email),
+        (Role::Leader, Action::AuthorInteraction(from)) => is_self(from),
+        (Role::Leader, _) => true,
+        (Role::Member, Action::EditMember(email)) => is_self(email),
+        (Role::Member, Action::AuthorInteraction(from)) => is_self(from),
+        (Role::Member, Action::EditObjective(Some(owner))) => is_self(owner),
+        (Role::Member, _) => false,
+    };
+
+    if allowed {
+        Ok(())
+    } else {
+        Err(Error::PermissionDenied(format!(
+            "{} ({}) cannot {}",
+            user.unwrap_or("anonymous"),
+            role.label(),
+            action.describe()
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn team() -> Team {
+        Team::new("Engineering")
+            .add_leader("leader@example.com")
+            .add_member("member@example.com")
+    }
+
+    #[test]
+    fn test_roles() {
+        let team = team();
+        assert_eq!(Role::of(&team, Some("leader@example.com")), Role::Leader);
+        assert_eq!(Role::of(&team, Some("member@example.com")), Role::Member);
+        assert_eq!(Role::of(&team, Some("other@example.com")), Role::Observer);
+        assert_eq!(Role::of(&team, None), Role::Observer);
+    }
+
+    #[test]
+    fn test_leader_permissions() {
+        let team = team();
+        let leader = Some("leader@example.com");
+        assert!(authorize(&team, leader, Action::EditTeam).is_ok());
+        assert!(authorize(&team, leader, Action::EditManifesto).is_ok());
+        assert!(authorize(&team, leader, Action::EditConfig).is_ok());
+        assert!(authorize(&team, leader, Action::EditMember("member@example.com")).is_ok());
+        assert!(authorize(&team, leader, Action::EditObjective(None)).is_ok());
+        assert!(authorize(&team, leader, Action::SetCredentials("leader@example.com")).is_ok());
+
+        // Leaders reset pincodes with a token rather than choosing them
+        let result = authorize(&team, leader, Action::SetCredentials("member@example.com"));
+        assert!(matches!(result, Err(Error::PermissionDenied(_))));
+
+        // Leaders cannot put words in other people's mouths
+        let result = authorize(
+            &team,
+            leader,
+            Action::AuthorInteraction("member@example.com"),
+        );
+        assert!(matches!(result, Err(Error::PermissionDenied(_))));
+    }
+
+    #[test]
+    fn test_member_permissions() {
+        let team = team();
+        let member = Some("member@example.com");
+        assert!(authorize(&team, member, Action::EditMember("member@example.com")).is_ok());
+        assert!(authorize(&team, member, Action::SetCredentials("member@example.com")).is_ok());
+        assert!(authorize(
+            &team,
+            member,
+            Action::AuthorInteraction("member@example.com")
+        )
+        .is_ok());
+        assert!(authorize(
+            &team,
+            member,
+            Action::EditObjective(Some("member@example.com"))
+        )
+        .is_ok());
+
+        assert!(authorize(&team, member, Action::EditTeam).is_err());
+        assert!(authorize(&team, member, Action::EditVision).is_err());
+        assert!(authorize(&team, member, Action::EditMember("leader@example.com")).is_err());
+        assert!(authorize(&team, member, Action::SetCredentials("leader@example.com")).is_err());
+        assert!(authorize(&team, member, Action::EditObjective(None)).is_err());
+    }
+
+    #[test]
+    fn test_observer_is_read_only() {
+        let team = team();
+        let observer = Some("other@example.com");
+        assert!(authorize(&team, observer, Action::EditMember("other@example.com")).is_err());
+        assert!(authorize(
+            &team,
+            observer,
+            Action::AuthorInteraction("other@example.com")
+        )
+        .is_err());
+        assert!(authorize(&team, None, Action::EditTeam).is_err());
+    }
+}
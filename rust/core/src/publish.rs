@@ -0,0 +1,400 @@
+//! Publishing
+//!
+//! Generates markdown files from the .team/ sources at the paths set in the
+//! `publish` section of .team/config.yaml.
+
+use std::collections::BTreeMap;
+use std::path::{Component, Path, PathBuf};
+
+use chrono_tz::Tz;
+
+use crate::analytics::Analytics;
+use crate::time::{format_in, parse_timezone, week_start};
+use crate::{
+    Error, Interaction, InteractionKind, Manifesto, Member, Objective, Result, Team, TeamStorage,
+};
+
+/// Generate all configured markdown files
+///
+/// Targets are relative to the storage root, a leading `/` included, and
+/// may not leave it. Timestamps are shown in `timezone` if given, otherwise in the timezone
+/// from the publish configuration, falling back to UTC. Returns the paths
+/// that were written.
+pub fn publish(storage: &TeamStorage, timezone: Option<Tz>) -> Result<Vec<PathBuf>> {
+    let team = storage.require_team()?;
+    let config = storage.load_config()?.unwrap_or_default();
+    let paths = config.publish.unwrap_or_default();
+    let tz = match (timezone, &paths.timezone) {
+        (Some(tz), _) => tz,
+        (None, Some(name)) => parse_timezone(name)?,
+        (None, None) => Tz::UTC,
+    };
+
+    let mut written = Vec::new();
+    let mut write = |target: &str, content: String| -> Result<()> {
+        let path = publish_path(storage.root(), target)?;
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(&path, content)?;
+        written.push(path);
+        Ok(())
+    };
+
+    if let Some(target) = &paths.manifesto {
+        let manifesto = storage.team_manifesto()?.unwrap_or_default();
+        write(target, render_manifesto(&team, &manifesto))?;
+    }
+    if let Some(target) = &paths.vision {
+        let vision = storage.load_vision()?.or(team.vision.clone());
+        write(target, render_section(&team, "Vision", vision.as_deref()))?;
+    }
+    if let Some(target) = &paths.interactions {
+        let interactions = storage.load_interactions(true)?;
+        let members = storage.load_members()?;
+        let analytics = Analytics::load(storage)?;
+        let mut content = render_interactions(&interactions, &members, tz);
+        content.push_str(&render_recognition(&analytics, &members));
+        write(target, content)?;
+    }
+    if let Some(target) = &paths.okrs {
+        let objectives = storage.load_objectives(true)?;
+        let members = storage.load_members()?;
+        if target.ends_with('/') {
+            // A directory gets one file per quarter
+            let mut quarters: BTreeMap<String, Vec<Objective>> = BTreeMap::new();
+            for objective in objectives {
+                let quarter = objective
+                    .quarter
+                    .clone()
+                    .unwrap_or_else(|| "unscheduled".to_string());
+                quarters.entry(quarter).or_default().push(objective);
+            }
+            for (quarter, objectives) in &quarters {
+                let content = render_objectives(&team, quarter, objectives, &members);
+                write(&format!("{}{}.md", target, quarter), content)?;
+            }
+        } else {
+            write(
+                target,
+                render_objectives(&team, "OKRs", &objectives, &members),
+            )?;
+        }
+    }
+
+    Ok(written)
+}
+
+/// Resolve a publish target under the storage root
+///
+/// Rejects targets with `..` components so a config file cannot write
+/// outside the repository.
+fn publish_path(root: &Path, target: &str) -> Result<PathBuf> {
+    let relative = Path::new(target.trim_start_matches('/'));
+    if !relative
+        .components()
+        .all(|c| matches!(c, Component::Normal(_) | Component::CurDir))
+        || relative.file_name().is_none()
+    {
+        return Err(Error::InvalidConfig(format!(
+            "Publish path must stay inside the repository: {}",
+            target
+        )));
+    }
+    Ok(root.join(relative))
+}
+
+/// Render the manifesto with a numbered heading per principle
+///
+/// Each principle heading gets an anchor matching its id so other documents
+/// can link to it.
+pub fn render_manifesto(team: &Team, manifesto: &Manifesto) -> String {
+    if manifesto.principles.is_empty() {
+        return render_section(team, "Manifesto", manifesto.preamble.as_deref());
+    }
+
+    let mut out = format!("# {} Manifesto\n", team.name);
+    if let Some(preamble) = manifesto.preamble.as_deref().map(str::trim) {
+        out.push_str(&format!("\n{}\n", preamble));
+    }
+    for (i, principle) in manifesto.principles.iter().enumerate() {
+        out.push_str(&format!(
+            "\n<a id=\"{}\"></a>\n## {}. {}\n",
+            principle.id,
+            i + 1,
+            principle.title
+        ));
+        if let Some(description) = principle.description.as_deref().map(str::trim) {
+            out.push_str(&format!("\n{}\n", description));
+        }
+    }
+    out
+}
+
+/// Render a titled free-text section such as the manifesto or vision
+fn render_section(team: &Team, title: &str, content: Option<&str>) -> String {
+    let body = content
+        .map(str::trim)
+        .filter(|c| !c.is_empty())
+        .unwrap_or("_Not written yet._");
+    format!("# {} {}\n\n{}\n", team.name, title, body)
+}
+
+/// Render shared objectives with the progress of each key result
+pub fn render_objectives(
+    team: &Team,
+    title: &str,
+    objectives: &[Objective],
+    members: &[Member],
+) -> String {
+    let mut out = format!("# {} {}\n", team.name, title);
+    if objectives.is_empty() {
+        out.push_str("\nNo shared objectives yet.\n");
+        return out;
+    }
+
+    for objective in objectives {
+        out.push_str(&format!(
+            "\n<a id=\"{}\"></a>\n## {} ({:.0}%)\n",
+            objective.id,
+            objective.title,
+            objective.overall_progress() * 100.0
+        ));
+        let mut meta = Vec::new();
+        if let Some(owner) = &objective.owner {
+            meta.push(format!("Owner: {}", display_name(members, owner)));
+        }
+        if let Some(quarter) = &objective.quarter {
+            meta.push(format!("Quarter: {}", quarter));
+        }
+        if !meta.is_empty() {
+            out.push_str(&format!("\n_{}_\n", meta.join(" · ")));
+        }
+        if let Some(description) = objective.description.as_deref().map(str::trim) {
+            out.push_str(&format!("\n{}\n", description));
+        }
+        if !objective.key_results.is_empty() {
+            out.push('\n');
+        }
+        for kr in &objective.key_results {
+            out.push_str(&format!(
+                "- {:>3.0}% {}\n",
+                kr.progress * 100.0,
+                kr.description
+            ));
+        }
+    }
+    out
+}
+
+/// Render shared interactions as a markdown log grouped by week, newest first
+pub fn render_interactions(interactions: &[Interaction], members: &[Member], tz: Tz) -> String {
+    let mut out = format!("# Interactions\n\n_Times are shown in {}._\n", tz.name());
+    if interactions.is_empty() {
+        out.push_str("\nNothing shared yet.\n");
+        return out;
+    }
+
+    let mut sorted: Vec<&Interaction> = interactions.iter().collect();
+    sorted.sort_by_key(|i| std::cmp::Reverse(i.timestamp));
+
+    let mut current_week = None;
+    for interaction in sorted {
+        let week = week_start(interaction.timestamp, tz);
+        if current_week != Some(week) {
+            out.push_str(&format!("\n## Week of {}\n\n", week.format("%Y-%m-%d")));
+            current_week = Some(week);
+        }
+
+        let with: Vec<&str> = interaction
+            .with
+            .iter()
+            .map(|email| display_name(members, email))
+            .collect();
+        out.push_str(&format!(
+            "- **{}** {} from {} to {}: {}\n",
+            format_in(interaction.timestamp, tz, "%Y-%m-%d %H:%M"),
+            interaction.kind.label(),
+            display_name(members, &interaction.from),
+            with.join(", "),
+            interaction.note.replace('\n', " "),
+        ));
+    }
+    out
+}
+
+/// Render a table of kudos and feedback given and received per member
+pub fn render_recognition(analytics: &Analytics, members: &[Member]) -> String {
+    let stats = analytics.all_member_stats();
+    if stats.is_empty() {
+        return String::new();
+    }
+
+    let mut out = String::from(
+        "\n## Recognition\n\n\
+         | Member | Kudos given | Kudos received | Feedback given | Feedback received |\n\
+         |---|---|---|---|---|\n",
+    );
+    for member in &stats {
+        out.push_str(&format!(
+            "| {} | {} | {} | {} | {} |\n",
+            display_name(members, &member.email),
+            member.sent_of(InteractionKind::Appreciation),
+            member.received_of(InteractionKind::Appreciation),
+            member.sent_of(InteractionKind::Feedback),
+            member.received_of(InteractionKind::Feedback),
+        ));
+    }
+    if let Some(reciprocity) = analytics.reciprocity() {
+        out.push_str(&format!(
+            "\n{:.0}% of appreciation between two people goes both ways.\n",
+            reciprocity * 100.0
+        ));
+    }
+    out
+}
+
+/// A member's display name, or the email if they have no profile
+fn display_name<'a>(members: &'a [Member], email: &'a str) -> &'a str {
+    members
+        .iter()
+        .find(|m| m.has_email(email))
+        .map_or(email, |m| m.display_name())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{KeyResult, OkrVisibility, Principle, PublishConfig, TeamConfig};
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_render_interactions_in_timezone() {
+        let mut kudos = Interaction::appreciation(
+            "alice@example.com",
+            vec!["bob@example.com".to_string()],
+            "Great demo",
+        );
+        // Sunday night in UTC is Monday morning in Tokyo, a new week
+        kudos.timestamp = "2026-02-01T23:30:00Z".parse().unwrap();
+        let members = vec![Member::new("alice@example.com").with_name("Alice")];
+
+        let utc = render_interactions(&[kudos.clone()], &members, Tz::UTC);
+        assert!(utc.contains("## Week of 2026-01-26"));
+        assert!(utc.contains("**2026-02-01 23:30** Appreciation from Alice to bob@example.com"));
+
+        let tokyo = render_interactions(&[kudos], &members, Tz::Asia__Tokyo);
+        assert!(tokyo.contains("_Times are shown in Asia/Tokyo._"));
+        assert!(tokyo.contains("## Week of 2026-02-02"));
+        assert!(tokyo.contains("**2026-02-02 08:30**"));
+    }
+
+    #[test]
+    fn test_render_manifesto() {
+        let team = Team::new("Crew");
+        let manifesto = Manifesto::new()
+            .with_preamble("How we work")
+            .with_principle(Principle::new("Ship small").with_description("Merge daily"))
+            .with_principle(Principle::new("Ask early"));
+
+        assert_eq!(
+            render_manifesto(&team, &manifesto),
+            "# Crew Manifesto\n\nHow we work\n\n<a id=\"ship-small\"></a>\n## 1. Ship small\n\n\
+             Merge daily\n\n<a id=\"ask-early\"></a>\n## 2. Ask early\n"
+        );
+        assert_eq!(
+            render_manifesto(&team, &Manifesto::from_text("Be kind")),
+            "# Crew Manifesto\n\nBe kind\n"
+        );
+    }
+
+    #[test]
+    fn test_render_recognition() {
+        let members = vec![Member::new("alice@example.com").with_name("Alice")];
+        let mut thanks = Interaction::appreciation(
+            "alice@example.com",
+            vec!["bob@example.com".to_string()],
+            "Thanks",
+        );
+        thanks.id = "1".to_string();
+        let mut back = Interaction::appreciation(
+            "bob@example.com",
+            vec!["alice@example.com".to_string()],
+            "You too",
+        );
+        back.id = "2".to_string();
+
+        let table = render_recognition(&Analytics::new(vec![thanks, back]), &members);
+        assert!(table.contains("| Alice | 1 | 1 | 0 | 0 |"));
+        assert!(table.contains("| bob@example.com | 1 | 1 | 0 | 0 |"));
+        assert!(table.contains("100% of appreciation"));
+        assert!(render_recognition(&Analytics::default(), &members).is_empty());
+    }
+
+    #[test]
+    fn test_render_objectives() {
+        let team = Team::new("Crew");
+        let members = vec![Member::new("alice@example.com").with_name("Alice")];
+        let mut objective = Objective::new("Faster releases")
+            .with_owner("alice@example.com")
+            .with_quarter("2026-Q1");
+        objective.id = "faster".to_string();
+        let mut kr = KeyResult::new("Weekly deploys");
+        kr.set_progress(0.5);
+        objective.add_key_result(kr);
+
+        assert_eq!(
+            render_objectives(&team, "2026-Q1", &[objective], &members),
+            "# Crew 2026-Q1\n\n<a id=\"faster\"></a>\n## Faster releases (50%)\n\n\
+             _Owner: Alice · Quarter: 2026-Q1_\n\n-  50% Weekly deploys\n"
+        );
+    }
+
+    #[test]
+    fn test_publish_path_stays_in_root() {
+        let root = Path::new("/repo");
+        assert_eq!(
+            publish_path(root, "/docs/VISION.md").unwrap(),
+            root.join("docs/VISION.md")
+        );
+        assert!(publish_path(root, "/../outside.md").is_err());
+        assert!(publish_path(root, "docs/../../etc/passwd").is_err());
+        assert!(publish_path(root, "/").is_err());
+    }
+
+    #[test]
+    fn test_publish_writes_configured_paths() {
+        let temp = TempDir::new().unwrap();
+        let storage = TeamStorage::new(temp.path());
+        let team = Team::new("My Team")
+            .add_leader("leader@example.com")
+            .with_vision("Ship it");
+        let config = TeamConfig::new().with_publish(PublishConfig {
+            vision: Some("/docs/VISION.md".to_string()),
+            interactions: Some("/INTERACTIONS.md".to_string()),
+            okrs: Some("/okrs/".to_string()),
+            timezone: Some("Europe/Amsterdam".to_string()),
+            ..PublishConfig::default()
+        });
+        storage
+            .initialize_team(&team, &config, &Member::new("leader@example.com"), "1234")
+            .unwrap();
+
+        storage
+            .save_objective(
+                &Objective::new("Grow")
+                    .with_visibility(OkrVisibility::Shared)
+                    .with_quarter("2026-Q2"),
+            )
+            .unwrap();
+
+        let written = publish(&storage, None).unwrap();
+        assert_eq!(written.len(), 3);
+        assert!(temp.path().join("okrs/2026-Q2.md").exists());
+
+        let vision = std::fs::read_to_string(temp.path().join("docs/VISION.md")).unwrap();
+        assert_eq!(vision, "# My Team Vision\n\nShip it\n");
+        let log = std::fs::read_to_string(temp.path().join("INTERACTIONS.md")).unwrap();
+        assert!(log.contains("Europe/Amsterdam"));
+    }
+}
@@ -0,0 +1,487 @@
+//! Pulse scheduling
+//!
+//! Works out which pulse prompts are due for whom: a prompt is due once its
+//! cadence has passed since the member last did the matching thing, or
+//! straight away if they never did. The CLI, TUI and FFI all ask the same
+//! scheduler.
+
+use chrono::{DateTime, Datelike, Utc};
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+
+use crate::time::parse_timezone;
+use crate::{
+    same_email, Cadence, Interaction, InteractionKind, Objective, PulseConfig, PulsePrompt, Result,
+    Team, TeamStorage,
+};
+
+/// A prompt that is due for a member
+#[derive(Debug, Clone, PartialEq)]
+pub struct DuePrompt {
+    /// Who the prompt is for
+    pub member: String,
+    /// What they are asked to do
+    pub prompt: PulsePrompt,
+    /// When they last did it, `None` if never
+    pub last: Option<DateTime<Utc>>,
+    /// The objective to update, for OKR updates
+    pub objective: Option<Objective>,
+}
+
+impl DuePrompt {
+    /// A reminder to show the member, e.g. "It's been 9 days since your last check-in"
+    pub fn message(&self, now: DateTime<Utc>) -> String {
+        let activity = match self.prompt {
+            PulsePrompt::CheckIn => "check-in",
+            PulsePrompt::OkrUpdate => "OKR update",
+            PulsePrompt::Retro => "retrospective",
+        };
+        if let Some(objective) = &self.objective {
+            let name = objective.quarter.as_deref().unwrap_or(&objective.title);
+            return match self.last {
+                Some(_) => format!("Your OKR for {} hasn't been updated", name),
+                None => format!("Your OKR for {} has no updates yet", name),
+            };
+        }
+        match self.last {
+            Some(last) => {
+                let days = (now - last).num_days();
+                let ago = if days == 1 {
+                    "1 day".to_string()
+                } else {
+                    format!("{} days", days)
+                };
+                format!("It's been {} since your last {}", ago, activity)
+            }
+            None => format!("You haven't logged a {} yet", activity),
+        }
+    }
+}
+
+/// A due prompt the member put off, kept in .personal/pulse.yaml
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Snooze {
+    /// Who snoozed the prompt
+    pub member: String,
+    /// The prompt that was snoozed
+    pub prompt: PulsePrompt,
+    /// The objective, for OKR updates
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub objective: Option<String>,
+    /// When the prompt may come up again
+    pub until: DateTime<Utc>,
+}
+
+impl Snooze {
+    /// Snooze a due prompt until a moment
+    pub fn new(due: &DuePrompt, until: DateTime<Utc>) -> Self {
+        Self {
+            member: due.member.clone(),
+            prompt: due.prompt,
+            objective: due.objective.as_ref().map(|o| o.id.clone()),
+            until,
+        }
+    }
+
+    /// Whether this snooze is about the same prompt as `due`
+    pub fn matches(&self, due: &DuePrompt) -> bool {
+        self.prompt == due.prompt
+            && same_email(&self.member, &due.member)
+            && self.objective.as_deref() == due.objective.as_ref().map(|o| o.id.as_str())
+    }
+}
+
+/// Drop the prompts that are snoozed at `now`
+pub fn without_snoozed(
+    due: Vec<DuePrompt>,
+    snoozes: &[Snooze],
+    now: DateTime<Utc>,
+) -> Vec<DuePrompt> {
+    due.into_iter()
+        .filter(|d| !snoozes.iter().any(|s| s.until > now && s.matches(d)))
+        .collect()
+}
+
+/// The pulse schedule over a team's interactions and objectives
+#[derive(Debug, Clone)]
+pub struct Pulse {
+    config: Option<PulseConfig>,
+    interactions: Vec<Interaction>,
+    objectives: Vec<Objective>,
+    timezone: Tz,
+}
+
+impl Default for Pulse {
+    fn default() -> Self {
+        Self::new(None, Vec::new(), Vec::new())
+    }
+}
+
+impl Pulse {
+    /// Build a schedule; without a config nothing is ever due
+    pub fn new(
+        config: Option<PulseConfig>,
+        interactions: Vec<Interaction>,
+        objectives: Vec<Objective>,
+    ) -> Self {
+        Self {
+            config,
+            interactions,
+            objectives,
+            timezone: Tz::UTC,
+        }
+    }
+
+    /// Decide which quarter it is in a timezone rather than UTC (builder pattern)
+    pub fn with_timezone(mut self, timezone: Tz) -> Self {
+        self.timezone = timezone;
+        self
+    }
+
+    /// Build a schedule from the team storage
+    ///
+    /// Uses everything the team can see plus what is in this machine's
+    /// .personal/ directory, so the local member's private check-ins and
+    /// objectives count too. Quarters follow the team's publish timezone.
+    pub fn load(storage: &TeamStorage) -> Result<Self> {
+        Self::load_from(storage, false)
+    }
+
+    /// Build a schedule from shared interactions and objectives only
+    ///
+    /// For reminders that reach the whole team, such as webhooks, so nobody's
+    /// private check-ins or objectives show up in them.
+    pub fn load_shared(storage: &TeamStorage) -> Result<Self> {
+        Self::load_from(storage, true)
+    }
+
+    fn load_from(storage: &TeamStorage, shared_only: bool) -> Result<Self> {
+        let config = storage.load_config()?.unwrap_or_default();
+        let timezone = config
+            .publish
+            .and_then(|p| p.timezone)
+            .and_then(|name| parse_timezone(&name).ok())
+            .unwrap_or(Tz::UTC);
+        let config = config.pulse;
+        let mut interactions = storage.load_team_kudos()?;
+        interactions.extend(storage.load_team_feedback()?);
+        interactions.extend(storage.load_interactions(true)?);
+        let mut objectives = storage.load_objectives(true)?;
+        if shared_only {
+            // Received kudos and feedback live in the team directory even when private
+            interactions.retain(|i| i.shared);
+        } else {
+            interactions.extend(storage.load_interactions(false)?);
+            objectives.extend(storage.load_objectives(false)?);
+        }
+        Ok(Self::new(config, interactions, objectives).with_timezone(timezone))
+    }
+
+    /// The configuration the schedule follows
+    pub fn config(&self) -> Option<&PulseConfig> {
+        self.config.as_ref()
+    }
+
+    /// Prompts due for a member at `now`
+    pub fn due_for(&self, email: &str, now: DateTime<Utc>) -> Vec<DuePrompt> {
+        let Some(config) = &self.config else {
+            return Vec::new();
+        };
+        let mut due = Vec::new();
+        for prompt in PulsePrompt::ALL {
+            let cadence = config.cadence(prompt, email);
+            if cadence == Cadence::Off {
+                continue;
+            }
+            match prompt {
+                PulsePrompt::OkrUpdate => {
+                    for objective in self.open_objectives(email, now) {
+                        if is_due(cadence, objective.updated, now) {
+                            due.push(DuePrompt {
+                                member: email.to_string(),
+                                prompt,
+                                last: objective.updated,
+                                objective: Some(objective.clone()),
+                            });
+                        }
+                    }
+                }
+                PulsePrompt::CheckIn | PulsePrompt::Retro => {
+                    let kind = if prompt == PulsePrompt::CheckIn {
+                        InteractionKind::CheckIn
+                    } else {
+                        InteractionKind::Retrospective
+                    };
+                    let last = self.last_interaction(email, kind);
+                    if is_due(cadence, last, now) {
+                        due.push(DuePrompt {
+                            member: email.to_string(),
+                            prompt,
+                            last,
+                            objective: None,
+                        });
+                    }
+                }
+            }
+        }
+        due
+    }
+
+    /// Prompts due for every active member of the team at `now`
+    pub fn due(&self, team: &Team, now: DateTime<Utc>) -> Vec<DuePrompt> {
+        team.leaders
+            .iter()
+            .chain(team.members.iter())
+            .flat_map(|email| self.due_for(email, now))
+            .collect()
+    }
+
+    /// When the member last took part in an interaction of a kind
+    fn last_interaction(&self, email: &str, kind: InteractionKind) -> Option<DateTime<Utc>> {
+        self.interactions
+            .iter()
+            .filter(|i| i.kind == kind)
+            .filter(|i| same_email(&i.from, email) || i.with.iter().any(|w| same_email(w, email)))
+            .map(|i| i.timestamp)
+            .max()
+    }
+
+    /// Unfinished objectives the member owns for this quarter or later
+    fn open_objectives(&self, email: &str, now: DateTime<Utc>) -> Vec<&Objective> {
+        let current = quarter_of(now, self.timezone);
+        let mut seen = std::collections::BTreeSet::new();
+        self.objectives
+            .iter()
+            .filter(|o| {
+                o.owner
+                    .as_deref()
+                    .is_some_and(|owner| same_email(owner, email))
+            })
+            .filter(|o| o.quarter.as_ref().is_none_or(|q| *q >= current))
+            .filter(|o| o.overall_progress() < 1.0)
+            .filter(|o| seen.insert(o.id.clone()))
+            .collect()
+    }
+}
+
+/// Whether a prompt with a cadence is due given when it was last acted on
+fn is_due(cadence: Cadence, last: Option<DateTime<Utc>>, now: DateTime<Utc>) -> bool {
+    match last {
+        Some(last) => cadence.next_after(last).is_some_and(|next| next <= now),
+        None => true,
+    }
+}
+
+/// The quarter a moment falls in within a timezone, in the "2026-Q1" form
+/// objectives use
+pub fn quarter_of(moment: DateTime<Utc>, tz: Tz) -> String {
+    let local = moment.with_timezone(&tz);
+    format!("{}-Q{}", local.year(), local.month0() / 3 + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{KeyResult, Member, TeamConfig};
+    use tempfile::TempDir;
+
+    fn at(kind: InteractionKind, from: &str, with: &[&str], timestamp: &str) -> Interaction {
+        let with = with.iter().map(|w| w.to_string()).collect();
+        Interaction {
+            timestamp: timestamp.parse().unwrap(),
+            ..Interaction::new(kind, from, with, "")
+        }
+    }
+
+    fn now() -> DateTime<Utc> {
+        "2026-10-14T10:00:00Z".parse().unwrap()
+    }
+
+    #[test]
+    fn test_check_in_due_after_cadence() {
+        let config = PulseConfig::default()
+            .with_cadence(PulsePrompt::Retro, Cadence::Off)
+            .with_member_cadence("bob@x.com", PulsePrompt::CheckIn, Cadence::Biweekly);
+        let interactions = vec![
+            // A check-in with a leader counts for both of them
+            at(
+                InteractionKind::CheckIn,
+                "alice@x.com",
+                &["bob@x.com"],
+                "2026-10-05T10:00:00Z",
+            ),
+            at(
+                InteractionKind::Appreciation,
+                "alice@x.com",
+                &["bob@x.com"],
+                "2026-10-13T10:00:00Z",
+            ),
+        ];
+        let pulse = Pulse::new(Some(config), interactions, Vec::new());
+
+        let alice = pulse.due_for("alice@x.com", now());
+        assert_eq!(alice.len(), 1);
+        assert_eq!(alice[0].prompt, PulsePrompt::CheckIn);
+        assert_eq!(
+            alice[0].message(now()),
+            "It's been 9 days since your last check-in"
+        );
+
+        // Bob checks in every two weeks, so he is not due yet
+        assert!(pulse.due_for("bob@x.com", now()).is_empty());
+
+        let carol = pulse.due_for("carol@x.com", now());
+        assert_eq!(carol[0].message(now()), "You haven't logged a check-in yet");
+    }
+
+    #[test]
+    fn test_okr_update_due_per_objective() {
+        let config = PulseConfig::default()
+            .with_cadence(PulsePrompt::CheckIn, Cadence::Off)
+            .with_cadence(PulsePrompt::Retro, Cadence::Off);
+        let objective = |title: &str, quarter: &str, updated: &str| {
+            let mut o = Objective::new(title)
+                .with_owner("alice@x.com")
+                .with_quarter(quarter);
+            o.id = title.to_string();
+            o.add_key_result(KeyResult::new("Something"));
+            o.updated = Some(updated.parse().unwrap());
+            o
+        };
+        let mut done = objective("Done", "2026-Q4", "2026-08-01T00:00:00Z");
+        done.set_progress(0, 1.0);
+        let objectives = vec![
+            objective("Stale", "2026-Q4", "2026-09-01T00:00:00Z"),
+            objective("Fresh", "2026-Q4", "2026-10-01T00:00:00Z"),
+            objective("Last quarter", "2026-Q3", "2026-07-01T00:00:00Z"),
+            done,
+        ];
+        let pulse = Pulse::new(Some(config), Vec::new(), objectives);
+
+        let due = pulse.due_for("Alice@x.com", now());
+        assert_eq!(due.len(), 1);
+        assert_eq!(due[0].objective.as_ref().unwrap().id, "Stale");
+        assert_eq!(
+            due[0].message(now()),
+            "Your OKR for 2026-Q4 hasn't been updated"
+        );
+    }
+
+    #[test]
+    fn test_nothing_due_without_config() {
+        let pulse = Pulse::new(None, Vec::new(), Vec::new());
+        assert!(pulse.due_for("alice@x.com", now()).is_empty());
+    }
+
+    #[test]
+    fn test_load_and_due_for_team() {
+        let temp = TempDir::new().unwrap();
+        let storage = TeamStorage::new(temp.path());
+        let team = Team::new("T")
+            .add_leader("alice@x.com")
+            .add_member("bob@x.com");
+        let config = TeamConfig::new().with_pulse(
+            PulseConfig::default()
+                .with_cadence(PulsePrompt::OkrUpdate, Cadence::Off)
+                .with_cadence(PulsePrompt::Retro, Cadence::Off),
+        );
+        storage
+            .initialize_team(&team, &config, &Member::new("alice@x.com"), "1234")
+            .unwrap();
+        storage
+            .save_interaction(
+                &at(
+                    InteractionKind::CheckIn,
+                    "alice@x.com",
+                    &[],
+                    "2026-10-12T10:00:00Z",
+                )
+                .shared(),
+            )
+            .unwrap();
+
+        let pulse = Pulse::load(&storage).unwrap();
+        let due: Vec<String> = pulse
+            .due(&team, now())
+            .into_iter()
+            .map(|d| d.member)
+            .collect();
+        assert_eq!(due, vec!["bob@x.com"]);
+    }
+
+    #[test]
+    fn test_load_shared_leaves_out_private() {
+        let temp = TempDir::new().unwrap();
+        let storage = TeamStorage::new(temp.path());
+        let team = Team::new("T").add_leader("alice@x.com");
+        let config = TeamConfig::new().with_pulse(
+            PulseConfig::default()
+                .with_cadence(PulsePrompt::CheckIn, Cadence::Weekly)
+                .with_cadence(PulsePrompt::Retro, Cadence::Off),
+        );
+        storage
+            .initialize_team(&team, &config, &Member::new("alice@x.com"), "1234")
+            .unwrap();
+        storage
+            .save_interaction(&at(
+                InteractionKind::CheckIn,
+                "alice@x.com",
+                &[],
+                "2026-10-12T10:00:00Z",
+            ))
+            .unwrap();
+        let mut objective = Objective::new("Secret plan")
+            .with_owner("alice@x.com")
+            .with_quarter("2026-Q4");
+        objective.add_key_result(KeyResult::new("Something"));
+        objective.updated = Some("2026-09-01T00:00:00Z".parse().unwrap());
+        storage.save_objective(&objective).unwrap();
+
+        // The local member's own schedule counts private things
+        let own = Pulse::load(&storage).unwrap().due(&team, now());
+        assert_eq!(own.len(), 1);
+        assert_eq!(own[0].objective.as_ref().unwrap().title, "Secret plan");
+
+        // The team's schedule does not
+        let shared = Pulse::load_shared(&storage).unwrap().due(&team, now());
+        assert_eq!(shared.len(), 1);
+        assert_eq!(shared[0].prompt, PulsePrompt::CheckIn);
+        assert!(shared.iter().all(|d| d.objective.is_none()));
+    }
+
+    #[test]
+    fn test_snoozes() {
+        let temp = TempDir::new().unwrap();
+        let storage = TeamStorage::new(temp.path());
+        let pulse = Pulse::new(Some(PulseConfig::default()), Vec::new(), Vec::new());
+        let due = pulse.due_for("alice@x.com", now());
+        assert_eq!(due.len(), 2);
+
+        let tomorrow = now() + chrono::Duration::days(1);
+        storage
+            .snooze_pulse_prompt(&due[0], tomorrow, now())
+            .unwrap();
+        let snoozes = storage.load_pulse_snoozes().unwrap();
+        assert_eq!(snoozes.len(), 1);
+
+        let left = without_snoozed(due.clone(), &snoozes, now());
+        assert_eq!(left.len(), 1);
+        assert_eq!(left[0].prompt, PulsePrompt::Retro);
+        // Bob's prompts are not affected by Alice's snooze
+        assert_eq!(
+            without_snoozed(pulse.due_for("bob@x.com", now()), &snoozes, now()).len(),
+            2
+        );
+        // Once the snooze runs out the prompt is back
+        assert_eq!(without_snoozed(due, &snoozes, tomorrow).len(), 2);
+    }
+
+    #[test]
+    fn test_quarter_of() {
+        assert_eq!(quarter_of(now(), Tz::UTC), "2026-Q4");
+        let moment = "2026-03-31T23:00:00Z".parse().unwrap();
+        assert_eq!(quarter_of(moment, Tz::UTC), "2026-Q1");
+        // Already the next quarter for a team in Amsterdam
+        assert_eq!(quarter_of(moment, Tz::Europe__Amsterdam), "2026-Q2");
+    }
+}
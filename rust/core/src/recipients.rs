@@ -0,0 +1,179 @@
+//! Recipient lookup
+//!
+//! Matches what someone typed in a recipient field against the team's
+//! members by display name, email or alias, tolerating small typos.
+
+use crate::Member;
+
+/// Find the member the input unambiguously refers to
+///
+/// Matches an email or alias exactly, or a display name case-insensitively
+/// when only one member has that name.
+pub fn resolve<'a>(members: &'a [Member], input: &str) -> Option<&'a Member> {
+    let input = input.trim();
+    if input.is_empty() {
+        return None;
+    }
+    if let Some(member) = members.iter().find(|m| m.has_email(input)) {
+        return Some(member);
+    }
+
+    let mut by_name = members.iter().filter(|m| {
+        m.name
+            .as_deref()
+            .is_some_and(|n| n.eq_ignore_ascii_case(input))
+    });
+    match (by_name.next(), by_name.next()) {
+        (Some(member), None) => Some(member),
+        _ => None,
+    }
+}
+
+/// Rank members by how well they match the input, best first
+///
+/// An empty input lists everyone by display name.
+pub fn suggest<'a>(members: &'a [Member], input: &str, limit: usize) -> Vec<&'a Member> {
+    let query = input.trim().to_lowercase();
+    let mut scored: Vec<(u8, String, &Member)> = members
+        .iter()
+        .filter_map(|member| {
+            let score = if query.is_empty() {
+                0
+            } else {
+                candidates(member)
+                    .iter()
+                    .filter_map(|c| match_score(c, &query))
+                    .min()?
+            };
+            Some((score, member.display_name().to_lowercase(), member))
+        })
+        .collect();
+
+    scored.sort_by(|a, b| (a.0, &a.1).cmp(&(b.0, &b.1)));
+    scored.into_iter().take(limit).map(|(_, _, m)| m).collect()
+}
+
+/// Lowercased strings a member can be found by
+fn candidates(member: &Member) -> Vec<String> {
+    let mut candidates = vec![member.email.to_lowercase()];
+    candidates.extend(member.aliases.iter().map(|a| a.to_lowercase()));
+    if let Some(name) = &member.name {
+        let name = name.to_lowercase();
+        candidates.extend(name.split_whitespace().map(|w| w.to_string()));
+        candidates.push(name);
+    }
+    candidates
+}
+
+/// Score how well a query matches a candidate; lower is better
+fn match_score(candidate: &str, query: &str) -> Option<u8> {
+    if candidate == query {
+        Some(0)
+    } else if candidate.starts_with(query) {
+        Some(1)
+    } else if candidate.contains(query) {
+        Some(2)
+    } else if is_subsequence(query, candidate) {
+        Some(3)
+    } else {
+        // Allow roughly one typo per four characters typed so far
+        let allowed = (query.chars().count() / 4).max(1);
+        let prefix: String = candidate.chars().take(query.chars().count()).collect();
+        (edit_distance(&prefix, query) <= allowed || edit_distance(candidate, query) <= allowed)
+            .then_some(4)
+    }
+}
+
+/// Whether all characters of `needle` appear in `haystack` in order
+fn is_subsequence(needle: &str, haystack: &str) -> bool {
+    let mut chars = haystack.chars();
+    needle.chars().all(|n| chars.any(|h| h == n))
+}
+
+/// Edit distance between two strings, counting swapped neighbours as one edit
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut rows = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for (i, row) in rows.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, cell) in rows[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            let mut best = (rows[i - 1][j] + 1)
+                .min(rows[i][j - 1] + 1)
+                .min(rows[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                best = best.min(rows[i - 2][j - 2] + 1);
+            }
+            rows[i][j] = best;
+        }
+    }
+    rows[a.len()][b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn members() -> Vec<Member> {
+        vec![
+            Member::new("alice@example.com").with_name("Alice Jansen"),
+            Member::new("bob@example.com")
+                .with_name("Bob de Vries")
+                .with_alias("bobby@home.example"),
+            Member::new("carol@example.com"),
+        ]
+    }
+
+    #[test]
+    fn test_resolve() {
+        let members = members();
+        assert_eq!(
+            resolve(&members, "ALICE@example.com").map(|m| m.email.as_str()),
+            Some("alice@example.com")
+        );
+        assert_eq!(
+            resolve(&members, "bobby@home.example").map(|m| m.email.as_str()),
+            Some("bob@example.com")
+        );
+        assert_eq!(
+            resolve(&members, "bob de vries").map(|m| m.email.as_str()),
+            Some("bob@example.com")
+        );
+        assert!(resolve(&members, "alice").is_none());
+        assert!(resolve(&members, "").is_none());
+    }
+
+    #[test]
+    fn test_suggest() {
+        let members = members();
+        let emails = |input: &str| -> Vec<String> {
+            suggest(&members, input, 5)
+                .into_iter()
+                .map(|m| m.email.clone())
+                .collect()
+        };
+
+        assert_eq!(emails("ali"), vec!["alice@example.com"]);
+        assert_eq!(emails("vries"), vec!["bob@example.com"]);
+        // Typos still find the member
+        assert_eq!(emails("alcie"), vec!["alice@example.com"]);
+        assert!(emails("zzz").is_empty());
+        // An empty input lists everyone
+        assert_eq!(emails("").len(), 3);
+    }
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+        assert_eq!(edit_distance("", "abc"), 3);
+        assert_eq!(edit_distance("same", "same"), 0);
+        assert_eq!(edit_distance("alice", "alcie"), 1);
+    }
+}
@@ -0,0 +1,439 @@
+//! Permission-checked access to team storage
+//!
+//! Wraps [`TeamStorage`] so every write is checked against the role of the
+//! authenticated user. Reads go straight to the underlying storage. Writes
+//! that succeed are published on the attached [`EventBus`], if any.
+
+use std::borrow::Cow;
+
+use super::TeamStorage;
+use crate::events::{Change, Event, EventBus};
+use crate::permissions::{authorize, Action, Role};
+use crate::{
+    auth::MemberCredentials, Error, FeedbackRequest, Interaction, Manifesto, Member, Objective,
+    OkrVisibility, Result, Team, TeamConfig,
+};
+
+/// Team storage acting on behalf of an authenticated user
+pub struct AuthorizedStorage<'a> {
+    storage: &'a TeamStorage,
+    user: Option<Cow<'a, str>>,
+    events: Option<&'a EventBus>,
+}
+
+impl<'a> AuthorizedStorage<'a> {
+    /// Act on behalf of a user who has already been authenticated
+    ///
+    /// Pass `None` for read-only observer access.
+    pub fn new(storage: &'a TeamStorage, user: Option<&'a str>) -> Self {
+        Self {
+            storage,
+            user: user.map(Cow::Borrowed),
+            events: None,
+        }
+    }
+
+    /// Publish successful changes on an event bus
+    pub fn with_events(mut self, events: &'a EventBus) -> Self {
+        self.events = Some(events);
+        self
+    }
+
+    /// Authenticate a member with their pincode
+    ///
+    /// The member may log in with an alias; the session holds their
+    /// primary email, which is what their role is looked up by.
+    pub fn login(storage: &'a TeamStorage, email: &str, pincode: &str) -> Result<Self> {
+        if !storage.verify_pincode(email, pincode)? {
+            return Err(Error::AuthFailed(format!(
+                "Incorrect pincode for {}",
+                email
+            )));
+        }
+        Ok(Self {
+            storage,
+            user: Some(Cow::Owned(storage.resolve_email(email)?)),
+            events: None,
+        })
+    }
+
+    /// The underlying storage, for reading
+    pub fn storage(&self) -> &TeamStorage {
+        self.storage
+    }
+
+    /// The authenticated user, if any
+    pub fn user(&self) -> Option<&str> {
+        self.user.as_deref()
+    }
+
+    fn publish(&self, change: Change) {
+        if let Some(events) = self.events {
+            events.publish(&Event::new(change));
+        }
+    }
+
+    /// The role of the authenticated user in the current team
+    pub fn role(&self) -> Result<Role> {
+        Ok(Role::of(&self.storage.require_team()?, self.user()))
+    }
+
+    /// Check an action against the team as currently stored
+    pub fn authorize(&self, action: Action) -> Result<()> {
+        authorize(&self.storage.require_team()?, self.user(), action)
+    }
+
+    /// Save team configuration (leaders only)
+    pub fn save_team(&self, team: &Team) -> Result<()> {
+        self.authorize(Action::EditTeam)?;
+        self.storage.save_team(team)
+    }
+
+    /// Save the manifesto (leaders only)
+    pub fn save_manifesto(&self, manifesto: &Manifesto) -> Result<()> {
+        self.authorize(Action::EditManifesto)?;
+        self.storage.save_manifesto(manifesto)?;
+        self.publish(Change::ManifestoChanged(manifesto.clone()));
+        Ok(())
+    }
+
+    /// Save vision content (leaders only)
+    pub fn save_vision(&self, content: &str) -> Result<()> {
+        self.authorize(Action::EditVision)?;
+        self.storage.save_vision(content)
+    }
+
+    /// Save team configuration (leaders only)
+    pub fn save_config(&self, config: &TeamConfig) -> Result<()> {
+        self.authorize(Action::EditConfig)?;
+        self.storage.save_config(config)
+    }
+
+    /// Save a member's profile (own profile, or any profile for leaders)
+    pub fn save_member(&self, member: &Member) -> Result<()> {
+        self.authorize(Action::EditMember(&member.email))?;
+        self.storage.save_member(member)
+    }
+
+    /// Save the user's own credentials
+    ///
+    /// Leaders cannot choose a pincode for someone else; they issue a reset
+    /// token with [`Self::issue_reset_token`] instead.
+    pub fn save_credentials(&self, creds: &MemberCredentials) -> Result<()> {
+        self.authorize(Action::SetCredentials(&creds.email))?;
+        self.storage.save_credentials(creds)
+    }
+
+    /// Save an interaction authored by the user
+    pub fn save_interaction(&self, interaction: &Interaction) -> Result<()> {
+        self.authorize_author(interaction)?;
+        self.storage.save_interaction(interaction)?;
+        self.publish(Change::InteractionCreated(interaction.clone()));
+        Ok(())
+    }
+
+    /// Save kudos authored by the user
+    pub fn save_kudos(&self, interaction: &Interaction) -> Result<()> {
+        self.authorize_author(interaction)?;
+        self.storage.save_kudos(interaction)?;
+        self.publish(Change::InteractionCreated(interaction.clone()));
+        Ok(())
+    }
+
+    /// Save feedback authored by the user
+    pub fn save_feedback(&self, interaction: &Interaction) -> Result<()> {
+        self.authorize_author(interaction)?;
+        self.storage.save_feedback(interaction)?;
+        self.publish(Change::InteractionCreated(interaction.clone()));
+        Ok(())
+    }
+
+    /// Check that the user authored an interaction, and the one it would
+    /// overwrite if its ID is taken
+    fn authorize_author(&self, interaction: &Interaction) -> Result<()> {
+        self.authorize(Action::AuthorInteraction(&interaction.from))?;
+        if let Some(existing) = self.storage.find_interaction(&interaction.id)? {
+            self.authorize(Action::AuthorInteraction(&existing.from))?;
+        }
+        Ok(())
+    }
+
+    /// Save an objective (own objectives, or team objectives for leaders)
+    ///
+    /// Overwriting an objective also needs permission to edit the one
+    /// already saved under its ID.
+    pub fn save_objective(&self, objective: &Objective) -> Result<()> {
+        self.authorize(Action::EditObjective(objective.owner.as_deref()))?;
+        let shared = objective.visibility == OkrVisibility::Shared;
+        let find = |shared: bool| -> Result<Option<Objective>> {
+            Ok(self
+                .storage
+                .load_objectives(shared)?
+                .into_iter()
+                .find(|o| o.id == objective.id))
+        };
+        let previous = find(shared)?;
+        let existing = match &previous {
+            Some(previous) => Some(previous.clone()),
+            None => find(!shared)?,
+        };
+        if let Some(existing) = &existing {
+            self.authorize(Action::EditObjective(existing.owner.as_deref()))?;
+        }
+        self.storage.save_objective(objective)?;
+
+        match previous {
+            None => self.publish(Change::ObjectiveCreated(objective.clone())),
+            Some(previous) if previous.overall_progress() != objective.overall_progress() => self
+                .publish(Change::ObjectiveProgressed {
+                    objective: objective.clone(),
+                    previous: previous.overall_progress(),
+                }),
+            Some(_) => {}
+        }
+        Ok(())
+    }
+
+    /// Ask a teammate for feedback on the user's behalf
+    pub fn save_feedback_request(&self, request: &FeedbackRequest) -> Result<()> {
+        self.authorize(Action::AuthorInteraction(&request.from))?;
+        self.storage.save_feedback_request(request)?;
+        self.publish(Change::FeedbackRequested(request.clone()));
+        Ok(())
+    }
+
+    /// Import interactions from another tool on everyone's behalf (leaders only)
+    ///
+    /// Returns how many were new; ones imported before are skipped.
+    pub fn import_interactions(&self, interactions: &[Interaction]) -> Result<usize> {
+        self.authorize(Action::EditTeam)?;
+        let mut created = 0;
+        for interaction in interactions {
+            if self.storage.save_imported(interaction)? {
+                created += 1;
+            }
+        }
+        Ok(created)
+    }
+
+    /// Change the authenticated user's own pincode
+    pub fn change_pincode(&self, current: &str, new_pincode: &str) -> Result<()> {
+        let email = self
+            .user()
+            .ok_or_else(|| Error::PermissionDenied("Log in to change your pincode".to_string()))?;
+        self.storage.change_pincode(email, current, new_pincode)
+    }
+
+    /// Issue a pincode reset token for a member (leaders only)
+    pub fn issue_reset_token(&self, email: &str) -> Result<String> {
+        self.authorize(Action::EditTeam)?;
+        let issued_by = self.user().unwrap_or_default();
+        self.storage.issue_reset_token(email, issued_by)
+    }
+
+    /// Add a new member to the team (leaders only)
+    pub fn add_member(&self, member: &Member) -> Result<Team> {
+        self.authorize(Action::EditTeam)?;
+        let mut team = self.storage.require_team()?;
+        self.storage.save_member(member)?;
+        team.push_member(&member.email);
+        self.storage.save_team(&team)?;
+        self.publish(Change::MemberAdded(member.clone()));
+        Ok(team)
+    }
+
+    /// Promote a member to team leader (leaders only)
+    pub fn promote_member(&self, email: &str) -> Result<Team> {
+        self.authorize(Action::EditTeam)?;
+        let team = self.storage.promote_member(email)?;
+        self.publish(Change::MemberRoleChanged {
+            email: email.to_string(),
+            leader: true,
+        });
+        Ok(team)
+    }
+
+    /// Demote a team leader to member (leaders only)
+    pub fn demote_member(&self, email: &str) -> Result<Team> {
+        self.authorize(Action::EditTeam)?;
+        let team = self.storage.demote_member(email)?;
+        self.publish(Change::MemberRoleChanged {
+            email: email.to_string(),
+            leader: false,
+        });
+        Ok(team)
+    }
+
+    /// Archive a member who has left the team (leaders only)
+    pub fn archive_member(&self, email: &str) -> Result<Team> {
+        self.authorize(Action::EditTeam)?;
+        let team = self.storage.archive_member(email)?;
+        self.publish(Change::MemberArchived(email.to_string()));
+        Ok(team)
+    }
+
+    /// Remove a member and their directory (leaders only)
+    pub fn remove_member(&self, email: &str) -> Result<Team> {
+        self.authorize(Action::EditTeam)?;
+        let team = self.storage.remove_member(email)?;
+        self.publish(Change::MemberRemoved(email.to_string()));
+        Ok(team)
+    }
+
+    /// Merge one member directory into another (leaders only)
+    pub fn merge_members(&self, from: &str, into: &str) -> Result<Team> {
+        self.authorize(Action::EditTeam)?;
+        self.storage.merge_members(from, into)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::storage_with_team;
+    use super::*;
+
+    #[test]
+    fn test_login() {
+        let (_temp, storage) = storage_with_team();
+
+        let session = AuthorizedStorage::login(&storage, "leader@example.com", "leaderpin");
+        assert_eq!(session.unwrap().role().unwrap(), Role::Leader);
+
+        let result = AuthorizedStorage::login(&storage, "leader@example.com", "wrongpin");
+        assert!(matches!(result, Err(Error::AuthFailed(_))));
+    }
+
+    #[test]
+    fn test_login_with_alias() {
+        let (_temp, storage) = storage_with_team();
+        storage
+            .save_member(&Member::new("leader@example.com").with_alias("lea@home.example"))
+            .unwrap();
+
+        let session = AuthorizedStorage::login(&storage, "Lea@home.example", "leaderpin").unwrap();
+        assert_eq!(session.user(), Some("leader@example.com"));
+        assert_eq!(session.role().unwrap(), Role::Leader);
+    }
+
+    #[test]
+    fn test_leader_can_manage_team() {
+        let (_temp, storage) = storage_with_team();
+        let session = AuthorizedStorage::new(&storage, Some("leader@example.com"));
+
+        let team = session.add_member(&Member::new("new@example.com")).unwrap();
+        assert!(team.is_member("new@example.com"));
+        assert!(session
+            .save_manifesto(&Manifesto::from_text("We care"))
+            .is_ok());
+        assert!(session.promote_member("member@example.com").is_ok());
+    }
+
+    #[test]
+    fn test_member_writes_only_own_data() {
+        let (_temp, storage) = storage_with_team();
+        let session = AuthorizedStorage::new(&storage, Some("member@example.com"));
+
+        assert!(session
+            .save_member(&Member::new("member@example.com").with_name("Me"))
+            .is_ok());
+        assert!(session
+            .save_member(&Member::new("leader@example.com").with_name("Boss"))
+            .is_err());
+        assert!(session.save_vision("Take over").is_err());
+        assert!(session
+            .add_member(&Member::new("friend@example.com"))
+            .is_err());
+
+        let own = Interaction::appreciation(
+            "member@example.com",
+            vec!["leader@example.com".to_string()],
+            "Thanks!",
+        );
+        assert!(session.save_kudos(&own).is_ok());
+
+        let forged = Interaction::appreciation(
+            "leader@example.com",
+            vec!["member@example.com".to_string()],
+            "You deserve a raise",
+        );
+        assert!(matches!(
+            session.save_kudos(&forged),
+            Err(Error::PermissionDenied(_))
+        ));
+    }
+
+    #[test]
+    fn test_member_cannot_overwrite_others_by_id() {
+        let (_temp, storage) = storage_with_team();
+        let leader = AuthorizedStorage::new(&storage, Some("leader@example.com"));
+        let member = AuthorizedStorage::new(&storage, Some("member@example.com"));
+
+        let goal = Objective::new("Ship v2")
+            .with_owner("leader@example.com")
+            .with_visibility(OkrVisibility::Shared);
+        leader.save_objective(&goal).unwrap();
+        let team_goal = Objective::new("Hire").with_visibility(OkrVisibility::Shared);
+        leader.save_objective(&team_goal).unwrap();
+
+        // Same ID, but claiming to own it
+        for stored in [&goal, &team_goal] {
+            let hijacked = Objective {
+                title: "Mine now".to_string(),
+                owner: Some("member@example.com".to_string()),
+                ..stored.clone()
+            };
+            assert!(matches!(
+                member.save_objective(&hijacked),
+                Err(Error::PermissionDenied(_))
+            ));
+        }
+        let titles: Vec<String> = storage
+            .load_objectives(true)
+            .unwrap()
+            .into_iter()
+            .map(|o| o.title)
+            .collect();
+        assert_eq!(titles, vec!["Hire", "Ship v2"]);
+
+        let kudos = Interaction::appreciation(
+            "leader@example.com",
+            vec!["member@example.com".to_string()],
+            "Great work",
+        );
+        leader.save_kudos(&kudos).unwrap();
+        let rewritten = Interaction {
+            from: "member@example.com".to_string(),
+            note: "Forged".to_string(),
+            ..kudos.clone()
+        };
+        assert!(member.save_kudos(&rewritten).is_err());
+        assert!(member.save_feedback(&rewritten).is_err());
+        assert!(member.save_interaction(&rewritten).is_err());
+        let stored = storage.find_interaction(&kudos.id).unwrap().unwrap();
+        assert_eq!(stored.note, "Great work");
+    }
+
+    #[test]
+    fn test_only_leaders_issue_reset_tokens() {
+        let (_temp, storage) = storage_with_team();
+        storage
+            .save_member(&Member::new("member@example.com"))
+            .unwrap();
+
+        let member = AuthorizedStorage::new(&storage, Some("member@example.com"));
+        assert!(member.issue_reset_token("member@example.com").is_err());
+
+        let leader = AuthorizedStorage::new(&storage, Some("leader@example.com"));
+        assert!(leader.issue_reset_token("member@example.com").is_ok());
+    }
+
+    #[test]
+    fn test_observer_is_read_only() {
+        let (_temp, storage) = storage_with_team();
+        let session = AuthorizedStorage::new(&storage, None);
+
+        assert_eq!(session.role().unwrap(), Role::Observer);
+        assert!(session.save_team(&Team::new("Hijacked")).is_err());
+        assert!(session.storage().load_team().unwrap().is_some());
+    }
+}
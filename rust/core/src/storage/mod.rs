@@ -2,9 +2,60 @@
 //!
 //! Handles the .team/ and .personal/ directory structures.
 
-use crate::{auth::MemberCredentials, Error, Interaction, Member, Result, Team, TeamConfig};
+mod authorized;
+
+pub use authorized::AuthorizedStorage;
+
+use crate::auth::{MemberCredentials, PincodeAttempts, ResetToken};
+use crate::outbox::OutboxEntry;
+use crate::pulse::{DuePrompt, Snooze};
+use crate::sync::{self, SyncConflict};
+use crate::{
+    normalize_email, same_email, Error, FeedbackRequest, Interaction, InteractionKind, Manifesto,
+    Member, Objective, OkrVisibility, Result, ShellHook, Team, TeamConfig,
+};
+use chrono::{DateTime, Utc};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
 
+/// Top-level keys of .team/config.yaml written by [`TeamStorage::save_team`]
+const TEAM_KEYS: &[&str] = &[
+    "name",
+    "manifesto",
+    "vision",
+    "leaders",
+    "members",
+    "archived",
+];
+
+/// Top-level keys of .team/config.yaml written by [`TeamStorage::save_config`]
+const CONFIG_KEYS: &[&str] = &["publish", "webhooks", "linting", "backup", "pulse", "hooks"];
+
+/// Local session details stored in .personal/session.yaml
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct LocalSession {
+    /// Email of the member who last logged in on this machine
+    #[serde(default)]
+    last_user: Option<String>,
+}
+
+/// Pulse prompts snoozed on this machine, stored in .personal/pulse.yaml
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct LocalPulse {
+    #[serde(default)]
+    snoozes: Vec<Snooze>,
+}
+
+/// Shell hooks approved on this machine, stored in .personal/hooks.yaml
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct LocalHooks {
+    /// [`ShellHook::digest`] of each approved command
+    #[serde(default)]
+    approved: Vec<String>,
+}
+
 /// Paths for the team data storage
 pub struct TeamStorage {
     root: PathBuf,
@@ -18,6 +69,11 @@ impl TeamStorage {
         }
     }
 
+    /// Get the root directory the team data lives in
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
     /// Get the path to the .team directory
     pub fn team_dir(&self) -> PathBuf {
         self.root.join(".team")
@@ -49,9 +105,60 @@ impl TeamStorage {
         std::fs::create_dir_all(personal_dir.join("journal"))?;
         std::fs::create_dir_all(personal_dir.join("drafts"))?;
 
+        self.ignore_personal()?;
+        self.register_merge_driver()?;
         Ok(())
     }
 
+    /// Make sure git never picks up .personal/, adding it to the .gitignore
+    /// next to .team/ if it is not there yet
+    ///
+    /// Returns whether the .gitignore changed.
+    pub fn ignore_personal(&self) -> Result<bool> {
+        self.append_git_rule(
+            ".gitignore",
+            &[".personal", ".personal/", "/.personal", "/.personal/"],
+            "# Private data of interactions, never committed\n.personal/\n",
+        )
+    }
+
+    /// Make sure git merges .team/ YAML files with `interactions
+    /// merge-driver`, adding it to the .gitattributes next to .team/ if it is
+    /// not there yet
+    ///
+    /// The driver itself is configured per clone with
+    /// [`git::configure_merge_driver`](crate::git::configure_merge_driver);
+    /// until then git merges these files as text. Returns whether the
+    /// .gitattributes changed.
+    pub fn register_merge_driver(&self) -> Result<bool> {
+        let rule = format!(".team/**/*.yaml merge={}", crate::git::MERGE_DRIVER);
+        self.append_git_rule(
+            ".gitattributes",
+            &[rule.as_str()],
+            &format!("# Merge team data as models, not lines\n{}\n", rule),
+        )
+    }
+
+    /// Append `rule` to a git file in the root unless it has one of `lines`
+    fn append_git_rule(&self, file: &str, lines: &[&str], rule: &str) -> Result<bool> {
+        let path = self.root.join(file);
+        let mut content = if path.exists() {
+            std::fs::read_to_string(&path)?
+        } else {
+            String::new()
+        };
+        if content.lines().any(|line| lines.contains(&line.trim())) {
+            return Ok(false);
+        }
+
+        if !content.is_empty() && !content.ends_with('\n') {
+            content.push('\n');
+        }
+        content.push_str(rule);
+        std::fs::write(path, content)?;
+        Ok(true)
+    }
+
     /// Load team configuration
     pub fn load_team(&self) -> Result<Option<Team>> {
         let config_path = self.team_dir().join("config.yaml");
@@ -66,26 +173,35 @@ impl TeamStorage {
 
     /// Save team configuration
     pub fn save_team(&self, team: &Team) -> Result<()> {
-        let config_path = self.team_dir().join("config.yaml");
-        let content = serde_yaml::to_string(team)?;
-        std::fs::write(config_path, content)?;
-        Ok(())
+        let mut team = team.clone();
+        team.normalize();
+
+        self.write_config_section(&team, TEAM_KEYS)
     }
 
-    /// Load manifesto content
-    pub fn load_manifesto(&self) -> Result<Option<String>> {
+    /// Load the manifesto from manifesto.yaml
+    pub fn load_manifesto(&self) -> Result<Option<Manifesto>> {
         let path = self.team_dir().join("manifesto.yaml");
         if !path.exists() {
             return Ok(None);
         }
         let content = std::fs::read_to_string(&path)?;
-        Ok(Some(content))
+        Manifesto::parse(&content).map(Some)
     }
 
-    /// Save manifesto content
-    pub fn save_manifesto(&self, content: &str) -> Result<()> {
+    /// Load the manifesto, falling back to the one in config.yaml
+    pub fn team_manifesto(&self) -> Result<Option<Manifesto>> {
+        match self.load_manifesto()? {
+            Some(manifesto) => Ok(Some(manifesto)),
+            None => Ok(self.load_team()?.and_then(|team| team.manifesto)),
+        }
+    }
+
+    /// Save the manifesto to manifesto.yaml
+    pub fn save_manifesto(&self, manifesto: &Manifesto) -> Result<()> {
+        manifesto.validate()?;
         let path = self.team_dir().join("manifesto.yaml");
-        std::fs::write(path, content)?;
+        std::fs::write(path, serde_yaml::to_string(manifesto)?)?;
         Ok(())
     }
 
@@ -120,19 +236,87 @@ impl TeamStorage {
 
     /// Save team configuration
     pub fn save_config(&self, config: &TeamConfig) -> Result<()> {
+        if let Some(timezone) = config.publish.as_ref().and_then(|p| p.timezone.as_ref()) {
+            crate::time::parse_timezone(timezone)?;
+        }
+        if let Some(webhooks) = &config.webhooks {
+            for hook in &webhooks.custom {
+                crate::webhook::validate(hook)?;
+            }
+        }
+        self.write_config_section(config, CONFIG_KEYS)
+    }
+
+    /// Write one part of .team/config.yaml, keeping the other part
+    ///
+    /// The team and its configuration share the file, so saving one must not
+    /// drop the other. `keys` are the top-level keys this part owns; they are
+    /// replaced, everything else is left as it was.
+    fn write_config_section<T: Serialize>(&self, value: &T, keys: &[&str]) -> Result<()> {
         let config_path = self.team_dir().join("config.yaml");
-        let content = serde_yaml::to_string(config)?;
+        let mut mapping = if config_path.exists() {
+            let content = std::fs::read_to_string(&config_path)?;
+            match serde_yaml::from_str(&content)? {
+                serde_yaml::Value::Mapping(mapping) => mapping,
+                _ => serde_yaml::Mapping::new(),
+            }
+        } else {
+            serde_yaml::Mapping::new()
+        };
+
+        for key in keys {
+            mapping.remove(*key);
+        }
+        if let serde_yaml::Value::Mapping(section) = serde_yaml::to_value(value)? {
+            mapping.extend(section);
+        }
+
+        let content = serde_yaml::to_string(&mapping)?;
         std::fs::write(config_path, content)?;
         Ok(())
     }
 
     /// Get the path to a member's directory
     pub fn member_dir(&self, email: &str) -> PathBuf {
-        self.team_dir().join("members").join(email)
+        self.team_dir().join("members").join(normalize_email(email))
     }
 
-    /// Load a member's profile
+    /// Resolve an email or alias to the member's primary email
+    ///
+    /// Falls back to the normalised email when no member is known by it.
+    pub fn resolve_email(&self, email: &str) -> Result<String> {
+        let email = normalize_email(email);
+        if self.member_dir(&email).exists() {
+            return Ok(email);
+        }
+
+        for primary in self.list_members()? {
+            if let Some(member) = self.read_profile(&primary)? {
+                if member.has_email(&email) {
+                    return Ok(normalize_email(&member.email));
+                }
+            }
+        }
+        Ok(email)
+    }
+
+    /// Copy an interaction with every participant resolved to a primary email
+    fn resolved(&self, interaction: &Interaction) -> Result<Interaction> {
+        let mut interaction = interaction.clone();
+        interaction.from = self.resolve_email(&interaction.from)?;
+        for email in &mut interaction.with {
+            *email = self.resolve_email(email)?;
+        }
+        Ok(interaction)
+    }
+
+    /// Load a member's profile, by primary email or alias
     pub fn load_member(&self, email: &str) -> Result<Option<Member>> {
+        self.read_profile(&self.resolve_email(email)?)
+    }
+
+    /// Read the profile stored in a member's directory
+    fn read_profile(&self, email: &str) -> Result<Option<Member>> {
         let profile_path = self.member_dir(email).join("profile.yaml");
         if !profile_path.exists() {
             return Ok(None);
@@ -145,18 +329,26 @@ impl TeamStorage {
 
     /// Save a member's profile
     pub fn save_member(&self, member: &Member) -> Result<()> {
+        let mut member = member.clone();
+        member.normalize();
+        if let Some(timezone) = &member.timezone {
+            crate::time::parse_timezone(timezone)?;
+        }
+
         let member_dir = self.member_dir(&member.email);
         std::fs::create_dir_all(&member_dir)?;
 
         let profile_path = member_dir.join("profile.yaml");
-        let content = serde_yaml::to_string(member)?;
+        let content = serde_yaml::to_string(&member)?;
         std::fs::write(profile_path, content)?;
         Ok(())
     }
 
-    /// Load a member's credentials
+    /// Load a member's credentials, by primary email or alias
     pub fn load_credentials(&self, email: &str) -> Result<Option<MemberCredentials>> {
-        let creds_path = self.member_dir(email).join("credentials.yaml");
+        let creds_path = self
+            .member_dir(&self.resolve_email(email)?)
+            .join("credentials.yaml");
         if !creds_path.exists() {
             return Ok(None);
         }
@@ -168,22 +360,277 @@ impl TeamStorage {
 
     /// Save a member's credentials
     pub fn save_credentials(&self, creds: &MemberCredentials) -> Result<()> {
+        let creds = MemberCredentials {
+            email: normalize_email(&creds.email),
+            credentials: creds.credentials.clone(),
+        };
         let member_dir = self.member_dir(&creds.email);
         std::fs::create_dir_all(&member_dir)?;
 
         let creds_path = member_dir.join("credentials.yaml");
-        let content = serde_yaml::to_string(creds)?;
+        let content = serde_yaml::to_string(&creds)?;
         std::fs::write(creds_path, content)?;
         Ok(())
     }
 
     /// Verify a member's pincode
+    ///
+    /// Attempts are throttled per member: after repeated failures this
+    /// returns [`Error::TooManyAttempts`] without checking the pincode.
     pub fn verify_pincode(&self, email: &str, pincode: &str) -> Result<bool> {
-        let creds = self.load_credentials(email)?;
-        match creds {
-            Some(c) => Ok(c.verify(pincode)),
-            None => Err(Error::CredentialsNotFound(email.to_string())),
+        let creds = self
+            .load_credentials(email)?
+            .ok_or_else(|| Error::CredentialsNotFound(email.to_string()))?;
+        self.check_attempt(email, || creds.verify(pincode))
+    }
+
+    /// Time a member has to wait before their next pincode attempt, if any
+    pub fn pincode_retry_after(&self, email: &str) -> Result<Option<chrono::Duration>> {
+        let attempts = self.load_attempts()?;
+        Ok(attempts
+            .get(&self.resolve_email(email)?)
+            .and_then(|a| a.retry_after(chrono::Utc::now())))
+    }
+
+    /// Run a throttled pincode check and record its outcome
+    ///
+    /// Attempts are counted per member, under their primary email, so
+    /// switching between aliases does not earn more of them.
+    fn check_attempt(&self, email: &str, check: impl FnOnce() -> bool) -> Result<bool> {
+        let email = self.resolve_email(email)?;
+        let now = chrono::Utc::now();
+        let mut attempts = self.load_attempts()?;
+        if let Some(wait) = attempts.get(&email).and_then(|a| a.retry_after(now)) {
+            // Round up so callers never retry a moment too early
+            let millis = wait.num_milliseconds().max(0) as u64;
+            return Err(Error::TooManyAttempts {
+                retry_after_secs: millis.div_ceil(1000),
+            });
+        }
+
+        let verified = check();
+        if verified {
+            if attempts.remove(&email).is_none() {
+                return Ok(true);
+            }
+        } else {
+            attempts.entry(email).or_default().record_failure(now);
         }
+        self.save_attempts(&attempts)?;
+        Ok(verified)
+    }
+
+    /// Load failed pincode attempts from .personal/attempts.yaml
+    fn load_attempts(&self) -> Result<BTreeMap<String, PincodeAttempts>> {
+        let path = self.personal_dir().join("attempts.yaml");
+        if !path.exists() {
+            return Ok(BTreeMap::new());
+        }
+        let content = std::fs::read_to_string(&path)?;
+        Ok(serde_yaml::from_str(&content)?)
+    }
+
+    /// Save failed pincode attempts to .personal/attempts.yaml
+    fn save_attempts(&self, attempts: &BTreeMap<String, PincodeAttempts>) -> Result<()> {
+        std::fs::create_dir_all(self.personal_dir())?;
+        let content = serde_yaml::to_string(attempts)?;
+        std::fs::write(self.personal_dir().join("attempts.yaml"), content)?;
+        Ok(())
+    }
+
+    /// Change a member's pincode after checking the current one
+    ///
+    /// Only the credentials change: `.personal/` data is not encrypted with
+    /// the pincode today, so there is nothing to re-encrypt.
+    pub fn change_pincode(&self, email: &str, current: &str, new_pincode: &str) -> Result<()> {
+        let mut creds = self
+            .load_credentials(email)?
+            .ok_or_else(|| Error::CredentialsNotFound(email.to_string()))?;
+        if !self.check_attempt(email, || creds.verify(current))? {
+            return Err(Error::AuthFailed(format!(
+                "Incorrect pincode for {}",
+                email
+            )));
+        }
+
+        creds.credentials.update_pincode(new_pincode)?;
+        self.save_credentials(&creds)
+    }
+
+    /// Issue a one-time pincode reset token for a member
+    ///
+    /// Returns the plaintext token for the leader to hand over; only its hash
+    /// is stored. Issuing a new token replaces any earlier one.
+    pub fn issue_reset_token(&self, email: &str, issued_by: &str) -> Result<String> {
+        let member_dir = self.member_dir(email);
+        if !member_dir.exists() {
+            return Err(Error::MemberNotFound(email.to_string()));
+        }
+
+        let (reset, token) = ResetToken::issue(email, issued_by);
+        let content = serde_yaml::to_string(&reset)?;
+        std::fs::write(member_dir.join("reset.yaml"), content)?;
+        Ok(token)
+    }
+
+    /// Load a member's pending reset token
+    pub fn load_reset_token(&self, email: &str) -> Result<Option<ResetToken>> {
+        let path = self.member_dir(email).join("reset.yaml");
+        if !path.exists() {
+            return Ok(None);
+        }
+        let content = std::fs::read_to_string(&path)?;
+        Ok(Some(serde_yaml::from_str(&content)?))
+    }
+
+    /// Redeem a reset token and set a new pincode
+    ///
+    /// Token guesses are throttled like pincode attempts, and a redeemed
+    /// token is removed so it cannot be used twice. `.personal/` data is not
+    /// encrypted with the pincode today, so nothing is lost by resetting it.
+    pub fn redeem_reset_token(&self, email: &str, token: &str, new_pincode: &str) -> Result<()> {
+        let email = self.resolve_email(email)?;
+        let reset = self
+            .load_reset_token(&email)?
+            .ok_or_else(|| Error::AuthFailed(format!("No pincode reset pending for {}", email)))?;
+        let now = chrono::Utc::now();
+        if !self.check_attempt(&email, || reset.verify(token, now))? {
+            return Err(Error::AuthFailed(
+                "Reset token is invalid or has expired".to_string(),
+            ));
+        }
+
+        let creds = MemberCredentials::new(&email, new_pincode)?;
+        self.save_credentials(&creds)?;
+        std::fs::remove_file(self.member_dir(&email).join("reset.yaml"))?;
+        Ok(())
+    }
+
+    /// Load the email of the member who last logged in on this machine
+    pub fn load_last_user(&self) -> Result<Option<String>> {
+        let path = self.personal_dir().join("session.yaml");
+        if !path.exists() {
+            return Ok(None);
+        }
+        let content = std::fs::read_to_string(&path)?;
+        let session: LocalSession = serde_yaml::from_str(&content)?;
+        Ok(session.last_user)
+    }
+
+    /// Remember the member who logged in, in .personal/ so it stays local
+    pub fn save_last_user(&self, email: &str) -> Result<()> {
+        let personal_dir = self.personal_dir();
+        std::fs::create_dir_all(&personal_dir)?;
+        let session = LocalSession {
+            last_user: Some(email.to_string()),
+        };
+        let content = serde_yaml::to_string(&session)?;
+        std::fs::write(personal_dir.join("session.yaml"), content)?;
+        Ok(())
+    }
+
+    /// Load the pulse prompts snoozed on this machine
+    pub fn load_pulse_snoozes(&self) -> Result<Vec<Snooze>> {
+        let path = self.personal_dir().join("pulse.yaml");
+        if !path.exists() {
+            return Ok(Vec::new());
+        }
+        let content = std::fs::read_to_string(path)?;
+        let pulse: LocalPulse = serde_yaml::from_str(&content)?;
+        Ok(pulse.snoozes)
+    }
+
+    /// Snooze a due pulse prompt until a moment, dropping snoozes that
+    /// ran out before `now`
+    pub fn snooze_pulse_prompt(
+        &self,
+        due: &DuePrompt,
+        until: DateTime<Utc>,
+        now: DateTime<Utc>,
+    ) -> Result<()> {
+        let mut snoozes = self.load_pulse_snoozes()?;
+        snoozes.retain(|s| s.until > now && !s.matches(due));
+        snoozes.push(Snooze::new(due, until));
+
+        let personal_dir = self.personal_dir();
+        std::fs::create_dir_all(&personal_dir)?;
+        let content = serde_yaml::to_string(&LocalPulse { snoozes })?;
+        std::fs::write(personal_dir.join("pulse.yaml"), content)?;
+        Ok(())
+    }
+
+    /// Digests of the shell hook commands approved on this machine
+    pub fn load_approved_hooks(&self) -> Result<Vec<String>> {
+        let path = self.personal_dir().join("hooks.yaml");
+        if !path.exists() {
+            return Ok(Vec::new());
+        }
+        let content = std::fs::read_to_string(path)?;
+        let hooks: LocalHooks = serde_yaml::from_str(&content)?;
+        Ok(hooks.approved)
+    }
+
+    /// Whether a shell hook's command was approved on this machine
+    pub fn is_hook_approved(&self, hook: &ShellHook) -> Result<bool> {
+        Ok(self.load_approved_hooks()?.contains(&hook.digest()))
+    }
+
+    /// Allow a shell hook's command to run on this machine
+    ///
+    /// The approval is for this exact command; when someone changes it in
+    /// .team/config.yaml, it has to be approved again.
+    pub fn approve_hook(&self, hook: &ShellHook) -> Result<()> {
+        let mut approved = self.load_approved_hooks()?;
+        let digest = hook.digest();
+        if approved.contains(&digest) {
+            return Ok(());
+        }
+        approved.push(digest);
+
+        let personal_dir = self.personal_dir();
+        std::fs::create_dir_all(&personal_dir)?;
+        let content = serde_yaml::to_string(&LocalHooks { approved })?;
+        std::fs::write(personal_dir.join("hooks.yaml"), content)?;
+        Ok(())
+    }
+
+    /// Get the path to the notifications waiting to be sent from this machine
+    pub fn outbox_dir(&self) -> PathBuf {
+        self.personal_dir().join("outbox")
+    }
+
+    /// Load the outbox, oldest entry first
+    pub fn load_outbox(&self) -> Result<Vec<OutboxEntry>> {
+        let mut entries: Vec<OutboxEntry> = read_yaml_dir(&self.outbox_dir())?;
+        entries.sort_by_key(|e| e.created);
+        Ok(entries)
+    }
+
+    /// Save an outbox entry
+    pub fn save_outbox_entry(&self, entry: &OutboxEntry) -> Result<()> {
+        let dir = self.outbox_dir();
+        std::fs::create_dir_all(&dir)?;
+        let content = serde_yaml::to_string(entry)?;
+        std::fs::write(dir.join(format!("{}.yaml", entry.id)), content)?;
+        Ok(())
+    }
+
+    /// Remove an outbox entry, returning whether it existed
+    pub fn remove_outbox_entry(&self, id: &str) -> Result<bool> {
+        let path = self.outbox_dir().join(format!("{}.yaml", id));
+        if !path.exists() {
+            return Ok(false);
+        }
+        std::fs::remove_file(path)?;
+        Ok(true)
+    }
+
+    /// Find the conflict copies cloud sync clients left in .team/ and
+    /// .personal/
+    pub fn sync_conflicts(&self) -> Result<Vec<SyncConflict>> {
+        let mut conflicts = sync::find_conflicts(&self.team_dir())?;
+        conflicts.extend(sync::find_conflicts(&self.personal_dir())?);
+        Ok(conflicts)
     }
 
     /// Get the path to the interactions directory (team or personal)
@@ -207,12 +654,13 @@ impl TeamStorage {
 
     /// Save an interaction
     pub fn save_interaction(&self, interaction: &Interaction) -> Result<()> {
+        let interaction = self.resolved(interaction)?;
         let dir = self.interactions_dir(interaction.shared);
         std::fs::create_dir_all(&dir)?;
 
         let filename = format!("{}.yaml", interaction.id);
         let path = dir.join(filename);
-        let content = serde_yaml::to_string(interaction)?;
+        let content = serde_yaml::to_string(&interaction)?;
         std::fs::write(path, content)?;
         Ok(())
     }
@@ -222,8 +670,9 @@ impl TeamStorage {
     /// - Always saves to recipient's .team/members/{email}/kudos/
     /// - If shared, also saves to .team/team/interactions/
     pub fn save_kudos(&self, interaction: &Interaction) -> Result<()> {
+        let interaction = self.resolved(interaction)?;
         let filename = format!("{}.yaml", interaction.id);
-        let content = serde_yaml::to_string(interaction)?;
+        let content = serde_yaml::to_string(&interaction)?;
 
         // Save to sender's personal sent folder
         let sent_dir = self.sent_kudos_dir();
@@ -247,6 +696,42 @@ impl TeamStorage {
         Ok(())
     }
 
+    /// Save an imported interaction where it would have been saved had it
+    /// been logged here, leaving out the sender's .personal/ copy
+    ///
+    /// Returns `false` without writing if it was imported before.
+    pub fn save_imported(&self, interaction: &Interaction) -> Result<bool> {
+        let interaction = self.resolved(interaction)?;
+        let filename = format!("{}.yaml", interaction.id);
+
+        let mut dirs: Vec<PathBuf> = match interaction.kind {
+            InteractionKind::Appreciation => interaction
+                .with
+                .iter()
+                .map(|email| self.received_kudos_dir(email))
+                .collect(),
+            InteractionKind::Feedback => interaction
+                .with
+                .iter()
+                .map(|email| self.received_feedback_dir(email))
+                .collect(),
+            _ => Vec::new(),
+        };
+        if interaction.shared || dirs.is_empty() {
+            dirs.push(self.interactions_dir(interaction.shared));
+        }
+
+        if dirs.iter().any(|dir| dir.join(&filename).exists()) {
+            return Ok(false);
+        }
+        let content = serde_yaml::to_string(&interaction)?;
+        for dir in dirs {
+            std::fs::create_dir_all(&dir)?;
+            std::fs::write(dir.join(&filename), &content)?;
+        }
+        Ok(true)
+    }
+
     /// Load an interaction by ID
     pub fn load_interaction(&self, id: &str, shared: bool) -> Result<Option<Interaction>> {
         let path = self.interactions_dir(shared).join(format!("{}.yaml", id));
@@ -259,6 +744,31 @@ impl TeamStorage {
         Ok(Some(interaction))
     }
 
+    /// Find a saved interaction by ID, wherever it was saved: team or
+    /// personal interactions, sent items or a member's received ones
+    pub fn find_interaction(&self, id: &str) -> Result<Option<Interaction>> {
+        let mut dirs = vec![
+            self.interactions_dir(true),
+            self.interactions_dir(false),
+            self.sent_kudos_dir(),
+            self.sent_feedback_dir(),
+        ];
+        for email in self.list_members()? {
+            dirs.push(self.received_kudos_dir(&email));
+            dirs.push(self.received_feedback_dir(&email));
+        }
+
+        let filename = format!("{}.yaml", id);
+        for dir in dirs {
+            let path = dir.join(&filename);
+            if path.exists() {
+                let content = std::fs::read_to_string(&path)?;
+                return Ok(Some(serde_yaml::from_str(&content)?));
+            }
+        }
+        Ok(None)
+    }
+
     /// List all interactions (returns IDs)
     pub fn list_interactions(&self, shared: bool) -> Result<Vec<String>> {
         let dir = self.interactions_dir(shared);
@@ -270,7 +780,7 @@ impl TeamStorage {
         for entry in std::fs::read_dir(dir)? {
             let entry = entry?;
             if let Some(name) = entry.file_name().to_str() {
-                if name.ends_with(".yaml") {
+                if is_yaml_file(&entry.path()) {
                     ids.push(name.trim_end_matches(".yaml").to_string());
                 }
             }
@@ -278,6 +788,18 @@ impl TeamStorage {
         Ok(ids)
     }
 
+    /// Load all interactions (team or personal), newest first
+    pub fn load_interactions(&self, shared: bool) -> Result<Vec<Interaction>> {
+        let mut interactions = Vec::new();
+        for id in self.list_interactions(shared)? {
+            if let Ok(Some(interaction)) = self.load_interaction(&id, shared) {
+                interactions.push(interaction);
+            }
+        }
+        interactions.sort_by_key(|i| std::cmp::Reverse(i.timestamp));
+        Ok(interactions)
+    }
+
     /// Load all sent kudos
     pub fn load_sent_kudos(&self) -> Result<Vec<Interaction>> {
         let dir = self.sent_kudos_dir();
@@ -289,7 +811,7 @@ impl TeamStorage {
         for entry in std::fs::read_dir(dir)? {
             let entry = entry?;
             let path = entry.path();
-            if path.extension().and_then(|s| s.to_str()) == Some("yaml") {
+            if is_yaml_file(&path) {
                 let content = std::fs::read_to_string(&path)?;
                 if let Ok(interaction) = serde_yaml::from_str::<Interaction>(&content) {
                     kudos.push(interaction);
@@ -298,7 +820,7 @@ impl TeamStorage {
         }
 
         // Sort by timestamp, newest first
-        kudos.sort_by(|a, b| b.timestamp.cmp(&a.timestamp));
+        kudos.sort_by_key(|i| std::cmp::Reverse(i.timestamp));
         Ok(kudos)
     }
 
@@ -313,7 +835,7 @@ impl TeamStorage {
         for entry in std::fs::read_dir(dir)? {
             let entry = entry?;
             let path = entry.path();
-            if path.extension().and_then(|s| s.to_str()) == Some("yaml") {
+            if is_yaml_file(&path) {
                 let content = std::fs::read_to_string(&path)?;
                 if let Ok(interaction) = serde_yaml::from_str::<Interaction>(&content) {
                     kudos.push(interaction);
@@ -322,10 +844,38 @@ impl TeamStorage {
         }
 
         // Sort by timestamp, newest first
-        kudos.sort_by(|a, b| b.timestamp.cmp(&a.timestamp));
+        kudos.sort_by_key(|i| std::cmp::Reverse(i.timestamp));
         Ok(kudos)
     }
 
+    /// Load the kudos every member has received, each kudos once
+    pub fn load_team_kudos(&self) -> Result<Vec<Interaction>> {
+        self.load_from_every_member(|email| self.load_received_kudos(email))
+    }
+
+    /// Load the feedback every member has received, each feedback once
+    pub fn load_team_feedback(&self) -> Result<Vec<Interaction>> {
+        self.load_from_every_member(|email| self.load_received_feedback(email))
+    }
+
+    /// Collect interactions from every member directory, dropping duplicates
+    /// of interactions sent to several people
+    fn load_from_every_member(
+        &self,
+        load: impl Fn(&str) -> Result<Vec<Interaction>>,
+    ) -> Result<Vec<Interaction>> {
+        let mut all: Vec<Interaction> = Vec::new();
+        for email in self.list_members()? {
+            for interaction in load(&email)? {
+                if !all.iter().any(|i| i.id == interaction.id) {
+                    all.push(interaction);
+                }
+            }
+        }
+        all.sort_by_key(|i| std::cmp::Reverse(i.timestamp));
+        Ok(all)
+    }
+
     /// Get the path to sent feedback (personal)
     pub fn sent_feedback_dir(&self) -> PathBuf {
         self.personal_dir().join("feedback/sent")
@@ -341,8 +891,9 @@ impl TeamStorage {
     /// - Always saves to recipient's .team/members/{email}/feedback/
     /// - If shared, also saves to .team/team/interactions/
     pub fn save_feedback(&self, interaction: &Interaction) -> Result<()> {
+        let interaction = self.resolved(interaction)?;
         let filename = format!("{}.yaml", interaction.id);
-        let content = serde_yaml::to_string(interaction)?;
+        let content = serde_yaml::to_string(&interaction)?;
 
         // Save to sender's personal sent folder
         let sent_dir = self.sent_feedback_dir();
@@ -377,7 +928,7 @@ impl TeamStorage {
         for entry in std::fs::read_dir(dir)? {
             let entry = entry?;
             let path = entry.path();
-            if path.extension().and_then(|s| s.to_str()) == Some("yaml") {
+            if is_yaml_file(&path) {
                 let content = std::fs::read_to_string(&path)?;
                 if let Ok(interaction) = serde_yaml::from_str::<Interaction>(&content) {
                     feedback.push(interaction);
@@ -386,7 +937,7 @@ impl TeamStorage {
         }
 
         // Sort by timestamp, newest first
-        feedback.sort_by(|a, b| b.timestamp.cmp(&a.timestamp));
+        feedback.sort_by_key(|i| std::cmp::Reverse(i.timestamp));
         Ok(feedback)
     }
 
@@ -401,7 +952,7 @@ impl TeamStorage {
         for entry in std::fs::read_dir(dir)? {
             let entry = entry?;
             let path = entry.path();
-            if path.extension().and_then(|s| s.to_str()) == Some("yaml") {
+            if is_yaml_file(&path) {
                 let content = std::fs::read_to_string(&path)?;
                 if let Ok(interaction) = serde_yaml::from_str::<Interaction>(&content) {
                     feedback.push(interaction);
@@ -410,10 +961,68 @@ impl TeamStorage {
         }
 
         // Sort by timestamp, newest first
-        feedback.sort_by(|a, b| b.timestamp.cmp(&a.timestamp));
+        feedback.sort_by_key(|i| std::cmp::Reverse(i.timestamp));
         Ok(feedback)
     }
 
+    /// Get the path to objectives (team or personal)
+    pub fn okrs_dir(&self, shared: bool) -> PathBuf {
+        if shared {
+            self.team_dir().join("team/okrs")
+        } else {
+            self.personal_dir().join("okrs")
+        }
+    }
+
+    /// Save an objective, to .team/ if shared or .personal/ if private
+    pub fn save_objective(&self, objective: &Objective) -> Result<()> {
+        let dir = self.okrs_dir(objective.visibility == OkrVisibility::Shared);
+        std::fs::create_dir_all(&dir)?;
+        let content = serde_yaml::to_string(objective)?;
+        std::fs::write(dir.join(format!("{}.yaml", objective.id)), content)?;
+        Ok(())
+    }
+
+    /// Load team or personal objectives, sorted by title
+    pub fn load_objectives(&self, shared: bool) -> Result<Vec<Objective>> {
+        let mut objectives: Vec<Objective> = read_yaml_dir(&self.okrs_dir(shared))?;
+        objectives.sort_by_key(|o| o.title.to_lowercase());
+        Ok(objectives)
+    }
+
+    /// Get the path to feedback requests addressed to a member
+    pub fn feedback_requests_dir(&self, email: &str) -> PathBuf {
+        self.member_dir(email).join("requests")
+    }
+
+    /// Save a feedback request in the asked member's directory
+    pub fn save_feedback_request(&self, request: &FeedbackRequest) -> Result<()> {
+        let mut request = request.clone();
+        request.from = normalize_email(&request.from);
+        request.to = normalize_email(&request.to);
+
+        let dir = self.feedback_requests_dir(&request.to);
+        std::fs::create_dir_all(&dir)?;
+        let content = serde_yaml::to_string(&request)?;
+        std::fs::write(dir.join(format!("{}.yaml", request.id)), content)?;
+        Ok(())
+    }
+
+    /// Feedback requests addressed to a member that they have not answered,
+    /// oldest first
+    pub fn pending_feedback_requests(&self, email: &str) -> Result<Vec<FeedbackRequest>> {
+        let requests: Vec<FeedbackRequest> = read_yaml_dir(&self.feedback_requests_dir(email))?;
+        let mut pending = Vec::new();
+        for request in requests {
+            let replies = self.load_received_feedback(&request.from)?;
+            if !replies.iter().any(|r| request.is_answered_by(r)) {
+                pending.push(request);
+            }
+        }
+        pending.sort_by_key(|r| r.timestamp);
+        Ok(pending)
+    }
+
     /// Initialize a new team with config, team info, and first member
     pub fn initialize_team(
         &self,
@@ -459,11 +1068,343 @@ impl TeamStorage {
         }
         Ok(members)
     }
+
+    /// Load every member profile, sorted by display name
+    pub fn load_members(&self) -> Result<Vec<Member>> {
+        let mut members = Vec::new();
+        for email in self.list_members()? {
+            if let Some(member) = self.read_profile(&email)? {
+                members.push(member);
+            }
+        }
+        members.sort_by_key(|m| m.display_name().to_lowercase());
+        Ok(members)
+    }
+
+    /// Promote a member to team leader
+    pub fn promote_member(&self, email: &str) -> Result<Team> {
+        let mut team = self.require_team()?;
+        if !team.promote(email) {
+            return Err(Error::MemberNotFound(email.to_string()));
+        }
+        self.save_team(&team)?;
+        Ok(team)
+    }
+
+    /// Demote a team leader to member
+    pub fn demote_member(&self, email: &str) -> Result<Team> {
+        let mut team = self.require_team()?;
+        ensure_other_leader(&team, email)?;
+        if !team.demote(email) {
+            return Err(Error::MemberNotFound(email.to_string()));
+        }
+        self.save_team(&team)?;
+        Ok(team)
+    }
+
+    /// Archive a member who has left the team
+    ///
+    /// The profile and received interactions are kept so history stays
+    /// readable, but the credentials are removed so they can no longer log in.
+    pub fn archive_member(&self, email: &str) -> Result<Team> {
+        let mut team = self.require_team()?;
+        ensure_other_leader(&team, email)?;
+        if !team.archive_member(email) {
+            return Err(Error::MemberNotFound(email.to_string()));
+        }
+        self.save_team(&team)?;
+
+        let creds_path = self.member_dir(email).join("credentials.yaml");
+        if creds_path.exists() {
+            std::fs::remove_file(creds_path)?;
+        }
+        Ok(team)
+    }
+
+    /// Remove a member and their directory from the team
+    ///
+    /// Use this for people who were added by mistake; prefer
+    /// [`TeamStorage::archive_member`] for people who have left.
+    pub fn remove_member(&self, email: &str) -> Result<Team> {
+        let mut team = self.require_team()?;
+        ensure_other_leader(&team, email)?;
+        if !team.remove_member(email) {
+            return Err(Error::MemberNotFound(email.to_string()));
+        }
+        self.save_team(&team)?;
+
+        let member_dir = self.member_dir(email);
+        if member_dir.exists() {
+            std::fs::remove_dir_all(member_dir)?;
+        }
+        Ok(team)
+    }
+
+    /// Merge one member directory into another
+    ///
+    /// Received kudos, feedback and feedback requests move to `into`, the old
+    /// email becomes an alias on the remaining profile, and interactions,
+    /// feedback requests, objectives and pulse overrides referring to the old
+    /// email are rewritten. The merged member keeps the stronger role.
+    ///
+    /// The old directory is removed once everything in it was carried over;
+    /// anything storage does not know about is left in place.
+    pub fn merge_members(&self, from: &str, into: &str) -> Result<Team> {
+        let from = normalize_email(from);
+        let into = normalize_email(into);
+        if from == into {
+            return Err(Error::InvalidConfig(
+                "Cannot merge a member into themselves".to_string(),
+            ));
+        }
+
+        let mut team = self.require_team()?;
+        let from_dir = self.member_dir(&from);
+        let into_dir = self.member_dir(&into);
+        if !from_dir.exists() {
+            return Err(Error::MemberNotFound(from));
+        }
+        if !into_dir.exists() {
+            return Err(Error::MemberNotFound(into));
+        }
+
+        // Profile: the old email and its aliases become aliases of the new one
+        let source = self
+            .read_profile(&from)?
+            .unwrap_or_else(|| Member::new(&from));
+        let mut target = self
+            .read_profile(&into)?
+            .unwrap_or_else(|| Member::new(&into));
+        target.absorb(&source);
+        self.save_member(&target)?;
+
+        // Credentials: keep the remaining member's, or take over the old ones
+        let into_creds = into_dir.join("credentials.yaml");
+        if !into_creds.exists() {
+            if let Some(mut creds) = self.load_credentials(&from)? {
+                creds.email = into.clone();
+                self.save_credentials(&creds)?;
+            }
+        }
+        // Likewise for a pending pincode reset
+        if self.load_reset_token(&into)?.is_none() {
+            if let Some(mut reset) = self.load_reset_token(&from)? {
+                reset.email = into.clone();
+                std::fs::write(into_dir.join("reset.yaml"), serde_yaml::to_string(&reset)?)?;
+            }
+        }
+
+        for folder in ["kudos", "feedback", "requests"] {
+            move_yaml_files(&from_dir.join(folder), &into_dir.join(folder))?;
+        }
+
+        // Point every interaction that mentions the old email at the new one
+        let mut dirs = vec![
+            self.interactions_dir(true),
+            self.interactions_dir(false),
+            self.sent_kudos_dir(),
+            self.sent_feedback_dir(),
+        ];
+        for email in self.list_members()? {
+            dirs.push(self.received_kudos_dir(&email));
+            dirs.push(self.received_feedback_dir(&email));
+            rewrite_request_emails(&self.feedback_requests_dir(&email), &from, &into)?;
+        }
+        for dir in dirs {
+            rewrite_interaction_emails(&dir, &from, &into)?;
+        }
+
+        for shared in [true, false] {
+            for mut objective in self.load_objectives(shared)? {
+                if objective
+                    .owner
+                    .as_deref()
+                    .is_some_and(|o| same_email(o, &from))
+                {
+                    objective.owner = Some(into.clone());
+                    self.save_objective(&objective)?;
+                }
+            }
+        }
+
+        if let Some(mut config) = self.load_config()? {
+            if let Some(pulse) = config.pulse.as_mut() {
+                let old = pulse
+                    .members
+                    .keys()
+                    .find(|email| same_email(email, &from))
+                    .cloned();
+                if let Some(cadences) = old.and_then(|email| pulse.members.remove(&email)) {
+                    let has_own = pulse.members.keys().any(|email| same_email(email, &into));
+                    if !has_own {
+                        pulse.members.insert(into.clone(), cadences);
+                    }
+                    self.save_config(&config)?;
+                }
+            }
+        }
+
+        team.merge_member(&from, &into);
+        self.save_team(&team)?;
+
+        for file in ["profile.yaml", "credentials.yaml", "reset.yaml"] {
+            let path = from_dir.join(file);
+            if path.exists() {
+                std::fs::remove_file(path)?;
+            }
+        }
+        for folder in ["kudos", "feedback", "requests"] {
+            remove_dir_if_empty(&from_dir.join(folder))?;
+        }
+        remove_dir_if_empty(&from_dir)?;
+        Ok(team)
+    }
+
+    /// Load the team, failing if it has not been initialized
+    pub(crate) fn require_team(&self) -> Result<Team> {
+        self.load_team()?
+            .ok_or_else(|| Error::TeamNotFound(self.team_dir().display().to_string()))
+    }
+}
+
+/// Whether a path is a YAML file storage should read, which excludes the
+/// conflict copies cloud sync clients leave next to a file
+fn is_yaml_file(path: &Path) -> bool {
+    path.extension().and_then(|s| s.to_str()) == Some("yaml") && !sync::is_conflict_copy(path)
+}
+
+/// Parse every YAML file in a directory, skipping files that do not parse
+fn read_yaml_dir<T: DeserializeOwned>(dir: &Path) -> Result<Vec<T>> {
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+    let mut items = Vec::new();
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if is_yaml_file(&path) {
+            let content = std::fs::read_to_string(&path)?;
+            if let Ok(item) = serde_yaml::from_str(&content) {
+                items.push(item);
+            }
+        }
+    }
+    Ok(items)
+}
+
+/// Move the YAML files from one directory into another
+///
+/// Files that already exist in the destination are the same interaction
+/// (file names are interaction ids), so the source copy is dropped.
+fn move_yaml_files(from: &Path, into: &Path) -> Result<()> {
+    if !from.exists() {
+        return Ok(());
+    }
+    std::fs::create_dir_all(into)?;
+    for entry in std::fs::read_dir(from)? {
+        let path = entry?.path();
+        let Some(name) = path.file_name() else {
+            continue;
+        };
+        let target = into.join(name);
+        if target.exists() {
+            std::fs::remove_file(&path)?;
+        } else {
+            std::fs::rename(&path, target)?;
+        }
+    }
+    Ok(())
+}
+
+/// Remove a directory if it exists and has nothing left in it
+fn remove_dir_if_empty(dir: &Path) -> Result<()> {
+    if dir.exists() && std::fs::read_dir(dir)?.next().is_none() {
+        std::fs::remove_dir(dir)?;
+    }
+    Ok(())
+}
+
+/// Replace one email with another in every feedback request in a directory
+fn rewrite_request_emails(dir: &Path, from: &str, into: &str) -> Result<()> {
+    if !dir.exists() {
+        return Ok(());
+    }
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if !is_yaml_file(&path) {
+            continue;
+        }
+        let content = std::fs::read_to_string(&path)?;
+        let Ok(mut request) = serde_yaml::from_str::<FeedbackRequest>(&content) else {
+            continue;
+        };
+
+        if !same_email(&request.from, from) && !same_email(&request.to, from) {
+            continue;
+        }
+        if same_email(&request.from, from) {
+            request.from = into.to_string();
+        }
+        if same_email(&request.to, from) {
+            request.to = into.to_string();
+        }
+        std::fs::write(&path, serde_yaml::to_string(&request)?)?;
+    }
+    Ok(())
+}
+
+/// Replace one email with another in every interaction in a directory
+fn rewrite_interaction_emails(dir: &Path, from: &str, into: &str) -> Result<()> {
+    if !dir.exists() {
+        return Ok(());
+    }
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if !is_yaml_file(&path) {
+            continue;
+        }
+        let content = std::fs::read_to_string(&path)?;
+        let Ok(mut interaction) = serde_yaml::from_str::<Interaction>(&content) else {
+            continue;
+        };
+
+        let mentions = |email: &str| same_email(email, from);
+        if !mentions(&interaction.from) && !interaction.with.iter().any(|e| mentions(e)) {
+            continue;
+        }
+        if mentions(&interaction.from) {
+            interaction.from = into.to_string();
+        }
+        let mut with = Vec::new();
+        for email in interaction.with.drain(..) {
+            let email = if mentions(&email) {
+                into.to_string()
+            } else {
+                email
+            };
+            if !with.contains(&email) {
+                with.push(email);
+            }
+        }
+        interaction.with = with;
+        std::fs::write(&path, serde_yaml::to_string(&interaction)?)?;
+    }
+    Ok(())
+}
+
+/// Refuse to take the leader role away from the last remaining leader
+fn ensure_other_leader(team: &Team, email: &str) -> Result<()> {
+    if team.is_leader(email) && team.leaders.len() == 1 {
+        return Err(Error::InvalidConfig(
+            "A team needs at least one leader".to_string(),
+        ));
+    }
+    Ok(())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{Cadence, Principle, PulseConfig, PulsePrompt};
     use tempfile::TempDir;
 
     #[test]
@@ -516,13 +1457,49 @@ mod tests {
         let storage = TeamStorage::new(temp.path());
         storage.initialize().unwrap();
 
-        let manifesto = "# Our Manifesto\n\nWe value collaboration.";
-        storage.save_manifesto(manifesto).unwrap();
+        let manifesto = Manifesto::new()
+            .with_preamble("How we work together")
+            .with_principle(Principle::new("Ship small"))
+            .with_principle(Principle::new("Ask for help").with_description("Early and often"));
+        storage.save_manifesto(&manifesto).unwrap();
 
         let loaded = storage.load_manifesto().unwrap().unwrap();
         assert_eq!(manifesto, loaded);
     }
 
+    #[test]
+    fn test_legacy_manifesto_loads_as_preamble() {
+        let temp = TempDir::new().unwrap();
+        let storage = TeamStorage::new(temp.path());
+        storage.initialize().unwrap();
+
+        let legacy = "# Our Manifesto\n\nWe value collaboration.";
+        std::fs::write(temp.path().join(".team/manifesto.yaml"), legacy).unwrap();
+
+        let loaded = storage.load_manifesto().unwrap().unwrap();
+        assert_eq!(loaded.preamble.as_deref(), Some(legacy));
+        assert!(loaded.principles.is_empty());
+    }
+
+    #[test]
+    fn test_team_manifesto_falls_back_to_config() {
+        let temp = TempDir::new().unwrap();
+        let storage = TeamStorage::new(temp.path());
+        storage.initialize().unwrap();
+        storage
+            .save_team(&Team::new("T").with_manifesto("We collaborate"))
+            .unwrap();
+
+        let manifesto = storage.team_manifesto().unwrap().unwrap();
+        assert_eq!(manifesto.preamble.as_deref(), Some("We collaborate"));
+
+        storage
+            .save_manifesto(&Manifesto::new().with_principle(Principle::new("Be kind")))
+            .unwrap();
+        let manifesto = storage.team_manifesto().unwrap().unwrap();
+        assert_eq!(manifesto.principles[0].id, "be-kind");
+    }
+
     #[test]
     fn test_config_save_load() {
         let temp = TempDir::new().unwrap();
@@ -582,6 +1559,397 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_emails_are_normalized() {
+        let (_temp, storage) = storage_with_team();
+        storage
+            .save_member(&Member::new("Alice@Example.com").with_alias("Alice@Home.example"))
+            .unwrap();
+
+        assert!(storage.member_dir("alice@example.com").exists());
+        let member = storage.load_member("ALICE@example.com").unwrap().unwrap();
+        assert_eq!(member.email, "alice@example.com");
+
+        // Aliases resolve to the primary member
+        assert_eq!(
+            storage.resolve_email("alice@home.example").unwrap(),
+            "alice@example.com"
+        );
+        assert!(storage.load_member("alice@home.example").unwrap().is_some());
+    }
+
+    #[test]
+    fn test_kudos_to_alias_reach_primary() {
+        let (_temp, storage) = storage_with_team();
+        storage
+            .save_member(&Member::new("alice@example.com").with_alias("alice@home.example"))
+            .unwrap();
+
+        let kudos = Interaction::appreciation(
+            "Bob@Example.com",
+            vec!["Alice@Home.example".to_string()],
+            "Thanks!",
+        );
+        storage.save_kudos(&kudos).unwrap();
+
+        assert!(!storage.member_dir("alice@home.example").exists());
+        let received = storage.load_received_kudos("alice@example.com").unwrap();
+        assert_eq!(received.len(), 1);
+        assert_eq!(received[0].from, "bob@example.com");
+        assert_eq!(received[0].with, vec!["alice@example.com"]);
+    }
+
+    #[test]
+    fn test_team_and_config_share_file() {
+        let (_temp, storage) = storage_with_team();
+
+        let config = storage.load_config().unwrap().unwrap();
+        assert_eq!(config, TeamConfig::with_defaults());
+
+        let mut team = storage.load_team().unwrap().unwrap();
+        team.push_member("new@example.com");
+        storage.save_team(&team).unwrap();
+        assert_eq!(
+            storage.load_config().unwrap().unwrap(),
+            TeamConfig::with_defaults()
+        );
+
+        storage.save_config(&TeamConfig::new()).unwrap();
+        assert!(storage.load_config().unwrap().unwrap().publish.is_none());
+        assert!(storage
+            .load_team()
+            .unwrap()
+            .unwrap()
+            .is_member("new@example.com"));
+    }
+
+    #[test]
+    fn test_save_member_validates_timezone() {
+        let (_temp, storage) = storage_with_team();
+
+        let member = Member::new("tz@example.com").with_timezone("Europe/Amsterdam");
+        assert!(storage.save_member(&member).is_ok());
+
+        let member = Member::new("tz@example.com").with_timezone("Amsterdam");
+        let result = storage.save_member(&member);
+        assert!(matches!(result, Err(Error::InvalidTimezone(_))));
+    }
+
+    #[test]
+    fn test_merge_members() {
+        let (_temp, storage) = storage_with_team();
+        storage
+            .save_member(&Member::new("member@home.example").with_name("Member"))
+            .unwrap();
+
+        let kudos = Interaction::appreciation(
+            "leader@example.com",
+            vec!["member@home.example".to_string()],
+            "Thanks!",
+        );
+        storage.save_kudos(&kudos).unwrap();
+
+        let team = storage
+            .merge_members("member@home.example", "member@example.com")
+            .unwrap();
+        assert!(team.is_member("member@example.com"));
+        assert!(!storage.member_dir("member@home.example").exists());
+
+        let member = storage.load_member("member@example.com").unwrap().unwrap();
+        assert_eq!(member.name, Some("Member".to_string()));
+        assert!(member.has_email("member@home.example"));
+
+        let received = storage.load_received_kudos("member@example.com").unwrap();
+        assert_eq!(received.len(), 1);
+        assert_eq!(received[0].with, vec!["member@example.com"]);
+
+        // The sender's copy is rewritten too
+        let sent = storage.load_sent_kudos().unwrap();
+        assert_eq!(sent[0].with, vec!["member@example.com"]);
+    }
+
+    #[test]
+    fn test_merge_members_carries_requests_and_objectives() {
+        let (_temp, storage) = storage_with_team();
+        storage
+            .save_member(&Member::new("member@home.example"))
+            .unwrap();
+        storage
+            .save_config(&TeamConfig::with_defaults().with_pulse(
+                PulseConfig::default().with_member_cadence(
+                    "member@home.example",
+                    PulsePrompt::CheckIn,
+                    Cadence::Monthly,
+                ),
+            ))
+            .unwrap();
+
+        let asked = FeedbackRequest::new("leader@example.com", "member@home.example");
+        storage.save_feedback_request(&asked).unwrap();
+        let asking = FeedbackRequest::new("member@home.example", "leader@example.com");
+        storage.save_feedback_request(&asking).unwrap();
+        let objective = Objective::new("Learn Rust")
+            .with_owner("member@home.example")
+            .with_visibility(OkrVisibility::Shared);
+        storage.save_objective(&objective).unwrap();
+        storage
+            .issue_reset_token("member@home.example", "leader@example.com")
+            .unwrap();
+
+        storage
+            .merge_members("member@home.example", "member@example.com")
+            .unwrap();
+        assert!(!storage.member_dir("member@home.example").exists());
+
+        let pending = storage
+            .pending_feedback_requests("member@example.com")
+            .unwrap();
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].to, "member@example.com");
+        let pending = storage
+            .pending_feedback_requests("leader@example.com")
+            .unwrap();
+        assert_eq!(pending[0].from, "member@example.com");
+
+        let objectives = storage.load_objectives(true).unwrap();
+        assert_eq!(objectives[0].owner.as_deref(), Some("member@example.com"));
+
+        let reset = storage.load_reset_token("member@example.com").unwrap();
+        assert_eq!(reset.unwrap().email, "member@example.com");
+
+        let pulse = storage.load_config().unwrap().unwrap().pulse.unwrap();
+        assert!(pulse.members.contains_key("member@example.com"));
+        assert!(!pulse.members.contains_key("member@home.example"));
+    }
+
+    #[test]
+    fn test_merge_members_keeps_unknown_files() {
+        let (_temp, storage) = storage_with_team();
+        storage
+            .save_member(&Member::new("member@home.example"))
+            .unwrap();
+        let notes = storage.member_dir("member@home.example").join("notes.md");
+        std::fs::write(&notes, "Keep me").unwrap();
+
+        storage
+            .merge_members("member@home.example", "member@example.com")
+            .unwrap();
+        assert!(notes.exists());
+        assert!(!storage
+            .member_dir("member@home.example")
+            .join("profile.yaml")
+            .exists());
+    }
+
+    #[test]
+    fn test_change_pincode() {
+        let temp = TempDir::new().unwrap();
+        let storage = TeamStorage::new(temp.path());
+        storage.initialize().unwrap();
+        storage
+            .save_credentials(&MemberCredentials::new("user@example.com", "oldpin").unwrap())
+            .unwrap();
+
+        let result = storage.change_pincode("user@example.com", "wrongpin", "newpin");
+        assert!(matches!(result, Err(Error::AuthFailed(_))));
+
+        storage
+            .change_pincode("user@example.com", "oldpin", "newpin")
+            .unwrap();
+        assert!(storage
+            .verify_pincode("user@example.com", "newpin")
+            .unwrap());
+        assert!(!storage
+            .verify_pincode("user@example.com", "oldpin")
+            .unwrap());
+    }
+
+    #[test]
+    fn test_verify_pincode_is_throttled() {
+        let temp = TempDir::new().unwrap();
+        let storage = TeamStorage::new(temp.path());
+        storage.initialize().unwrap();
+        storage
+            .save_credentials(&MemberCredentials::new("user@example.com", "goodpin").unwrap())
+            .unwrap();
+
+        for _ in 0..crate::auth::FREE_ATTEMPTS {
+            assert!(!storage
+                .verify_pincode("user@example.com", "badpin")
+                .unwrap());
+        }
+        assert!(storage
+            .pincode_retry_after("user@example.com")
+            .unwrap()
+            .is_some());
+
+        // Even the right pincode is refused while backing off
+        let result = storage.verify_pincode("user@example.com", "goodpin");
+        assert!(matches!(result, Err(Error::TooManyAttempts { .. })));
+
+        // Other members are not affected
+        assert!(storage
+            .pincode_retry_after("other@example.com")
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn test_aliases_share_pincode_attempts() {
+        let (_temp, storage) = storage_with_team();
+        storage
+            .save_member(&Member::new("member@example.com").with_alias("member@home.example"))
+            .unwrap();
+
+        for attempt in 0..crate::auth::FREE_ATTEMPTS {
+            let email = if attempt % 2 == 0 {
+                "member@example.com"
+            } else {
+                "member@home.example"
+            };
+            assert!(!storage.verify_pincode(email, "badpin").unwrap());
+        }
+        for email in ["member@example.com", "MEMBER@home.example"] {
+            assert!(storage.pincode_retry_after(email).unwrap().is_some());
+            let result = storage.verify_pincode(email, "memberpin");
+            assert!(matches!(result, Err(Error::TooManyAttempts { .. })));
+        }
+    }
+
+    #[test]
+    fn test_reset_token_flow() {
+        let (_temp, storage) = storage_with_team();
+
+        let token = storage
+            .issue_reset_token("member@example.com", "leader@example.com")
+            .unwrap();
+        assert!(storage
+            .load_reset_token("member@example.com")
+            .unwrap()
+            .is_some());
+
+        let result = storage.redeem_reset_token("member@example.com", "AAAA-AAAA", "newpin");
+        assert!(matches!(result, Err(Error::AuthFailed(_))));
+
+        storage
+            .redeem_reset_token("member@example.com", &token, "newpin")
+            .unwrap();
+        assert!(storage
+            .verify_pincode("member@example.com", "newpin")
+            .unwrap());
+
+        // Tokens are single use
+        assert!(storage
+            .redeem_reset_token("member@example.com", &token, "otherpin")
+            .is_err());
+    }
+
+    #[test]
+    fn test_reset_token_guesses_are_throttled() {
+        let (_temp, storage) = storage_with_team();
+        let token = storage
+            .issue_reset_token("member@example.com", "leader@example.com")
+            .unwrap();
+
+        for _ in 0..crate::auth::FREE_ATTEMPTS {
+            let result = storage.redeem_reset_token("member@example.com", "AAAA-AAAA", "newpin");
+            assert!(matches!(result, Err(Error::AuthFailed(_))));
+        }
+        // Even the right token is refused while locked out
+        let result = storage.redeem_reset_token("member@example.com", &token, "newpin");
+        assert!(matches!(result, Err(Error::TooManyAttempts { .. })));
+        assert!(storage
+            .verify_pincode("member@example.com", "newpin")
+            .is_err());
+    }
+
+    #[test]
+    fn test_load_team_kudos_once_each() {
+        let (_temp, storage) = storage_with_team();
+        let kudos = Interaction::appreciation(
+            "leader@example.com",
+            vec![
+                "member@example.com".to_string(),
+                "other@example.com".to_string(),
+            ],
+            "Thanks both",
+        )
+        .with_principles(vec!["be-kind".to_string()]);
+        storage.save_kudos(&kudos).unwrap();
+
+        let loaded = storage.load_team_kudos().unwrap();
+        assert_eq!(loaded.len(), 1);
+        assert_eq!(loaded[0].principles, vec!["be-kind"]);
+    }
+
+    #[test]
+    fn test_objectives_save_load() {
+        let (_temp, storage) = storage_with_team();
+        let team_goal = Objective::new("Ship v2").with_visibility(OkrVisibility::Shared);
+        let own_goal = Objective::new("Learn Rust");
+        storage.save_objective(&team_goal).unwrap();
+        storage.save_objective(&own_goal).unwrap();
+
+        assert_eq!(storage.load_objectives(true).unwrap(), vec![team_goal]);
+        assert_eq!(storage.load_objectives(false).unwrap(), vec![own_goal]);
+    }
+
+    #[test]
+    fn test_sync_conflict_copies_are_not_loaded() {
+        let (_temp, storage) = storage_with_team();
+        let goal = Objective::new("Ship v2").with_visibility(OkrVisibility::Shared);
+        storage.save_objective(&goal).unwrap();
+        let dir = storage.okrs_dir(true);
+        std::fs::copy(
+            dir.join(format!("{}.yaml", goal.id)),
+            dir.join(format!("{} (1).yaml", goal.id)),
+        )
+        .unwrap();
+
+        assert_eq!(storage.load_objectives(true).unwrap(), vec![goal]);
+        let conflicts = storage.sync_conflicts().unwrap();
+        assert_eq!(conflicts.len(), 1);
+        assert!(conflicts[0].diff().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_pending_feedback_requests() {
+        let (_temp, storage) = storage_with_team();
+        let request = FeedbackRequest::new("Member@example.com", "leader@example.com")
+            .with_topic("My first PR");
+        storage.save_feedback_request(&request).unwrap();
+
+        let pending = storage
+            .pending_feedback_requests("leader@example.com")
+            .unwrap();
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].from, "member@example.com");
+
+        let reply = Interaction::feedback(
+            "leader@example.com",
+            vec!["member@example.com".to_string()],
+            "Nice and small",
+        );
+        storage.save_feedback(&reply).unwrap();
+        assert!(storage
+            .pending_feedback_requests("leader@example.com")
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_last_user_save_load() {
+        let temp = TempDir::new().unwrap();
+        let storage = TeamStorage::new(temp.path());
+
+        assert!(storage.load_last_user().unwrap().is_none());
+        storage.save_last_user("user@example.com").unwrap();
+        assert_eq!(
+            storage.load_last_user().unwrap(),
+            Some("user@example.com".to_string())
+        );
+    }
+
     #[test]
     fn test_initialize_team() {
         let temp = TempDir::new().unwrap();
@@ -609,6 +1977,24 @@ mod tests {
             .unwrap());
     }
 
+    #[test]
+    fn test_git_rules() {
+        let temp = TempDir::new().unwrap();
+        let storage = TeamStorage::new(temp.path());
+        std::fs::write(temp.path().join(".gitignore"), "target/").unwrap();
+
+        storage.initialize().unwrap();
+        let content = std::fs::read_to_string(temp.path().join(".gitignore")).unwrap();
+        assert!(content.starts_with("target/\n"));
+        assert!(content.lines().any(|l| l == ".personal/"));
+
+        assert!(!storage.ignore_personal().unwrap());
+
+        let attributes = std::fs::read_to_string(temp.path().join(".gitattributes")).unwrap();
+        assert!(attributes.contains(".team/**/*.yaml merge=interactions\n"));
+        assert!(!storage.register_merge_driver().unwrap());
+    }
+
     #[test]
     fn test_list_members() {
         let temp = TempDir::new().unwrap();
@@ -627,4 +2013,92 @@ mod tests {
         assert!(members.contains(&"user1@example.com".to_string()));
         assert!(members.contains(&"user2@example.com".to_string()));
     }
+
+    /// A team with a leader and a member who both have a pincode; shared
+    /// with the [`AuthorizedStorage`] tests
+    pub(super) fn storage_with_team() -> (TempDir, TeamStorage) {
+        let temp = TempDir::new().unwrap();
+        let storage = TeamStorage::new(temp.path());
+        let team = Team::new("My Team")
+            .add_leader("leader@example.com")
+            .add_member("member@example.com");
+        let leader = Member::new("leader@example.com");
+        storage
+            .initialize_team(&team, &TeamConfig::with_defaults(), &leader, "leaderpin")
+            .unwrap();
+        storage
+            .save_member(&Member::new("member@example.com"))
+            .unwrap();
+        storage
+            .save_credentials(&MemberCredentials::new("member@example.com", "memberpin").unwrap())
+            .unwrap();
+        (temp, storage)
+    }
+
+    #[test]
+    fn test_promote_and_demote_member() {
+        let (_temp, storage) = storage_with_team();
+
+        let team = storage.promote_member("member@example.com").unwrap();
+        assert!(team.is_leader("member@example.com"));
+
+        let team = storage.demote_member("leader@example.com").unwrap();
+        assert!(!team.is_leader("leader@example.com"));
+        assert!(team.is_member("leader@example.com"));
+
+        // Changes are persisted
+        let loaded = storage.load_team().unwrap().unwrap();
+        assert_eq!(loaded.leaders, vec!["member@example.com"]);
+    }
+
+    #[test]
+    fn test_cannot_demote_last_leader() {
+        let (_temp, storage) = storage_with_team();
+
+        assert!(storage.demote_member("leader@example.com").is_err());
+        assert!(storage.archive_member("leader@example.com").is_err());
+        assert!(storage.remove_member("leader@example.com").is_err());
+    }
+
+    #[test]
+    fn test_archive_member_keeps_history() {
+        let (_temp, storage) = storage_with_team();
+
+        let kudos = Interaction::appreciation(
+            "leader@example.com",
+            vec!["member@example.com".to_string()],
+            "Thanks!",
+        );
+        storage.save_kudos(&kudos).unwrap();
+
+        let team = storage.archive_member("member@example.com").unwrap();
+        assert!(!team.is_member("member@example.com"));
+        assert!(team.is_archived("member@example.com"));
+
+        // Profile and kudos remain, credentials are gone
+        assert!(storage.load_member("member@example.com").unwrap().is_some());
+        assert_eq!(
+            storage
+                .load_received_kudos("member@example.com")
+                .unwrap()
+                .len(),
+            1
+        );
+        assert!(storage
+            .load_credentials("member@example.com")
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn test_remove_member() {
+        let (_temp, storage) = storage_with_team();
+
+        let team = storage.remove_member("member@example.com").unwrap();
+        assert!(!team.is_member("member@example.com"));
+        assert!(!storage.member_dir("member@example.com").exists());
+
+        let result = storage.remove_member("member@example.com");
+        assert!(matches!(result, Err(Error::MemberNotFound(_))));
+    }
 }
@@ -0,0 +1,173 @@
+//! Conflict copies made by cloud sync clients
+//!
+//! A team folder kept in Google Drive, OneDrive, Dropbox or Syncthing is
+//! not merged like a git repository: when two machines change the same file
+//! before syncing, the client keeps one version and saves the other next to
+//! it under a new name, such as `profile (1).yaml` or
+//! `config-conflict-PC.yaml`. Storage skips these copies when loading, so
+//! they are found here and resolved by keeping one of the two versions.
+
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use regex::Regex;
+
+use crate::diff::{self, FieldChange};
+use crate::Result;
+
+/// The name of the file a conflict copy was made of, `None` if the file
+/// name is not that of a conflict copy
+///
+/// Recognises `name (1).yaml` (Google Drive), `name-conflict-PC.yaml`
+/// (OneDrive), `name (… conflicted copy …).yaml` (Dropbox) and
+/// `name.sync-conflict-….yaml` (Syncthing).
+pub fn original_name(file_name: &str) -> Option<String> {
+    static PATTERN: OnceLock<Regex> = OnceLock::new();
+    let pattern = PATTERN.get_or_init(|| {
+        Regex::new(
+            r"(?x)
+            ^(?P<name>.+?)
+            (
+                \s\(\d+\)                        # Google Drive
+              | -conflict-[^.]+                  # OneDrive
+              | \s\([^)]*conflicted\scopy[^)]*\) # Dropbox
+              | \.sync-conflict-[^.]+            # Syncthing
+            )
+            \.(?P<ext>yaml|yml)$",
+        )
+        .expect("conflict pattern is valid")
+    });
+    let captures = pattern.captures(file_name)?;
+    Some(format!("{}.{}", &captures["name"], &captures["ext"]))
+}
+
+/// Whether a path is a conflict copy
+pub fn is_conflict_copy(path: &Path) -> bool {
+    path.file_name()
+        .and_then(|name| name.to_str())
+        .and_then(original_name)
+        .is_some()
+}
+
+/// A conflict copy and the file it was made of
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SyncConflict {
+    /// The copy
+    pub copy: PathBuf,
+    /// The file it was made of, which may no longer exist
+    pub original: PathBuf,
+}
+
+impl SyncConflict {
+    /// The conflict for a copy, `None` if the path is not a conflict copy
+    pub fn for_copy(copy: impl AsRef<Path>) -> Option<Self> {
+        let copy = copy.as_ref();
+        let name = original_name(copy.file_name()?.to_str()?)?;
+        Some(Self {
+            copy: copy.to_path_buf(),
+            original: copy.with_file_name(name),
+        })
+    }
+
+    /// The fields the copy changes compared to the original
+    pub fn diff(&self) -> Result<Vec<FieldChange>> {
+        let original = match self.original.exists() {
+            true => std::fs::read_to_string(&self.original)?,
+            false => String::new(),
+        };
+        let copy = std::fs::read_to_string(&self.copy)?;
+        diff::diff(&original, &copy)
+    }
+
+    /// Resolve by keeping the original, deleting the copy
+    pub fn keep_original(&self) -> Result<()> {
+        std::fs::remove_file(&self.copy)?;
+        Ok(())
+    }
+
+    /// Resolve by replacing the original with the copy
+    pub fn keep_copy(&self) -> Result<()> {
+        std::fs::rename(&self.copy, &self.original)?;
+        Ok(())
+    }
+}
+
+/// Every conflict copy below a directory, sorted by path
+pub fn find_conflicts(dir: &Path) -> Result<Vec<SyncConflict>> {
+    let mut conflicts = Vec::new();
+    collect(dir, &mut conflicts)?;
+    conflicts.sort_by(|a, b| a.copy.cmp(&b.copy));
+    Ok(conflicts)
+}
+
+fn collect(dir: &Path, conflicts: &mut Vec<SyncConflict>) -> Result<()> {
+    if !dir.is_dir() {
+        return Ok(());
+    }
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect(&path, conflicts)?;
+        } else if let Some(conflict) = SyncConflict::for_copy(&path) {
+            conflicts.push(conflict);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_original_name() {
+        assert_eq!(
+            original_name("profile (1).yaml").as_deref(),
+            Some("profile.yaml")
+        );
+        assert_eq!(
+            original_name("config-conflict-PC.yaml").as_deref(),
+            Some("config.yaml")
+        );
+        assert_eq!(
+            original_name("ship (Ana's conflicted copy 2026-10-01).yaml").as_deref(),
+            Some("ship.yaml")
+        );
+        assert_eq!(
+            original_name("manifesto.sync-conflict-20261001-120000-ABC1234.yaml").as_deref(),
+            Some("manifesto.yaml")
+        );
+        assert_eq!(original_name("profile.yaml"), None);
+        assert_eq!(original_name("2026-10-01-1a2b3c.yaml"), None);
+        assert_eq!(original_name("notes (1).md"), None);
+    }
+
+    #[test]
+    fn test_find_and_resolve() {
+        let temp = TempDir::new().unwrap();
+        let dir = temp.path().join(".team/members/a@x.com");
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("profile.yaml"), "email: a@x.com\nname: Ana\n").unwrap();
+        std::fs::write(dir.join("profile (1).yaml"), "email: a@x.com\nname: Anna\n").unwrap();
+        std::fs::write(temp.path().join(".team/vision-conflict-PC.yaml"), "Grow\n").unwrap();
+
+        let conflicts = find_conflicts(temp.path()).unwrap();
+        assert_eq!(conflicts.len(), 2);
+        let profile = &conflicts[0];
+        assert_eq!(profile.original, dir.join("profile.yaml"));
+        let changes: Vec<String> = profile
+            .diff()
+            .unwrap()
+            .iter()
+            .map(|c| c.to_string())
+            .collect();
+        assert_eq!(changes, vec!["name: Ana → Anna"]);
+
+        profile.keep_copy().unwrap();
+        let content = std::fs::read_to_string(dir.join("profile.yaml")).unwrap();
+        assert!(content.contains("Anna"));
+        conflicts[1].keep_original().unwrap();
+        assert!(find_conflicts(temp.path()).unwrap().is_empty());
+    }
+}
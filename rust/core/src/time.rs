@@ -0,0 +1,124 @@
+//! Timezone handling
+//!
+//! Timestamps are stored in UTC. They are shown in the viewer's timezone,
+//! and weeks run from Monday to Monday in the timezone they are asked for.
+
+use chrono::{DateTime, Datelike, Duration, LocalResult, NaiveDate, TimeZone, Utc};
+use chrono_tz::Tz;
+
+use crate::{Error, Member, Result};
+
+/// Parse an IANA timezone name such as `Europe/Amsterdam`
+pub fn parse_timezone(name: &str) -> Result<Tz> {
+    name.trim()
+        .parse::<Tz>()
+        .map_err(|_| Error::InvalidTimezone(name.to_string()))
+}
+
+/// The timezone a member views timestamps in, UTC if unset or invalid
+pub fn member_timezone(member: Option<&Member>) -> Tz {
+    member
+        .and_then(|m| m.timezone.as_deref())
+        .and_then(|name| parse_timezone(name).ok())
+        .unwrap_or(Tz::UTC)
+}
+
+/// Format a UTC timestamp in a timezone using a chrono format string
+pub fn format_in(timestamp: DateTime<Utc>, tz: Tz, format: &str) -> String {
+    timestamp.with_timezone(&tz).format(format).to_string()
+}
+
+/// The Monday a timestamp's week starts on, in the given timezone
+pub fn week_start(timestamp: DateTime<Utc>, tz: Tz) -> NaiveDate {
+    let date = timestamp.with_timezone(&tz).date_naive();
+    date - Duration::days(date.weekday().num_days_from_monday() as i64)
+}
+
+/// The start and end (exclusive) of the week containing `now`, in the given timezone
+pub fn week_bounds(now: DateTime<Utc>, tz: Tz) -> (DateTime<Utc>, DateTime<Utc>) {
+    let monday = week_start(now, tz);
+    (
+        start_of_day(monday, tz),
+        start_of_day(monday + Duration::days(7), tz),
+    )
+}
+
+/// Whether a timestamp falls in the same week as `now`, in the given timezone
+pub fn is_this_week(timestamp: DateTime<Utc>, now: DateTime<Utc>, tz: Tz) -> bool {
+    let (start, end) = week_bounds(now, tz);
+    timestamp >= start && timestamp < end
+}
+
+/// Midnight at the start of a local date, as UTC
+///
+/// When a DST change skips midnight, the first hour that exists is used.
+fn start_of_day(date: NaiveDate, tz: Tz) -> DateTime<Utc> {
+    for hour in 0..3 {
+        let local = date.and_hms_opt(hour, 0, 0).expect("valid time");
+        match tz.from_local_datetime(&local) {
+            LocalResult::Single(t) | LocalResult::Ambiguous(t, _) => return t.with_timezone(&Utc),
+            LocalResult::None => continue,
+        }
+    }
+    Utc.from_utc_datetime(&date.and_hms_opt(0, 0, 0).expect("valid time"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utc(s: &str) -> DateTime<Utc> {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_parse_timezone() {
+        assert_eq!(
+            parse_timezone("Europe/Amsterdam").unwrap(),
+            Tz::Europe__Amsterdam
+        );
+        assert!(matches!(
+            parse_timezone("Mars/Olympus"),
+            Err(Error::InvalidTimezone(_))
+        ));
+    }
+
+    #[test]
+    fn test_member_timezone() {
+        let member = Member::new("a@example.com").with_timezone("Asia/Tokyo");
+        assert_eq!(member_timezone(Some(&member)), Tz::Asia__Tokyo);
+        assert_eq!(member_timezone(None), Tz::UTC);
+
+        let invalid = Member::new("a@example.com").with_timezone("Nowhere");
+        assert_eq!(member_timezone(Some(&invalid)), Tz::UTC);
+    }
+
+    #[test]
+    fn test_format_in() {
+        let timestamp = utc("2026-01-31T23:30:00Z");
+        assert_eq!(format_in(timestamp, Tz::UTC, "%m/%d %H:%M"), "01/31 23:30");
+        assert_eq!(
+            format_in(timestamp, Tz::Europe__Amsterdam, "%m/%d %H:%M"),
+            "02/01 00:30"
+        );
+    }
+
+    #[test]
+    fn test_week_bounds_follow_timezone() {
+        // Sunday evening in UTC is already Monday in Tokyo
+        let now = utc("2026-02-01T20:00:00Z");
+        assert_eq!(week_start(now, Tz::UTC).to_string(), "2026-01-26");
+        assert_eq!(week_start(now, Tz::Asia__Tokyo).to_string(), "2026-02-02");
+
+        let (start, end) = week_bounds(now, Tz::Europe__Amsterdam);
+        assert_eq!(start, utc("2026-01-25T23:00:00Z"));
+        assert_eq!(end, utc("2026-02-01T23:00:00Z"));
+
+        assert!(is_this_week(utc("2026-01-26T08:00:00Z"), now, Tz::UTC));
+        assert!(!is_this_week(
+            utc("2026-01-26T08:00:00Z"),
+            now,
+            Tz::Asia__Tokyo
+        ));
+    }
+}
@@ -0,0 +1,370 @@
+//! Webhooks
+//!
+//! Posts notifications to the webhooks in `.team/config.yaml`. The Slack and
+//! Discord URLs get a built-in payload; custom webhooks render their own
+//! template from the notification's fields and can sign the body with
+//! HMAC-SHA256 so the receiver can check where it came from. URLs, secrets
+//! and the `Authorization` header can be [secret references](crate::secrets),
+//! resolved just before sending.
+
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use sha2::{Digest, Sha256};
+
+use crate::pulse::DuePrompt;
+use crate::secrets;
+use crate::{CustomWebhook, Error, Result, WebhookConfig};
+
+/// Header carrying the signature when a webhook does not name one
+pub const DEFAULT_SIGNATURE_HEADER: &str = "X-Interactions-Signature";
+
+/// How long to wait for a webhook to answer
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Something to tell a webhook about
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Notification {
+    /// Fields templates can refer to; always has `event` and `text`, and
+    /// `event_id` for team events and other events with an identity
+    pub fields: BTreeMap<String, String>,
+}
+
+impl Notification {
+    /// Create a notification for an event with a human-readable text
+    pub fn new(event: impl Into<String>, text: impl Into<String>) -> Self {
+        let mut fields = BTreeMap::new();
+        fields.insert("event".to_string(), event.into());
+        fields.insert("text".to_string(), text.into());
+        Self { fields }
+    }
+
+    /// Add a field (builder pattern)
+    pub fn with(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.fields.insert(key.into(), value.into());
+        self
+    }
+
+    /// Set the id used to recognise the same event twice (builder pattern)
+    pub fn with_id(self, id: impl Into<String>) -> Self {
+        self.with("event_id", id)
+    }
+
+    /// A reminder for a due pulse prompt
+    ///
+    /// Reminding the same member of the same prompt twice on one day counts
+    /// as the same event; OKR updates for different objectives do not.
+    pub fn pulse(due: &DuePrompt, now: DateTime<Utc>) -> Self {
+        let prompt = serde_yaml::to_string(&due.prompt).unwrap_or_default();
+        let prompt = prompt.trim();
+        let mut id = format!("pulse:{}:{}", due.member, prompt);
+        if let Some(objective) = &due.objective {
+            id.push(':');
+            id.push_str(&objective.id);
+        }
+        let mut notification = Self::new("pulse", format!("{}: {}", due.member, due.message(now)))
+            .with_id(format!("{}:{}", id, now.date_naive()))
+            .with("member", &due.member)
+            .with("prompt", prompt)
+            .with("message", due.message(now));
+        if let Some(objective) = &due.objective {
+            notification = notification
+                .with("objective", &objective.id)
+                .with("objective_title", &objective.title);
+        }
+        notification
+    }
+
+    /// The event name
+    pub fn event(&self) -> &str {
+        self.fields.get("event").map_or("", String::as_str)
+    }
+
+    /// The human-readable text
+    pub fn text(&self) -> &str {
+        self.fields.get("text").map_or("", String::as_str)
+    }
+
+    /// The event id, or a digest of the fields if the event has none
+    pub fn id(&self) -> String {
+        match self.fields.get("event_id") {
+            Some(id) => id.clone(),
+            None => {
+                let json = serde_json::to_string(&self.fields).unwrap_or_default();
+                hex::encode(Sha256::digest(json.as_bytes()))
+            }
+        }
+    }
+}
+
+/// Every webhook in the config: Slack and Discord first, then custom ones
+///
+/// Signal has no plain HTTP webhook and is not included.
+pub fn endpoints(config: &WebhookConfig) -> Vec<CustomWebhook> {
+    let mut hooks = Vec::new();
+    if let Some(url) = &config.slack {
+        hooks.push(
+            CustomWebhook::new(url)
+                .with_name("slack")
+                .with_template(r#"{"text": "{{text}}"}"#),
+        );
+    }
+    if let Some(url) = &config.discord {
+        hooks.push(
+            CustomWebhook::new(url)
+                .with_name("discord")
+                .with_template(r#"{"content": "{{text}}"}"#),
+        );
+    }
+    hooks.extend(config.custom.iter().cloned());
+    hooks
+}
+
+/// Render the body a webhook receives for a notification
+///
+/// `{{field}}` placeholders are replaced by the field's value, escaped for
+/// use inside a JSON string when the payload is JSON. Unknown fields render
+/// as nothing. Without a template the fields are sent as a JSON object.
+pub fn render(hook: &CustomWebhook, notification: &Notification) -> String {
+    let Some(template) = &hook.template else {
+        return serde_json::to_string(&notification.fields).unwrap_or_default();
+    };
+    let json = content_type(hook).contains("json");
+
+    let mut body = String::new();
+    let mut rest = template.as_str();
+    while let Some(start) = rest.find("{{") {
+        let Some(end) = rest[start..].find("}}") else {
+            break;
+        };
+        body.push_str(&rest[..start]);
+        let key = rest[start + 2..start + end].trim();
+        let value = notification.fields.get(key).map_or("", String::as_str);
+        if json {
+            let quoted = serde_json::to_string(value).unwrap_or_default();
+            body.push_str(&quoted[1..quoted.len() - 1]);
+        } else {
+            body.push_str(value);
+        }
+        rest = &rest[start + end + 2..];
+    }
+    body.push_str(rest);
+    body
+}
+
+/// Sign a body with HMAC-SHA256, as "sha256=<hex digest>"
+pub fn sign(secret: &str, body: &str) -> String {
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts any key length");
+    mac.update(body.as_bytes());
+    format!("sha256={}", hex::encode(mac.finalize().into_bytes()))
+}
+
+/// Header whose value may be a secret reference; other headers are sent as
+/// they are in the config
+pub const SECRET_HEADER: &str = "Authorization";
+
+/// Resolve the secret references in a webhook: its URL, secret and
+/// `Authorization` header
+pub fn resolve(hook: &CustomWebhook) -> Result<CustomWebhook> {
+    let mut resolved = hook.clone();
+    resolved.url = secrets::resolve(&hook.url)?;
+    resolved.secret = hook.secret.as_deref().map(secrets::resolve).transpose()?;
+    for (name, value) in resolved.headers.iter_mut() {
+        if name.eq_ignore_ascii_case(SECRET_HEADER) {
+            *value = secrets::resolve(value)?;
+        }
+    }
+    Ok(resolved)
+}
+
+/// Post a notification to a webhook
+///
+/// Fails if a secret reference cannot be resolved, or the webhook cannot be
+/// reached or does not answer with a 2xx status.
+pub fn send(hook: &CustomWebhook, notification: &Notification) -> Result<()> {
+    let fail = |message: String| Error::Webhook(format!("{}: {}", hook.label(), message));
+    let hook = &resolve(hook).map_err(|e| fail(e.to_string()))?;
+    let body = render(hook, notification);
+    let agent = ureq::AgentBuilder::new().timeout(TIMEOUT).build();
+    let mut request = agent
+        .post(&hook.url)
+        .set("Content-Type", content_type(hook));
+    for (name, value) in &hook.headers {
+        request = request.set(name, value);
+    }
+    if let Some(secret) = &hook.secret {
+        let header = hook
+            .signature_header
+            .as_deref()
+            .unwrap_or(DEFAULT_SIGNATURE_HEADER);
+        request = request.set(header, &sign(secret, &body));
+    }
+    request.send_string(&body).map(|_| ()).map_err(|e| match e {
+        // The error's own message names the URL, which may be the secret
+        ureq::Error::Status(code, _) => fail(format!("answered with HTTP {}", code)),
+        ureq::Error::Transport(transport) => fail(transport.kind().to_string()),
+    })
+}
+
+/// Post a notification to every webhook that wants it, returning the failures
+pub fn broadcast(hooks: &[CustomWebhook], notification: &Notification) -> Vec<Error> {
+    hooks
+        .iter()
+        .filter(|hook| hook.wants(notification.event()))
+        .filter_map(|hook| send(hook, notification).err())
+        .collect()
+}
+
+/// Check that a webhook can be used
+///
+/// References are checked for their form only; they are resolved when the
+/// webhook is used.
+pub fn validate(hook: &CustomWebhook) -> Result<()> {
+    for (name, value) in &hook.headers {
+        if secrets::is_reference(value) && !name.eq_ignore_ascii_case(SECRET_HEADER) {
+            return Err(Error::InvalidConfig(format!(
+                "Webhook '{}' header {} cannot be a secret reference; only {} can",
+                hook.label(),
+                name,
+                SECRET_HEADER
+            )));
+        }
+    }
+    let values = std::iter::once(&hook.url)
+        .chain(hook.secret.as_ref())
+        .chain(hook.headers.values());
+    for value in values {
+        secrets::validate(value)?;
+    }
+    if secrets::is_reference(&hook.url) {
+        return Ok(());
+    }
+    if !(hook.url.starts_with("http://") || hook.url.starts_with("https://")) {
+        return Err(Error::InvalidConfig(format!(
+            "Webhook '{}' needs an http:// or https:// URL",
+            hook.label()
+        )));
+    }
+    Ok(())
+}
+
+fn content_type(hook: &CustomWebhook) -> &str {
+    hook.content_type.as_deref().unwrap_or("application/json")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::mpsc;
+    use std::thread;
+
+    /// What the local test server received
+    struct Received {
+        body: String,
+        headers: BTreeMap<String, String>,
+    }
+
+    /// Serve one request on localhost, answering with `status`
+    fn serve_once(status: u16) -> (String, mpsc::Receiver<Received>) {
+        let server = tiny_http::Server::http("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/hook", server.server_addr().to_ip().unwrap());
+        let (tx, rx) = mpsc::channel();
+        thread::spawn(move || {
+            let mut request = server.recv().unwrap();
+            let mut body = String::new();
+            request.as_reader().read_to_string(&mut body).unwrap();
+            let headers = request
+                .headers()
+                .iter()
+                .map(|h| (h.field.to_string().to_lowercase(), h.value.to_string()))
+                .collect();
+            tx.send(Received { body, headers }).unwrap();
+            request.respond(tiny_http::Response::empty(status)).unwrap();
+        });
+        (url, rx)
+    }
+
+    #[test]
+    fn test_render_template() {
+        let notification = Notification::new("pulse", "Say \"hi\"").with("member", "a@x.com");
+        let hook =
+            CustomWebhook::new("http://x").with_template(r#"{"msg": "{{ text }} {{nope}}"}"#);
+        assert_eq!(render(&hook, &notification), r#"{"msg": "Say \"hi\" "}"#);
+
+        let mut plain = hook.clone().with_template("{{member}}: {{text}}");
+        plain.content_type = Some("text/plain".to_string());
+        assert_eq!(render(&plain, &notification), "a@x.com: Say \"hi\"");
+
+        let fields: BTreeMap<String, String> =
+            serde_json::from_str(&render(&CustomWebhook::new("http://x"), &notification)).unwrap();
+        assert_eq!(fields["event"], "pulse");
+    }
+
+    #[test]
+    fn test_sign() {
+        // RFC 4231 test case 2
+        assert_eq!(
+            sign("Jefe", "what do ya want for nothing?"),
+            "sha256=5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+    }
+
+    #[test]
+    fn test_send_to_local_server() {
+        let (url, received) = serve_once(200);
+        std::env::set_var("INTERACTIONS_TEST_WEBHOOK", url);
+        std::env::set_var("INTERACTIONS_TEST_TOKEN", "Bearer t0ken");
+        let hook = CustomWebhook::new("env:INTERACTIONS_TEST_WEBHOOK")
+            .with_template(r#"{"text": "{{text}}"}"#)
+            .with_header("X-Team", "platform")
+            .with_header("Authorization", "env:INTERACTIONS_TEST_TOKEN")
+            .with_secret("s3cret");
+        let notification = Notification::new("test", "Hello");
+
+        send(&hook, &notification).unwrap();
+        let request = received.recv().unwrap();
+        assert_eq!(request.body, r#"{"text": "Hello"}"#);
+        assert_eq!(request.headers["x-team"], "platform");
+        assert_eq!(request.headers["authorization"], "Bearer t0ken");
+        assert_eq!(request.headers["content-type"], "application/json");
+        assert_eq!(
+            request.headers["x-interactions-signature"],
+            sign("s3cret", &request.body)
+        );
+    }
+
+    #[test]
+    fn test_send_fails_on_error_status() {
+        let (url, _received) = serve_once(500);
+        let hook = CustomWebhook::new(url).with_name("bot");
+        let error = send(&hook, &Notification::new("test", "Hello")).unwrap_err();
+        assert!(matches!(error, Error::Webhook(message) if message.starts_with("bot:")));
+    }
+
+    #[test]
+    fn test_endpoints_and_validate() {
+        let config = WebhookConfig {
+            slack: Some("https://hooks.slack.com/x".to_string()),
+            custom: vec![CustomWebhook::new("ftp://nope")],
+            ..WebhookConfig::default()
+        };
+        let hooks = endpoints(&config);
+        assert_eq!(hooks.len(), 2);
+        assert_eq!(hooks[0].label(), "slack");
+        assert!(validate(&hooks[0]).is_ok());
+        assert!(validate(&hooks[1]).is_err());
+        assert!(validate(&CustomWebhook::new("env:INTERACTIONS_HOOK")).is_ok());
+        assert!(validate(&CustomWebhook::new("env:HOOK")).is_err());
+        let hook = CustomWebhook::new("env:INTERACTIONS_HOOK");
+        assert!(validate(&hook.clone().with_secret("file:")).is_err());
+        assert!(validate(&hook.clone().with_header("authorization", "file:bot")).is_ok());
+        assert!(validate(&hook.with_header("X-Token", "file:bot")).is_err());
+
+        // Without a name, messages show the host but not the token
+        let unnamed = CustomWebhook::new("https://hooks.slack.com/services/T0/B0/xyz");
+        assert_eq!(unnamed.label(), "https://hooks.slack.com/…");
+    }
+}
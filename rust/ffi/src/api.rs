@@ -3,6 +3,9 @@
 //! This module exposes the core library types and functions to Flutter
 //! via flutter_rust_bridge.
 
+use std::collections::VecDeque;
+use std::sync::Mutex;
+
 use flutter_rust_bridge::frb;
 
 // Re-export core types with FFI-friendly wrappers
@@ -76,13 +79,15 @@ pub struct Team {
     /// Team identifier/name
     pub name: String,
     /// Behavior norms and cultural principles the team strives for
-    pub manifesto: Option<String>,
+    pub manifesto: Option<Manifesto>,
     /// What the team aims to achieve
     pub vision: Option<String>,
     /// Email addresses of team leaders
     pub leaders: Vec<String>,
     /// Email addresses of team members
     pub members: Vec<String>,
+    /// Email addresses of former members
+    pub archived: Vec<String>,
 }
 
 impl Team {
@@ -95,6 +100,7 @@ impl Team {
             vision: None,
             leaders: Vec::new(),
             members: Vec::new(),
+            archived: Vec::new(),
         }
     }
 
@@ -120,6 +126,10 @@ pub struct PublishConfig {
     pub vision: Option<String>,
     /// Path to publish OKRs
     pub okrs: Option<String>,
+    /// Path to publish the log of shared interactions
+    pub interactions: Option<String>,
+    /// IANA timezone to show published timestamps in (UTC if unset)
+    pub timezone: Option<String>,
 }
 
 /// Configuration for webhook notifications.
@@ -131,6 +141,105 @@ pub struct WebhookConfig {
     pub slack: Option<String>,
     /// Signal configuration
     pub signal: Option<String>,
+    /// Webhooks posting a payload of your own design
+    pub custom: Vec<CustomWebhook>,
+}
+
+/// A webhook posting to any URL with a configurable payload.
+#[frb(dart_metadata=("freezed"))]
+pub struct CustomWebhook {
+    /// Name used in messages about this webhook
+    pub name: Option<String>,
+    /// URL to post to, or a reference to it
+    pub url: String,
+    /// Payload with `{{field}}` placeholders; the fields as a JSON object if unset
+    pub template: Option<String>,
+    /// Content type of the payload (application/json if unset)
+    pub content_type: Option<String>,
+    /// Extra HTTP headers; only the Authorization value may be a reference
+    pub headers: Vec<WebhookHeader>,
+    /// Key to sign payloads with HMAC-SHA256, or a reference to it
+    pub secret: Option<String>,
+    /// Header carrying the signature (X-Interactions-Signature if unset)
+    pub signature_header: Option<String>,
+    /// Events to post, such as `interaction_created`; all of them if empty
+    pub events: Vec<String>,
+}
+
+/// An extra HTTP header sent by a custom webhook.
+#[frb(dart_metadata=("freezed"))]
+pub struct WebhookHeader {
+    /// Header name
+    pub name: String,
+    /// Header value, or a reference to it
+    pub value: String,
+}
+
+impl From<&CustomWebhook> for interactions_core::CustomWebhook {
+    fn from(hook: &CustomWebhook) -> Self {
+        interactions_core::CustomWebhook {
+            name: hook.name.clone(),
+            url: hook.url.clone(),
+            template: hook.template.clone(),
+            content_type: hook.content_type.clone(),
+            headers: hook
+                .headers
+                .iter()
+                .map(|h| (h.name.clone(), h.value.clone()))
+                .collect(),
+            secret: hook.secret.clone(),
+            signature_header: hook.signature_header.clone(),
+            events: hook.events.clone(),
+        }
+    }
+}
+
+impl From<interactions_core::CustomWebhook> for CustomWebhook {
+    fn from(hook: interactions_core::CustomWebhook) -> Self {
+        CustomWebhook {
+            name: hook.name,
+            url: hook.url,
+            template: hook.template,
+            content_type: hook.content_type,
+            headers: hook
+                .headers
+                .into_iter()
+                .map(|(name, value)| WebhookHeader { name, value })
+                .collect(),
+            secret: hook.secret,
+            signature_header: hook.signature_header,
+            events: hook.events,
+        }
+    }
+}
+
+/// A command run when something changes in the team.
+///
+/// It only runs on machines where it was approved, see [`approve_shell_hook`].
+#[frb(dart_metadata=("freezed"))]
+pub struct ShellHook {
+    /// Events to run for, such as `member_added`; all of them if empty
+    pub on: Vec<String>,
+    /// The command, run with `sh -c` (`cmd /C` on Windows)
+    pub run: String,
+}
+
+impl From<&ShellHook> for interactions_core::ShellHook {
+    fn from(hook: &ShellHook) -> Self {
+        interactions_core::ShellHook {
+            on: hook.on.clone(),
+            run: hook.run.clone(),
+        }
+    }
+}
+
+impl From<interactions_core::ShellHook> for ShellHook {
+    fn from(hook: interactions_core::ShellHook) -> Self {
+        ShellHook {
+            on: hook.on,
+            run: hook.run,
+        }
+    }
 }
 
 /// Configuration for linting on PRs.
@@ -149,6 +258,65 @@ pub struct BackupConfig {
     pub protected_branch: Option<String>,
 }
 
+/// Cadences per pulse prompt; unset prompts fall back to the next level.
+///
+/// Each is weekly, biweekly, monthly, quarterly or off.
+#[frb(dart_metadata=("freezed"))]
+pub struct PulseCadences {
+    /// How often to ask for a check-in
+    pub checkin: Option<String>,
+    /// How often to ask for OKR progress
+    pub okr_update: Option<String>,
+    /// How often to ask for a retrospective
+    pub retro: Option<String>,
+}
+
+fn core_cadences(cadences: &PulseCadences) -> Result<interactions_core::PulseCadences, String> {
+    let parse = |cadence: &Option<String>| {
+        cadence
+            .as_deref()
+            .map(serde_yaml::from_str::<interactions_core::Cadence>)
+            .transpose()
+            .map_err(|e| e.to_string())
+    };
+    Ok(interactions_core::PulseCadences {
+        checkin: parse(&cadences.checkin)?,
+        okr_update: parse(&cadences.okr_update)?,
+        retro: parse(&cadences.retro)?,
+    })
+}
+
+impl From<interactions_core::PulseCadences> for PulseCadences {
+    fn from(cadences: interactions_core::PulseCadences) -> Self {
+        let name = |cadence: Option<interactions_core::Cadence>| {
+            cadence.and_then(|c| serde_yaml::to_string(&c).ok().map(|s| s.trim().to_string()))
+        };
+        PulseCadences {
+            checkin: name(cadences.checkin),
+            okr_update: name(cadences.okr_update),
+            retro: name(cadences.retro),
+        }
+    }
+}
+
+/// One member's overrides of the team's pulse cadences.
+#[frb(dart_metadata=("freezed"))]
+pub struct MemberPulse {
+    /// The member's email
+    pub email: String,
+    /// Their cadences
+    pub cadences: PulseCadences,
+}
+
+/// Configuration for pulse prompts.
+#[frb(dart_metadata=("freezed"))]
+pub struct PulseConfig {
+    /// Team-wide cadences
+    pub team: PulseCadences,
+    /// Per-member overrides
+    pub members: Vec<MemberPulse>,
+}
+
 /// Team configuration stored in .team/config.yaml
 #[frb(dart_metadata=("freezed"))]
 pub struct TeamConfig {
@@ -160,6 +328,10 @@ pub struct TeamConfig {
     pub linting: Option<LintingConfig>,
     /// Backup configuration
     pub backup: Option<BackupConfig>,
+    /// Pulse prompt cadences
+    pub pulse: Option<PulseConfig>,
+    /// Commands run when something changes
+    pub hooks: Vec<ShellHook>,
 }
 
 impl TeamConfig {
@@ -171,6 +343,8 @@ impl TeamConfig {
             webhooks: None,
             linting: None,
             backup: None,
+            pulse: None,
+            hooks: Vec::new(),
         }
     }
 
@@ -182,6 +356,8 @@ impl TeamConfig {
                 manifesto: Some("/MANIFESTO.md".to_string()),
                 vision: Some("/VISION.md".to_string()),
                 okrs: Some("/okrs/".to_string()),
+                interactions: Some("/INTERACTIONS.md".to_string()),
+                timezone: None,
             }),
             webhooks: None,
             linting: Some(LintingConfig {
@@ -191,6 +367,15 @@ impl TeamConfig {
             backup: Some(BackupConfig {
                 protected_branch: Some("main".to_string()),
             }),
+            pulse: Some(PulseConfig {
+                team: PulseCadences {
+                    checkin: None,
+                    okr_update: None,
+                    retro: None,
+                },
+                members: Vec::new(),
+            }),
+            hooks: Vec::new(),
         }
     }
 }
@@ -206,6 +391,8 @@ pub struct Member {
     pub bio: Option<String>,
     /// Timezone for pulse notifications
     pub timezone: Option<String>,
+    /// Other email addresses this member is known by
+    pub aliases: Vec<String>,
 }
 
 impl Member {
@@ -217,6 +404,7 @@ impl Member {
             name: None,
             bio: None,
             timezone: None,
+            aliases: Vec::new(),
         }
     }
 
@@ -274,6 +462,11 @@ pub struct Interaction {
     pub timestamp: String,
     /// Whether this is shared with the team or private
     pub shared: bool,
+    /// Ids of the manifesto principles this interaction relates to
+    pub principles: Vec<String>,
+    /// Where an imported interaction came from (e.g. "slack"), `None` if
+    /// it was logged here
+    pub imported_from: Option<String>,
 }
 
 impl Interaction {
@@ -300,6 +493,8 @@ impl Interaction {
             note,
             timestamp,
             shared: false,
+            principles: Vec::new(),
+            imported_from: None,
         }
     }
 
@@ -373,6 +568,10 @@ pub struct Objective {
     pub owner: Option<String>,
     /// Quarter (e.g., "2026-Q1")
     pub quarter: Option<String>,
+    /// Ids of the manifesto principles this objective serves
+    pub principles: Vec<String>,
+    /// When the objective or its progress last changed, as ISO 8601
+    pub updated: Option<String>,
 }
 
 impl Objective {
@@ -393,6 +592,8 @@ impl Objective {
             visibility: OkrVisibility::default(),
             owner: None,
             quarter: None,
+            principles: Vec::new(),
+            updated: Some(chrono::Utc::now().to_rfc3339()),
         }
     }
 
@@ -407,6 +608,60 @@ impl Objective {
     }
 }
 
+/// A principle in the team manifesto.
+#[frb(dart_metadata=("freezed"))]
+pub struct Principle {
+    /// Stable identifier other entities use to refer to it
+    pub id: String,
+    /// Short title
+    pub title: String,
+    /// What living this principle looks like
+    pub description: Option<String>,
+}
+
+/// The team manifesto: an optional preamble and ordered principles.
+#[frb(dart_metadata=("freezed"))]
+pub struct Manifesto {
+    /// Free text introducing the principles
+    pub preamble: Option<String>,
+    /// Principles in order
+    pub principles: Vec<Principle>,
+}
+
+impl From<interactions_core::Manifesto> for Manifesto {
+    fn from(core: interactions_core::Manifesto) -> Self {
+        Manifesto {
+            preamble: core.preamble,
+            principles: core
+                .principles
+                .into_iter()
+                .map(|p| Principle {
+                    id: p.id,
+                    title: p.title,
+                    description: p.description,
+                })
+                .collect(),
+        }
+    }
+}
+
+impl From<&Manifesto> for interactions_core::Manifesto {
+    fn from(manifesto: &Manifesto) -> Self {
+        interactions_core::Manifesto {
+            preamble: manifesto.preamble.clone(),
+            principles: manifesto
+                .principles
+                .iter()
+                .map(|p| interactions_core::Principle {
+                    id: p.id.clone(),
+                    title: p.title.clone(),
+                    description: p.description.clone(),
+                })
+                .collect(),
+        }
+    }
+}
+
 // ============================================================================
 // YAML Serialization Functions
 // ============================================================================
@@ -465,10 +720,14 @@ pub fn member_credentials_from_yaml(yaml: String) -> Result<MemberCredentials, S
 pub fn team_to_yaml(team: &Team) -> Result<String, String> {
     let core = interactions_core::Team {
         name: team.name.clone(),
-        manifesto: team.manifesto.clone(),
+        manifesto: team
+            .manifesto
+            .as_ref()
+            .map(interactions_core::Manifesto::from),
         vision: team.vision.clone(),
         leaders: team.leaders.clone(),
         members: team.members.clone(),
+        archived: team.archived.clone(),
     };
     serde_yaml::to_string(&core).map_err(|e| e.to_string())
 }
@@ -479,10 +738,11 @@ pub fn team_from_yaml(yaml: String) -> Result<Team, String> {
     let core: interactions_core::Team = serde_yaml::from_str(&yaml).map_err(|e| e.to_string())?;
     Ok(Team {
         name: core.name,
-        manifesto: core.manifesto,
+        manifesto: core.manifesto.map(Manifesto::from),
         vision: core.vision,
         leaders: core.leaders,
         members: core.members,
+        archived: core.archived,
     })
 }
 
@@ -497,6 +757,8 @@ pub fn team_config_to_yaml(config: &TeamConfig) -> Result<String, String> {
                 manifesto: p.manifesto.clone(),
                 vision: p.vision.clone(),
                 okrs: p.okrs.clone(),
+                interactions: p.interactions.clone(),
+                timezone: p.timezone.clone(),
             }),
         webhooks: config
             .webhooks
@@ -505,6 +767,7 @@ pub fn team_config_to_yaml(config: &TeamConfig) -> Result<String, String> {
                 discord: w.discord.clone(),
                 slack: w.slack.clone(),
                 signal: w.signal.clone(),
+                custom: w.custom.iter().map(Into::into).collect(),
             }),
         linting: config
             .linting
@@ -519,6 +782,21 @@ pub fn team_config_to_yaml(config: &TeamConfig) -> Result<String, String> {
             .map(|b| interactions_core::BackupConfig {
                 protected_branch: b.protected_branch.clone(),
             }),
+        pulse: config
+            .pulse
+            .as_ref()
+            .map(|p| -> Result<_, String> {
+                Ok(interactions_core::PulseConfig {
+                    team: core_cadences(&p.team)?,
+                    members: p
+                        .members
+                        .iter()
+                        .map(|m| Ok((m.email.clone(), core_cadences(&m.cadences)?)))
+                        .collect::<Result<_, String>>()?,
+                })
+            })
+            .transpose()?,
+        hooks: config.hooks.iter().map(Into::into).collect(),
     };
     serde_yaml::to_string(&core).map_err(|e| e.to_string())
 }
@@ -533,11 +811,14 @@ pub fn team_config_from_yaml(yaml: String) -> Result<TeamConfig, String> {
             manifesto: p.manifesto,
             vision: p.vision,
             okrs: p.okrs,
+            interactions: p.interactions,
+            timezone: p.timezone,
         }),
         webhooks: core.webhooks.map(|w| WebhookConfig {
             discord: w.discord,
             slack: w.slack,
             signal: w.signal,
+            custom: w.custom.into_iter().map(Into::into).collect(),
         }),
         linting: core.linting.map(|l| LintingConfig {
             enabled: l.enabled,
@@ -546,6 +827,18 @@ pub fn team_config_from_yaml(yaml: String) -> Result<TeamConfig, String> {
         backup: core.backup.map(|b| BackupConfig {
             protected_branch: b.protected_branch,
         }),
+        pulse: core.pulse.map(|p| PulseConfig {
+            team: p.team.into(),
+            members: p
+                .members
+                .into_iter()
+                .map(|(email, cadences)| MemberPulse {
+                    email,
+                    cadences: cadences.into(),
+                })
+                .collect(),
+        }),
+        hooks: core.hooks.into_iter().map(Into::into).collect(),
     })
 }
 
@@ -557,6 +850,7 @@ pub fn member_to_yaml(member: &Member) -> Result<String, String> {
         name: member.name.clone(),
         bio: member.bio.clone(),
         timezone: member.timezone.clone(),
+        aliases: member.aliases.clone(),
     };
     serde_yaml::to_string(&core).map_err(|e| e.to_string())
 }
@@ -570,12 +864,17 @@ pub fn member_from_yaml(yaml: String) -> Result<Member, String> {
         name: core.name,
         bio: core.bio,
         timezone: core.timezone,
+        aliases: core.aliases,
     })
 }
 
 /// Serialize interaction to YAML string.
 #[frb(sync)]
 pub fn interaction_to_yaml(interaction: &Interaction) -> Result<String, String> {
+    serde_yaml::to_string(&core_interaction(interaction)).map_err(|e| e.to_string())
+}
+
+fn core_interaction(interaction: &Interaction) -> interactions_core::Interaction {
     let kind = match interaction.kind {
         InteractionKind::Appreciation => interactions_core::InteractionKind::Appreciation,
         InteractionKind::Feedback => interactions_core::InteractionKind::Feedback,
@@ -588,7 +887,7 @@ pub fn interaction_to_yaml(interaction: &Interaction) -> Result<String, String>
         .map(|dt| dt.with_timezone(&chrono::Utc))
         .unwrap_or_else(|_| chrono::Utc::now());
 
-    let core = interactions_core::Interaction {
+    interactions_core::Interaction {
         id: interaction.id.clone(),
         kind,
         from: interaction.from.clone(),
@@ -596,8 +895,9 @@ pub fn interaction_to_yaml(interaction: &Interaction) -> Result<String, String>
         note: interaction.note.clone(),
         timestamp,
         shared: interaction.shared,
-    };
-    serde_yaml::to_string(&core).map_err(|e| e.to_string())
+        principles: interaction.principles.clone(),
+        imported_from: interaction.imported_from.clone(),
+    }
 }
 
 /// Parse interaction from YAML string.
@@ -622,6 +922,8 @@ pub fn interaction_from_yaml(yaml: String) -> Result<Interaction, String> {
         note: core.note,
         timestamp: core.timestamp.to_rfc3339(),
         shared: core.shared,
+        principles: core.principles,
+        imported_from: core.imported_from,
     })
 }
 
@@ -651,10 +953,28 @@ pub fn objective_to_yaml(objective: &Objective) -> Result<String, String> {
         visibility,
         owner: objective.owner.clone(),
         quarter: objective.quarter.clone(),
+        principles: objective.principles.clone(),
+        updated: objective
+            .updated
+            .as_deref()
+            .and_then(|u| chrono::DateTime::parse_from_rfc3339(u).ok())
+            .map(|dt| dt.with_timezone(&chrono::Utc)),
     };
     serde_yaml::to_string(&core).map_err(|e| e.to_string())
 }
 
+/// Record that an objective or its progress changed just now.
+///
+/// Call this on an edit before saving; `objective_to_yaml` keeps `updated`
+/// as it is so saving alone does not reset pulse reminders.
+#[frb(sync)]
+pub fn touch_objective(objective: Objective) -> Objective {
+    Objective {
+        updated: Some(chrono::Utc::now().to_rfc3339()),
+        ..objective
+    }
+}
+
 /// Parse objective from YAML string.
 #[frb(sync)]
 pub fn objective_from_yaml(yaml: String) -> Result<Objective, String> {
@@ -684,5 +1004,404 @@ pub fn objective_from_yaml(yaml: String) -> Result<Objective, String> {
         visibility,
         owner: core.owner,
         quarter: core.quarter,
+        principles: core.principles,
+        updated: core.updated.map(|u| u.to_rfc3339()),
     })
 }
+
+// ============================================================================
+// Storage Functions
+// ============================================================================
+
+/// Normalise an email address for comparison and storage.
+#[frb(sync)]
+pub fn normalize_email(email: String) -> String {
+    interactions_core::normalize_email(&email)
+}
+
+/// Outcome of a pincode check against a team directory.
+#[frb(dart_metadata=("freezed"))]
+pub struct PincodeCheck {
+    /// Whether the pincode was correct
+    pub verified: bool,
+    /// The member's primary email, for the session; they may have logged
+    /// in with an alias
+    pub email: String,
+    /// Seconds to wait before the next attempt is allowed (0 if none)
+    pub retry_after_secs: u64,
+}
+
+/// Verify a member's pincode in the team at `root`.
+///
+/// Failed attempts are throttled per member. While throttled the pincode
+/// is not checked and `retry_after_secs` says how long to wait.
+pub fn verify_member_pincode(
+    root: String,
+    email: String,
+    pincode: String,
+) -> Result<PincodeCheck, String> {
+    let storage = interactions_core::TeamStorage::new(&root);
+    let primary = storage.resolve_email(&email).map_err(|e| e.to_string())?;
+    match storage.verify_pincode(&email, &pincode) {
+        Ok(verified) => Ok(PincodeCheck {
+            verified,
+            email: primary,
+            retry_after_secs: pincode_retry_after_secs(root, email)?,
+        }),
+        Err(interactions_core::Error::TooManyAttempts { retry_after_secs }) => Ok(PincodeCheck {
+            verified: false,
+            email: primary,
+            retry_after_secs,
+        }),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+/// Seconds a member has to wait before their next pincode attempt (0 if none).
+pub fn pincode_retry_after_secs(root: String, email: String) -> Result<u64, String> {
+    let storage = interactions_core::TeamStorage::new(&root);
+    let wait = storage
+        .pincode_retry_after(&email)
+        .map_err(|e| e.to_string())?;
+    Ok(wait.map_or(0, |w| {
+        let millis = w.num_milliseconds().max(0) as u64;
+        millis.div_ceil(1000)
+    }))
+}
+
+/// Whether a shell hook may run on this machine for the team at `root`.
+pub fn shell_hook_approved(root: String, hook: ShellHook) -> Result<bool, String> {
+    interactions_core::TeamStorage::new(&root)
+        .is_hook_approved(&(&hook).into())
+        .map_err(|e| e.to_string())
+}
+
+/// Let a shell hook run on this machine for the team at `root`.
+///
+/// The approval is for this exact command; a changed one has to be
+/// approved again.
+pub fn approve_shell_hook(root: String, hook: ShellHook) -> Result<(), String> {
+    interactions_core::TeamStorage::new(&root)
+        .approve_hook(&(&hook).into())
+        .map_err(|e| e.to_string())
+}
+
+/// Load the manifesto of the team at `root`, if it has one.
+///
+/// Legacy plain-text manifestos come back as a preamble without principles.
+pub fn load_team_manifesto(root: String) -> Result<Option<Manifesto>, String> {
+    let storage = interactions_core::TeamStorage::new(&root);
+    let manifesto = storage.team_manifesto().map_err(|e| e.to_string())?;
+    Ok(manifesto.map(Manifesto::from))
+}
+
+/// How often kudos have recognised a manifesto principle.
+#[frb(dart_metadata=("freezed"))]
+pub struct PrincipleRecognition {
+    /// Principle id
+    pub id: String,
+    /// Principle title
+    pub title: String,
+    /// Number of kudos that named this principle
+    pub count: u32,
+}
+
+/// Count, per manifesto principle, the kudos that named it, in manifesto order.
+pub fn principle_report(root: String) -> Result<Vec<PrincipleRecognition>, String> {
+    let storage = interactions_core::TeamStorage::new(&root);
+    let manifesto = storage
+        .team_manifesto()
+        .map_err(|e| e.to_string())?
+        .unwrap_or_default();
+    let kudos = storage.load_team_kudos().map_err(|e| e.to_string())?;
+    Ok(manifesto
+        .recognition(&kudos)
+        .into_iter()
+        .map(|(principle, count)| PrincipleRecognition {
+            id: principle.id.clone(),
+            title: principle.title.clone(),
+            count: count as u32,
+        })
+        .collect())
+}
+
+// ============================================================================
+// Analytics Functions
+// ============================================================================
+
+/// Interactions a member sent and received.
+#[frb(dart_metadata=("freezed"))]
+pub struct MemberInteractionStats {
+    /// The member's email
+    pub email: String,
+    /// Kudos the member gave
+    pub kudos_given: u32,
+    /// Kudos the member received
+    pub kudos_received: u32,
+    /// Feedback the member gave
+    pub feedback_given: u32,
+    /// Feedback the member received
+    pub feedback_received: u32,
+    /// Interactions of any kind the member logged
+    pub total_sent: u32,
+    /// Interactions of any kind the member was part of
+    pub total_received: u32,
+}
+
+/// Appreciations from one member to another.
+#[frb(dart_metadata=("freezed"))]
+pub struct AppreciationEdge {
+    /// Who gave the appreciation
+    pub from: String,
+    /// Who received it
+    pub to: String,
+    /// How many times
+    pub count: u32,
+}
+
+/// Number of interactions in one week.
+#[frb(dart_metadata=("freezed"))]
+pub struct WeekActivity {
+    /// The Monday the week starts on (YYYY-MM-DD)
+    pub week_start: String,
+    /// Interactions in that week
+    pub count: u32,
+}
+
+fn load_analytics(root: &str) -> Result<interactions_core::analytics::Analytics, String> {
+    let storage = interactions_core::TeamStorage::new(root);
+    interactions_core::analytics::Analytics::load(&storage).map_err(|e| e.to_string())
+}
+
+/// Sent and received counts for everyone in the team at `root`.
+pub fn member_interaction_stats(root: String) -> Result<Vec<MemberInteractionStats>, String> {
+    use interactions_core::InteractionKind as Kind;
+    let analytics = load_analytics(&root)?;
+    Ok(analytics
+        .all_member_stats()
+        .into_iter()
+        .map(|s| MemberInteractionStats {
+            kudos_given: s.sent_of(Kind::Appreciation) as u32,
+            kudos_received: s.received_of(Kind::Appreciation) as u32,
+            feedback_given: s.sent_of(Kind::Feedback) as u32,
+            feedback_received: s.received_of(Kind::Feedback) as u32,
+            total_sent: s.total_sent() as u32,
+            total_received: s.total_received() as u32,
+            email: s.email,
+        })
+        .collect())
+}
+
+/// Who appreciates whom in the team at `root`, most frequent first.
+pub fn appreciation_graph(root: String) -> Result<Vec<AppreciationEdge>, String> {
+    let analytics = load_analytics(&root)?;
+    Ok(analytics
+        .appreciation_graph()
+        .into_iter()
+        .map(|e| AppreciationEdge {
+            from: e.from,
+            to: e.to,
+            count: e.count as u32,
+        })
+        .collect())
+}
+
+/// Share (0.0 to 1.0) of appreciating pairs where it goes both ways, if any.
+pub fn appreciation_reciprocity(root: String) -> Result<Option<f32>, String> {
+    Ok(load_analytics(&root)?.reciprocity())
+}
+
+/// Team members nobody has appreciated in the last `weeks` weeks.
+pub fn unrecognised_members(root: String, weeks: u32) -> Result<Vec<String>, String> {
+    let storage = interactions_core::TeamStorage::new(&root);
+    let team = storage
+        .load_team()
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| "No team found".to_string())?;
+    let members: Vec<String> = team
+        .leaders
+        .iter()
+        .chain(team.members.iter())
+        .cloned()
+        .collect();
+    Ok(load_analytics(&root)?.unrecognised(&members, weeks, chrono::Utc::now()))
+}
+
+/// Interactions per week for the last `weeks` weeks, oldest first.
+///
+/// Weeks start on Monday in `timezone` (an IANA name), or UTC if not given.
+pub fn weekly_activity(
+    root: String,
+    weeks: u32,
+    timezone: Option<String>,
+) -> Result<Vec<WeekActivity>, String> {
+    let tz = match timezone {
+        Some(name) => interactions_core::time::parse_timezone(&name).map_err(|e| e.to_string())?,
+        None => chrono_tz::Tz::UTC,
+    };
+    let analytics = load_analytics(&root)?;
+    Ok(analytics
+        .activity(weeks as usize, chrono::Utc::now(), tz)
+        .into_iter()
+        .map(|a| WeekActivity {
+            week_start: a.week.format("%Y-%m-%d").to_string(),
+            count: a.count as u32,
+        })
+        .collect())
+}
+
+// ============================================================================
+// Export Functions
+// ============================================================================
+
+/// Export interactions and objectives from the team at `root`.
+///
+/// `format` is csv, json or md. `kinds` is a comma-separated filter such as
+/// "kudos,feedback,okr" and `since` a YYYY-MM-DD date. Private items are
+/// only included when `email` and `pincode` authenticate their owner.
+pub fn export_team_data(
+    root: String,
+    format: String,
+    kinds: Option<String>,
+    since: Option<String>,
+    email: Option<String>,
+    pincode: Option<String>,
+) -> Result<String, String> {
+    use interactions_core::export::{parse_since, Export, ExportFilter, ExportFormat};
+
+    let storage = interactions_core::TeamStorage::new(&root);
+    let format: ExportFormat = format
+        .parse()
+        .map_err(|e: interactions_core::Error| e.to_string())?;
+    let mut filter = ExportFilter::all();
+    if let Some(kinds) = kinds {
+        filter = filter.with_kinds(&kinds).map_err(|e| e.to_string())?;
+    }
+    if let Some(since) = since {
+        filter = filter.since(parse_since(&since).map_err(|e| e.to_string())?);
+    }
+
+    let session = match (&email, &pincode) {
+        (Some(email), Some(pincode)) => {
+            interactions_core::AuthorizedStorage::login(&storage, email, pincode)
+                .map_err(|e| e.to_string())?
+        }
+        _ => interactions_core::AuthorizedStorage::new(&storage, None),
+    };
+    Export::collect(&session, &filter)
+        .and_then(|export| export.render(format))
+        .map_err(|e| e.to_string())
+}
+
+// ============================================================================
+// Pulse Functions
+// ============================================================================
+
+/// A pulse prompt that is due for a member.
+#[frb(dart_metadata=("freezed"))]
+pub struct DuePulsePrompt {
+    /// check_in, okr_update or retro
+    pub prompt: String,
+    /// Reminder to show, e.g. "It's been 9 days since your last check-in"
+    pub message: String,
+    /// The objective to update, for OKR updates
+    pub objective_id: Option<String>,
+}
+
+/// Pulse prompts due now for the member with `email` in the team at `root`.
+pub fn due_pulse_prompts(root: String, email: String) -> Result<Vec<DuePulsePrompt>, String> {
+    let storage = interactions_core::TeamStorage::new(&root);
+    let pulse = interactions_core::pulse::Pulse::load(&storage).map_err(|e| e.to_string())?;
+    let now = chrono::Utc::now();
+    Ok(pulse
+        .due_for(&email, now)
+        .into_iter()
+        .map(|due| DuePulsePrompt {
+            prompt: serde_yaml::to_string(&due.prompt)
+                .map(|s| s.trim().to_string())
+                .unwrap_or_default(),
+            message: due.message(now),
+            objective_id: due.objective.map(|o| o.id),
+        })
+        .collect())
+}
+
+// ============================================================================
+// Event Functions
+// ============================================================================
+
+/// Events announced through this library, waiting for the app to take them
+static TEAM_EVENTS: Mutex<VecDeque<TeamEvent>> = Mutex::new(VecDeque::new());
+
+/// Something that changed in the team.
+#[frb(dart_metadata=("freezed"))]
+pub struct TeamEvent {
+    /// Unique id, assigned when the event was created
+    pub id: String,
+    /// Event name, e.g. interaction_created
+    pub name: String,
+    /// Human-readable text, e.g. "alice@x.com gave kudos to bob@x.com: Thanks"
+    pub text: String,
+    /// The event's fields, as sent to webhooks
+    pub fields: Vec<TeamEventField>,
+}
+
+/// A field of a team event.
+#[frb(dart_metadata=("freezed"))]
+pub struct TeamEventField {
+    /// Field name, e.g. from
+    pub key: String,
+    /// Field value
+    pub value: String,
+}
+
+impl From<&interactions_core::Event> for TeamEvent {
+    fn from(event: &interactions_core::Event) -> Self {
+        let notification = event.notification();
+        TeamEvent {
+            id: event.id.clone(),
+            name: event.name().to_string(),
+            text: notification.text().to_string(),
+            fields: notification
+                .fields
+                .into_iter()
+                .map(|(key, value)| TeamEventField { key, value })
+                .collect(),
+        }
+    }
+}
+
+/// Announce an interaction the app has just saved to the team at `root`.
+///
+/// Runs the team's webhooks and shell hooks if the interaction is shared, and
+/// queues the event for [`take_team_events`]. Returns the hooks that failed;
+/// the interaction stays saved either way.
+pub fn announce_interaction(root: String, interaction: Interaction) -> Result<Vec<String>, String> {
+    let storage = interactions_core::TeamStorage::new(&root);
+    let bus = interactions_core::EventBus::for_team(&storage)
+        .map_err(|e| e.to_string())?
+        .with(|event: &interactions_core::Event| {
+            if let Ok(mut queue) = TEAM_EVENTS.lock() {
+                queue.push_back(event.into());
+            }
+            Ok(())
+        });
+    bus.publish(&interactions_core::Event::new(
+        interactions_core::Change::InteractionCreated(core_interaction(&interaction)),
+    ));
+    Ok(bus
+        .take_failures()
+        .into_iter()
+        .map(|e| e.to_string())
+        .collect())
+}
+
+/// Take the events announced since the last call, oldest first.
+///
+/// The app polls this to show changes as they happen.
+pub fn take_team_events() -> Vec<TeamEvent> {
+    TEAM_EVENTS
+        .lock()
+        .map(|mut queue| queue.drain(..).collect())
+        .unwrap_or_default()
+}
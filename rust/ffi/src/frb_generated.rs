@@ -37,7 +37,7 @@ flutter_rust_bridge::frb_generated_boilerplate!(
     default_rust_auto_opaque = RustAutoOpaqueNom,
 );
 pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_VERSION: &str = "2.11.1";
-pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = 1882716962;
+pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = 348746638;
 
 // Section: executor
 
@@ -45,6 +45,94 @@ flutter_rust_bridge::frb_generated_default_handler!();
 
 // Section: wire_funcs
 
+fn wire__crate__api__announce_interaction_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    root: impl CstDecode<String>,
+    interaction: impl CstDecode<crate::api::Interaction>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "announce_interaction",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_root = root.cst_decode();
+            let api_interaction = interaction.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, String>((move || {
+                    let output_ok = crate::api::announce_interaction(api_root, api_interaction)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__appreciation_graph_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    root: impl CstDecode<String>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "appreciation_graph",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_root = root.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, String>((move || {
+                    let output_ok = crate::api::appreciation_graph(api_root)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__appreciation_reciprocity_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    root: impl CstDecode<String>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "appreciation_reciprocity",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_root = root.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, String>((move || {
+                    let output_ok = crate::api::appreciation_reciprocity(api_root)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__approve_shell_hook_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    root: impl CstDecode<String>,
+    hook: impl CstDecode<crate::api::ShellHook>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "approve_shell_hook",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_root = root.cst_decode();
+            let api_hook = hook.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, String>((move || {
+                    let output_ok = crate::api::approve_shell_hook(api_root, api_hook)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
 fn wire__crate__api__credentials_create_impl(
     pincode: impl CstDecode<String>,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
@@ -120,6 +208,67 @@ fn wire__crate__api__credentials_verify_impl(
         },
     )
 }
+fn wire__crate__api__due_pulse_prompts_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    root: impl CstDecode<String>,
+    email: impl CstDecode<String>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "due_pulse_prompts",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_root = root.cst_decode();
+            let api_email = email.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, String>((move || {
+                    let output_ok = crate::api::due_pulse_prompts(api_root, api_email)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__export_team_data_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    root: impl CstDecode<String>,
+    format: impl CstDecode<String>,
+    kinds: impl CstDecode<Option<String>>,
+    since: impl CstDecode<Option<String>>,
+    email: impl CstDecode<Option<String>>,
+    pincode: impl CstDecode<Option<String>>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "export_team_data",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_root = root.cst_decode();
+            let api_format = format.cst_decode();
+            let api_kinds = kinds.cst_decode();
+            let api_since = since.cst_decode();
+            let api_email = email.cst_decode();
+            let api_pincode = pincode.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, String>((move || {
+                    let output_ok = crate::api::export_team_data(
+                        api_root,
+                        api_format,
+                        api_kinds,
+                        api_since,
+                        api_email,
+                        api_pincode,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
 fn wire__crate__api__interaction_appreciation_impl(
     from: impl CstDecode<String>,
     with_members: impl CstDecode<Vec<String>>,
@@ -293,6 +442,27 @@ fn wire__crate__api__key_result_create_impl(
         },
     )
 }
+fn wire__crate__api__load_team_manifesto_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    root: impl CstDecode<String>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "load_team_manifesto",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_root = root.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, String>((move || {
+                    let output_ok = crate::api::load_team_manifesto(api_root)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
 fn wire__crate__api__member_create_impl(
     email: impl CstDecode<String>,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
@@ -426,6 +596,27 @@ fn wire__crate__api__member_from_yaml_impl(
         },
     )
 }
+fn wire__crate__api__member_interaction_stats_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    root: impl CstDecode<String>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "member_interaction_stats",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_root = root.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, String>((move || {
+                    let output_ok = crate::api::member_interaction_stats(api_root)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
 fn wire__crate__api__member_to_yaml_impl(
     member: impl CstDecode<crate::api::Member>,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
@@ -444,6 +635,24 @@ fn wire__crate__api__member_to_yaml_impl(
         },
     )
 }
+fn wire__crate__api__normalize_email_impl(
+    email: impl CstDecode<String>,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "normalize_email",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let api_email = email.cst_decode();
+            transform_result_dco::<_, _, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::normalize_email(api_email))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
 fn wire__crate__api__objective_create_impl(
     title: impl CstDecode<String>,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
@@ -536,6 +745,90 @@ fn wire__crate__api__okr_visibility_default_impl(
         },
     )
 }
+fn wire__crate__api__pincode_retry_after_secs_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    root: impl CstDecode<String>,
+    email: impl CstDecode<String>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "pincode_retry_after_secs",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_root = root.cst_decode();
+            let api_email = email.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, String>((move || {
+                    let output_ok = crate::api::pincode_retry_after_secs(api_root, api_email)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__principle_report_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    root: impl CstDecode<String>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "principle_report",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_root = root.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, String>((move || {
+                    let output_ok = crate::api::principle_report(api_root)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__shell_hook_approved_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    root: impl CstDecode<String>,
+    hook: impl CstDecode<crate::api::ShellHook>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "shell_hook_approved",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_root = root.cst_decode();
+            let api_hook = hook.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, String>((move || {
+                    let output_ok = crate::api::shell_hook_approved(api_root, api_hook)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__take_team_events_impl(port_: flutter_rust_bridge::for_generated::MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "take_team_events",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            move |context| {
+                transform_result_dco::<_, _, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::take_team_events())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
 fn wire__crate__api__team_config_create_impl(
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
@@ -700,34 +993,126 @@ fn wire__crate__api__team_to_yaml_impl(
         },
     )
 }
-
-// Section: dart2rust
-
-impl CstDecode<bool> for bool {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    fn cst_decode(self) -> bool {
-        self
-    }
-}
-impl CstDecode<f32> for f32 {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    fn cst_decode(self) -> f32 {
-        self
-    }
+fn wire__crate__api__touch_objective_impl(
+    objective: impl CstDecode<crate::api::Objective>,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "touch_objective",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let api_objective = objective.cst_decode();
+            transform_result_dco::<_, _, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::touch_objective(api_objective))?;
+                Ok(output_ok)
+            })())
+        },
+    )
 }
-impl CstDecode<i32> for i32 {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    fn cst_decode(self) -> i32 {
-        self
-    }
+fn wire__crate__api__unrecognised_members_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    root: impl CstDecode<String>,
+    weeks: impl CstDecode<u32>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "unrecognised_members",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_root = root.cst_decode();
+            let api_weeks = weeks.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, String>((move || {
+                    let output_ok = crate::api::unrecognised_members(api_root, api_weeks)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
 }
-impl CstDecode<crate::api::InteractionKind> for i32 {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    fn cst_decode(self) -> crate::api::InteractionKind {
-        match self {
-            0 => crate::api::InteractionKind::Appreciation,
-            1 => crate::api::InteractionKind::Feedback,
-            2 => crate::api::InteractionKind::Apology,
+fn wire__crate__api__verify_member_pincode_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    root: impl CstDecode<String>,
+    email: impl CstDecode<String>,
+    pincode: impl CstDecode<String>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "verify_member_pincode",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_root = root.cst_decode();
+            let api_email = email.cst_decode();
+            let api_pincode = pincode.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, String>((move || {
+                    let output_ok =
+                        crate::api::verify_member_pincode(api_root, api_email, api_pincode)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__weekly_activity_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    root: impl CstDecode<String>,
+    weeks: impl CstDecode<u32>,
+    timezone: impl CstDecode<Option<String>>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "weekly_activity",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_root = root.cst_decode();
+            let api_weeks = weeks.cst_decode();
+            let api_timezone = timezone.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, String>((move || {
+                    let output_ok = crate::api::weekly_activity(api_root, api_weeks, api_timezone)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+
+// Section: dart2rust
+
+impl CstDecode<bool> for bool {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> bool {
+        self
+    }
+}
+impl CstDecode<f32> for f32 {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> f32 {
+        self
+    }
+}
+impl CstDecode<i32> for i32 {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> i32 {
+        self
+    }
+}
+impl CstDecode<crate::api::InteractionKind> for i32 {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::api::InteractionKind {
+        match self {
+            0 => crate::api::InteractionKind::Appreciation,
+            1 => crate::api::InteractionKind::Feedback,
+            2 => crate::api::InteractionKind::Apology,
             3 => crate::api::InteractionKind::CheckIn,
             4 => crate::api::InteractionKind::Retrospective,
             _ => unreachable!("Invalid variant for InteractionKind: {}", self),
@@ -744,6 +1129,18 @@ impl CstDecode<crate::api::OkrVisibility> for i32 {
         }
     }
 }
+impl CstDecode<u32> for u32 {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> u32 {
+        self
+    }
+}
+impl CstDecode<u64> for u64 {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> u64 {
+        self
+    }
+}
 impl CstDecode<u8> for u8 {
     // Codec=Cst (C-struct based), see doc to use other codecs
     fn cst_decode(self) -> u8 {
@@ -758,6 +1155,20 @@ impl SseDecode for String {
     }
 }
 
+impl SseDecode for crate::api::AppreciationEdge {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_from = <String>::sse_decode(deserializer);
+        let mut var_to = <String>::sse_decode(deserializer);
+        let mut var_count = <u32>::sse_decode(deserializer);
+        return crate::api::AppreciationEdge {
+            from: var_from,
+            to: var_to,
+            count: var_count,
+        };
+    }
+}
+
 impl SseDecode for crate::api::BackupConfig {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -787,6 +1198,44 @@ impl SseDecode for crate::api::Credentials {
     }
 }
 
+impl SseDecode for crate::api::CustomWebhook {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_name = <Option<String>>::sse_decode(deserializer);
+        let mut var_url = <String>::sse_decode(deserializer);
+        let mut var_template = <Option<String>>::sse_decode(deserializer);
+        let mut var_contentType = <Option<String>>::sse_decode(deserializer);
+        let mut var_headers = <Vec<crate::api::WebhookHeader>>::sse_decode(deserializer);
+        let mut var_secret = <Option<String>>::sse_decode(deserializer);
+        let mut var_signatureHeader = <Option<String>>::sse_decode(deserializer);
+        let mut var_events = <Vec<String>>::sse_decode(deserializer);
+        return crate::api::CustomWebhook {
+            name: var_name,
+            url: var_url,
+            template: var_template,
+            content_type: var_contentType,
+            headers: var_headers,
+            secret: var_secret,
+            signature_header: var_signatureHeader,
+            events: var_events,
+        };
+    }
+}
+
+impl SseDecode for crate::api::DuePulsePrompt {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_prompt = <String>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        let mut var_objectiveId = <Option<String>>::sse_decode(deserializer);
+        return crate::api::DuePulsePrompt {
+            prompt: var_prompt,
+            message: var_message,
+            objective_id: var_objectiveId,
+        };
+    }
+}
+
 impl SseDecode for f32 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -811,6 +1260,8 @@ impl SseDecode for crate::api::Interaction {
         let mut var_note = <String>::sse_decode(deserializer);
         let mut var_timestamp = <String>::sse_decode(deserializer);
         let mut var_shared = <bool>::sse_decode(deserializer);
+        let mut var_principles = <Vec<String>>::sse_decode(deserializer);
+        let mut var_importedFrom = <Option<String>>::sse_decode(deserializer);
         return crate::api::Interaction {
             id: var_id,
             kind: var_kind,
@@ -819,6 +1270,8 @@ impl SseDecode for crate::api::Interaction {
             note: var_note,
             timestamp: var_timestamp,
             shared: var_shared,
+            principles: var_principles,
+            imported_from: var_importedFrom,
         };
     }
 }
@@ -876,6 +1329,42 @@ impl SseDecode for Vec<String> {
     }
 }
 
+impl SseDecode for Vec<crate::api::AppreciationEdge> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::AppreciationEdge>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::CustomWebhook> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::CustomWebhook>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::DuePulsePrompt> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::DuePulsePrompt>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
 impl SseDecode for Vec<crate::api::KeyResult> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -888,6 +1377,32 @@ impl SseDecode for Vec<crate::api::KeyResult> {
     }
 }
 
+impl SseDecode for Vec<crate::api::MemberInteractionStats> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::MemberInteractionStats>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::MemberPulse> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::MemberPulse>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
 impl SseDecode for Vec<u8> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -900,6 +1415,102 @@ impl SseDecode for Vec<u8> {
     }
 }
 
+impl SseDecode for Vec<crate::api::Principle> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::Principle>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::PrincipleRecognition> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::PrincipleRecognition>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::ShellHook> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::ShellHook>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::TeamEvent> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::TeamEvent>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::TeamEventField> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::TeamEventField>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::WebhookHeader> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::WebhookHeader>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::WeekActivity> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::WeekActivity>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for crate::api::Manifesto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_preamble = <Option<String>>::sse_decode(deserializer);
+        let mut var_principles = <Vec<crate::api::Principle>>::sse_decode(deserializer);
+        return crate::api::Manifesto {
+            preamble: var_preamble,
+            principles: var_principles,
+        };
+    }
+}
+
 impl SseDecode for crate::api::Member {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -907,11 +1518,13 @@ impl SseDecode for crate::api::Member {
         let mut var_name = <Option<String>>::sse_decode(deserializer);
         let mut var_bio = <Option<String>>::sse_decode(deserializer);
         let mut var_timezone = <Option<String>>::sse_decode(deserializer);
+        let mut var_aliases = <Vec<String>>::sse_decode(deserializer);
         return crate::api::Member {
             email: var_email,
             name: var_name,
             bio: var_bio,
             timezone: var_timezone,
+            aliases: var_aliases,
         };
     }
 }
@@ -928,6 +1541,40 @@ impl SseDecode for crate::api::MemberCredentials {
     }
 }
 
+impl SseDecode for crate::api::MemberInteractionStats {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_email = <String>::sse_decode(deserializer);
+        let mut var_kudosGiven = <u32>::sse_decode(deserializer);
+        let mut var_kudosReceived = <u32>::sse_decode(deserializer);
+        let mut var_feedbackGiven = <u32>::sse_decode(deserializer);
+        let mut var_feedbackReceived = <u32>::sse_decode(deserializer);
+        let mut var_totalSent = <u32>::sse_decode(deserializer);
+        let mut var_totalReceived = <u32>::sse_decode(deserializer);
+        return crate::api::MemberInteractionStats {
+            email: var_email,
+            kudos_given: var_kudosGiven,
+            kudos_received: var_kudosReceived,
+            feedback_given: var_feedbackGiven,
+            feedback_received: var_feedbackReceived,
+            total_sent: var_totalSent,
+            total_received: var_totalReceived,
+        };
+    }
+}
+
+impl SseDecode for crate::api::MemberPulse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_email = <String>::sse_decode(deserializer);
+        let mut var_cadences = <crate::api::PulseCadences>::sse_decode(deserializer);
+        return crate::api::MemberPulse {
+            email: var_email,
+            cadences: var_cadences,
+        };
+    }
+}
+
 impl SseDecode for crate::api::Objective {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -938,6 +1585,8 @@ impl SseDecode for crate::api::Objective {
         let mut var_visibility = <crate::api::OkrVisibility>::sse_decode(deserializer);
         let mut var_owner = <Option<String>>::sse_decode(deserializer);
         let mut var_quarter = <Option<String>>::sse_decode(deserializer);
+        let mut var_principles = <Vec<String>>::sse_decode(deserializer);
+        let mut var_updated = <Option<String>>::sse_decode(deserializer);
         return crate::api::Objective {
             id: var_id,
             title: var_title,
@@ -946,6 +1595,8 @@ impl SseDecode for crate::api::Objective {
             visibility: var_visibility,
             owner: var_owner,
             quarter: var_quarter,
+            principles: var_principles,
+            updated: var_updated,
         };
     }
 }
@@ -984,6 +1635,17 @@ impl SseDecode for Option<crate::api::BackupConfig> {
     }
 }
 
+impl SseDecode for Option<f32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<f32>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
 impl SseDecode for Option<crate::api::LintingConfig> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -995,6 +1657,17 @@ impl SseDecode for Option<crate::api::LintingConfig> {
     }
 }
 
+impl SseDecode for Option<crate::api::Manifesto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::Manifesto>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
 impl SseDecode for Option<crate::api::PublishConfig> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -1006,27 +1679,122 @@ impl SseDecode for Option<crate::api::PublishConfig> {
     }
 }
 
-impl SseDecode for Option<crate::api::WebhookConfig> {
+impl SseDecode for Option<crate::api::PulseConfig> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         if (<bool>::sse_decode(deserializer)) {
-            return Some(<crate::api::WebhookConfig>::sse_decode(deserializer));
+            return Some(<crate::api::PulseConfig>::sse_decode(deserializer));
         } else {
             return None;
         }
     }
 }
 
-impl SseDecode for crate::api::PublishConfig {
+impl SseDecode for Option<crate::api::WebhookConfig> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::WebhookConfig>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for crate::api::PincodeCheck {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_verified = <bool>::sse_decode(deserializer);
+        let mut var_email = <String>::sse_decode(deserializer);
+        let mut var_retryAfterSecs = <u64>::sse_decode(deserializer);
+        return crate::api::PincodeCheck {
+            verified: var_verified,
+            email: var_email,
+            retry_after_secs: var_retryAfterSecs,
+        };
+    }
+}
+
+impl SseDecode for crate::api::Principle {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <String>::sse_decode(deserializer);
+        let mut var_title = <String>::sse_decode(deserializer);
+        let mut var_description = <Option<String>>::sse_decode(deserializer);
+        return crate::api::Principle {
+            id: var_id,
+            title: var_title,
+            description: var_description,
+        };
+    }
+}
+
+impl SseDecode for crate::api::PrincipleRecognition {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <String>::sse_decode(deserializer);
+        let mut var_title = <String>::sse_decode(deserializer);
+        let mut var_count = <u32>::sse_decode(deserializer);
+        return crate::api::PrincipleRecognition {
+            id: var_id,
+            title: var_title,
+            count: var_count,
+        };
+    }
+}
+
+impl SseDecode for crate::api::PublishConfig {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut var_manifesto = <Option<String>>::sse_decode(deserializer);
         let mut var_vision = <Option<String>>::sse_decode(deserializer);
         let mut var_okrs = <Option<String>>::sse_decode(deserializer);
+        let mut var_interactions = <Option<String>>::sse_decode(deserializer);
+        let mut var_timezone = <Option<String>>::sse_decode(deserializer);
         return crate::api::PublishConfig {
             manifesto: var_manifesto,
             vision: var_vision,
             okrs: var_okrs,
+            interactions: var_interactions,
+            timezone: var_timezone,
+        };
+    }
+}
+
+impl SseDecode for crate::api::PulseCadences {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_checkin = <Option<String>>::sse_decode(deserializer);
+        let mut var_okrUpdate = <Option<String>>::sse_decode(deserializer);
+        let mut var_retro = <Option<String>>::sse_decode(deserializer);
+        return crate::api::PulseCadences {
+            checkin: var_checkin,
+            okr_update: var_okrUpdate,
+            retro: var_retro,
+        };
+    }
+}
+
+impl SseDecode for crate::api::PulseConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_team = <crate::api::PulseCadences>::sse_decode(deserializer);
+        let mut var_members = <Vec<crate::api::MemberPulse>>::sse_decode(deserializer);
+        return crate::api::PulseConfig {
+            team: var_team,
+            members: var_members,
+        };
+    }
+}
+
+impl SseDecode for crate::api::ShellHook {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_on = <Vec<String>>::sse_decode(deserializer);
+        let mut var_run = <String>::sse_decode(deserializer);
+        return crate::api::ShellHook {
+            on: var_on,
+            run: var_run,
         };
     }
 }
@@ -1035,16 +1803,18 @@ impl SseDecode for crate::api::Team {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut var_name = <String>::sse_decode(deserializer);
-        let mut var_manifesto = <Option<String>>::sse_decode(deserializer);
+        let mut var_manifesto = <Option<crate::api::Manifesto>>::sse_decode(deserializer);
         let mut var_vision = <Option<String>>::sse_decode(deserializer);
         let mut var_leaders = <Vec<String>>::sse_decode(deserializer);
         let mut var_members = <Vec<String>>::sse_decode(deserializer);
+        let mut var_archived = <Vec<String>>::sse_decode(deserializer);
         return crate::api::Team {
             name: var_name,
             manifesto: var_manifesto,
             vision: var_vision,
             leaders: var_leaders,
             members: var_members,
+            archived: var_archived,
         };
     }
 }
@@ -1056,15 +1826,61 @@ impl SseDecode for crate::api::TeamConfig {
         let mut var_webhooks = <Option<crate::api::WebhookConfig>>::sse_decode(deserializer);
         let mut var_linting = <Option<crate::api::LintingConfig>>::sse_decode(deserializer);
         let mut var_backup = <Option<crate::api::BackupConfig>>::sse_decode(deserializer);
+        let mut var_pulse = <Option<crate::api::PulseConfig>>::sse_decode(deserializer);
+        let mut var_hooks = <Vec<crate::api::ShellHook>>::sse_decode(deserializer);
         return crate::api::TeamConfig {
             publish: var_publish,
             webhooks: var_webhooks,
             linting: var_linting,
             backup: var_backup,
+            pulse: var_pulse,
+            hooks: var_hooks,
+        };
+    }
+}
+
+impl SseDecode for crate::api::TeamEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <String>::sse_decode(deserializer);
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_text = <String>::sse_decode(deserializer);
+        let mut var_fields = <Vec<crate::api::TeamEventField>>::sse_decode(deserializer);
+        return crate::api::TeamEvent {
+            id: var_id,
+            name: var_name,
+            text: var_text,
+            fields: var_fields,
+        };
+    }
+}
+
+impl SseDecode for crate::api::TeamEventField {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_key = <String>::sse_decode(deserializer);
+        let mut var_value = <String>::sse_decode(deserializer);
+        return crate::api::TeamEventField {
+            key: var_key,
+            value: var_value,
         };
     }
 }
 
+impl SseDecode for u32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u64::<NativeEndian>().unwrap()
+    }
+}
+
 impl SseDecode for u8 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -1083,10 +1899,36 @@ impl SseDecode for crate::api::WebhookConfig {
         let mut var_discord = <Option<String>>::sse_decode(deserializer);
         let mut var_slack = <Option<String>>::sse_decode(deserializer);
         let mut var_signal = <Option<String>>::sse_decode(deserializer);
+        let mut var_custom = <Vec<crate::api::CustomWebhook>>::sse_decode(deserializer);
         return crate::api::WebhookConfig {
             discord: var_discord,
             slack: var_slack,
             signal: var_signal,
+            custom: var_custom,
+        };
+    }
+}
+
+impl SseDecode for crate::api::WebhookHeader {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_value = <String>::sse_decode(deserializer);
+        return crate::api::WebhookHeader {
+            name: var_name,
+            value: var_value,
+        };
+    }
+}
+
+impl SseDecode for crate::api::WeekActivity {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_weekStart = <String>::sse_decode(deserializer);
+        let mut var_count = <u32>::sse_decode(deserializer);
+        return crate::api::WeekActivity {
+            week_start: var_weekStart,
+            count: var_count,
         };
     }
 }
@@ -1118,6 +1960,25 @@ fn pde_ffi_dispatcher_sync_impl(
 
 // Section: rust2dart
 
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::AppreciationEdge {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.from.into_into_dart().into_dart(),
+            self.to.into_into_dart().into_dart(),
+            self.count.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::AppreciationEdge {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::AppreciationEdge>
+    for crate::api::AppreciationEdge
+{
+    fn into_into_dart(self) -> crate::api::AppreciationEdge {
+        self
+    }
+}
 // Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::api::BackupConfig {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
@@ -1147,6 +2008,45 @@ impl flutter_rust_bridge::IntoIntoDart<crate::api::Credentials> for crate::api::
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::CustomWebhook {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.name.into_into_dart().into_dart(),
+            self.url.into_into_dart().into_dart(),
+            self.template.into_into_dart().into_dart(),
+            self.content_type.into_into_dart().into_dart(),
+            self.headers.into_into_dart().into_dart(),
+            self.secret.into_into_dart().into_dart(),
+            self.signature_header.into_into_dart().into_dart(),
+            self.events.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::CustomWebhook {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::CustomWebhook> for crate::api::CustomWebhook {
+    fn into_into_dart(self) -> crate::api::CustomWebhook {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::DuePulsePrompt {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.prompt.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+            self.objective_id.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::DuePulsePrompt {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::DuePulsePrompt> for crate::api::DuePulsePrompt {
+    fn into_into_dart(self) -> crate::api::DuePulsePrompt {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::api::Interaction {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
@@ -1157,6 +2057,8 @@ impl flutter_rust_bridge::IntoDart for crate::api::Interaction {
             self.note.into_into_dart().into_dart(),
             self.timestamp.into_into_dart().into_dart(),
             self.shared.into_into_dart().into_dart(),
+            self.principles.into_into_dart().into_dart(),
+            self.imported_from.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
@@ -1222,6 +2124,22 @@ impl flutter_rust_bridge::IntoIntoDart<crate::api::LintingConfig> for crate::api
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::Manifesto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.preamble.into_into_dart().into_dart(),
+            self.principles.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::Manifesto {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::Manifesto> for crate::api::Manifesto {
+    fn into_into_dart(self) -> crate::api::Manifesto {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::api::Member {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
@@ -1229,6 +2147,7 @@ impl flutter_rust_bridge::IntoDart for crate::api::Member {
             self.name.into_into_dart().into_dart(),
             self.bio.into_into_dart().into_dart(),
             self.timezone.into_into_dart().into_dart(),
+            self.aliases.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
@@ -1258,6 +2177,48 @@ impl flutter_rust_bridge::IntoIntoDart<crate::api::MemberCredentials>
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::MemberInteractionStats {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.email.into_into_dart().into_dart(),
+            self.kudos_given.into_into_dart().into_dart(),
+            self.kudos_received.into_into_dart().into_dart(),
+            self.feedback_given.into_into_dart().into_dart(),
+            self.feedback_received.into_into_dart().into_dart(),
+            self.total_sent.into_into_dart().into_dart(),
+            self.total_received.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::MemberInteractionStats
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::MemberInteractionStats>
+    for crate::api::MemberInteractionStats
+{
+    fn into_into_dart(self) -> crate::api::MemberInteractionStats {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::MemberPulse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.email.into_into_dart().into_dart(),
+            self.cadences.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::MemberPulse {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::MemberPulse> for crate::api::MemberPulse {
+    fn into_into_dart(self) -> crate::api::MemberPulse {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::api::Objective {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
@@ -1268,6 +2229,8 @@ impl flutter_rust_bridge::IntoDart for crate::api::Objective {
             self.visibility.into_into_dart().into_dart(),
             self.owner.into_into_dart().into_dart(),
             self.quarter.into_into_dart().into_dart(),
+            self.principles.into_into_dart().into_dart(),
+            self.updated.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
@@ -1295,12 +2258,70 @@ impl flutter_rust_bridge::IntoIntoDart<crate::api::OkrVisibility> for crate::api
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::PincodeCheck {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.verified.into_into_dart().into_dart(),
+            self.email.into_into_dart().into_dart(),
+            self.retry_after_secs.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::PincodeCheck {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::PincodeCheck> for crate::api::PincodeCheck {
+    fn into_into_dart(self) -> crate::api::PincodeCheck {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::Principle {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.title.into_into_dart().into_dart(),
+            self.description.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::Principle {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::Principle> for crate::api::Principle {
+    fn into_into_dart(self) -> crate::api::Principle {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::PrincipleRecognition {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.title.into_into_dart().into_dart(),
+            self.count.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::PrincipleRecognition
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::PrincipleRecognition>
+    for crate::api::PrincipleRecognition
+{
+    fn into_into_dart(self) -> crate::api::PrincipleRecognition {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::api::PublishConfig {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
             self.manifesto.into_into_dart().into_dart(),
             self.vision.into_into_dart().into_dart(),
             self.okrs.into_into_dart().into_dart(),
+            self.interactions.into_into_dart().into_dart(),
+            self.timezone.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
@@ -1312,6 +2333,55 @@ impl flutter_rust_bridge::IntoIntoDart<crate::api::PublishConfig> for crate::api
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::PulseCadences {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.checkin.into_into_dart().into_dart(),
+            self.okr_update.into_into_dart().into_dart(),
+            self.retro.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::PulseCadences {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::PulseCadences> for crate::api::PulseCadences {
+    fn into_into_dart(self) -> crate::api::PulseCadences {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::PulseConfig {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.team.into_into_dart().into_dart(),
+            self.members.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::PulseConfig {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::PulseConfig> for crate::api::PulseConfig {
+    fn into_into_dart(self) -> crate::api::PulseConfig {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::ShellHook {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.on.into_into_dart().into_dart(),
+            self.run.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::ShellHook {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::ShellHook> for crate::api::ShellHook {
+    fn into_into_dart(self) -> crate::api::ShellHook {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::api::Team {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
@@ -1320,6 +2390,7 @@ impl flutter_rust_bridge::IntoDart for crate::api::Team {
             self.vision.into_into_dart().into_dart(),
             self.leaders.into_into_dart().into_dart(),
             self.members.into_into_dart().into_dart(),
+            self.archived.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
@@ -1338,6 +2409,8 @@ impl flutter_rust_bridge::IntoDart for crate::api::TeamConfig {
             self.webhooks.into_into_dart().into_dart(),
             self.linting.into_into_dart().into_dart(),
             self.backup.into_into_dart().into_dart(),
+            self.pulse.into_into_dart().into_dart(),
+            self.hooks.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
@@ -1349,27 +2422,103 @@ impl flutter_rust_bridge::IntoIntoDart<crate::api::TeamConfig> for crate::api::T
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::WebhookConfig {
+impl flutter_rust_bridge::IntoDart for crate::api::TeamEvent {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.discord.into_into_dart().into_dart(),
-            self.slack.into_into_dart().into_dart(),
-            self.signal.into_into_dart().into_dart(),
+            self.id.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.text.into_into_dart().into_dart(),
+            self.fields.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::WebhookConfig {}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::WebhookConfig> for crate::api::WebhookConfig {
-    fn into_into_dart(self) -> crate::api::WebhookConfig {
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::TeamEvent {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::TeamEvent> for crate::api::TeamEvent {
+    fn into_into_dart(self) -> crate::api::TeamEvent {
         self
     }
 }
-
-impl SseEncode for String {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::TeamEventField {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.key.into_into_dart().into_dart(),
+            self.value.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::TeamEventField {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::TeamEventField> for crate::api::TeamEventField {
+    fn into_into_dart(self) -> crate::api::TeamEventField {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::WebhookConfig {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.discord.into_into_dart().into_dart(),
+            self.slack.into_into_dart().into_dart(),
+            self.signal.into_into_dart().into_dart(),
+            self.custom.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::WebhookConfig {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::WebhookConfig> for crate::api::WebhookConfig {
+    fn into_into_dart(self) -> crate::api::WebhookConfig {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::WebhookHeader {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.name.into_into_dart().into_dart(),
+            self.value.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::WebhookHeader {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::WebhookHeader> for crate::api::WebhookHeader {
+    fn into_into_dart(self) -> crate::api::WebhookHeader {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::WeekActivity {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.week_start.into_into_dart().into_dart(),
+            self.count.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::WeekActivity {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::WeekActivity> for crate::api::WeekActivity {
+    fn into_into_dart(self) -> crate::api::WeekActivity {
+        self
+    }
+}
+
+impl SseEncode for String {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
+    }
+}
+
+impl SseEncode for crate::api::AppreciationEdge {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.from, serializer);
+        <String>::sse_encode(self.to, serializer);
+        <u32>::sse_encode(self.count, serializer);
     }
 }
 
@@ -1395,6 +2544,29 @@ impl SseEncode for crate::api::Credentials {
     }
 }
 
+impl SseEncode for crate::api::CustomWebhook {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<String>>::sse_encode(self.name, serializer);
+        <String>::sse_encode(self.url, serializer);
+        <Option<String>>::sse_encode(self.template, serializer);
+        <Option<String>>::sse_encode(self.content_type, serializer);
+        <Vec<crate::api::WebhookHeader>>::sse_encode(self.headers, serializer);
+        <Option<String>>::sse_encode(self.secret, serializer);
+        <Option<String>>::sse_encode(self.signature_header, serializer);
+        <Vec<String>>::sse_encode(self.events, serializer);
+    }
+}
+
+impl SseEncode for crate::api::DuePulsePrompt {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.prompt, serializer);
+        <String>::sse_encode(self.message, serializer);
+        <Option<String>>::sse_encode(self.objective_id, serializer);
+    }
+}
+
 impl SseEncode for f32 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -1419,6 +2591,8 @@ impl SseEncode for crate::api::Interaction {
         <String>::sse_encode(self.note, serializer);
         <String>::sse_encode(self.timestamp, serializer);
         <bool>::sse_encode(self.shared, serializer);
+        <Vec<String>>::sse_encode(self.principles, serializer);
+        <Option<String>>::sse_encode(self.imported_from, serializer);
     }
 }
 
@@ -1468,6 +2642,36 @@ impl SseEncode for Vec<String> {
     }
 }
 
+impl SseEncode for Vec<crate::api::AppreciationEdge> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::AppreciationEdge>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::CustomWebhook> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::CustomWebhook>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::DuePulsePrompt> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::DuePulsePrompt>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<crate::api::KeyResult> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -1478,6 +2682,26 @@ impl SseEncode for Vec<crate::api::KeyResult> {
     }
 }
 
+impl SseEncode for Vec<crate::api::MemberInteractionStats> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::MemberInteractionStats>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::MemberPulse> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::MemberPulse>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<u8> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -1488,6 +2712,84 @@ impl SseEncode for Vec<u8> {
     }
 }
 
+impl SseEncode for Vec<crate::api::Principle> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::Principle>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::PrincipleRecognition> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::PrincipleRecognition>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::ShellHook> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::ShellHook>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::TeamEvent> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::TeamEvent>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::TeamEventField> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::TeamEventField>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::WebhookHeader> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::WebhookHeader>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::WeekActivity> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::WeekActivity>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for crate::api::Manifesto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<String>>::sse_encode(self.preamble, serializer);
+        <Vec<crate::api::Principle>>::sse_encode(self.principles, serializer);
+    }
+}
+
 impl SseEncode for crate::api::Member {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -1495,6 +2797,7 @@ impl SseEncode for crate::api::Member {
         <Option<String>>::sse_encode(self.name, serializer);
         <Option<String>>::sse_encode(self.bio, serializer);
         <Option<String>>::sse_encode(self.timezone, serializer);
+        <Vec<String>>::sse_encode(self.aliases, serializer);
     }
 }
 
@@ -1506,6 +2809,27 @@ impl SseEncode for crate::api::MemberCredentials {
     }
 }
 
+impl SseEncode for crate::api::MemberInteractionStats {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.email, serializer);
+        <u32>::sse_encode(self.kudos_given, serializer);
+        <u32>::sse_encode(self.kudos_received, serializer);
+        <u32>::sse_encode(self.feedback_given, serializer);
+        <u32>::sse_encode(self.feedback_received, serializer);
+        <u32>::sse_encode(self.total_sent, serializer);
+        <u32>::sse_encode(self.total_received, serializer);
+    }
+}
+
+impl SseEncode for crate::api::MemberPulse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.email, serializer);
+        <crate::api::PulseCadences>::sse_encode(self.cadences, serializer);
+    }
+}
+
 impl SseEncode for crate::api::Objective {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -1516,6 +2840,8 @@ impl SseEncode for crate::api::Objective {
         <crate::api::OkrVisibility>::sse_encode(self.visibility, serializer);
         <Option<String>>::sse_encode(self.owner, serializer);
         <Option<String>>::sse_encode(self.quarter, serializer);
+        <Vec<String>>::sse_encode(self.principles, serializer);
+        <Option<String>>::sse_encode(self.updated, serializer);
     }
 }
 
@@ -1555,6 +2881,16 @@ impl SseEncode for Option<crate::api::BackupConfig> {
     }
 }
 
+impl SseEncode for Option<f32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <f32>::sse_encode(value, serializer);
+        }
+    }
+}
+
 impl SseEncode for Option<crate::api::LintingConfig> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -1565,6 +2901,16 @@ impl SseEncode for Option<crate::api::LintingConfig> {
     }
 }
 
+impl SseEncode for Option<crate::api::Manifesto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::Manifesto>::sse_encode(value, serializer);
+        }
+    }
+}
+
 impl SseEncode for Option<crate::api::PublishConfig> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -1575,6 +2921,16 @@ impl SseEncode for Option<crate::api::PublishConfig> {
     }
 }
 
+impl SseEncode for Option<crate::api::PulseConfig> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::PulseConfig>::sse_encode(value, serializer);
+        }
+    }
+}
+
 impl SseEncode for Option<crate::api::WebhookConfig> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -1585,12 +2941,66 @@ impl SseEncode for Option<crate::api::WebhookConfig> {
     }
 }
 
+impl SseEncode for crate::api::PincodeCheck {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.verified, serializer);
+        <String>::sse_encode(self.email, serializer);
+        <u64>::sse_encode(self.retry_after_secs, serializer);
+    }
+}
+
+impl SseEncode for crate::api::Principle {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.title, serializer);
+        <Option<String>>::sse_encode(self.description, serializer);
+    }
+}
+
+impl SseEncode for crate::api::PrincipleRecognition {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.title, serializer);
+        <u32>::sse_encode(self.count, serializer);
+    }
+}
+
 impl SseEncode for crate::api::PublishConfig {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <Option<String>>::sse_encode(self.manifesto, serializer);
         <Option<String>>::sse_encode(self.vision, serializer);
         <Option<String>>::sse_encode(self.okrs, serializer);
+        <Option<String>>::sse_encode(self.interactions, serializer);
+        <Option<String>>::sse_encode(self.timezone, serializer);
+    }
+}
+
+impl SseEncode for crate::api::PulseCadences {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<String>>::sse_encode(self.checkin, serializer);
+        <Option<String>>::sse_encode(self.okr_update, serializer);
+        <Option<String>>::sse_encode(self.retro, serializer);
+    }
+}
+
+impl SseEncode for crate::api::PulseConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::PulseCadences>::sse_encode(self.team, serializer);
+        <Vec<crate::api::MemberPulse>>::sse_encode(self.members, serializer);
+    }
+}
+
+impl SseEncode for crate::api::ShellHook {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<String>>::sse_encode(self.on, serializer);
+        <String>::sse_encode(self.run, serializer);
     }
 }
 
@@ -1598,10 +3008,11 @@ impl SseEncode for crate::api::Team {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <String>::sse_encode(self.name, serializer);
-        <Option<String>>::sse_encode(self.manifesto, serializer);
+        <Option<crate::api::Manifesto>>::sse_encode(self.manifesto, serializer);
         <Option<String>>::sse_encode(self.vision, serializer);
         <Vec<String>>::sse_encode(self.leaders, serializer);
         <Vec<String>>::sse_encode(self.members, serializer);
+        <Vec<String>>::sse_encode(self.archived, serializer);
     }
 }
 
@@ -1612,27 +3023,78 @@ impl SseEncode for crate::api::TeamConfig {
         <Option<crate::api::WebhookConfig>>::sse_encode(self.webhooks, serializer);
         <Option<crate::api::LintingConfig>>::sse_encode(self.linting, serializer);
         <Option<crate::api::BackupConfig>>::sse_encode(self.backup, serializer);
+        <Option<crate::api::PulseConfig>>::sse_encode(self.pulse, serializer);
+        <Vec<crate::api::ShellHook>>::sse_encode(self.hooks, serializer);
     }
 }
 
-impl SseEncode for u8 {
+impl SseEncode for crate::api::TeamEvent {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_u8(self).unwrap();
+        <String>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.name, serializer);
+        <String>::sse_encode(self.text, serializer);
+        <Vec<crate::api::TeamEventField>>::sse_encode(self.fields, serializer);
     }
 }
 
-impl SseEncode for () {
+impl SseEncode for crate::api::TeamEventField {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {}
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.key, serializer);
+        <String>::sse_encode(self.value, serializer);
+    }
 }
 
-impl SseEncode for crate::api::WebhookConfig {
+impl SseEncode for u32 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <Option<String>>::sse_encode(self.discord, serializer);
-        <Option<String>>::sse_encode(self.slack, serializer);
+        serializer.cursor.write_u32::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for u64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u64::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for u8 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u8(self).unwrap();
+    }
+}
+
+impl SseEncode for () {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {}
+}
+
+impl SseEncode for crate::api::WebhookConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<String>>::sse_encode(self.discord, serializer);
+        <Option<String>>::sse_encode(self.slack, serializer);
         <Option<String>>::sse_encode(self.signal, serializer);
+        <Vec<crate::api::CustomWebhook>>::sse_encode(self.custom, serializer);
+    }
+}
+
+impl SseEncode for crate::api::WebhookHeader {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.name, serializer);
+        <String>::sse_encode(self.value, serializer);
+    }
+}
+
+impl SseEncode for crate::api::WeekActivity {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.week_start, serializer);
+        <u32>::sse_encode(self.count, serializer);
     }
 }
 
@@ -1663,6 +3125,16 @@ mod io {
             String::from_utf8(vec).unwrap()
         }
     }
+    impl CstDecode<crate::api::AppreciationEdge> for wire_cst_appreciation_edge {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::AppreciationEdge {
+            crate::api::AppreciationEdge {
+                from: self.from.cst_decode(),
+                to: self.to.cst_decode(),
+                count: self.count.cst_decode(),
+            }
+        }
+    }
     impl CstDecode<crate::api::BackupConfig> for wire_cst_backup_config {
         // Codec=Cst (C-struct based), see doc to use other codecs
         fn cst_decode(self) -> crate::api::BackupConfig {
@@ -1685,6 +3157,12 @@ mod io {
             CstDecode::<crate::api::Credentials>::cst_decode(*wrap).into()
         }
     }
+    impl CstDecode<f32> for *mut f32 {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> f32 {
+            unsafe { *flutter_rust_bridge::for_generated::box_from_leak_ptr(self) }
+        }
+    }
     impl CstDecode<crate::api::Interaction> for *mut wire_cst_interaction {
         // Codec=Cst (C-struct based), see doc to use other codecs
         fn cst_decode(self) -> crate::api::Interaction {
@@ -1699,6 +3177,13 @@ mod io {
             CstDecode::<crate::api::LintingConfig>::cst_decode(*wrap).into()
         }
     }
+    impl CstDecode<crate::api::Manifesto> for *mut wire_cst_manifesto {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::Manifesto {
+            let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+            CstDecode::<crate::api::Manifesto>::cst_decode(*wrap).into()
+        }
+    }
     impl CstDecode<crate::api::Member> for *mut wire_cst_member {
         // Codec=Cst (C-struct based), see doc to use other codecs
         fn cst_decode(self) -> crate::api::Member {
@@ -1727,6 +3212,20 @@ mod io {
             CstDecode::<crate::api::PublishConfig>::cst_decode(*wrap).into()
         }
     }
+    impl CstDecode<crate::api::PulseConfig> for *mut wire_cst_pulse_config {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::PulseConfig {
+            let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+            CstDecode::<crate::api::PulseConfig>::cst_decode(*wrap).into()
+        }
+    }
+    impl CstDecode<crate::api::ShellHook> for *mut wire_cst_shell_hook {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::ShellHook {
+            let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+            CstDecode::<crate::api::ShellHook>::cst_decode(*wrap).into()
+        }
+    }
     impl CstDecode<crate::api::Team> for *mut wire_cst_team {
         // Codec=Cst (C-struct based), see doc to use other codecs
         fn cst_decode(self) -> crate::api::Team {
@@ -1757,6 +3256,31 @@ mod io {
             }
         }
     }
+    impl CstDecode<crate::api::CustomWebhook> for wire_cst_custom_webhook {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::CustomWebhook {
+            crate::api::CustomWebhook {
+                name: self.name.cst_decode(),
+                url: self.url.cst_decode(),
+                template: self.template.cst_decode(),
+                content_type: self.content_type.cst_decode(),
+                headers: self.headers.cst_decode(),
+                secret: self.secret.cst_decode(),
+                signature_header: self.signature_header.cst_decode(),
+                events: self.events.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::api::DuePulsePrompt> for wire_cst_due_pulse_prompt {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::DuePulsePrompt {
+            crate::api::DuePulsePrompt {
+                prompt: self.prompt.cst_decode(),
+                message: self.message.cst_decode(),
+                objective_id: self.objective_id.cst_decode(),
+            }
+        }
+    }
     impl CstDecode<crate::api::Interaction> for wire_cst_interaction {
         // Codec=Cst (C-struct based), see doc to use other codecs
         fn cst_decode(self) -> crate::api::Interaction {
@@ -1768,6 +3292,8 @@ mod io {
                 note: self.note.cst_decode(),
                 timestamp: self.timestamp.cst_decode(),
                 shared: self.shared.cst_decode(),
+                principles: self.principles.cst_decode(),
+                imported_from: self.imported_from.cst_decode(),
             }
         }
     }
@@ -1800,6 +3326,36 @@ mod io {
             vec.into_iter().map(CstDecode::cst_decode).collect()
         }
     }
+    impl CstDecode<Vec<crate::api::AppreciationEdge>> for *mut wire_cst_list_appreciation_edge {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<crate::api::AppreciationEdge> {
+            let vec = unsafe {
+                let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
+                flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
+            };
+            vec.into_iter().map(CstDecode::cst_decode).collect()
+        }
+    }
+    impl CstDecode<Vec<crate::api::CustomWebhook>> for *mut wire_cst_list_custom_webhook {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<crate::api::CustomWebhook> {
+            let vec = unsafe {
+                let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
+                flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
+            };
+            vec.into_iter().map(CstDecode::cst_decode).collect()
+        }
+    }
+    impl CstDecode<Vec<crate::api::DuePulsePrompt>> for *mut wire_cst_list_due_pulse_prompt {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<crate::api::DuePulsePrompt> {
+            let vec = unsafe {
+                let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
+                flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
+            };
+            vec.into_iter().map(CstDecode::cst_decode).collect()
+        }
+    }
     impl CstDecode<Vec<crate::api::KeyResult>> for *mut wire_cst_list_key_result {
         // Codec=Cst (C-struct based), see doc to use other codecs
         fn cst_decode(self) -> Vec<crate::api::KeyResult> {
@@ -1810,6 +3366,28 @@ mod io {
             vec.into_iter().map(CstDecode::cst_decode).collect()
         }
     }
+    impl CstDecode<Vec<crate::api::MemberInteractionStats>>
+        for *mut wire_cst_list_member_interaction_stats
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<crate::api::MemberInteractionStats> {
+            let vec = unsafe {
+                let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
+                flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
+            };
+            vec.into_iter().map(CstDecode::cst_decode).collect()
+        }
+    }
+    impl CstDecode<Vec<crate::api::MemberPulse>> for *mut wire_cst_list_member_pulse {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<crate::api::MemberPulse> {
+            let vec = unsafe {
+                let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
+                flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
+            };
+            vec.into_iter().map(CstDecode::cst_decode).collect()
+        }
+    }
     impl CstDecode<Vec<u8>> for *mut wire_cst_list_prim_u_8_strict {
         // Codec=Cst (C-struct based), see doc to use other codecs
         fn cst_decode(self) -> Vec<u8> {
@@ -1819,6 +3397,85 @@ mod io {
             }
         }
     }
+    impl CstDecode<Vec<crate::api::Principle>> for *mut wire_cst_list_principle {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<crate::api::Principle> {
+            let vec = unsafe {
+                let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
+                flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
+            };
+            vec.into_iter().map(CstDecode::cst_decode).collect()
+        }
+    }
+    impl CstDecode<Vec<crate::api::PrincipleRecognition>> for *mut wire_cst_list_principle_recognition {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<crate::api::PrincipleRecognition> {
+            let vec = unsafe {
+                let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
+                flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
+            };
+            vec.into_iter().map(CstDecode::cst_decode).collect()
+        }
+    }
+    impl CstDecode<Vec<crate::api::ShellHook>> for *mut wire_cst_list_shell_hook {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<crate::api::ShellHook> {
+            let vec = unsafe {
+                let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
+                flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
+            };
+            vec.into_iter().map(CstDecode::cst_decode).collect()
+        }
+    }
+    impl CstDecode<Vec<crate::api::TeamEvent>> for *mut wire_cst_list_team_event {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<crate::api::TeamEvent> {
+            let vec = unsafe {
+                let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
+                flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
+            };
+            vec.into_iter().map(CstDecode::cst_decode).collect()
+        }
+    }
+    impl CstDecode<Vec<crate::api::TeamEventField>> for *mut wire_cst_list_team_event_field {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<crate::api::TeamEventField> {
+            let vec = unsafe {
+                let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
+                flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
+            };
+            vec.into_iter().map(CstDecode::cst_decode).collect()
+        }
+    }
+    impl CstDecode<Vec<crate::api::WebhookHeader>> for *mut wire_cst_list_webhook_header {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<crate::api::WebhookHeader> {
+            let vec = unsafe {
+                let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
+                flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
+            };
+            vec.into_iter().map(CstDecode::cst_decode).collect()
+        }
+    }
+    impl CstDecode<Vec<crate::api::WeekActivity>> for *mut wire_cst_list_week_activity {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<crate::api::WeekActivity> {
+            let vec = unsafe {
+                let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
+                flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
+            };
+            vec.into_iter().map(CstDecode::cst_decode).collect()
+        }
+    }
+    impl CstDecode<crate::api::Manifesto> for wire_cst_manifesto {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::Manifesto {
+            crate::api::Manifesto {
+                preamble: self.preamble.cst_decode(),
+                principles: self.principles.cst_decode(),
+            }
+        }
+    }
     impl CstDecode<crate::api::Member> for wire_cst_member {
         // Codec=Cst (C-struct based), see doc to use other codecs
         fn cst_decode(self) -> crate::api::Member {
@@ -1827,6 +3484,7 @@ mod io {
                 name: self.name.cst_decode(),
                 bio: self.bio.cst_decode(),
                 timezone: self.timezone.cst_decode(),
+                aliases: self.aliases.cst_decode(),
             }
         }
     }
@@ -1839,6 +3497,29 @@ mod io {
             }
         }
     }
+    impl CstDecode<crate::api::MemberInteractionStats> for wire_cst_member_interaction_stats {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::MemberInteractionStats {
+            crate::api::MemberInteractionStats {
+                email: self.email.cst_decode(),
+                kudos_given: self.kudos_given.cst_decode(),
+                kudos_received: self.kudos_received.cst_decode(),
+                feedback_given: self.feedback_given.cst_decode(),
+                feedback_received: self.feedback_received.cst_decode(),
+                total_sent: self.total_sent.cst_decode(),
+                total_received: self.total_received.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::api::MemberPulse> for wire_cst_member_pulse {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::MemberPulse {
+            crate::api::MemberPulse {
+                email: self.email.cst_decode(),
+                cadences: self.cadences.cst_decode(),
+            }
+        }
+    }
     impl CstDecode<crate::api::Objective> for wire_cst_objective {
         // Codec=Cst (C-struct based), see doc to use other codecs
         fn cst_decode(self) -> crate::api::Objective {
@@ -1850,6 +3531,38 @@ mod io {
                 visibility: self.visibility.cst_decode(),
                 owner: self.owner.cst_decode(),
                 quarter: self.quarter.cst_decode(),
+                principles: self.principles.cst_decode(),
+                updated: self.updated.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::api::PincodeCheck> for wire_cst_pincode_check {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::PincodeCheck {
+            crate::api::PincodeCheck {
+                verified: self.verified.cst_decode(),
+                email: self.email.cst_decode(),
+                retry_after_secs: self.retry_after_secs.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::api::Principle> for wire_cst_principle {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::Principle {
+            crate::api::Principle {
+                id: self.id.cst_decode(),
+                title: self.title.cst_decode(),
+                description: self.description.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::api::PrincipleRecognition> for wire_cst_principle_recognition {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::PrincipleRecognition {
+            crate::api::PrincipleRecognition {
+                id: self.id.cst_decode(),
+                title: self.title.cst_decode(),
+                count: self.count.cst_decode(),
             }
         }
     }
@@ -1860,6 +3573,36 @@ mod io {
                 manifesto: self.manifesto.cst_decode(),
                 vision: self.vision.cst_decode(),
                 okrs: self.okrs.cst_decode(),
+                interactions: self.interactions.cst_decode(),
+                timezone: self.timezone.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::api::PulseCadences> for wire_cst_pulse_cadences {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::PulseCadences {
+            crate::api::PulseCadences {
+                checkin: self.checkin.cst_decode(),
+                okr_update: self.okr_update.cst_decode(),
+                retro: self.retro.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::api::PulseConfig> for wire_cst_pulse_config {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::PulseConfig {
+            crate::api::PulseConfig {
+                team: self.team.cst_decode(),
+                members: self.members.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::api::ShellHook> for wire_cst_shell_hook {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::ShellHook {
+            crate::api::ShellHook {
+                on: self.on.cst_decode(),
+                run: self.run.cst_decode(),
             }
         }
     }
@@ -1872,6 +3615,7 @@ mod io {
                 vision: self.vision.cst_decode(),
                 leaders: self.leaders.cst_decode(),
                 members: self.members.cst_decode(),
+                archived: self.archived.cst_decode(),
             }
         }
     }
@@ -1883,6 +3627,28 @@ mod io {
                 webhooks: self.webhooks.cst_decode(),
                 linting: self.linting.cst_decode(),
                 backup: self.backup.cst_decode(),
+                pulse: self.pulse.cst_decode(),
+                hooks: self.hooks.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::api::TeamEvent> for wire_cst_team_event {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::TeamEvent {
+            crate::api::TeamEvent {
+                id: self.id.cst_decode(),
+                name: self.name.cst_decode(),
+                text: self.text.cst_decode(),
+                fields: self.fields.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::api::TeamEventField> for wire_cst_team_event_field {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::TeamEventField {
+            crate::api::TeamEventField {
+                key: self.key.cst_decode(),
+                value: self.value.cst_decode(),
             }
         }
     }
@@ -1893,9 +3659,42 @@ mod io {
                 discord: self.discord.cst_decode(),
                 slack: self.slack.cst_decode(),
                 signal: self.signal.cst_decode(),
+                custom: self.custom.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::api::WebhookHeader> for wire_cst_webhook_header {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::WebhookHeader {
+            crate::api::WebhookHeader {
+                name: self.name.cst_decode(),
+                value: self.value.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::api::WeekActivity> for wire_cst_week_activity {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::WeekActivity {
+            crate::api::WeekActivity {
+                week_start: self.week_start.cst_decode(),
+                count: self.count.cst_decode(),
+            }
+        }
+    }
+    impl NewWithNullPtr for wire_cst_appreciation_edge {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                from: core::ptr::null_mut(),
+                to: core::ptr::null_mut(),
+                count: Default::default(),
             }
         }
     }
+    impl Default for wire_cst_appreciation_edge {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
     impl NewWithNullPtr for wire_cst_backup_config {
         fn new_with_null_ptr() -> Self {
             Self {
@@ -1921,6 +3720,39 @@ mod io {
             Self::new_with_null_ptr()
         }
     }
+    impl NewWithNullPtr for wire_cst_custom_webhook {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                name: core::ptr::null_mut(),
+                url: core::ptr::null_mut(),
+                template: core::ptr::null_mut(),
+                content_type: core::ptr::null_mut(),
+                headers: core::ptr::null_mut(),
+                secret: core::ptr::null_mut(),
+                signature_header: core::ptr::null_mut(),
+                events: core::ptr::null_mut(),
+            }
+        }
+    }
+    impl Default for wire_cst_custom_webhook {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_due_pulse_prompt {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                prompt: core::ptr::null_mut(),
+                message: core::ptr::null_mut(),
+                objective_id: core::ptr::null_mut(),
+            }
+        }
+    }
+    impl Default for wire_cst_due_pulse_prompt {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
     impl NewWithNullPtr for wire_cst_interaction {
         fn new_with_null_ptr() -> Self {
             Self {
@@ -1931,6 +3763,8 @@ mod io {
                 note: core::ptr::null_mut(),
                 timestamp: core::ptr::null_mut(),
                 shared: Default::default(),
+                principles: core::ptr::null_mut(),
+                imported_from: core::ptr::null_mut(),
             }
         }
     }
@@ -1966,6 +3800,19 @@ mod io {
             Self::new_with_null_ptr()
         }
     }
+    impl NewWithNullPtr for wire_cst_manifesto {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                preamble: core::ptr::null_mut(),
+                principles: core::ptr::null_mut(),
+            }
+        }
+    }
+    impl Default for wire_cst_manifesto {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
     impl NewWithNullPtr for wire_cst_member {
         fn new_with_null_ptr() -> Self {
             Self {
@@ -1973,6 +3820,7 @@ mod io {
                 name: core::ptr::null_mut(),
                 bio: core::ptr::null_mut(),
                 timezone: core::ptr::null_mut(),
+                aliases: core::ptr::null_mut(),
             }
         }
     }
@@ -1994,6 +3842,37 @@ mod io {
             Self::new_with_null_ptr()
         }
     }
+    impl NewWithNullPtr for wire_cst_member_interaction_stats {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                email: core::ptr::null_mut(),
+                kudos_given: Default::default(),
+                kudos_received: Default::default(),
+                feedback_given: Default::default(),
+                feedback_received: Default::default(),
+                total_sent: Default::default(),
+                total_received: Default::default(),
+            }
+        }
+    }
+    impl Default for wire_cst_member_interaction_stats {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_member_pulse {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                email: core::ptr::null_mut(),
+                cadences: Default::default(),
+            }
+        }
+    }
+    impl Default for wire_cst_member_pulse {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
     impl NewWithNullPtr for wire_cst_objective {
         fn new_with_null_ptr() -> Self {
             Self {
@@ -2004,6 +3883,8 @@ mod io {
                 visibility: Default::default(),
                 owner: core::ptr::null_mut(),
                 quarter: core::ptr::null_mut(),
+                principles: core::ptr::null_mut(),
+                updated: core::ptr::null_mut(),
             }
         }
     }
@@ -2012,12 +3893,56 @@ mod io {
             Self::new_with_null_ptr()
         }
     }
+    impl NewWithNullPtr for wire_cst_pincode_check {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                verified: Default::default(),
+                email: core::ptr::null_mut(),
+                retry_after_secs: Default::default(),
+            }
+        }
+    }
+    impl Default for wire_cst_pincode_check {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_principle {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                id: core::ptr::null_mut(),
+                title: core::ptr::null_mut(),
+                description: core::ptr::null_mut(),
+            }
+        }
+    }
+    impl Default for wire_cst_principle {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_principle_recognition {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                id: core::ptr::null_mut(),
+                title: core::ptr::null_mut(),
+                count: Default::default(),
+            }
+        }
+    }
+    impl Default for wire_cst_principle_recognition {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
     impl NewWithNullPtr for wire_cst_publish_config {
         fn new_with_null_ptr() -> Self {
             Self {
                 manifesto: core::ptr::null_mut(),
                 vision: core::ptr::null_mut(),
                 okrs: core::ptr::null_mut(),
+                interactions: core::ptr::null_mut(),
+                timezone: core::ptr::null_mut(),
             }
         }
     }
@@ -2026,6 +3951,46 @@ mod io {
             Self::new_with_null_ptr()
         }
     }
+    impl NewWithNullPtr for wire_cst_pulse_cadences {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                checkin: core::ptr::null_mut(),
+                okr_update: core::ptr::null_mut(),
+                retro: core::ptr::null_mut(),
+            }
+        }
+    }
+    impl Default for wire_cst_pulse_cadences {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_pulse_config {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                team: Default::default(),
+                members: core::ptr::null_mut(),
+            }
+        }
+    }
+    impl Default for wire_cst_pulse_config {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_shell_hook {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                on: core::ptr::null_mut(),
+                run: core::ptr::null_mut(),
+            }
+        }
+    }
+    impl Default for wire_cst_shell_hook {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
     impl NewWithNullPtr for wire_cst_team {
         fn new_with_null_ptr() -> Self {
             Self {
@@ -2034,6 +3999,7 @@ mod io {
                 vision: core::ptr::null_mut(),
                 leaders: core::ptr::null_mut(),
                 members: core::ptr::null_mut(),
+                archived: core::ptr::null_mut(),
             }
         }
     }
@@ -2049,6 +4015,8 @@ mod io {
                 webhooks: core::ptr::null_mut(),
                 linting: core::ptr::null_mut(),
                 backup: core::ptr::null_mut(),
+                pulse: core::ptr::null_mut(),
+                hooks: core::ptr::null_mut(),
             }
         }
     }
@@ -2057,12 +4025,41 @@ mod io {
             Self::new_with_null_ptr()
         }
     }
+    impl NewWithNullPtr for wire_cst_team_event {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                id: core::ptr::null_mut(),
+                name: core::ptr::null_mut(),
+                text: core::ptr::null_mut(),
+                fields: core::ptr::null_mut(),
+            }
+        }
+    }
+    impl Default for wire_cst_team_event {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_team_event_field {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                key: core::ptr::null_mut(),
+                value: core::ptr::null_mut(),
+            }
+        }
+    }
+    impl Default for wire_cst_team_event_field {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
     impl NewWithNullPtr for wire_cst_webhook_config {
         fn new_with_null_ptr() -> Self {
             Self {
                 discord: core::ptr::null_mut(),
                 slack: core::ptr::null_mut(),
                 signal: core::ptr::null_mut(),
+                custom: core::ptr::null_mut(),
             }
         }
     }
@@ -2071,6 +4068,66 @@ mod io {
             Self::new_with_null_ptr()
         }
     }
+    impl NewWithNullPtr for wire_cst_webhook_header {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                name: core::ptr::null_mut(),
+                value: core::ptr::null_mut(),
+            }
+        }
+    }
+    impl Default for wire_cst_webhook_header {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_week_activity {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                week_start: core::ptr::null_mut(),
+                count: Default::default(),
+            }
+        }
+    }
+    impl Default for wire_cst_week_activity {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_interactions_wire__crate__api__announce_interaction(
+        port_: i64,
+        root: *mut wire_cst_list_prim_u_8_strict,
+        interaction: *mut wire_cst_interaction,
+    ) {
+        wire__crate__api__announce_interaction_impl(port_, root, interaction)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_interactions_wire__crate__api__appreciation_graph(
+        port_: i64,
+        root: *mut wire_cst_list_prim_u_8_strict,
+    ) {
+        wire__crate__api__appreciation_graph_impl(port_, root)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_interactions_wire__crate__api__appreciation_reciprocity(
+        port_: i64,
+        root: *mut wire_cst_list_prim_u_8_strict,
+    ) {
+        wire__crate__api__appreciation_reciprocity_impl(port_, root)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_interactions_wire__crate__api__approve_shell_hook(
+        port_: i64,
+        root: *mut wire_cst_list_prim_u_8_strict,
+        hook: *mut wire_cst_shell_hook,
+    ) {
+        wire__crate__api__approve_shell_hook_impl(port_, root, hook)
+    }
 
     #[unsafe(no_mangle)]
     pub extern "C" fn frbgen_interactions_wire__crate__api__credentials_create(
@@ -2101,6 +4158,28 @@ mod io {
         wire__crate__api__credentials_verify_impl(that, pincode)
     }
 
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_interactions_wire__crate__api__due_pulse_prompts(
+        port_: i64,
+        root: *mut wire_cst_list_prim_u_8_strict,
+        email: *mut wire_cst_list_prim_u_8_strict,
+    ) {
+        wire__crate__api__due_pulse_prompts_impl(port_, root, email)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_interactions_wire__crate__api__export_team_data(
+        port_: i64,
+        root: *mut wire_cst_list_prim_u_8_strict,
+        format: *mut wire_cst_list_prim_u_8_strict,
+        kinds: *mut wire_cst_list_prim_u_8_strict,
+        since: *mut wire_cst_list_prim_u_8_strict,
+        email: *mut wire_cst_list_prim_u_8_strict,
+        pincode: *mut wire_cst_list_prim_u_8_strict,
+    ) {
+        wire__crate__api__export_team_data_impl(port_, root, format, kinds, since, email, pincode)
+    }
+
     #[unsafe(no_mangle)]
     pub extern "C" fn frbgen_interactions_wire__crate__api__interaction_appreciation(
         from: *mut wire_cst_list_prim_u_8_strict,
@@ -2164,6 +4243,14 @@ mod io {
         wire__crate__api__key_result_create_impl(description)
     }
 
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_interactions_wire__crate__api__load_team_manifesto(
+        port_: i64,
+        root: *mut wire_cst_list_prim_u_8_strict,
+    ) {
+        wire__crate__api__load_team_manifesto_impl(port_, root)
+    }
+
     #[unsafe(no_mangle)]
     pub extern "C" fn frbgen_interactions_wire__crate__api__member_create(
         email: *mut wire_cst_list_prim_u_8_strict,
@@ -2215,6 +4302,14 @@ mod io {
         wire__crate__api__member_from_yaml_impl(yaml)
     }
 
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_interactions_wire__crate__api__member_interaction_stats(
+        port_: i64,
+        root: *mut wire_cst_list_prim_u_8_strict,
+    ) {
+        wire__crate__api__member_interaction_stats_impl(port_, root)
+    }
+
     #[unsafe(no_mangle)]
     pub extern "C" fn frbgen_interactions_wire__crate__api__member_to_yaml(
         member: *mut wire_cst_member,
@@ -2222,6 +4317,13 @@ mod io {
         wire__crate__api__member_to_yaml_impl(member)
     }
 
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_interactions_wire__crate__api__normalize_email(
+        email: *mut wire_cst_list_prim_u_8_strict,
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__normalize_email_impl(email)
+    }
+
     #[unsafe(no_mangle)]
     pub extern "C" fn frbgen_interactions_wire__crate__api__objective_create(
         title: *mut wire_cst_list_prim_u_8_strict,
@@ -2255,6 +4357,37 @@ mod io {
         wire__crate__api__okr_visibility_default_impl(port_)
     }
 
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_interactions_wire__crate__api__pincode_retry_after_secs(
+        port_: i64,
+        root: *mut wire_cst_list_prim_u_8_strict,
+        email: *mut wire_cst_list_prim_u_8_strict,
+    ) {
+        wire__crate__api__pincode_retry_after_secs_impl(port_, root, email)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_interactions_wire__crate__api__principle_report(
+        port_: i64,
+        root: *mut wire_cst_list_prim_u_8_strict,
+    ) {
+        wire__crate__api__principle_report_impl(port_, root)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_interactions_wire__crate__api__shell_hook_approved(
+        port_: i64,
+        root: *mut wire_cst_list_prim_u_8_strict,
+        hook: *mut wire_cst_shell_hook,
+    ) {
+        wire__crate__api__shell_hook_approved_impl(port_, root, hook)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_interactions_wire__crate__api__take_team_events(port_: i64) {
+        wire__crate__api__take_team_events_impl(port_)
+    }
+
     #[unsafe(no_mangle)]
     pub extern "C" fn frbgen_interactions_wire__crate__api__team_config_create(
     ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
@@ -2318,6 +4451,42 @@ mod io {
         wire__crate__api__team_to_yaml_impl(team)
     }
 
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_interactions_wire__crate__api__touch_objective(
+        objective: *mut wire_cst_objective,
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__touch_objective_impl(objective)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_interactions_wire__crate__api__unrecognised_members(
+        port_: i64,
+        root: *mut wire_cst_list_prim_u_8_strict,
+        weeks: u32,
+    ) {
+        wire__crate__api__unrecognised_members_impl(port_, root, weeks)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_interactions_wire__crate__api__verify_member_pincode(
+        port_: i64,
+        root: *mut wire_cst_list_prim_u_8_strict,
+        email: *mut wire_cst_list_prim_u_8_strict,
+        pincode: *mut wire_cst_list_prim_u_8_strict,
+    ) {
+        wire__crate__api__verify_member_pincode_impl(port_, root, email, pincode)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_interactions_wire__crate__api__weekly_activity(
+        port_: i64,
+        root: *mut wire_cst_list_prim_u_8_strict,
+        weeks: u32,
+        timezone: *mut wire_cst_list_prim_u_8_strict,
+    ) {
+        wire__crate__api__weekly_activity_impl(port_, root, weeks, timezone)
+    }
+
     #[unsafe(no_mangle)]
     pub extern "C" fn frbgen_interactions_cst_new_box_autoadd_backup_config(
     ) -> *mut wire_cst_backup_config {
@@ -2334,6 +4503,11 @@ mod io {
         )
     }
 
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_interactions_cst_new_box_autoadd_f_32(value: f32) -> *mut f32 {
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(value)
+    }
+
     #[unsafe(no_mangle)]
     pub extern "C" fn frbgen_interactions_cst_new_box_autoadd_interaction(
     ) -> *mut wire_cst_interaction {
@@ -2350,6 +4524,12 @@ mod io {
         )
     }
 
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_interactions_cst_new_box_autoadd_manifesto() -> *mut wire_cst_manifesto
+    {
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(wire_cst_manifesto::new_with_null_ptr())
+    }
+
     #[unsafe(no_mangle)]
     pub extern "C" fn frbgen_interactions_cst_new_box_autoadd_member() -> *mut wire_cst_member {
         flutter_rust_bridge::for_generated::new_leak_box_ptr(wire_cst_member::new_with_null_ptr())
@@ -2377,6 +4557,22 @@ mod io {
         )
     }
 
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_interactions_cst_new_box_autoadd_pulse_config(
+    ) -> *mut wire_cst_pulse_config {
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(
+            wire_cst_pulse_config::new_with_null_ptr(),
+        )
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_interactions_cst_new_box_autoadd_shell_hook(
+    ) -> *mut wire_cst_shell_hook {
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(
+            wire_cst_shell_hook::new_with_null_ptr(),
+        )
+    }
+
     #[unsafe(no_mangle)]
     pub extern "C" fn frbgen_interactions_cst_new_box_autoadd_team() -> *mut wire_cst_team {
         flutter_rust_bridge::for_generated::new_leak_box_ptr(wire_cst_team::new_with_null_ptr())
@@ -2412,6 +4608,48 @@ mod io {
         flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
     }
 
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_interactions_cst_new_list_appreciation_edge(
+        len: i32,
+    ) -> *mut wire_cst_list_appreciation_edge {
+        let wrap = wire_cst_list_appreciation_edge {
+            ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(
+                <wire_cst_appreciation_edge>::new_with_null_ptr(),
+                len,
+            ),
+            len,
+        };
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_interactions_cst_new_list_custom_webhook(
+        len: i32,
+    ) -> *mut wire_cst_list_custom_webhook {
+        let wrap = wire_cst_list_custom_webhook {
+            ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(
+                <wire_cst_custom_webhook>::new_with_null_ptr(),
+                len,
+            ),
+            len,
+        };
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_interactions_cst_new_list_due_pulse_prompt(
+        len: i32,
+    ) -> *mut wire_cst_list_due_pulse_prompt {
+        let wrap = wire_cst_list_due_pulse_prompt {
+            ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(
+                <wire_cst_due_pulse_prompt>::new_with_null_ptr(),
+                len,
+            ),
+            len,
+        };
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
+    }
+
     #[unsafe(no_mangle)]
     pub extern "C" fn frbgen_interactions_cst_new_list_key_result(
         len: i32,
@@ -2426,6 +4664,34 @@ mod io {
         flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
     }
 
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_interactions_cst_new_list_member_interaction_stats(
+        len: i32,
+    ) -> *mut wire_cst_list_member_interaction_stats {
+        let wrap = wire_cst_list_member_interaction_stats {
+            ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(
+                <wire_cst_member_interaction_stats>::new_with_null_ptr(),
+                len,
+            ),
+            len,
+        };
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_interactions_cst_new_list_member_pulse(
+        len: i32,
+    ) -> *mut wire_cst_list_member_pulse {
+        let wrap = wire_cst_list_member_pulse {
+            ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(
+                <wire_cst_member_pulse>::new_with_null_ptr(),
+                len,
+            ),
+            len,
+        };
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
+    }
+
     #[unsafe(no_mangle)]
     pub extern "C" fn frbgen_interactions_cst_new_list_prim_u_8_strict(
         len: i32,
@@ -2437,6 +4703,111 @@ mod io {
         flutter_rust_bridge::for_generated::new_leak_box_ptr(ans)
     }
 
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_interactions_cst_new_list_principle(
+        len: i32,
+    ) -> *mut wire_cst_list_principle {
+        let wrap = wire_cst_list_principle {
+            ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(
+                <wire_cst_principle>::new_with_null_ptr(),
+                len,
+            ),
+            len,
+        };
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_interactions_cst_new_list_principle_recognition(
+        len: i32,
+    ) -> *mut wire_cst_list_principle_recognition {
+        let wrap = wire_cst_list_principle_recognition {
+            ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(
+                <wire_cst_principle_recognition>::new_with_null_ptr(),
+                len,
+            ),
+            len,
+        };
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_interactions_cst_new_list_shell_hook(
+        len: i32,
+    ) -> *mut wire_cst_list_shell_hook {
+        let wrap = wire_cst_list_shell_hook {
+            ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(
+                <wire_cst_shell_hook>::new_with_null_ptr(),
+                len,
+            ),
+            len,
+        };
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_interactions_cst_new_list_team_event(
+        len: i32,
+    ) -> *mut wire_cst_list_team_event {
+        let wrap = wire_cst_list_team_event {
+            ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(
+                <wire_cst_team_event>::new_with_null_ptr(),
+                len,
+            ),
+            len,
+        };
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_interactions_cst_new_list_team_event_field(
+        len: i32,
+    ) -> *mut wire_cst_list_team_event_field {
+        let wrap = wire_cst_list_team_event_field {
+            ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(
+                <wire_cst_team_event_field>::new_with_null_ptr(),
+                len,
+            ),
+            len,
+        };
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_interactions_cst_new_list_webhook_header(
+        len: i32,
+    ) -> *mut wire_cst_list_webhook_header {
+        let wrap = wire_cst_list_webhook_header {
+            ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(
+                <wire_cst_webhook_header>::new_with_null_ptr(),
+                len,
+            ),
+            len,
+        };
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_interactions_cst_new_list_week_activity(
+        len: i32,
+    ) -> *mut wire_cst_list_week_activity {
+        let wrap = wire_cst_list_week_activity {
+            ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(
+                <wire_cst_week_activity>::new_with_null_ptr(),
+                len,
+            ),
+            len,
+        };
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
+    }
+
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_appreciation_edge {
+        from: *mut wire_cst_list_prim_u_8_strict,
+        to: *mut wire_cst_list_prim_u_8_strict,
+        count: u32,
+    }
     #[repr(C)]
     #[derive(Clone, Copy)]
     pub struct wire_cst_backup_config {
@@ -2450,6 +4821,25 @@ mod io {
     }
     #[repr(C)]
     #[derive(Clone, Copy)]
+    pub struct wire_cst_custom_webhook {
+        name: *mut wire_cst_list_prim_u_8_strict,
+        url: *mut wire_cst_list_prim_u_8_strict,
+        template: *mut wire_cst_list_prim_u_8_strict,
+        content_type: *mut wire_cst_list_prim_u_8_strict,
+        headers: *mut wire_cst_list_webhook_header,
+        secret: *mut wire_cst_list_prim_u_8_strict,
+        signature_header: *mut wire_cst_list_prim_u_8_strict,
+        events: *mut wire_cst_list_String,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_due_pulse_prompt {
+        prompt: *mut wire_cst_list_prim_u_8_strict,
+        message: *mut wire_cst_list_prim_u_8_strict,
+        objective_id: *mut wire_cst_list_prim_u_8_strict,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
     pub struct wire_cst_interaction {
         id: *mut wire_cst_list_prim_u_8_strict,
         kind: i32,
@@ -2458,6 +4848,8 @@ mod io {
         note: *mut wire_cst_list_prim_u_8_strict,
         timestamp: *mut wire_cst_list_prim_u_8_strict,
         shared: bool,
+        principles: *mut wire_cst_list_String,
+        imported_from: *mut wire_cst_list_prim_u_8_strict,
     }
     #[repr(C)]
     #[derive(Clone, Copy)]
@@ -2480,23 +4872,102 @@ mod io {
     }
     #[repr(C)]
     #[derive(Clone, Copy)]
+    pub struct wire_cst_list_appreciation_edge {
+        ptr: *mut wire_cst_appreciation_edge,
+        len: i32,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_list_custom_webhook {
+        ptr: *mut wire_cst_custom_webhook,
+        len: i32,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_list_due_pulse_prompt {
+        ptr: *mut wire_cst_due_pulse_prompt,
+        len: i32,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
     pub struct wire_cst_list_key_result {
         ptr: *mut wire_cst_key_result,
         len: i32,
     }
     #[repr(C)]
     #[derive(Clone, Copy)]
+    pub struct wire_cst_list_member_interaction_stats {
+        ptr: *mut wire_cst_member_interaction_stats,
+        len: i32,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_list_member_pulse {
+        ptr: *mut wire_cst_member_pulse,
+        len: i32,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
     pub struct wire_cst_list_prim_u_8_strict {
         ptr: *mut u8,
         len: i32,
     }
     #[repr(C)]
     #[derive(Clone, Copy)]
+    pub struct wire_cst_list_principle {
+        ptr: *mut wire_cst_principle,
+        len: i32,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_list_principle_recognition {
+        ptr: *mut wire_cst_principle_recognition,
+        len: i32,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_list_shell_hook {
+        ptr: *mut wire_cst_shell_hook,
+        len: i32,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_list_team_event {
+        ptr: *mut wire_cst_team_event,
+        len: i32,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_list_team_event_field {
+        ptr: *mut wire_cst_team_event_field,
+        len: i32,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_list_webhook_header {
+        ptr: *mut wire_cst_webhook_header,
+        len: i32,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_list_week_activity {
+        ptr: *mut wire_cst_week_activity,
+        len: i32,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_manifesto {
+        preamble: *mut wire_cst_list_prim_u_8_strict,
+        principles: *mut wire_cst_list_principle,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
     pub struct wire_cst_member {
         email: *mut wire_cst_list_prim_u_8_strict,
         name: *mut wire_cst_list_prim_u_8_strict,
         bio: *mut wire_cst_list_prim_u_8_strict,
         timezone: *mut wire_cst_list_prim_u_8_strict,
+        aliases: *mut wire_cst_list_String,
     }
     #[repr(C)]
     #[derive(Clone, Copy)]
@@ -2506,6 +4977,23 @@ mod io {
     }
     #[repr(C)]
     #[derive(Clone, Copy)]
+    pub struct wire_cst_member_interaction_stats {
+        email: *mut wire_cst_list_prim_u_8_strict,
+        kudos_given: u32,
+        kudos_received: u32,
+        feedback_given: u32,
+        feedback_received: u32,
+        total_sent: u32,
+        total_received: u32,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_member_pulse {
+        email: *mut wire_cst_list_prim_u_8_strict,
+        cadences: wire_cst_pulse_cadences,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
     pub struct wire_cst_objective {
         id: *mut wire_cst_list_prim_u_8_strict,
         title: *mut wire_cst_list_prim_u_8_strict,
@@ -2514,6 +5002,29 @@ mod io {
         visibility: i32,
         owner: *mut wire_cst_list_prim_u_8_strict,
         quarter: *mut wire_cst_list_prim_u_8_strict,
+        principles: *mut wire_cst_list_String,
+        updated: *mut wire_cst_list_prim_u_8_strict,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_pincode_check {
+        verified: bool,
+        email: *mut wire_cst_list_prim_u_8_strict,
+        retry_after_secs: u64,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_principle {
+        id: *mut wire_cst_list_prim_u_8_strict,
+        title: *mut wire_cst_list_prim_u_8_strict,
+        description: *mut wire_cst_list_prim_u_8_strict,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_principle_recognition {
+        id: *mut wire_cst_list_prim_u_8_strict,
+        title: *mut wire_cst_list_prim_u_8_strict,
+        count: u32,
     }
     #[repr(C)]
     #[derive(Clone, Copy)]
@@ -2521,15 +5032,37 @@ mod io {
         manifesto: *mut wire_cst_list_prim_u_8_strict,
         vision: *mut wire_cst_list_prim_u_8_strict,
         okrs: *mut wire_cst_list_prim_u_8_strict,
+        interactions: *mut wire_cst_list_prim_u_8_strict,
+        timezone: *mut wire_cst_list_prim_u_8_strict,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_pulse_cadences {
+        checkin: *mut wire_cst_list_prim_u_8_strict,
+        okr_update: *mut wire_cst_list_prim_u_8_strict,
+        retro: *mut wire_cst_list_prim_u_8_strict,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_pulse_config {
+        team: wire_cst_pulse_cadences,
+        members: *mut wire_cst_list_member_pulse,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_shell_hook {
+        on: *mut wire_cst_list_String,
+        run: *mut wire_cst_list_prim_u_8_strict,
     }
     #[repr(C)]
     #[derive(Clone, Copy)]
     pub struct wire_cst_team {
         name: *mut wire_cst_list_prim_u_8_strict,
-        manifesto: *mut wire_cst_list_prim_u_8_strict,
+        manifesto: *mut wire_cst_manifesto,
         vision: *mut wire_cst_list_prim_u_8_strict,
         leaders: *mut wire_cst_list_String,
         members: *mut wire_cst_list_String,
+        archived: *mut wire_cst_list_String,
     }
     #[repr(C)]
     #[derive(Clone, Copy)]
@@ -2538,6 +5071,22 @@ mod io {
         webhooks: *mut wire_cst_webhook_config,
         linting: *mut wire_cst_linting_config,
         backup: *mut wire_cst_backup_config,
+        pulse: *mut wire_cst_pulse_config,
+        hooks: *mut wire_cst_list_shell_hook,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_team_event {
+        id: *mut wire_cst_list_prim_u_8_strict,
+        name: *mut wire_cst_list_prim_u_8_strict,
+        text: *mut wire_cst_list_prim_u_8_strict,
+        fields: *mut wire_cst_list_team_event_field,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_team_event_field {
+        key: *mut wire_cst_list_prim_u_8_strict,
+        value: *mut wire_cst_list_prim_u_8_strict,
     }
     #[repr(C)]
     #[derive(Clone, Copy)]
@@ -2545,6 +5094,19 @@ mod io {
         discord: *mut wire_cst_list_prim_u_8_strict,
         slack: *mut wire_cst_list_prim_u_8_strict,
         signal: *mut wire_cst_list_prim_u_8_strict,
+        custom: *mut wire_cst_list_custom_webhook,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_webhook_header {
+        name: *mut wire_cst_list_prim_u_8_strict,
+        value: *mut wire_cst_list_prim_u_8_strict,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_week_activity {
+        week_start: *mut wire_cst_list_prim_u_8_strict,
+        count: u32,
     }
 }
 #[cfg(not(target_family = "wasm"))]
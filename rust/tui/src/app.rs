@@ -1,6 +1,17 @@
 //! Application state management
 
-use interactions_core::{Interaction, InteractionKind, Member, Team, TeamConfig, TeamStorage};
+use chrono::Utc;
+use chrono_tz::Tz;
+use interactions_core::analytics::Analytics;
+use interactions_core::history::{self, HistoryEntry, Scope};
+use interactions_core::outbox::{self, Flush};
+use interactions_core::pulse::{self, DuePrompt, Pulse};
+use interactions_core::sync::SyncConflict;
+use interactions_core::{
+    git, normalize_email, recipients, time, AuthorizedStorage, Error, Event, EventBus,
+    FeedbackRequest, Interaction, InteractionKind, Manifesto, Member, Objective, PulsePrompt, Team,
+    TeamConfig, TeamStorage,
+};
 
 /// Sub-tabs for the Interactions tab
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -30,9 +41,20 @@ pub enum InteractionsView {
     Sent,
     Received,
 }
+use std::cell::Cell;
+use std::collections::HashMap;
 use std::path::{Path, PathBuf};
+use std::rc::Rc;
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
 use std::{env, fs};
 
+/// Lock the TUI after this long without key presses
+pub const LOCK_AFTER: Duration = Duration::from_secs(5 * 60);
+
+/// How often the outbox is retried while nothing new is queued
+const FLUSH_EVERY: Duration = Duration::from_secs(60);
+
 /// The main tabs in the TUI
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum Tab {
@@ -41,6 +63,7 @@ pub enum Tab {
     Team,
     Interactions,
     Okrs,
+    History,
     Settings,
 }
 
@@ -51,6 +74,7 @@ impl Tab {
             Tab::Team => "Team",
             Tab::Interactions => "Interactions",
             Tab::Okrs => "OKRs",
+            Tab::History => "History",
             Tab::Settings => "Settings",
         }
     }
@@ -61,6 +85,7 @@ impl Tab {
             Tab::Team,
             Tab::Interactions,
             Tab::Okrs,
+            Tab::History,
             Tab::Settings,
         ]
     }
@@ -129,20 +154,161 @@ pub struct AddMemberState {
     pub error_message: Option<String>,
 }
 
+/// Login screen step
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LoginStep {
+    #[default]
+    SelectMember,
+    Pincode,
+}
+
+/// State for the login screen
+#[derive(Debug, Clone, Default)]
+pub struct LoginState {
+    pub step: LoginStep,
+    /// Active team members who can log in
+    pub members: Vec<Member>,
+    pub selected_index: usize,
+    pub input_buffer: String,
+    pub error_message: Option<String>,
+    /// Whether the session was locked after inactivity
+    pub locked: bool,
+    /// When the selected member may try their pincode again after failures
+    pub retry_at: Option<Instant>,
+}
+
+impl LoginState {
+    /// Seconds left before another pincode attempt is allowed
+    pub fn wait_secs(&self) -> Option<u64> {
+        let remaining = self.retry_at?.checked_duration_since(Instant::now())?;
+        Some(remaining.as_secs() + u64::from(remaining.subsec_nanos() > 0))
+    }
+
+    /// The member currently highlighted in the list
+    pub fn selected_member(&self) -> Option<&Member> {
+        self.members.get(self.selected_index)
+    }
+}
+
+/// When a member may next try their pincode, if they are being throttled
+fn retry_at(storage: &TeamStorage, email: &str) -> Option<Instant> {
+    let wait = storage.pincode_retry_after(email).ok().flatten()?;
+    Some(Instant::now() + wait.to_std().ok()?)
+}
+
+/// What the pincode wizard is doing
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PincodeMode {
+    /// The logged-in user changes their own pincode
+    Change,
+    /// A member sets a new pincode with a reset token from a leader
+    Redeem { email: String },
+}
+
+/// Pincode wizard step
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PincodeStep {
+    Current,
+    Token,
+    New,
+    Confirm,
+}
+
+impl PincodeStep {
+    pub fn prompt(&self) -> &'static str {
+        match self {
+            PincodeStep::Current => "Enter your current pincode:",
+            PincodeStep::Token => "Enter the reset token from your team leader:",
+            PincodeStep::New => "Choose a new pincode (min 4 chars, hidden):",
+            PincodeStep::Confirm => "Confirm your new pincode:",
+        }
+    }
+
+    /// Whether input on this step should be masked
+    pub fn is_secret(&self) -> bool {
+        !matches!(self, PincodeStep::Token)
+    }
+}
+
+/// State for the change/reset pincode wizard
+#[derive(Debug, Clone)]
+pub struct PincodeState {
+    pub mode: PincodeMode,
+    pub step: PincodeStep,
+    /// Current pincode or reset token, depending on the mode
+    pub secret: String,
+    pub new_pincode: String,
+    pub input_buffer: String,
+    pub error_message: Option<String>,
+}
+
+impl PincodeState {
+    /// The steps for this wizard's mode, in order
+    pub fn steps(&self) -> [PincodeStep; 3] {
+        let first = match self.mode {
+            PincodeMode::Change => PincodeStep::Current,
+            PincodeMode::Redeem { .. } => PincodeStep::Token,
+        };
+        [first, PincodeStep::New, PincodeStep::Confirm]
+    }
+}
+
+/// Member management actions available to leaders on the Team tab
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MemberAction {
+    Promote,
+    Demote,
+    Archive,
+    Remove,
+}
+
+impl MemberAction {
+    pub fn title(&self) -> &'static str {
+        match self {
+            MemberAction::Promote => "Promote to Leader",
+            MemberAction::Demote => "Demote to Member",
+            MemberAction::Archive => "Archive Member",
+            MemberAction::Remove => "Remove Member",
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            MemberAction::Promote => "They will be able to manage the team and its manifesto.",
+            MemberAction::Demote => "They will keep their history but lose leader rights.",
+            MemberAction::Archive => {
+                "They can no longer log in. Their profile and past interactions are kept."
+            }
+            MemberAction::Remove => {
+                "Their profile and received interactions are deleted. Use archive for people who left."
+            }
+        }
+    }
+}
+
+/// State for the member action confirmation dialog
+#[derive(Debug, Clone)]
+pub struct MemberActionState {
+    pub action: MemberAction,
+    pub email: String,
+}
+
 /// Kudos wizard step
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum KudosStep {
     #[default]
     Recipient,
     Note,
+    Principle,
     Share,
 }
 
 impl KudosStep {
     pub fn prompt(&self) -> &'static str {
         match self {
-            KudosStep::Recipient => "Who are you giving kudos to? (email or name)",
+            KudosStep::Recipient => "Who are you giving kudos to? (name, email or * for everyone)",
             KudosStep::Note => "What would you like to say?",
+            KudosStep::Principle => "Which value did they live? (numbers, Enter to skip)",
             KudosStep::Share => "Share with the team? (y/n)",
         }
     }
@@ -150,23 +316,191 @@ impl KudosStep {
     pub fn next(&self) -> Option<KudosStep> {
         match self {
             KudosStep::Recipient => Some(KudosStep::Note),
-            KudosStep::Note => Some(KudosStep::Share),
+            KudosStep::Note => Some(KudosStep::Principle),
+            KudosStep::Principle => Some(KudosStep::Share),
             KudosStep::Share => None,
         }
     }
 }
 
+/// Recipient input that selects the whole team
+pub const WHOLE_TEAM: &str = "*";
+
+/// Maximum number of recipient suggestions shown at once
+const MAX_SUGGESTIONS: usize = 5;
+
+/// Live recipient suggestions for the kudos and feedback wizards
+#[derive(Debug, Clone, Default)]
+pub struct RecipientPicker {
+    /// Active team members who can be picked
+    pub directory: Vec<Member>,
+    /// Members matching the current input, best first
+    pub suggestions: Vec<Member>,
+    pub selected_index: usize,
+    /// Whether the user moved through the suggestions since the last edit
+    pub browsed: bool,
+    /// Unknown recipient the user was warned about; submitting it again sends anyway
+    pub pending_unknown: Option<String>,
+}
+
+impl RecipientPicker {
+    /// Create a picker over the given members, suggesting everyone
+    pub fn new(directory: Vec<Member>) -> Self {
+        let mut picker = Self {
+            directory,
+            ..Self::default()
+        };
+        picker.update("");
+        picker
+    }
+
+    /// Recompute suggestions for the current input
+    pub fn update(&mut self, input: &str) {
+        self.suggestions = recipients::suggest(&self.directory, input, MAX_SUGGESTIONS)
+            .into_iter()
+            .cloned()
+            .collect();
+        self.selected_index = 0;
+        self.browsed = false;
+        self.pending_unknown = None;
+    }
+
+    /// Highlight the previous suggestion
+    pub fn previous(&mut self) {
+        if !self.suggestions.is_empty() {
+            self.browsed = true;
+            self.selected_index = if self.selected_index == 0 {
+                self.suggestions.len() - 1
+            } else {
+                self.selected_index - 1
+            };
+        }
+    }
+
+    /// Highlight the next suggestion
+    pub fn next(&mut self) {
+        if !self.suggestions.is_empty() {
+            self.browsed = true;
+            self.selected_index = (self.selected_index + 1) % self.suggestions.len();
+        }
+    }
+
+    /// The highlighted suggestion
+    pub fn selected(&self) -> Option<&Member> {
+        self.suggestions.get(self.selected_index)
+    }
+
+    /// Display name for a chosen recipient, falling back to the email
+    pub fn label<'a>(&'a self, email: &'a str) -> &'a str {
+        self.directory
+            .iter()
+            .find(|m| m.has_email(email))
+            .map_or(email, |m| m.display_name())
+    }
+
+    /// Decide which email the input refers to
+    ///
+    /// Exact emails, aliases and names win, then the highlighted suggestion.
+    /// A full email that matches nobody has to be submitted twice to confirm,
+    /// unless a suggestion was picked with the arrow keys.
+    pub fn choose(&mut self, input: &str) -> Result<String, String> {
+        if let Some(member) = recipients::resolve(&self.directory, input) {
+            return Ok(member.email.clone());
+        }
+        let typed_email = input.contains('@');
+        if (!typed_email && !input.is_empty()) || self.browsed {
+            if let Some(member) = self.selected() {
+                return Ok(member.email.clone());
+            }
+        }
+        if input.is_empty() {
+            return Err("Please enter a recipient".to_string());
+        }
+        if !typed_email {
+            return Err(format!("No team member matches '{}'", input));
+        }
+        if self.pending_unknown.as_deref() == Some(input) {
+            return Ok(normalize_email(input));
+        }
+        self.pending_unknown = Some(input.to_string());
+        let hint = match self.selected() {
+            Some(member) => format!(" (did you mean {}?)", member.email),
+            None => String::new(),
+        };
+        Err(format!(
+            "{} is not a team member{}. Press Enter again to send anyway",
+            input, hint
+        ))
+    }
+}
+
+/// Handle Enter on a wizard's recipient step
+///
+/// Adds the chosen member, or the whole team for [`WHOLE_TEAM`], to the
+/// recipients. Returns `true` once an empty input confirms the list.
+fn add_recipients(
+    picker: &mut RecipientPicker,
+    recipients: &mut Vec<String>,
+    input: &str,
+    whole_team: &[String],
+) -> Result<bool, String> {
+    if input.is_empty() && !recipients.is_empty() && !picker.browsed {
+        return Ok(true);
+    }
+
+    let chosen = if input == WHOLE_TEAM {
+        if whole_team.is_empty() {
+            return Err("There is nobody else in the team yet".to_string());
+        }
+        whole_team.to_vec()
+    } else {
+        vec![picker.choose(input)?]
+    };
+    for email in chosen {
+        if !recipients.contains(&email) {
+            recipients.push(email);
+        }
+    }
+    Ok(false)
+}
+
 /// State for the kudos wizard
 #[derive(Debug, Clone, Default)]
 pub struct KudosState {
     pub step: KudosStep,
-    pub recipient: String,
+    pub recipients: Vec<String>,
+    pub picker: RecipientPicker,
     pub note: String,
+    pub principles: Vec<String>,
     pub shared: bool,
     pub input_buffer: String,
     pub error_message: Option<String>,
 }
 
+/// Parse manifesto principles chosen by number or id, e.g. "1, 3" or "be-kind"
+fn parse_principles(input: &str, manifesto: Option<&Manifesto>) -> Result<Vec<String>, String> {
+    let principles = manifesto
+        .map(|m| m.principles.as_slice())
+        .unwrap_or_default();
+    let mut chosen = Vec::new();
+    for token in input
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|t| !t.is_empty())
+    {
+        let principle = match token.parse::<usize>() {
+            Ok(n) => n.checked_sub(1).and_then(|i| principles.get(i)),
+            Err(_) => principles.iter().find(|p| p.id == token),
+        };
+        let Some(principle) = principle else {
+            return Err(format!("No principle {}", token));
+        };
+        if !chosen.contains(&principle.id) {
+            chosen.push(principle.id.clone());
+        }
+    }
+    Ok(chosen)
+}
+
 /// Feedback wizard step
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum FeedbackStep {
@@ -177,17 +511,25 @@ pub enum FeedbackStep {
 }
 
 impl FeedbackStep {
-    pub fn prompt(&self) -> &'static str {
-        match self {
-            FeedbackStep::Recipient => "Who is this feedback for? (email or name)",
-            FeedbackStep::Note => "What feedback would you like to share?",
-            FeedbackStep::Share => "Share with the team? (y/n)",
+    pub fn prompt(&self, request: bool) -> &'static str {
+        match (self, request) {
+            (FeedbackStep::Recipient, false) => {
+                "Who is this feedback for? (name, email or * for everyone)"
+            }
+            (FeedbackStep::Recipient, true) => {
+                "Who would you like feedback from? (name, email or * for everyone)"
+            }
+            (FeedbackStep::Note, false) => "What feedback would you like to share?",
+            (FeedbackStep::Note, true) => "What should the feedback be about?",
+            (FeedbackStep::Share, _) => "Share with the team? (y/n)",
         }
     }
 
-    pub fn next(&self) -> Option<FeedbackStep> {
+    /// The step after this one; requests are never shared, so they end at the topic
+    pub fn next(&self, request: bool) -> Option<FeedbackStep> {
         match self {
             FeedbackStep::Recipient => Some(FeedbackStep::Note),
+            FeedbackStep::Note if request => None,
             FeedbackStep::Note => Some(FeedbackStep::Share),
             FeedbackStep::Share => None,
         }
@@ -197,14 +539,98 @@ impl FeedbackStep {
 /// State for the feedback wizard
 #[derive(Debug, Clone, Default)]
 pub struct FeedbackState {
+    /// Asking the recipients for feedback rather than giving it
+    pub request: bool,
     pub step: FeedbackStep,
-    pub recipient: String,
+    pub recipients: Vec<String>,
+    pub picker: RecipientPicker,
+    pub note: String,
+    pub shared: bool,
+    pub input_buffer: String,
+    pub error_message: Option<String>,
+}
+
+/// Step of the wizard for check-ins, retrospectives and apologies
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LogStep {
+    #[default]
+    With,
+    Note,
+    Share,
+}
+
+impl LogStep {
+    pub fn prompt(&self, kind: InteractionKind) -> &'static str {
+        match (self, kind) {
+            (LogStep::With, InteractionKind::Retrospective) => {
+                "Who took part? (name, email or * for everyone)"
+            }
+            (LogStep::With, InteractionKind::Apology) => {
+                "Who is this apology for? (name, email or * for everyone)"
+            }
+            (LogStep::With, _) => "Who did you check in with? (name, email or * for everyone)",
+            (LogStep::Note, InteractionKind::Retrospective) => "What came out of it?",
+            (LogStep::Note, InteractionKind::Apology) => "What would you like to say?",
+            (LogStep::Note, _) => "What did you talk about?",
+            (LogStep::Share, _) => "Share with the team? (y/n)",
+        }
+    }
+
+    pub fn next(&self) -> Option<LogStep> {
+        match self {
+            LogStep::With => Some(LogStep::Note),
+            LogStep::Note => Some(LogStep::Share),
+            LogStep::Share => None,
+        }
+    }
+}
+
+/// State for the wizard logging check-ins, retrospectives and apologies
+#[derive(Debug, Clone)]
+pub struct LogState {
+    pub kind: InteractionKind,
+    pub step: LogStep,
+    pub recipients: Vec<String>,
+    pub picker: RecipientPicker,
     pub note: String,
     pub shared: bool,
     pub input_buffer: String,
     pub error_message: Option<String>,
 }
 
+/// State for the wizard updating an objective's key results
+#[derive(Debug, Clone)]
+pub struct OkrUpdateState {
+    /// The objective with the progress entered so far
+    pub objective: Objective,
+    /// The key result being updated
+    pub index: usize,
+    pub input_buffer: String,
+    pub error_message: Option<String>,
+}
+
+/// Parse a progress percentage such as "40" or "40%" into 0.0 to 1.0
+fn parse_progress(input: &str) -> Result<f32, String> {
+    let number = input.trim().trim_end_matches('%').trim();
+    match number.parse::<f32>() {
+        Ok(percent) if (0.0..=100.0).contains(&percent) => Ok(percent / 100.0),
+        _ => Err("Enter a percentage from 0 to 100".to_string()),
+    }
+}
+
+/// The pulse prompts popup shown after logging in
+#[derive(Debug, Clone, Default)]
+pub struct PulseState {
+    /// Highlighted prompt in [`App::pulse_due`]
+    pub selected: usize,
+}
+
+/// How long a snoozed pulse prompt stays hidden
+const PULSE_SNOOZE: chrono::Duration = chrono::Duration::days(1);
+
+/// Commits the history tab reads from git
+const HISTORY_LIMIT: usize = 200;
+
 /// State for the directory navigation wizard
 #[derive(Debug, Clone)]
 pub struct NavigateDirState {
@@ -275,7 +701,7 @@ impl NavigateDirState {
             }
 
             // Sort directories alphabetically
-            dirs.sort_by(|a, b| a.name.to_lowercase().cmp(&b.name.to_lowercase()));
+            dirs.sort_by_key(|d| d.name.to_lowercase());
             self.entries.extend(dirs);
         }
     }
@@ -361,9 +787,25 @@ pub struct App {
     /// Team storage handler
     pub storage: TeamStorage,
 
+    /// Team webhooks and shell hooks, told about every change saved;
+    /// webhook notifications are only queued, see [`App::flush_outbox`]
+    pub events: EventBus,
+
+    /// Set when a shared event queued webhook notifications
+    outbox_queued: Rc<Cell<bool>>,
+
+    /// The outbox flush running in the background, if any
+    outbox_flush: Option<JoinHandle<interactions_core::Result<Flush>>>,
+
+    /// When the outbox was last flushed
+    last_flush: Instant,
+
     /// Loaded team data (if available)
     pub team: Option<Team>,
 
+    /// Loaded manifesto (if available)
+    pub manifesto: Option<Manifesto>,
+
     /// Quick actions for dashboard
     pub quick_actions: Vec<QuickAction>,
 
@@ -376,6 +818,9 @@ pub struct App {
     /// Add member wizard state (Some when adding a member)
     pub add_member_state: Option<AddMemberState>,
 
+    /// Member action confirmation state (Some when confirming a role change)
+    pub member_action_state: Option<MemberActionState>,
+
     /// Directory navigation wizard state (Some when navigating)
     pub navigate_dir_state: Option<NavigateDirState>,
 
@@ -385,9 +830,30 @@ pub struct App {
     /// Feedback wizard state (Some when giving feedback)
     pub feedback_state: Option<FeedbackState>,
 
+    /// Check-in, retrospective or apology wizard state (Some when logging one)
+    pub log_state: Option<LogState>,
+
+    /// OKR update wizard state (Some when updating progress)
+    pub okr_update_state: Option<OkrUpdateState>,
+
+    /// Pulse prompts popup state (Some when showing due prompts)
+    pub pulse_state: Option<PulseState>,
+
+    /// Whether the pulse prompts have been offered this session
+    pub pulse_shown: bool,
+
     /// Current user email (for logging interactions)
     pub current_user: Option<String>,
 
+    /// Pincode wizard state (Some when changing or resetting a pincode)
+    pub pincode_state: Option<PincodeState>,
+
+    /// Login screen state (Some when logging in or locked)
+    pub login_state: Option<LoginState>,
+
+    /// Time of the last key press, for locking after inactivity
+    pub last_activity: Instant,
+
     /// Current sub-tab in Interactions tab
     pub interactions_subtab: InteractionsSubTab,
 
@@ -408,6 +874,34 @@ pub struct App {
 
     /// Selected interaction index in the current view
     pub interaction_index: usize,
+
+    /// Timezone of the current user, used to show timestamps
+    pub viewer_tz: Tz,
+
+    /// Team-wide interaction analytics
+    pub analytics: Analytics,
+
+    /// Member profiles, for showing names instead of emails
+    pub members: Vec<Member>,
+
+    /// Team objectives and the current user's own objectives
+    pub objectives: Vec<Objective>,
+
+    /// Feedback requests the current user has not answered yet
+    pub pending_requests: Vec<FeedbackRequest>,
+
+    /// Pulse prompts due for the current user
+    pub pulse_due: Vec<DuePrompt>,
+
+    /// How many kudos named each manifesto principle, by principle id
+    pub principle_kudos: HashMap<String, usize>,
+
+    /// Conflict copies a cloud sync client left in the team folder
+    pub sync_conflicts: Vec<SyncConflict>,
+
+    /// Changes to .team/ from git, newest first; read when the history tab
+    /// is opened
+    pub history: Vec<HistoryEntry>,
 }
 
 impl App {
@@ -421,47 +915,60 @@ impl App {
     pub fn with_directory(dir: PathBuf) -> Self {
         let storage = TeamStorage::new(&dir);
         let team = storage.load_team().ok().flatten();
+        let manifesto = storage.team_manifesto().ok().flatten();
         let is_initialized = storage.is_initialized();
-
-        // Get the current user from the first leader
-        let current_user = team.as_ref().and_then(|t| t.leaders.first().cloned());
-
-        // Load kudos and feedback
-        let sent_kudos = storage.load_sent_kudos().unwrap_or_default();
-        let received_kudos = current_user
-            .as_ref()
-            .and_then(|email| storage.load_received_kudos(email).ok())
-            .unwrap_or_default();
-        let sent_feedback = storage.load_sent_feedback().unwrap_or_default();
-        let received_feedback = current_user
-            .as_ref()
-            .and_then(|email| storage.load_received_feedback(email).ok())
-            .unwrap_or_default();
+        let outbox_queued = Rc::new(Cell::new(true));
+        let events = Self::team_events(&storage, &outbox_queued);
 
         let quick_actions = Self::build_quick_actions(is_initialized);
 
-        Self {
+        let mut app = Self {
             current_tab: Tab::default(),
             selected_index: 0,
             working_dir: dir,
             storage,
+            events,
+            outbox_queued,
+            outbox_flush: None,
+            last_flush: Instant::now(),
             team,
+            manifesto,
             quick_actions,
             status_message: None,
             init_state: None,
             add_member_state: None,
+            member_action_state: None,
             navigate_dir_state: None,
             kudos_state: None,
             feedback_state: None,
-            current_user,
+            log_state: None,
+            okr_update_state: None,
+            pulse_state: None,
+            pulse_shown: false,
+            pincode_state: None,
+            current_user: None,
+            login_state: None,
+            last_activity: Instant::now(),
             interactions_subtab: InteractionsSubTab::default(),
             interactions_view: InteractionsView::default(),
-            sent_kudos,
-            received_kudos,
-            sent_feedback,
-            received_feedback,
+            sent_kudos: Vec::new(),
+            received_kudos: Vec::new(),
+            sent_feedback: Vec::new(),
+            received_feedback: Vec::new(),
             interaction_index: 0,
-        }
+            viewer_tz: Tz::UTC,
+            analytics: Analytics::default(),
+            members: Vec::new(),
+            objectives: Vec::new(),
+            pending_requests: Vec::new(),
+            pulse_due: Vec::new(),
+            principle_kudos: HashMap::new(),
+            sync_conflicts: Vec::new(),
+            history: Vec::new(),
+        };
+        app.reload_interactions();
+        app.start_login();
+        app
     }
 
     /// Build quick actions based on initialization state
@@ -524,6 +1031,7 @@ impl App {
         let next_idx = (current_idx + 1) % tabs.len();
         self.current_tab = tabs[next_idx];
         self.selected_index = 0;
+        self.load_history_for_tab();
     }
 
     /// Move to the previous tab
@@ -540,6 +1048,24 @@ impl App {
         };
         self.current_tab = tabs[prev_idx];
         self.selected_index = 0;
+        self.load_history_for_tab();
+    }
+
+    /// Read the git history when the history tab is opened, leaving out
+    /// private interactions and requests the current user was not part of
+    fn load_history_for_tab(&mut self) {
+        if self.current_tab != Tab::History {
+            return;
+        }
+        let user = self.current_user.as_deref();
+        self.history =
+            match history::history_for(&self.storage, &Scope::Team, Some(HISTORY_LIMIT), user) {
+                Ok(entries) => entries,
+                Err(e) => {
+                    self.status_message = Some(format!("Cannot read history: {}", e));
+                    Vec::new()
+                }
+            };
     }
 
     /// Move to the next item in the current view
@@ -565,36 +1091,33 @@ impl App {
     /// Select the current item
     pub fn select_item(&mut self) {
         match self.current_tab {
-            Tab::Dashboard => {
-                if self.selected_index < self.quick_actions.len() {
-                    let action = &self.quick_actions[self.selected_index];
-                    match &action.kind {
-                        QuickActionKind::InitTeam => {
-                            self.start_init();
-                        }
-                        QuickActionKind::ChangeDirectory => {
-                            self.start_navigate_dir();
+            Tab::Dashboard if self.selected_index < self.quick_actions.len() => {
+                let action = &self.quick_actions[self.selected_index];
+                match &action.kind {
+                    QuickActionKind::InitTeam => {
+                        self.start_init();
+                    }
+                    QuickActionKind::ChangeDirectory => {
+                        self.start_navigate_dir();
+                    }
+                    QuickActionKind::LogInteraction(kind) => match kind {
+                        InteractionKind::Appreciation => {
+                            self.start_kudos();
                         }
-                        QuickActionKind::LogInteraction(kind) => match kind {
-                            InteractionKind::Appreciation => {
-                                self.start_kudos();
-                            }
-                            InteractionKind::Feedback => {
-                                self.start_feedback();
-                            }
-                            _ => {
-                                self.status_message =
-                                    Some("This interaction type not yet implemented".to_string());
-                            }
-                        },
-                        QuickActionKind::ViewTeam => {
-                            self.current_tab = Tab::Team;
-                            self.selected_index = 0;
+                        InteractionKind::Feedback => {
+                            self.start_feedback();
                         }
-                        QuickActionKind::ViewOkrs => {
-                            self.current_tab = Tab::Okrs;
-                            self.selected_index = 0;
+                        kind => {
+                            self.start_log(*kind);
                         }
+                    },
+                    QuickActionKind::ViewTeam => {
+                        self.current_tab = Tab::Team;
+                        self.selected_index = 0;
+                    }
+                    QuickActionKind::ViewOkrs => {
+                        self.current_tab = Tab::Okrs;
+                        self.selected_index = 0;
                     }
                 }
             }
@@ -727,9 +1250,19 @@ impl App {
             .initialize_team(&team, &config, &leader, &state.pincode)
         {
             Ok(()) => {
+                // Outside a git repository there is no merge driver to set up
+                if let Ok(exe) = env::current_exe() {
+                    let _ =
+                        git::configure_merge_driver(&self.working_dir, &exe.display().to_string());
+                }
                 self.team = Some(team);
+                self.events = Self::team_events(&self.storage, &self.outbox_queued);
                 self.quick_actions = Self::build_quick_actions(true);
                 self.selected_index = 0;
+                // The leader just chose their pincode, so log them in
+                self.current_user = Some(state.leader_email.clone());
+                let _ = self.storage.save_last_user(&state.leader_email);
+                self.reload_interactions();
                 self.status_message = Some(format!(
                     "Team '{}' initialized successfully!",
                     state.team_name
@@ -743,6 +1276,10 @@ impl App {
 
     /// Start the add member wizard
     pub fn start_add_member(&mut self) {
+        if !self.is_current_user_leader() {
+            self.status_message = Some("Only team leaders can add members".to_string());
+            return;
+        }
         self.add_member_state = Some(AddMemberState::default());
         self.status_message = None;
     }
@@ -832,17 +1369,11 @@ impl App {
             member = member.with_name(&state.name);
         }
 
-        // Save the member profile
-        if let Err(e) = self.storage.save_member(&member) {
-            self.status_message = Some(format!("Error saving member: {}", e));
-            return;
-        }
-
-        // Update the team with the new member
-        if let Some(team) = &mut self.team {
-            team.push_member(&state.email);
-            if let Err(e) = self.storage.save_team(team) {
-                self.status_message = Some(format!("Error updating team: {}", e));
+        // Save the member profile and add them to the team
+        match self.authorized().add_member(&member) {
+            Ok(team) => self.team = Some(team),
+            Err(e) => {
+                self.status_message = Some(format!("Error adding member: {}", e));
                 return;
             }
         }
@@ -856,6 +1387,173 @@ impl App {
         self.status_message = Some(format!("Added {} to the team!", display_name));
     }
 
+    /// Storage access on behalf of the current user, checked against their role
+    pub fn authorized(&self) -> AuthorizedStorage<'_> {
+        AuthorizedStorage::new(&self.storage, self.current_user.as_deref())
+            .with_events(&self.events)
+    }
+
+    /// The team's webhooks and shell hooks, with webhook notifications only
+    /// queued; `queued` is set when there is something to deliver
+    fn team_events(storage: &TeamStorage, queued: &Rc<Cell<bool>>) -> EventBus {
+        let queued = queued.clone();
+        EventBus::for_team_queued(storage)
+            .unwrap_or_default()
+            .with(move |event: &Event| {
+                if event.is_shared() {
+                    queued.set(true);
+                }
+                Ok(())
+            })
+    }
+
+    /// Deliver queued webhook notifications on a background thread
+    ///
+    /// Saving a change only queues them, so the screen never waits for the
+    /// network. Called after every key press and once a second while idle:
+    /// reports what the last flush could not deliver, and starts a new one
+    /// when something was queued or a retry may be due.
+    pub fn flush_outbox(&mut self) {
+        if self.outbox_flush.as_ref().is_some_and(|f| f.is_finished()) {
+            let finished = self.outbox_flush.take().expect("checked above").join();
+            match finished {
+                Ok(Ok(flush)) => self.add_warnings(flush.failures),
+                Ok(Err(e)) => self.add_warnings(vec![e]),
+                Err(_) => self.add_warnings(vec![Error::Webhook(
+                    "delivering the outbox stopped unexpectedly".to_string(),
+                )]),
+            }
+        }
+        if self.outbox_flush.is_some()
+            || !(self.outbox_queued.get() || self.last_flush.elapsed() >= FLUSH_EVERY)
+        {
+            return;
+        }
+
+        self.outbox_queued.set(false);
+        self.last_flush = Instant::now();
+        let storage = TeamStorage::new(self.storage.root());
+        self.outbox_flush = Some(thread::spawn(move || {
+            outbox::flush(&storage, Utc::now(), false)
+        }));
+    }
+
+    /// Add failed webhooks and shell hooks to the status message
+    ///
+    /// The change itself was saved, so this is a warning, not an error.
+    pub fn report_event_failures(&mut self) {
+        let failures = self.events.take_failures();
+        self.add_warnings(failures);
+    }
+
+    fn add_warnings(&mut self, failures: Vec<Error>) {
+        let Some(first) = failures.first() else {
+            return;
+        };
+        let warning = match failures.len() {
+            1 => format!("{}", first),
+            n => format!("{} (and {} more)", first, n - 1),
+        };
+        self.status_message = Some(match self.status_message.take() {
+            Some(message) => format!("{} - {}", message, warning),
+            None => warning,
+        });
+    }
+
+    /// Check if the current user is a leader of the loaded team
+    pub fn is_current_user_leader(&self) -> bool {
+        match (&self.team, &self.current_user) {
+            (Some(team), Some(email)) => team.is_leader(email),
+            _ => false,
+        }
+    }
+
+    /// Get the email of the member selected on the Team tab
+    pub fn selected_team_member(&self) -> Option<&str> {
+        let team = self.team.as_ref()?;
+        team.leaders
+            .iter()
+            .chain(team.members.iter())
+            .nth(self.selected_index)
+            .map(|s| s.as_str())
+    }
+
+    /// Start a promote/demote confirmation for the selected member
+    pub fn start_role_change(&mut self) {
+        let Some(email) = self.selected_team_member() else {
+            return;
+        };
+        let is_leader = self.team.as_ref().is_some_and(|t| t.is_leader(email));
+        let action = if is_leader {
+            MemberAction::Demote
+        } else {
+            MemberAction::Promote
+        };
+        self.start_member_action(action);
+    }
+
+    /// Start a confirmation for an action on the selected member
+    pub fn start_member_action(&mut self, action: MemberAction) {
+        if !self.is_current_user_leader() {
+            self.status_message = Some("Only team leaders can manage members".to_string());
+            return;
+        }
+        let Some(email) = self.selected_team_member() else {
+            return;
+        };
+        self.member_action_state = Some(MemberActionState {
+            action,
+            email: email.to_string(),
+        });
+        self.status_message = None;
+    }
+
+    /// Cancel the member action confirmation
+    pub fn cancel_member_action(&mut self) {
+        self.member_action_state = None;
+        self.status_message = Some("Cancelled".to_string());
+    }
+
+    /// Check if currently confirming a member action
+    pub fn is_member_action_mode(&self) -> bool {
+        self.member_action_state.is_some()
+    }
+
+    /// Apply the confirmed member action
+    pub fn confirm_member_action(&mut self) {
+        let Some(state) = self.member_action_state.take() else {
+            return;
+        };
+
+        let authorized = self.authorized();
+        let result = match state.action {
+            MemberAction::Promote => authorized.promote_member(&state.email),
+            MemberAction::Demote => authorized.demote_member(&state.email),
+            MemberAction::Archive => authorized.archive_member(&state.email),
+            MemberAction::Remove => authorized.remove_member(&state.email),
+        };
+
+        match result {
+            Ok(team) => {
+                self.team = Some(team);
+                let max_index = self.max_index_for_tab();
+                if self.selected_index >= max_index {
+                    self.selected_index = max_index.saturating_sub(1);
+                }
+                let verb = match state.action {
+                    MemberAction::Promote => "promoted to leader",
+                    MemberAction::Demote => "is now a member",
+                    MemberAction::Archive => "archived",
+                    MemberAction::Remove => "removed from the team",
+                };
+                self.status_message = Some(format!("{} {}", state.email, verb));
+            }
+            Err(e) => {
+                self.status_message = Some(format!("Error: {}", e));
+            }
+        }
+    }
+
     /// Start the directory navigation wizard
     pub fn start_navigate_dir(&mut self) {
         self.navigate_dir_state = Some(NavigateDirState::new(&self.working_dir));
@@ -948,23 +1646,406 @@ impl App {
         // Update to the new directory
         self.working_dir = new_dir.clone();
         self.storage = TeamStorage::new(&new_dir);
+        self.events = Self::team_events(&self.storage, &self.outbox_queued);
         self.team = self.storage.load_team().ok().flatten();
-        self.current_user = self.team.as_ref().and_then(|t| t.leaders.first().cloned());
+        self.manifesto = self.storage.team_manifesto().ok().flatten();
+        self.current_user = None;
         self.quick_actions = Self::build_quick_actions(self.storage.is_initialized());
         self.selected_index = 0;
         self.reload_interactions();
         self.status_message = Some(format!("Opened: {}", new_dir.display()));
+        self.start_login();
     }
 
-    /// Start the kudos wizard
-    pub fn start_kudos(&mut self) {
-        if !self.is_initialized() {
-            self.status_message = Some("Initialize a team first to give kudos".to_string());
+    /// Show the login screen if a team is loaded
+    pub fn start_login(&mut self) {
+        let Some(team) = &self.team else {
+            self.login_state = None;
             return;
-        }
-        self.kudos_state = Some(KudosState::default());
-        self.status_message = None;
-    }
+        };
+
+        let mut members: Vec<Member> = team
+            .leaders
+            .iter()
+            .chain(team.members.iter())
+            .map(|email| {
+                self.storage
+                    .load_member(email)
+                    .ok()
+                    .flatten()
+                    .unwrap_or_else(|| Member::new(email))
+            })
+            .collect();
+        members.sort_by_key(|m| m.display_name().to_lowercase());
+
+        let last_user = self.storage.load_last_user().ok().flatten();
+        let selected_index = last_user
+            .and_then(|email| members.iter().position(|m| m.email == email))
+            .unwrap_or(0);
+
+        self.login_state = Some(LoginState {
+            members,
+            selected_index,
+            ..LoginState::default()
+        });
+    }
+
+    /// Check if the login screen is showing
+    pub fn is_login_mode(&self) -> bool {
+        self.login_state.is_some()
+    }
+
+    /// Move the login selection up
+    pub fn login_previous(&mut self) {
+        if let Some(state) = &mut self.login_state {
+            if !state.members.is_empty() {
+                state.selected_index = if state.selected_index == 0 {
+                    state.members.len() - 1
+                } else {
+                    state.selected_index - 1
+                };
+            }
+        }
+    }
+
+    /// Move the login selection down
+    pub fn login_next(&mut self) {
+        if let Some(state) = &mut self.login_state {
+            if !state.members.is_empty() {
+                state.selected_index = (state.selected_index + 1) % state.members.len();
+            }
+        }
+    }
+
+    /// Handle character input on the pincode step
+    pub fn login_input_char(&mut self, c: char) {
+        if let Some(state) = &mut self.login_state {
+            if state.step == LoginStep::Pincode {
+                state.input_buffer.push(c);
+                state.error_message = None;
+            }
+        }
+    }
+
+    /// Handle backspace on the pincode step
+    pub fn login_input_backspace(&mut self) {
+        if let Some(state) = &mut self.login_state {
+            state.input_buffer.pop();
+        }
+    }
+
+    /// Go back to member selection, or browse read-only from there
+    ///
+    /// On a locked session this logs out instead, so someone else can log in.
+    pub fn login_back(&mut self) {
+        let Some(state) = &mut self.login_state else {
+            return;
+        };
+        if state.locked {
+            self.switch_user();
+        } else if state.step == LoginStep::Pincode {
+            state.step = LoginStep::SelectMember;
+            state.input_buffer.clear();
+            state.error_message = None;
+        } else {
+            self.login_state = None;
+            self.current_user = None;
+            self.reload_interactions();
+            self.status_message = Some("Browsing read-only as an observer".to_string());
+        }
+    }
+
+    /// Submit the current login step
+    pub fn login_submit(&mut self) {
+        let Some(state) = &mut self.login_state else {
+            return;
+        };
+
+        if state.step == LoginStep::SelectMember {
+            if let Some(email) = state.selected_member().map(|m| m.email.clone()) {
+                state.step = LoginStep::Pincode;
+                state.input_buffer.clear();
+                state.error_message = None;
+                state.retry_at = retry_at(&self.storage, &email);
+            }
+            return;
+        }
+
+        let Some(email) = state.selected_member().map(|m| m.email.clone()) else {
+            return;
+        };
+        let pincode = std::mem::take(&mut state.input_buffer);
+        if state.wait_secs().is_some() {
+            return;
+        }
+
+        match self.storage.verify_pincode(&email, &pincode) {
+            Ok(true) => self.complete_login(&email),
+            Ok(false) => {
+                state.error_message = Some("Incorrect pincode".to_string());
+                state.retry_at = retry_at(&self.storage, &email);
+            }
+            Err(interactions_core::Error::TooManyAttempts { .. }) => {
+                state.retry_at = retry_at(&self.storage, &email);
+            }
+            Err(e) => {
+                state.error_message = Some(e.to_string());
+            }
+        }
+    }
+
+    /// Log a member in once their pincode has been checked
+    fn complete_login(&mut self, email: &str) {
+        // Whatever address was used, the session holds the primary email
+        let email = &self
+            .storage
+            .resolve_email(email)
+            .unwrap_or_else(|_| normalize_email(email));
+        self.login_state = None;
+        let changed_user = self.current_user.as_deref() != Some(email);
+        self.current_user = Some(email.to_string());
+        let _ = self.storage.save_last_user(email);
+        if changed_user {
+            self.reload_interactions();
+        }
+        self.touch();
+        self.status_message = Some(format!("Logged in as {}", email));
+
+        // Nudge the member about due pulse prompts once per session
+        if !self.pulse_shown && !self.pulse_due.is_empty() {
+            self.pulse_shown = true;
+            self.pulse_state = Some(PulseState::default());
+        }
+    }
+
+    /// Lock the session, requiring the pincode to continue
+    pub fn lock(&mut self) {
+        let Some(email) = self.current_user.clone() else {
+            return;
+        };
+        // Close any open wizard so nothing half-typed stays on screen
+        self.init_state = None;
+        self.add_member_state = None;
+        self.member_action_state = None;
+        self.navigate_dir_state = None;
+        self.kudos_state = None;
+        self.feedback_state = None;
+        self.log_state = None;
+        self.okr_update_state = None;
+        self.pulse_state = None;
+        self.pincode_state = None;
+
+        let member = self
+            .storage
+            .load_member(&email)
+            .ok()
+            .flatten()
+            .unwrap_or_else(|| Member::new(&email));
+        self.login_state = Some(LoginState {
+            step: LoginStep::Pincode,
+            members: vec![member],
+            locked: true,
+            retry_at: retry_at(&self.storage, &email),
+            ..LoginState::default()
+        });
+    }
+
+    /// Log out and return to the login screen
+    pub fn switch_user(&mut self) {
+        self.current_user = None;
+        self.reload_interactions();
+        self.start_login();
+    }
+
+    /// Start changing the current user's pincode
+    pub fn start_change_pincode(&mut self) {
+        if self.current_user.is_none() {
+            self.status_message = Some("Log in to change your pincode".to_string());
+            return;
+        }
+        self.pincode_state = Some(PincodeState {
+            mode: PincodeMode::Change,
+            step: PincodeStep::Current,
+            secret: String::new(),
+            new_pincode: String::new(),
+            input_buffer: String::new(),
+            error_message: None,
+        });
+        self.status_message = None;
+    }
+
+    /// Start redeeming a reset token for the member picked on the login screen
+    pub fn start_redeem_reset(&mut self) {
+        let Some(email) = self
+            .login_state
+            .as_ref()
+            .and_then(|s| s.selected_member())
+            .map(|m| m.email.clone())
+        else {
+            return;
+        };
+        self.pincode_state = Some(PincodeState {
+            mode: PincodeMode::Redeem { email },
+            step: PincodeStep::Token,
+            secret: String::new(),
+            new_pincode: String::new(),
+            input_buffer: String::new(),
+            error_message: None,
+        });
+    }
+
+    /// Cancel the pincode wizard
+    pub fn cancel_pincode(&mut self) {
+        self.pincode_state = None;
+        if self.login_state.is_none() {
+            self.status_message = Some("Cancelled".to_string());
+        }
+    }
+
+    /// Check if currently in the pincode wizard
+    pub fn is_pincode_mode(&self) -> bool {
+        self.pincode_state.is_some()
+    }
+
+    /// Handle character input in the pincode wizard
+    pub fn pincode_input_char(&mut self, c: char) {
+        if let Some(state) = &mut self.pincode_state {
+            state.input_buffer.push(c);
+            state.error_message = None;
+        }
+    }
+
+    /// Handle backspace in the pincode wizard
+    pub fn pincode_input_backspace(&mut self) {
+        if let Some(state) = &mut self.pincode_state {
+            state.input_buffer.pop();
+        }
+    }
+
+    /// Submit the current pincode wizard step
+    pub fn pincode_submit(&mut self) {
+        let Some(state) = &mut self.pincode_state else {
+            return;
+        };
+        let input = std::mem::take(&mut state.input_buffer);
+
+        match state.step {
+            PincodeStep::Current | PincodeStep::Token => {
+                if input.is_empty() {
+                    state.error_message = Some("This field is required".to_string());
+                    return;
+                }
+                state.secret = input;
+                state.step = PincodeStep::New;
+            }
+            PincodeStep::New => {
+                if input.len() < 4 {
+                    state.error_message = Some("Pincode must be at least 4 characters".to_string());
+                    return;
+                }
+                state.new_pincode = input;
+                state.step = PincodeStep::Confirm;
+            }
+            PincodeStep::Confirm => {
+                if input != state.new_pincode {
+                    state.error_message = Some("Pincodes do not match".to_string());
+                    state.step = PincodeStep::New;
+                    state.new_pincode.clear();
+                    return;
+                }
+                self.complete_pincode();
+            }
+        }
+    }
+
+    /// Apply the pincode change or reset
+    fn complete_pincode(&mut self) {
+        let Some(state) = self.pincode_state.take() else {
+            return;
+        };
+
+        let result = match &state.mode {
+            PincodeMode::Change => self
+                .authorized()
+                .change_pincode(&state.secret, &state.new_pincode),
+            PincodeMode::Redeem { email } => {
+                self.storage
+                    .redeem_reset_token(email, &state.secret, &state.new_pincode)
+            }
+        };
+
+        match (result, state.mode.clone()) {
+            (Ok(()), PincodeMode::Change) => {
+                self.status_message = Some("Pincode changed".to_string());
+            }
+            (Ok(()), PincodeMode::Redeem { email }) => {
+                self.complete_login(&email);
+                self.status_message = Some(format!("Pincode reset, logged in as {}", email));
+            }
+            (Err(e), _) => {
+                // Start over from the first step so a wrong secret can be re-entered
+                let first = state.steps()[0];
+                self.pincode_state = Some(PincodeState {
+                    step: first,
+                    secret: String::new(),
+                    new_pincode: String::new(),
+                    error_message: Some(e.to_string()),
+                    ..state
+                });
+            }
+        }
+    }
+
+    /// Issue a pincode reset token for the member selected on the Team tab
+    pub fn issue_reset_token(&mut self) {
+        if !self.is_current_user_leader() {
+            self.status_message = Some("Only team leaders can reset pincodes".to_string());
+            return;
+        }
+        let Some(email) = self.selected_team_member().map(|e| e.to_string()) else {
+            return;
+        };
+
+        self.status_message = Some(match self.authorized().issue_reset_token(&email) {
+            Ok(token) => format!(
+                "Reset token for {}: {} (valid 24h, commit .team/ to share)",
+                email, token
+            ),
+            Err(e) => format!("Error: {}", e),
+        });
+    }
+
+    /// Record user activity
+    pub fn touch(&mut self) {
+        self.last_activity = Instant::now();
+    }
+
+    /// Lock the session if it has been idle for too long
+    pub fn check_inactivity(&mut self) {
+        if self.current_user.is_some()
+            && !self.is_login_mode()
+            && self.last_activity.elapsed() >= LOCK_AFTER
+        {
+            self.lock();
+        }
+    }
+
+    /// Start the kudos wizard
+    pub fn start_kudos(&mut self) {
+        if !self.is_initialized() {
+            self.status_message = Some("Initialize a team first to give kudos".to_string());
+            return;
+        }
+        if self.current_user.is_none() {
+            self.status_message = Some("Log in to give kudos (press l)".to_string());
+            return;
+        }
+        self.kudos_state = Some(KudosState {
+            picker: RecipientPicker::new(self.recipient_directory()),
+            ..KudosState::default()
+        });
+        self.status_message = None;
+    }
 
     /// Cancel the kudos wizard
     pub fn cancel_kudos(&mut self) {
@@ -982,18 +2063,34 @@ impl App {
         if let Some(state) = &mut self.kudos_state {
             state.input_buffer.push(c);
             state.error_message = None;
+            if state.step == KudosStep::Recipient {
+                state.picker.update(&state.input_buffer);
+            }
         }
     }
 
     /// Handle backspace during kudos mode
     pub fn kudos_input_backspace(&mut self) {
         if let Some(state) = &mut self.kudos_state {
+            if state.step == KudosStep::Recipient && state.input_buffer.is_empty() {
+                // Backspace on an empty input removes the last recipient
+                state.recipients.pop();
+                return;
+            }
             state.input_buffer.pop();
+            if state.step == KudosStep::Recipient {
+                state.picker.update(&state.input_buffer);
+            }
         }
     }
 
     /// Submit the current kudos step
     pub fn kudos_submit(&mut self) {
+        let team = self.whole_team();
+        let has_principles = self
+            .manifesto
+            .as_ref()
+            .is_some_and(|m| !m.principles.is_empty());
         let should_complete = {
             let Some(state) = &mut self.kudos_state else {
                 return;
@@ -1004,11 +2101,18 @@ impl App {
             // Validate and store based on current step
             match state.step {
                 KudosStep::Recipient => {
-                    if input.is_empty() {
-                        state.error_message = Some("Please enter a recipient".to_string());
-                        return;
+                    match add_recipients(&mut state.picker, &mut state.recipients, &input, &team) {
+                        Ok(true) => {}
+                        Ok(false) => {
+                            state.input_buffer.clear();
+                            state.picker.update("");
+                            return;
+                        }
+                        Err(message) => {
+                            state.error_message = Some(message);
+                            return;
+                        }
                     }
-                    state.recipient = input;
                 }
                 KudosStep::Note => {
                     if input.is_empty() {
@@ -1017,14 +2121,26 @@ impl App {
                     }
                     state.note = input;
                 }
+                KudosStep::Principle => match parse_principles(&input, self.manifesto.as_ref()) {
+                    Ok(principles) => state.principles = principles,
+                    Err(message) => {
+                        state.error_message = Some(message);
+                        return;
+                    }
+                },
                 KudosStep::Share => {
                     let lower = input.to_lowercase();
                     state.shared = lower == "y" || lower == "yes";
                 }
             }
 
-            // Move to next step or complete
-            if let Some(next_step) = state.step.next() {
+            // Move to next step or complete, skipping the principle step
+            // when the manifesto has none
+            let next = match state.step.next() {
+                Some(KudosStep::Principle) if !has_principles => Some(KudosStep::Share),
+                next => next,
+            };
+            if let Some(next_step) = next {
                 state.step = next_step;
                 state.input_buffer.clear();
                 false
@@ -1043,24 +2159,26 @@ impl App {
         let Some(state) = self.kudos_state.take() else {
             return;
         };
-
-        let from = self
-            .current_user
-            .clone()
-            .unwrap_or_else(|| "unknown".to_string());
+        let Some(from) = self.current_user.clone() else {
+            return;
+        };
 
         let mut interaction =
-            Interaction::appreciation(&from, vec![state.recipient.clone()], &state.note);
+            Interaction::appreciation(&from, state.recipients.clone(), &state.note)
+                .with_principles(state.principles.clone());
 
         if state.shared {
             interaction = interaction.shared();
         }
 
-        match self.storage.save_kudos(&interaction) {
+        match self.authorized().save_kudos(&interaction) {
             Ok(()) => {
                 let share_text = if state.shared { " (shared)" } else { "" };
-                self.status_message =
-                    Some(format!("Kudos sent to {}!{}", state.recipient, share_text));
+                self.status_message = Some(format!(
+                    "Kudos sent to {}!{}",
+                    state.recipients.join(", "),
+                    share_text
+                ));
                 self.reload_interactions();
             }
             Err(e) => {
@@ -1069,13 +2187,92 @@ impl App {
         }
     }
 
+    /// Active team members that kudos and feedback can be sent to
+    fn recipient_directory(&self) -> Vec<Member> {
+        let members = self.storage.load_members().unwrap_or_default();
+        match &self.team {
+            Some(team) => members
+                .into_iter()
+                .filter(|m| team.is_member(&m.email))
+                .collect(),
+            None => members,
+        }
+    }
+
+    /// Everyone in the team except the current user, for the whole-team shortcut
+    fn whole_team(&self) -> Vec<String> {
+        let Some(team) = &self.team else {
+            return Vec::new();
+        };
+        let me = self.current_user.as_deref().unwrap_or_default();
+        team.leaders
+            .iter()
+            .chain(team.members.iter())
+            .filter(|email| !interactions_core::same_email(email, me))
+            .cloned()
+            .collect()
+    }
+
+    /// Highlight the previous recipient suggestion in the open wizard
+    pub fn recipient_previous(&mut self) {
+        if let Some(picker) = self.recipient_picker() {
+            picker.previous();
+        }
+    }
+
+    /// Highlight the next recipient suggestion in the open wizard
+    pub fn recipient_next(&mut self) {
+        if let Some(picker) = self.recipient_picker() {
+            picker.next();
+        }
+    }
+
+    /// The recipient picker of the open wizard, if it is on the recipient step
+    fn recipient_picker(&mut self) -> Option<&mut RecipientPicker> {
+        if let Some(state) = &mut self.kudos_state {
+            return (state.step == KudosStep::Recipient).then_some(&mut state.picker);
+        }
+        if let Some(state) = &mut self.feedback_state {
+            return (state.step == FeedbackStep::Recipient).then_some(&mut state.picker);
+        }
+        if let Some(state) = &mut self.log_state {
+            return (state.step == LogStep::With).then_some(&mut state.picker);
+        }
+        None
+    }
+
     /// Start the feedback wizard
     pub fn start_feedback(&mut self) {
         if !self.is_initialized() {
             self.status_message = Some("Initialize a team first to share feedback".to_string());
             return;
         }
-        self.feedback_state = Some(FeedbackState::default());
+        if self.current_user.is_none() {
+            self.status_message = Some("Log in to share feedback (press l)".to_string());
+            return;
+        }
+        self.feedback_state = Some(FeedbackState {
+            picker: RecipientPicker::new(self.recipient_directory()),
+            ..FeedbackState::default()
+        });
+        self.status_message = None;
+    }
+
+    /// Start the feedback wizard to ask teammates for feedback
+    pub fn start_feedback_request(&mut self) {
+        if !self.is_initialized() {
+            self.status_message = Some("Initialize a team first to ask for feedback".to_string());
+            return;
+        }
+        if self.current_user.is_none() {
+            self.status_message = Some("Log in to ask for feedback (press l)".to_string());
+            return;
+        }
+        self.feedback_state = Some(FeedbackState {
+            request: true,
+            picker: RecipientPicker::new(self.recipient_directory()),
+            ..FeedbackState::default()
+        });
         self.status_message = None;
     }
 
@@ -1095,18 +2292,30 @@ impl App {
         if let Some(state) = &mut self.feedback_state {
             state.input_buffer.push(c);
             state.error_message = None;
+            if state.step == FeedbackStep::Recipient {
+                state.picker.update(&state.input_buffer);
+            }
         }
     }
 
     /// Handle backspace during feedback mode
     pub fn feedback_input_backspace(&mut self) {
         if let Some(state) = &mut self.feedback_state {
+            if state.step == FeedbackStep::Recipient && state.input_buffer.is_empty() {
+                // Backspace on an empty input removes the last recipient
+                state.recipients.pop();
+                return;
+            }
             state.input_buffer.pop();
+            if state.step == FeedbackStep::Recipient {
+                state.picker.update(&state.input_buffer);
+            }
         }
     }
 
     /// Submit the current feedback step
     pub fn feedback_submit(&mut self) {
+        let team = self.whole_team();
         let should_complete = {
             let Some(state) = &mut self.feedback_state else {
                 return;
@@ -1117,14 +2326,21 @@ impl App {
             // Validate and store based on current step
             match state.step {
                 FeedbackStep::Recipient => {
-                    if input.is_empty() {
-                        state.error_message = Some("Please enter a recipient".to_string());
-                        return;
+                    match add_recipients(&mut state.picker, &mut state.recipients, &input, &team) {
+                        Ok(true) => {}
+                        Ok(false) => {
+                            state.input_buffer.clear();
+                            state.picker.update("");
+                            return;
+                        }
+                        Err(message) => {
+                            state.error_message = Some(message);
+                            return;
+                        }
                     }
-                    state.recipient = input;
                 }
                 FeedbackStep::Note => {
-                    if input.is_empty() {
+                    if input.is_empty() && !state.request {
                         state.error_message = Some("Please write your feedback".to_string());
                         return;
                     }
@@ -1137,7 +2353,7 @@ impl App {
             }
 
             // Move to next step or complete
-            if let Some(next_step) = state.step.next() {
+            if let Some(next_step) = state.step.next(state.request) {
                 state.step = next_step;
                 state.input_buffer.clear();
                 false
@@ -1156,25 +2372,27 @@ impl App {
         let Some(state) = self.feedback_state.take() else {
             return;
         };
+        let Some(from) = self.current_user.clone() else {
+            return;
+        };
+        if state.request {
+            self.complete_feedback_request(&from, &state);
+            return;
+        }
 
-        let from = self
-            .current_user
-            .clone()
-            .unwrap_or_else(|| "unknown".to_string());
-
-        let mut interaction =
-            Interaction::feedback(&from, vec![state.recipient.clone()], &state.note);
+        let mut interaction = Interaction::feedback(&from, state.recipients.clone(), &state.note);
 
         if state.shared {
             interaction = interaction.shared();
         }
 
-        match self.storage.save_feedback(&interaction) {
+        match self.authorized().save_feedback(&interaction) {
             Ok(()) => {
                 let share_text = if state.shared { " (shared)" } else { "" };
                 self.status_message = Some(format!(
                     "Feedback sent to {}!{}",
-                    state.recipient, share_text
+                    state.recipients.join(", "),
+                    share_text
                 ));
                 self.reload_interactions();
             }
@@ -1184,6 +2402,352 @@ impl App {
         }
     }
 
+    /// Ask each chosen teammate for feedback
+    fn complete_feedback_request(&mut self, from: &str, state: &FeedbackState) {
+        for to in &state.recipients {
+            let mut request = FeedbackRequest::new(from, to);
+            if !state.note.is_empty() {
+                request = request.with_topic(&state.note);
+            }
+            if let Err(e) = self.authorized().save_feedback_request(&request) {
+                self.status_message = Some(format!("Error asking for feedback: {}", e));
+                return;
+            }
+        }
+        self.status_message = Some(format!(
+            "Asked {} for feedback",
+            state.recipients.join(", ")
+        ));
+    }
+
+    /// Start the wizard for logging a check-in, retrospective or apology
+    pub fn start_log(&mut self, kind: InteractionKind) {
+        let what = kind.label().to_lowercase();
+        if !self.is_initialized() {
+            self.status_message = Some(format!("Initialize a team first to log a {}", what));
+            return;
+        }
+        if self.current_user.is_none() {
+            self.status_message = Some(format!("Log in to log a {} (press l)", what));
+            return;
+        }
+        self.log_state = Some(LogState {
+            kind,
+            step: LogStep::default(),
+            recipients: Vec::new(),
+            picker: RecipientPicker::new(self.recipient_directory()),
+            note: String::new(),
+            shared: false,
+            input_buffer: String::new(),
+            error_message: None,
+        });
+        self.status_message = None;
+    }
+
+    /// Cancel the log wizard
+    pub fn cancel_log(&mut self) {
+        if let Some(state) = self.log_state.take() {
+            self.status_message = Some(format!("{} cancelled", state.kind.label()));
+        }
+    }
+
+    /// Check if currently in log mode
+    pub fn is_log_mode(&self) -> bool {
+        self.log_state.is_some()
+    }
+
+    /// Handle character input during log mode
+    pub fn log_input_char(&mut self, c: char) {
+        if let Some(state) = &mut self.log_state {
+            state.input_buffer.push(c);
+            state.error_message = None;
+            if state.step == LogStep::With {
+                state.picker.update(&state.input_buffer);
+            }
+        }
+    }
+
+    /// Handle backspace during log mode
+    pub fn log_input_backspace(&mut self) {
+        if let Some(state) = &mut self.log_state {
+            if state.step == LogStep::With && state.input_buffer.is_empty() {
+                // Backspace on an empty input removes the last participant
+                state.recipients.pop();
+                return;
+            }
+            state.input_buffer.pop();
+            if state.step == LogStep::With {
+                state.picker.update(&state.input_buffer);
+            }
+        }
+    }
+
+    /// Submit the current log step
+    pub fn log_submit(&mut self) {
+        let team = self.whole_team();
+        let should_complete = {
+            let Some(state) = &mut self.log_state else {
+                return;
+            };
+
+            let input = state.input_buffer.trim().to_string();
+            match state.step {
+                LogStep::With => {
+                    match add_recipients(&mut state.picker, &mut state.recipients, &input, &team) {
+                        Ok(true) => {}
+                        Ok(false) => {
+                            state.input_buffer.clear();
+                            state.picker.update("");
+                            return;
+                        }
+                        Err(message) => {
+                            state.error_message = Some(message);
+                            return;
+                        }
+                    }
+                }
+                LogStep::Note => {
+                    if input.is_empty() {
+                        state.error_message = Some("Please write a short note".to_string());
+                        return;
+                    }
+                    state.note = input;
+                }
+                LogStep::Share => {
+                    let lower = input.to_lowercase();
+                    state.shared = lower == "y" || lower == "yes";
+                }
+            }
+
+            if let Some(next_step) = state.step.next() {
+                state.step = next_step;
+                state.input_buffer.clear();
+                false
+            } else {
+                true
+            }
+        };
+
+        if should_complete {
+            self.complete_log();
+        }
+    }
+
+    /// Save the logged interaction
+    fn complete_log(&mut self) {
+        let Some(state) = self.log_state.take() else {
+            return;
+        };
+        let Some(from) = self.current_user.clone() else {
+            return;
+        };
+
+        let mut interaction =
+            Interaction::new(state.kind, &from, state.recipients.clone(), &state.note);
+        if state.shared {
+            interaction = interaction.shared();
+        }
+
+        match self.authorized().save_interaction(&interaction) {
+            Ok(()) => {
+                let share_text = if state.shared { " (shared)" } else { "" };
+                self.status_message = Some(format!(
+                    "{} with {} logged!{}",
+                    state.kind.label(),
+                    state.recipients.join(", "),
+                    share_text
+                ));
+                self.reload_interactions();
+            }
+            Err(e) => {
+                self.status_message = Some(format!("Error saving {}: {}", state.kind.label(), e));
+            }
+        }
+    }
+
+    /// Start the wizard for updating an objective's key results
+    pub fn start_okr_update(&mut self, objective: Objective) {
+        if self.current_user.is_none() {
+            self.status_message = Some("Log in to update objectives (press l)".to_string());
+            return;
+        }
+        self.okr_update_state = Some(OkrUpdateState {
+            objective,
+            index: 0,
+            input_buffer: String::new(),
+            error_message: None,
+        });
+        self.status_message = None;
+    }
+
+    /// Cancel the OKR update wizard
+    pub fn cancel_okr_update(&mut self) {
+        self.okr_update_state = None;
+        self.status_message = Some("OKR update cancelled".to_string());
+    }
+
+    /// Check if currently in OKR update mode
+    pub fn is_okr_update_mode(&self) -> bool {
+        self.okr_update_state.is_some()
+    }
+
+    /// Handle character input during OKR update mode
+    pub fn okr_update_input_char(&mut self, c: char) {
+        if let Some(state) = &mut self.okr_update_state {
+            state.input_buffer.push(c);
+            state.error_message = None;
+        }
+    }
+
+    /// Handle backspace during OKR update mode
+    pub fn okr_update_input_backspace(&mut self) {
+        if let Some(state) = &mut self.okr_update_state {
+            state.input_buffer.pop();
+        }
+    }
+
+    /// Submit the progress of the current key result
+    ///
+    /// An empty input keeps the progress as it was.
+    pub fn okr_update_submit(&mut self) {
+        let should_complete = {
+            let Some(state) = &mut self.okr_update_state else {
+                return;
+            };
+            if state.index < state.objective.key_results.len() {
+                let input = state.input_buffer.trim();
+                if !input.is_empty() {
+                    match parse_progress(input) {
+                        Ok(progress) => {
+                            state.objective.set_progress(state.index, progress);
+                        }
+                        Err(message) => {
+                            state.error_message = Some(message);
+                            return;
+                        }
+                    }
+                }
+                state.index += 1;
+                state.input_buffer.clear();
+            }
+            state.index >= state.objective.key_results.len()
+        };
+
+        if should_complete {
+            self.complete_okr_update();
+        }
+    }
+
+    /// Save the updated objective
+    fn complete_okr_update(&mut self) {
+        let Some(state) = self.okr_update_state.take() else {
+            return;
+        };
+        let mut objective = state.objective;
+        // Reviewing without changing a number still counts as an update
+        objective.touch();
+
+        match self.authorized().save_objective(&objective) {
+            Ok(()) => {
+                self.status_message = Some(format!(
+                    "Updated '{}' ({:.0}%)",
+                    objective.title,
+                    objective.overall_progress() * 100.0
+                ));
+                self.reload_interactions();
+            }
+            Err(e) => {
+                self.status_message = Some(format!("Error saving objective: {}", e));
+            }
+        }
+    }
+
+    /// Show the pulse prompts that are due for the current user
+    pub fn show_pulse_prompts(&mut self) {
+        if self.pulse_due.is_empty() {
+            self.status_message = Some("Nothing due, you're all caught up".to_string());
+            return;
+        }
+        self.pulse_state = Some(PulseState::default());
+    }
+
+    /// Check if the pulse prompts popup is open
+    pub fn is_pulse_mode(&self) -> bool {
+        self.pulse_state.is_some()
+    }
+
+    /// Close the pulse prompts popup for now
+    pub fn dismiss_pulse(&mut self) {
+        self.pulse_state = None;
+    }
+
+    /// Highlight the previous pulse prompt
+    pub fn pulse_previous(&mut self) {
+        let count = self.pulse_due.len();
+        if count == 0 {
+            return;
+        }
+        if let Some(state) = &mut self.pulse_state {
+            state.selected = (state.selected + count - 1) % count;
+        }
+    }
+
+    /// Highlight the next pulse prompt
+    pub fn pulse_next(&mut self) {
+        let count = self.pulse_due.len();
+        if let Some(state) = &mut self.pulse_state {
+            state.selected = (state.selected + 1) % count.max(1);
+        }
+    }
+
+    /// Open the wizard for a pulse prompt, or the highlighted one if `None`
+    pub fn open_pulse_prompt(&mut self, index: Option<usize>) {
+        let Some(state) = &self.pulse_state else {
+            return;
+        };
+        let Some(due) = self.pulse_due.get(index.unwrap_or(state.selected)).cloned() else {
+            return;
+        };
+        self.pulse_state = None;
+        match due.prompt {
+            PulsePrompt::CheckIn => self.start_log(InteractionKind::CheckIn),
+            PulsePrompt::Retro => self.start_log(InteractionKind::Retrospective),
+            PulsePrompt::OkrUpdate => match due.objective {
+                Some(objective) => self.start_okr_update(objective),
+                None => {
+                    self.status_message =
+                        Some("This OKR update has no objective to update".to_string());
+                }
+            },
+        }
+    }
+
+    /// Snooze the highlighted pulse prompt until tomorrow
+    pub fn snooze_pulse(&mut self) {
+        let Some(state) = &mut self.pulse_state else {
+            return;
+        };
+        if state.selected >= self.pulse_due.len() {
+            return;
+        }
+        let due = self.pulse_due.remove(state.selected);
+        if state.selected >= self.pulse_due.len() {
+            state.selected = self.pulse_due.len().saturating_sub(1);
+        }
+        if self.pulse_due.is_empty() {
+            self.pulse_state = None;
+        }
+
+        let now = Utc::now();
+        self.status_message = match self
+            .storage
+            .snooze_pulse_prompt(&due, now + PULSE_SNOOZE, now)
+        {
+            Ok(()) => Some(format!("{} snoozed until tomorrow", due.prompt.label())),
+            Err(e) => Some(format!("Error snoozing prompt: {}", e)),
+        };
+    }
+
     /// Get the maximum selectable index for the current tab
     fn max_index_for_tab(&self) -> usize {
         match self.current_tab {
@@ -1193,6 +2757,7 @@ impl App {
                 .as_ref()
                 .map(|t| t.members.len() + t.leaders.len() + 1) // +1 for "Add Member" action
                 .unwrap_or(0),
+            Tab::History => self.history.len(),
             _ => 0,
         }
     }
@@ -1225,6 +2790,63 @@ impl App {
             .and_then(|email| self.storage.load_received_feedback(email).ok())
             .unwrap_or_default();
         self.interaction_index = 0;
+
+        let viewer = self
+            .current_user
+            .as_ref()
+            .and_then(|email| self.storage.load_member(email).ok().flatten());
+        self.viewer_tz = time::member_timezone(viewer.as_ref());
+
+        self.analytics = Analytics::load(&self.storage).unwrap_or_default();
+        self.members = self.storage.load_members().unwrap_or_default();
+        self.objectives = self.storage.load_objectives(true).unwrap_or_default();
+        self.pending_requests = Vec::new();
+        self.pulse_due = Vec::new();
+        self.sync_conflicts = self.storage.sync_conflicts().unwrap_or_default();
+        if let Some(email) = &self.current_user {
+            self.objectives
+                .extend(self.storage.load_objectives(false).unwrap_or_default());
+            self.pending_requests = self
+                .storage
+                .pending_feedback_requests(email)
+                .unwrap_or_default();
+            let now = Utc::now();
+            let snoozes = self.storage.load_pulse_snoozes().unwrap_or_default();
+            self.pulse_due = Pulse::load(&self.storage)
+                .map(|pulse| pulse::without_snoozed(pulse.due_for(email, now), &snoozes, now))
+                .unwrap_or_default();
+        }
+        self.principle_kudos = self
+            .manifesto
+            .as_ref()
+            .map(|m| {
+                m.recognition(self.analytics.interactions())
+                    .into_iter()
+                    .map(|(p, count)| (p.id.clone(), count))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        // The history depends on who may see private items
+        self.history.clear();
+        self.load_history_for_tab();
+    }
+
+    /// A member's display name, or the email if they have no profile
+    pub fn display_name<'a>(&'a self, email: &'a str) -> &'a str {
+        self.members
+            .iter()
+            .find(|m| m.has_email(email))
+            .map_or(email, |m| m.display_name())
+    }
+
+    /// Number of kudos received in the viewer's current week
+    pub fn kudos_received_this_week(&self) -> usize {
+        let now = Utc::now();
+        self.received_kudos
+            .iter()
+            .filter(|k| time::is_this_week(k.timestamp, now, self.viewer_tz))
+            .count()
     }
 
     /// Toggle between sent and received views
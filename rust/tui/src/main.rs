@@ -9,9 +9,18 @@ use crossterm::{
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
-use interactions_core::{Member, Team, TeamConfig, TeamStorage};
+use interactions_core::export::{self, Export, ExportFilter, ExportFormat};
+use interactions_core::history;
+use interactions_core::import::{self, ImportMapping};
+use interactions_core::merge;
+use interactions_core::outbox::{self, Status};
+use interactions_core::pulse::Pulse;
+use interactions_core::webhook::{self, Notification};
+use interactions_core::{
+    git, lint, publish, time, AuthorizedStorage, Member, ShellHook, Team, TeamConfig, TeamStorage,
+};
 use ratatui::prelude::*;
-use std::{env, io, io::Write, process};
+use std::{env, fs, io, io::Write, path::Path, process, time::Duration};
 
 fn main() -> io::Result<()> {
     let args: Vec<String> = env::args().collect();
@@ -29,24 +38,19 @@ fn main() -> io::Result<()> {
 fn run_command(args: &[String]) -> io::Result<()> {
     match args[0].as_str() {
         "init" => run_init(),
-        "publish" => {
-            println!("Publishing content from .team/ sources...");
-            // TODO: Implement publish command
-            println!("Publish command not yet implemented");
-            Ok(())
-        }
-        "lint" => {
-            println!("Linting .team/ structure...");
-            // TODO: Implement lint command
-            println!("Lint command not yet implemented");
-            Ok(())
-        }
-        "pulse" => {
-            println!("Sending pulse reminders...");
-            // TODO: Implement pulse command
-            println!("Pulse command not yet implemented");
-            Ok(())
-        }
+        "publish" => run_publish(&args[1..]),
+        "lint" => run_lint(&args[1..]),
+        "pincode" => run_pincode(&args[1..]),
+        "merge-members" => run_merge_members(&args[1..]),
+        "principles" => run_principles(),
+        "export" => run_export(&args[1..]),
+        "import" => run_import(&args[1..]),
+        "pulse" => run_pulse(&args[1..]),
+        "outbox" => run_outbox(&args[1..]),
+        "hooks" => run_hooks(&args[1..]),
+        "merge-driver" => run_merge_driver(&args[1..]),
+        "conflicts" => run_conflicts(&args[1..]),
+        "history" => run_history(&args[1..]),
         "backup" => {
             println!("Backing up to protected branch...");
             // TODO: Implement backup command
@@ -90,8 +94,53 @@ USAGE:
 COMMANDS:
     init        Initialize a new team in the current directory
     publish     Generate markdown files from .team/ sources
+                  --timezone <tz>    Show timestamps in this IANA timezone
     lint        Validate .team/ structure (for PR checks)
-    pulse       Send reminders via configured webhooks
+                  --commits <range>  Also check commit authors against team roles
+                  --staged           Only check staged files (used by the pre-commit hook)
+    pincode     Manage pincodes
+                  change             Change your own pincode
+                  reset <email>      Issue a one-time reset token (leaders)
+                  redeem             Set a new pincode with a reset token
+    merge-members <from> <into>
+                Fold one member's directory into another (leaders)
+    principles  Show how often kudos named each manifesto principle
+    export      Export interactions and OKRs
+                  --format <csv|json|md>  Output format (default: json)
+                  --kind <kinds>     Comma-separated kinds, e.g. kudos,feedback,okr
+                  --since <date>     Only interactions from this date (YYYY-MM-DD)
+                  --output <file>    Write to a file instead of stdout
+                  --private          Log in to include your private items
+    import <slack|csv> <path>
+                Import appreciation history (leaders)
+                  --mapping <file>   YAML mapping of source users to member emails
+                  --pattern <regex>  Slack messages that count as kudos
+                  --dry-run          Show what would be imported without writing
+    pulse       Show which pulse prompts are due for whom
+                  --member <email>   Only prompts for this member
+                  --send             Post the prompts to the configured webhooks
+    outbox      Webhook notifications waiting to be delivered from this machine
+                  list               Show queued, failed and recently delivered ones
+                  flush              Send everything not delivered yet, now
+                  drop <id|--all>    Remove entries without sending them
+    hooks install
+                Install a git pre-commit hook that runs 'lint --staged' and
+                configure the merge driver for this clone
+                  --force            Replace an existing pre-commit hook
+    hooks list  Show the shell hooks in .team/config.yaml and whether they
+                may run on this machine
+    hooks approve <id|--all>
+                Let shell hooks run on this machine; a changed command has to
+                be approved again
+    merge-driver <base> <ours> <theirs> [<path>]
+                Merge .team/ YAML files as models (run by git)
+    history [<path|member|objective>]
+                Show who changed .team/ files, field by field, from git
+                  --limit <n>        Only the last n commits
+    conflicts   Copies cloud sync clients made of files changed on two machines
+                  list               Show each copy and how it differs
+                  keep <copy>        Replace the original with the copy
+                  drop <copy>        Delete the copy, keeping the original
     backup      Backup to protected branch (maintainers)
     restore     Restore from git history
     help        Print this help message
@@ -126,6 +175,24 @@ fn prompt_password(message: &str) -> io::Result<String> {
     Ok(input)
 }
 
+/// The value following a command-line flag, exiting if it is missing
+fn flag_value<'a>(args: &'a [String], flag: &str) -> Option<&'a str> {
+    let pos = args.iter().position(|a| a == flag)?;
+    match args.get(pos + 1) {
+        Some(value) => Some(value.as_str()),
+        None => {
+            eprintln!("Error: {} needs a value", flag);
+            process::exit(1);
+        }
+    }
+}
+
+/// Print an error and exit
+fn fail(e: interactions_core::Error) -> ! {
+    eprintln!("Error: {}", e);
+    process::exit(1);
+}
+
 /// Run the init command to create a new team
 fn run_init() -> io::Result<()> {
     let storage = TeamStorage::new(".");
@@ -186,7 +253,12 @@ fn run_init() -> io::Result<()> {
             println!("\nCreated:");
             println!("  .team/     - Team data (commit to git)");
             println!("  .personal/ - Your private data (gitignored)");
+            if git::configure_merge_driver(Path::new("."), &own_command()).is_ok() {
+                println!("  .gitattributes - Merges .team/ files as models, not lines");
+            }
             println!("\nRun 'interactions' to launch the TUI.");
+            println!("Run 'interactions hooks install' in each clone to check commits");
+            println!("before they are made and merge .team/ files as models.");
         }
         Err(e) => {
             eprintln!("Error initializing team: {}", e);
@@ -197,6 +269,803 @@ fn run_init() -> io::Result<()> {
     Ok(())
 }
 
+/// Run the publish command to generate markdown from .team/ sources
+fn run_publish(args: &[String]) -> io::Result<()> {
+    let storage = TeamStorage::new(".");
+
+    let timezone = match args.iter().position(|a| a == "--timezone") {
+        Some(pos) => {
+            let Some(name) = args.get(pos + 1) else {
+                eprintln!("Usage: interactions publish --timezone <tz>");
+                process::exit(1);
+            };
+            match time::parse_timezone(name) {
+                Ok(tz) => Some(tz),
+                Err(e) => {
+                    eprintln!("Error: {}", e);
+                    process::exit(1);
+                }
+            }
+        }
+        None => None,
+    };
+
+    println!("Publishing content from .team/ sources...");
+    match publish::publish(&storage, timezone) {
+        Ok(written) if written.is_empty() => {
+            println!("Nothing to publish. Configure paths under 'publish' in .team/config.yaml.");
+            Ok(())
+        }
+        Ok(written) => {
+            for path in written {
+                println!("  wrote {}", path.display());
+            }
+            Ok(())
+        }
+        Err(e) => {
+            eprintln!("Error publishing: {}", e);
+            process::exit(1);
+        }
+    }
+}
+
+/// Run the principles command: kudos per manifesto principle
+fn run_principles() -> io::Result<()> {
+    let storage = TeamStorage::new(".");
+    let report = storage.team_manifesto().and_then(|manifesto| {
+        let kudos = storage.load_team_kudos()?;
+        Ok((manifesto.unwrap_or_default(), kudos))
+    });
+    let (manifesto, kudos) = match report {
+        Ok(report) => report,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            process::exit(1);
+        }
+    };
+
+    if manifesto.principles.is_empty() {
+        println!("The manifesto has no principles yet.");
+        return Ok(());
+    }
+    for (principle, count) in manifesto.recognition(&kudos) {
+        println!("{:>4}  {} ({})", count, principle.title, principle.id);
+    }
+    Ok(())
+}
+
+/// Run the export command
+fn run_export(args: &[String]) -> io::Result<()> {
+    let storage = TeamStorage::new(".");
+    if !storage.is_initialized() {
+        eprintln!("Error: No team found. Run 'interactions init' first.");
+        process::exit(1);
+    }
+
+    let format: ExportFormat = flag_value(args, "--format")
+        .unwrap_or("json")
+        .parse()
+        .unwrap_or_else(|e| fail(e));
+    let mut filter = ExportFilter::all();
+    if let Some(kinds) = flag_value(args, "--kind") {
+        filter = filter.with_kinds(kinds).unwrap_or_else(|e| fail(e));
+    }
+    if let Some(since) = flag_value(args, "--since") {
+        filter = filter.since(export::parse_since(since).unwrap_or_else(|e| fail(e)));
+    }
+
+    // Private items are only exported for their authenticated owner
+    let email;
+    let session = if args.iter().any(|a| a == "--private") {
+        email = prompt("Your email: ")?;
+        let pincode = prompt_password("Your pincode: ")?;
+        AuthorizedStorage::login(&storage, &email, &pincode).unwrap_or_else(|e| fail(e))
+    } else {
+        AuthorizedStorage::new(&storage, None)
+    };
+
+    let output = Export::collect(&session, &filter)
+        .and_then(|export| export.render(format))
+        .unwrap_or_else(|e| fail(e));
+    match flag_value(args, "--output") {
+        Some(path) => {
+            fs::write(path, output)?;
+            eprintln!("Exported to {}", path);
+        }
+        None => print!("{}", output),
+    }
+    Ok(())
+}
+
+/// Import appreciation history from a Slack export or a CSV file
+fn run_import(args: &[String]) -> io::Result<()> {
+    let (Some(source), Some(path)) = (args.first(), args.get(1)) else {
+        eprintln!("Usage: interactions import <slack|csv> <path> [--mapping <file>] [--dry-run]");
+        process::exit(1);
+    };
+    let storage = TeamStorage::new(".");
+    if !storage.is_initialized() {
+        eprintln!("Error: No team found. Run 'interactions init' first.");
+        process::exit(1);
+    }
+
+    let mut mapping = match flag_value(args, "--mapping") {
+        Some(file) => ImportMapping::load(Path::new(file)).unwrap_or_else(|e| fail(e)),
+        None => ImportMapping::default(),
+    };
+    if let Some(pattern) = flag_value(args, "--pattern") {
+        mapping.kudos_pattern = Some(pattern.to_string());
+    }
+    let members = storage.load_members().unwrap_or_else(|e| fail(e));
+
+    let plan = match source.as_str() {
+        "slack" => import::plan_slack(Path::new(path), &mapping, &members),
+        "csv" => import::plan_csv(&fs::read_to_string(path)?, &mapping, &members),
+        other => {
+            eprintln!("Unknown import source '{}'. Use slack or csv.", other);
+            process::exit(1);
+        }
+    }
+    .unwrap_or_else(|e| fail(e));
+
+    print!("{}", plan.preview());
+    if args.iter().any(|a| a == "--dry-run") || plan.interactions.is_empty() {
+        return Ok(());
+    }
+
+    let email = prompt("Leader email: ")?;
+    let pincode = prompt_password("Pincode: ")?;
+    let session = AuthorizedStorage::login(&storage, &email, &pincode).unwrap_or_else(|e| fail(e));
+    let created = session
+        .import_interactions(&plan.interactions)
+        .unwrap_or_else(|e| fail(e));
+    println!(
+        "Imported {} interaction(s), {} already imported.",
+        created,
+        plan.interactions.len() - created
+    );
+    Ok(())
+}
+
+/// List the pulse prompts that are due, per member
+fn run_pulse(args: &[String]) -> io::Result<()> {
+    let storage = TeamStorage::new(".");
+    let team = match storage.load_team() {
+        Ok(Some(team)) => team,
+        Ok(None) => {
+            eprintln!("Error: No team found. Run 'interactions init' first.");
+            process::exit(1);
+        }
+        Err(e) => {
+            eprintln!("Error loading team: {}", e);
+            process::exit(1);
+        }
+    };
+    // Only shared data, since the reminders can go out to team webhooks
+    let pulse = match Pulse::load_shared(&storage) {
+        Ok(pulse) => pulse,
+        Err(e) => {
+            eprintln!("Error loading pulse schedule: {}", e);
+            process::exit(1);
+        }
+    };
+    if pulse.config().is_none() {
+        println!("No pulse configured. Add a 'pulse' section to .team/config.yaml.");
+        return Ok(());
+    }
+
+    let now = chrono::Utc::now();
+    let due = match args.iter().position(|a| a == "--member") {
+        Some(pos) => {
+            let Some(email) = args.get(pos + 1) else {
+                eprintln!("Usage: interactions pulse --member <email>");
+                process::exit(1);
+            };
+            pulse.due_for(email, now)
+        }
+        None => pulse.due(&team, now),
+    };
+    if due.is_empty() {
+        println!("Nothing due.");
+        return Ok(());
+    }
+
+    let mut member = None;
+    for prompt in &due {
+        if member != Some(&prompt.member) {
+            println!("{}", prompt.member);
+            member = Some(&prompt.member);
+        }
+        println!("  {}: {}", prompt.prompt.label(), prompt.message(now));
+    }
+
+    if args.iter().any(|a| a == "--send") {
+        let hooks = storage
+            .load_config()
+            .ok()
+            .flatten()
+            .and_then(|c| c.webhooks)
+            .map(|w| webhook::endpoints(&w))
+            .unwrap_or_default();
+        if hooks.is_empty() {
+            eprintln!("No webhooks configured in .team/config.yaml.");
+            process::exit(1);
+        }
+        let mut queued = 0;
+        for prompt in &due {
+            match outbox::enqueue(&storage, &hooks, &Notification::pulse(prompt, now), now) {
+                Ok(count) => queued += count,
+                Err(e) => {
+                    eprintln!("Error queueing reminder: {}", e);
+                    process::exit(1);
+                }
+            }
+        }
+        if queued == 0 {
+            println!("These reminders were already sent today.");
+        }
+        report_flush(outbox::flush(&storage, now, false));
+    }
+    Ok(())
+}
+
+/// List, send or drop the webhook notifications in the outbox
+fn run_outbox(args: &[String]) -> io::Result<()> {
+    let storage = TeamStorage::new(".");
+    let now = chrono::Utc::now();
+
+    match args.first().map(|s| s.as_str()) {
+        None | Some("list") => {
+            let entries = match storage.load_outbox() {
+                Ok(entries) => entries,
+                Err(e) => {
+                    eprintln!("Error loading outbox: {}", e);
+                    process::exit(1);
+                }
+            };
+            if entries.is_empty() {
+                println!("The outbox is empty.");
+                return Ok(());
+            }
+            for entry in &entries {
+                let notification = entry.notification();
+                println!(
+                    "{}  {:<9}  {:<20}  {}",
+                    entry.id,
+                    entry.status().label(),
+                    notification.event(),
+                    entry.hook.label()
+                );
+                println!("    {}", notification.text());
+                match entry.status() {
+                    Status::Pending if entry.attempts > 0 => println!(
+                        "    {} attempt(s), next at {}",
+                        entry.attempts,
+                        entry.next_attempt.format("%Y-%m-%d %H:%M UTC")
+                    ),
+                    Status::Failed => println!("    gave up after {} attempts", entry.attempts),
+                    _ => {}
+                }
+                if let Some(error) = &entry.last_error {
+                    println!("    {}", error);
+                }
+            }
+            Ok(())
+        }
+        Some("flush") => {
+            report_flush(outbox::flush(&storage, now, true));
+            Ok(())
+        }
+        Some("drop") => {
+            let Some(id) = args.get(1) else {
+                eprintln!("Usage: interactions outbox drop <id|--all>");
+                process::exit(1);
+            };
+            let prefix = if id == "--all" { "" } else { id.as_str() };
+            match outbox::discard(&storage, prefix) {
+                Ok(0) => {
+                    eprintln!("No outbox entry matches '{}'.", id);
+                    process::exit(1);
+                }
+                Ok(count) => println!("Dropped {} entry(ies).", count),
+                Err(e) => {
+                    eprintln!("Error: {}", e);
+                    process::exit(1);
+                }
+            }
+            Ok(())
+        }
+        Some(_) => {
+            eprintln!("Usage: interactions outbox <list|flush|drop <id|--all>>");
+            process::exit(1);
+        }
+    }
+}
+
+/// Show who changed .team files, field by field, from git history
+fn run_history(args: &[String]) -> io::Result<()> {
+    let mut limit = None;
+    let mut target = None;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--limit" => match args.next().and_then(|n| n.parse().ok()) {
+                Some(n) => limit = Some(n),
+                None => {
+                    eprintln!(
+                        "Usage: interactions history [<path|member|objective>] [--limit <n>]"
+                    );
+                    process::exit(1);
+                }
+            },
+            arg if target.is_none() => target = Some(arg),
+            arg => {
+                eprintln!("Unexpected argument: {}", arg);
+                process::exit(1);
+            }
+        }
+    }
+
+    let storage = TeamStorage::new(".");
+    let entries = history::Scope::parse(&storage, target)
+        .and_then(|scope| history::history(&storage, &scope, limit).map(|e| (scope, e)));
+    let (scope, entries) = match entries {
+        Ok(found) => found,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            process::exit(1);
+        }
+    };
+    if entries.is_empty() {
+        println!("No changes to {} in the git history.", scope);
+        return Ok(());
+    }
+
+    for entry in &entries {
+        let commit = &entry.commit;
+        println!(
+            "{}  {}  {} <{}>  {}",
+            &commit.hash[..8],
+            commit.date.format("%Y-%m-%d %H:%M UTC"),
+            entry.author_name(),
+            commit.author_email,
+            commit.subject
+        );
+        for file in &entry.files {
+            println!("    {}", file.path);
+            if file.changes.is_empty() {
+                println!("        (formatting only)");
+            }
+            for change in &file.changes {
+                println!("        {}", change);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// List sync conflict copies, or keep or drop one of them
+fn run_conflicts(args: &[String]) -> io::Result<()> {
+    let storage = TeamStorage::new(".");
+    let conflicts = match storage.sync_conflicts() {
+        Ok(conflicts) => conflicts,
+        Err(e) => {
+            eprintln!("Error finding sync conflicts: {}", e);
+            process::exit(1);
+        }
+    };
+    let relative = |path: &Path| {
+        path.strip_prefix(storage.root())
+            .unwrap_or(path)
+            .to_string_lossy()
+            .replace('\\', "/")
+    };
+
+    match args.first().map(|s| s.as_str()) {
+        None | Some("list") => {
+            if conflicts.is_empty() {
+                println!("No sync conflict copies found.");
+                return Ok(());
+            }
+            for conflict in &conflicts {
+                println!("{}", relative(&conflict.copy));
+                if !conflict.original.exists() {
+                    println!(
+                        "    copy of {}, which no longer exists",
+                        relative(&conflict.original)
+                    );
+                    continue;
+                }
+                println!("    copy of {}", relative(&conflict.original));
+                match conflict.diff() {
+                    Ok(changes) if changes.is_empty() => println!("    identical"),
+                    Ok(changes) => {
+                        for change in changes {
+                            println!("    {}", change);
+                        }
+                    }
+                    Err(e) => println!("    cannot compare: {}", e),
+                }
+            }
+            println!();
+            println!("Resolve each with 'interactions conflicts keep <copy>' or 'drop <copy>'.");
+            Ok(())
+        }
+        Some(action @ ("keep" | "drop")) => {
+            let Some(copy) = args.get(1) else {
+                eprintln!("Usage: interactions conflicts {} <copy>", action);
+                process::exit(1);
+            };
+            let copy = copy.trim_start_matches("./").replace('\\', "/");
+            let Some(conflict) = conflicts.iter().find(|c| relative(&c.copy) == copy) else {
+                eprintln!("{} is not a sync conflict copy.", copy);
+                process::exit(1);
+            };
+            let result = match action {
+                "keep" => conflict.keep_copy(),
+                _ => conflict.keep_original(),
+            };
+            match result {
+                Ok(()) if action == "keep" => {
+                    println!("Replaced {} with {}.", relative(&conflict.original), copy)
+                }
+                Ok(()) => println!("Deleted {}.", copy),
+                Err(e) => {
+                    eprintln!("Error: {}", e);
+                    process::exit(1);
+                }
+            }
+            Ok(())
+        }
+        Some(_) => {
+            eprintln!("Usage: interactions conflicts <list|keep <copy>|drop <copy>>");
+            process::exit(1);
+        }
+    }
+}
+
+/// Print what an outbox flush did, exiting with an error if anything failed
+fn report_flush(result: interactions_core::Result<outbox::Flush>) {
+    let flush = match result {
+        Ok(flush) => flush,
+        Err(e) => {
+            eprintln!("Error sending notifications: {}", e);
+            process::exit(1);
+        }
+    };
+    for error in &flush.failures {
+        eprintln!("Error: {}", error);
+    }
+    println!("Delivered {} notification(s).", flush.delivered);
+    if flush.waiting > 0 {
+        println!(
+            "{} waiting for a retry; 'interactions outbox flush' sends them now.",
+            flush.waiting
+        );
+    }
+    if !flush.failures.is_empty() {
+        eprintln!(
+            "{} failed and will be retried; see 'interactions outbox list'.",
+            flush.failures.len()
+        );
+        process::exit(1);
+    }
+}
+
+/// Run the lint command to validate .team/ and, optionally, commit permissions
+fn run_lint(args: &[String]) -> io::Result<()> {
+    if args.iter().any(|a| a == "--staged") {
+        return run_lint_staged();
+    }
+    let storage = TeamStorage::new(".");
+
+    println!("Linting .team/ structure...");
+    let mut issues = match lint::lint_storage(&storage) {
+        Ok(issues) => issues,
+        Err(e) => {
+            eprintln!("Error linting .team/: {}", e);
+            process::exit(1);
+        }
+    };
+
+    if let Some(pos) = args.iter().position(|a| a == "--commits") {
+        let Some(range) = args.get(pos + 1) else {
+            eprintln!("Usage: interactions lint --commits <range>");
+            process::exit(1);
+        };
+        println!("Checking commits in {}...", range);
+        match lint::lint_commits(Path::new("."), range) {
+            Ok(commit_issues) => issues.extend(commit_issues),
+            Err(e) => {
+                eprintln!("Error checking commits: {}", e);
+                process::exit(1);
+            }
+        }
+    }
+
+    if issues.is_empty() {
+        println!("No issues found.");
+        return Ok(());
+    }
+
+    for issue in &issues {
+        eprintln!("  {}", issue);
+    }
+    eprintln!("\n{} issue(s) found.", issues.len());
+    process::exit(1);
+}
+
+/// Check the staged files, exiting with an error so git aborts the commit
+fn run_lint_staged() -> io::Result<()> {
+    let issues = match lint::lint_staged(Path::new(".")) {
+        Ok(issues) => issues,
+        Err(e) => {
+            eprintln!("Error checking staged files: {}", e);
+            process::exit(1);
+        }
+    };
+    if issues.is_empty() {
+        return Ok(());
+    }
+
+    for issue in &issues {
+        eprintln!("  {}", issue);
+    }
+    eprintln!(
+        "\n{} issue(s) in staged files; commit aborted.",
+        issues.len()
+    );
+    process::exit(1);
+}
+
+/// Install the git pre-commit hook
+fn run_hooks(args: &[String]) -> io::Result<()> {
+    match args.first().map(|s| s.as_str()) {
+        Some("install") => {}
+        Some("list") => return run_hooks_list(),
+        Some("approve") => return run_hooks_approve(&args[1..]),
+        _ => {
+            eprintln!("Usage: interactions hooks <install [--force]|list|approve <id|--all>>");
+            process::exit(1);
+        }
+    }
+    let force = args.iter().any(|a| a == "--force");
+    let command = own_command();
+
+    let installed =
+        git::install_pre_commit_hook(Path::new("."), &command, force).and_then(|path| {
+            git::configure_merge_driver(Path::new("."), &command)?;
+            Ok(path)
+        });
+    match installed {
+        Ok(path) => {
+            println!("Installed {}", path.display());
+            println!("Commits now fail if they stage .personal/ or invalid .team/ files,");
+            println!("and git merges .team/ files listed in .gitattributes as models.");
+            Ok(())
+        }
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            process::exit(1);
+        }
+    }
+}
+
+/// The shell hooks in .team/config.yaml
+fn shell_hooks(storage: &TeamStorage) -> Vec<ShellHook> {
+    match storage.load_config() {
+        Ok(config) => config.map(|c| c.hooks).unwrap_or_default(),
+        Err(e) => fail(e),
+    }
+}
+
+/// Show the team's shell hooks and whether they may run on this machine
+fn run_hooks_list() -> io::Result<()> {
+    let storage = TeamStorage::new(".");
+    let hooks = shell_hooks(&storage);
+    if hooks.is_empty() {
+        println!("No shell hooks configured in .team/config.yaml.");
+        return Ok(());
+    }
+    let approved = storage.load_approved_hooks().unwrap_or_else(|e| fail(e));
+    for hook in &hooks {
+        let digest = hook.digest();
+        let status = if approved.contains(&digest) {
+            "approved"
+        } else {
+            "not approved"
+        };
+        let on = if hook.on.is_empty() {
+            "every event".to_string()
+        } else {
+            hook.on.join(", ")
+        };
+        println!("{}  {:<12}  {}", &digest[..12], status, on);
+        println!("    {}", hook.run);
+    }
+    Ok(())
+}
+
+/// Allow shell hooks from .team/config.yaml to run on this machine
+fn run_hooks_approve(args: &[String]) -> io::Result<()> {
+    let Some(id) = args.first() else {
+        eprintln!("Usage: interactions hooks approve <id|--all>");
+        process::exit(1);
+    };
+    let storage = TeamStorage::new(".");
+    let prefix = if id == "--all" { "" } else { id.as_str() };
+    let matching: Vec<ShellHook> = shell_hooks(&storage)
+        .into_iter()
+        .filter(|hook| hook.digest().starts_with(prefix))
+        .collect();
+    if matching.is_empty() {
+        eprintln!("No shell hook matches '{}'.", id);
+        process::exit(1);
+    }
+    for hook in &matching {
+        if let Err(e) = storage.approve_hook(hook) {
+            fail(e);
+        }
+        println!("Approved: {}", hook.run);
+    }
+    Ok(())
+}
+
+/// This binary, for git to call back; works without it being on PATH
+fn own_command() -> String {
+    env::current_exe()
+        .map(|p| p.display().to_string())
+        .unwrap_or_else(|_| "interactions".to_string())
+}
+
+/// Merge a .team/ YAML file for git, which passes the common ancestor, our
+/// version (where the result goes) and their version
+fn run_merge_driver(args: &[String]) -> io::Result<()> {
+    let (base, current, other, path) = match args {
+        [base, current, other] => (base, current, other, current),
+        [base, current, other, path, ..] => (base, current, other, path),
+        _ => {
+            eprintln!("Usage: interactions merge-driver <base> <ours> <theirs> [<path>]");
+            process::exit(1);
+        }
+    };
+    // Classify the file from .team/ on, wherever the team lives in the repo
+    let path = path.find(".team/").map_or(path.as_str(), |i| &path[i..]);
+
+    let merged = merge::merge(
+        path,
+        &fs::read_to_string(base)?,
+        &fs::read_to_string(current)?,
+        &fs::read_to_string(other)?,
+    );
+    match merged {
+        Ok(merged) if merged.is_clean() => return fs::write(current, merged.content),
+        Ok(merged) => eprintln!(
+            "{}: both sides changed {}",
+            path,
+            merged.conflicts.join(", ")
+        ),
+        Err(e) => eprintln!("{}: {}", path, e),
+    }
+
+    // Leave the usual conflict markers to resolve by hand
+    if let Err(e) = git::merge_file(Path::new(current), Path::new(base), Path::new(other)) {
+        eprintln!("Error: {}", e);
+    }
+    process::exit(1);
+}
+
+/// Prompt for a new pincode twice, exiting if it is too short or does not match
+fn prompt_new_pincode() -> io::Result<String> {
+    let pincode = prompt_password("New pincode: ")?;
+    if pincode.len() < 4 {
+        eprintln!("Error: Pincode must be at least 4 characters");
+        process::exit(1);
+    }
+    let pincode_confirm = prompt_password("Confirm new pincode: ")?;
+    if pincode != pincode_confirm {
+        eprintln!("Error: Pincodes do not match");
+        process::exit(1);
+    }
+    Ok(pincode)
+}
+
+/// Run the pincode command to change, reset or redeem a pincode
+fn run_pincode(args: &[String]) -> io::Result<()> {
+    let storage = TeamStorage::new(".");
+    if !storage.is_initialized() {
+        eprintln!("Error: No team found. Run 'interactions init' first.");
+        process::exit(1);
+    }
+
+    let result = match args.first().map(|s| s.as_str()) {
+        Some("change") => {
+            let email = prompt("Your email: ")?;
+            let current = prompt_password("Current pincode: ")?;
+            let new_pincode = prompt_new_pincode()?;
+            storage
+                .change_pincode(&email, &current, &new_pincode)
+                .map(|()| "Pincode changed.".to_string())
+        }
+        Some("reset") => {
+            let Some(email) = args.get(1) else {
+                eprintln!("Usage: interactions pincode reset <email>");
+                process::exit(1);
+            };
+            let leader = prompt("Your email (team leader): ")?;
+            let pincode = prompt_password("Your pincode: ")?;
+            AuthorizedStorage::login(&storage, &leader, &pincode)
+                .and_then(|authorized| authorized.issue_reset_token(email))
+                .map(|token| {
+                    format!(
+                        "Reset token for {}: {}\n\
+                         It is valid for 24 hours and can be used once.\n\
+                         Commit .team/ and hand the token over in person.",
+                        email, token
+                    )
+                })
+        }
+        Some("redeem") => {
+            let email = prompt("Your email: ")?;
+            let token = prompt("Reset token: ")?;
+            let new_pincode = prompt_new_pincode()?;
+            storage
+                .redeem_reset_token(&email, &token, &new_pincode)
+                .map(|()| "Pincode reset. Commit .team/ to remove the used token.".to_string())
+        }
+        _ => {
+            eprintln!("Usage: interactions pincode <change|reset <email>|redeem>");
+            process::exit(1);
+        }
+    };
+
+    match result {
+        Ok(message) => {
+            println!("{}", message);
+            Ok(())
+        }
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            process::exit(1);
+        }
+    }
+}
+
+/// Run the merge-members command to fold one member into another
+fn run_merge_members(args: &[String]) -> io::Result<()> {
+    let [from, into] = args else {
+        eprintln!("Usage: interactions merge-members <from> <into>");
+        process::exit(1);
+    };
+
+    let storage = TeamStorage::new(".");
+    if !storage.is_initialized() {
+        eprintln!("Error: No team found. Run 'interactions init' first.");
+        process::exit(1);
+    }
+
+    println!("Merging {} into {}.", from, into);
+    println!(
+        "Kudos and feedback move over and {} becomes an alias.\n",
+        from
+    );
+    let leader = prompt("Your email (team leader): ")?;
+    let pincode = prompt_password("Your pincode: ")?;
+
+    match AuthorizedStorage::login(&storage, &leader, &pincode)
+        .and_then(|authorized| authorized.merge_members(from, into))
+    {
+        Ok(_) => {
+            println!("Merged. Commit .team/ to share the change.");
+            Ok(())
+        }
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            process::exit(1);
+        }
+    }
+}
+
 /// Run the interactive TUI
 fn run_tui() -> io::Result<()> {
     // Setup terminal
@@ -227,10 +1096,46 @@ fn run_app<B: Backend>(terminal: &mut Terminal<B>, app: &mut App) -> io::Result<
     loop {
         terminal.draw(|f| ui::render(f, app))?;
 
+        // Wake up periodically so an idle session can be locked
+        if !event::poll(Duration::from_secs(1))? {
+            app.check_inactivity();
+            app.flush_outbox();
+            continue;
+        }
+
         if let Event::Key(key) = event::read()? {
             if key.kind == KeyEventKind::Press {
-                // Handle init mode separately
-                if app.is_init_mode() {
+                app.touch();
+
+                // The pincode wizard can open on top of the login screen
+                if app.is_pincode_mode() {
+                    match key.code {
+                        KeyCode::Esc => app.cancel_pincode(),
+                        KeyCode::Enter => app.pincode_submit(),
+                        KeyCode::Backspace => app.pincode_input_backspace(),
+                        KeyCode::Char(c) => app.pincode_input_char(c),
+                        _ => {}
+                    }
+                } else if let Some(state) = &app.login_state {
+                    // Handle login screen first, it covers everything else
+                    match (state.step, key.code) {
+                        (app::LoginStep::SelectMember, KeyCode::Char('q')) => return Ok(()),
+                        (app::LoginStep::SelectMember, KeyCode::Up | KeyCode::Char('k')) => {
+                            app.login_previous()
+                        }
+                        (app::LoginStep::SelectMember, KeyCode::Down | KeyCode::Char('j')) => {
+                            app.login_next()
+                        }
+                        (app::LoginStep::Pincode, KeyCode::Char(c)) => app.login_input_char(c),
+                        (app::LoginStep::Pincode, KeyCode::Backspace) => {
+                            app.login_input_backspace()
+                        }
+                        (app::LoginStep::Pincode, KeyCode::Tab) => app.start_redeem_reset(),
+                        (_, KeyCode::Enter) => app.login_submit(),
+                        (_, KeyCode::Esc) => app.login_back(),
+                        _ => {}
+                    }
+                } else if app.is_init_mode() {
                     match key.code {
                         KeyCode::Esc => app.cancel_init(),
                         KeyCode::Enter => app.init_submit(),
@@ -247,6 +1152,13 @@ fn run_app<B: Backend>(terminal: &mut Terminal<B>, app: &mut App) -> io::Result<
                         KeyCode::Char(c) => app.add_member_input_char(c),
                         _ => {}
                     }
+                } else if app.is_member_action_mode() {
+                    // Handle member action confirmation
+                    match key.code {
+                        KeyCode::Esc | KeyCode::Char('n') => app.cancel_member_action(),
+                        KeyCode::Enter | KeyCode::Char('y') => app.confirm_member_action(),
+                        _ => {}
+                    }
                 } else if app.is_navigate_dir_mode() {
                     // Handle directory navigation mode
                     if let Some(state) = &app.navigate_dir_state {
@@ -277,6 +1189,8 @@ fn run_app<B: Backend>(terminal: &mut Terminal<B>, app: &mut App) -> io::Result<
                     match key.code {
                         KeyCode::Esc => app.cancel_kudos(),
                         KeyCode::Enter => app.kudos_submit(),
+                        KeyCode::Up => app.recipient_previous(),
+                        KeyCode::Down => app.recipient_next(),
                         KeyCode::Backspace => app.kudos_input_backspace(),
                         KeyCode::Char(c) => app.kudos_input_char(c),
                         _ => {}
@@ -286,10 +1200,44 @@ fn run_app<B: Backend>(terminal: &mut Terminal<B>, app: &mut App) -> io::Result<
                     match key.code {
                         KeyCode::Esc => app.cancel_feedback(),
                         KeyCode::Enter => app.feedback_submit(),
+                        KeyCode::Up => app.recipient_previous(),
+                        KeyCode::Down => app.recipient_next(),
                         KeyCode::Backspace => app.feedback_input_backspace(),
                         KeyCode::Char(c) => app.feedback_input_char(c),
                         _ => {}
                     }
+                } else if app.is_log_mode() {
+                    // Handle check-in, retrospective and apology mode
+                    match key.code {
+                        KeyCode::Esc => app.cancel_log(),
+                        KeyCode::Enter => app.log_submit(),
+                        KeyCode::Up => app.recipient_previous(),
+                        KeyCode::Down => app.recipient_next(),
+                        KeyCode::Backspace => app.log_input_backspace(),
+                        KeyCode::Char(c) => app.log_input_char(c),
+                        _ => {}
+                    }
+                } else if app.is_okr_update_mode() {
+                    match key.code {
+                        KeyCode::Esc => app.cancel_okr_update(),
+                        KeyCode::Enter => app.okr_update_submit(),
+                        KeyCode::Backspace => app.okr_update_input_backspace(),
+                        KeyCode::Char(c) => app.okr_update_input_char(c),
+                        _ => {}
+                    }
+                } else if app.is_pulse_mode() {
+                    // Handle the pulse prompts popup
+                    match key.code {
+                        KeyCode::Esc | KeyCode::Char('q') => app.dismiss_pulse(),
+                        KeyCode::Up | KeyCode::Char('k') => app.pulse_previous(),
+                        KeyCode::Down | KeyCode::Char('j') => app.pulse_next(),
+                        KeyCode::Enter => app.open_pulse_prompt(None),
+                        KeyCode::Char('s') => app.snooze_pulse(),
+                        KeyCode::Char(c @ '1'..='9') => {
+                            app.open_pulse_prompt(Some(c as usize - '1' as usize))
+                        }
+                        _ => {}
+                    }
                 } else {
                     match key.code {
                         KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
@@ -320,19 +1268,51 @@ fn run_app<B: Backend>(terminal: &mut Terminal<B>, app: &mut App) -> io::Result<
                             app.next_subtab();
                         }
                         KeyCode::Enter => app.select_item(),
-                        KeyCode::Char('a') if app.current_tab == app::Tab::Team => {
+                        KeyCode::Char('a')
+                            if app.current_tab == app::Tab::Team && app.team.is_some() =>
+                        {
                             // Quick shortcut to add member when on Team tab
-                            if app.team.is_some() {
-                                app.start_add_member();
-                            }
+                            app.start_add_member();
+                        }
+                        KeyCode::Char('p') if app.current_tab == app::Tab::Team => {
+                            app.start_role_change();
+                        }
+                        KeyCode::Char('x') if app.current_tab == app::Tab::Team => {
+                            app.start_member_action(app::MemberAction::Archive);
+                        }
+                        KeyCode::Char('d') if app.current_tab == app::Tab::Team => {
+                            app.start_member_action(app::MemberAction::Remove);
+                        }
+                        KeyCode::Char('r') if app.current_tab == app::Tab::Team => {
+                            app.issue_reset_token();
+                        }
+                        KeyCode::Char('u') if app.current_tab == app::Tab::Dashboard => {
+                            app.show_pulse_prompts();
+                        }
+                        KeyCode::Char('f') if app.current_tab == app::Tab::Dashboard => {
+                            app.start_feedback_request();
+                        }
+                        KeyCode::Char('c') if app.current_tab == app::Tab::Settings => {
+                            app.start_change_pincode();
                         }
                         KeyCode::Char('o') => {
                             // Quick shortcut to open folder navigator
                             app.start_navigate_dir();
                         }
+                        KeyCode::Char('l') => {
+                            // Lock the session, or log in when browsing read-only
+                            if app.current_user.is_some() {
+                                app.lock();
+                            } else {
+                                app.start_login();
+                            }
+                        }
                         _ => {}
                     }
                 }
+
+                app.report_event_failures();
+                app.flush_outbox();
             }
         }
     }
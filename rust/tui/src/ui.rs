@@ -2,30 +2,37 @@
 
 use crate::app::{
     AddMemberStep, App, FeedbackStep, InitStep, InteractionsSubTab, InteractionsView, KudosStep,
-    Tab,
+    LogStep, LoginStep, MemberAction, PincodeMode, RecipientPicker, Tab,
 };
+use chrono::Utc;
+use interactions_core::time;
 use ratatui::{
     prelude::*,
     widgets::{
-        Block, Borders, Clear, List, ListItem, Paragraph, Scrollbar, ScrollbarOrientation,
-        ScrollbarState, Tabs, Wrap,
+        Block, Borders, Clear, LineGauge, List, ListItem, Paragraph, Scrollbar,
+        ScrollbarOrientation, ScrollbarState, Sparkline, Tabs, Wrap,
     },
 };
 
 /// Render the entire UI
 pub fn render(frame: &mut Frame, app: &App) {
+    let banner = if app.sync_conflicts.is_empty() { 0 } else { 1 };
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
-            Constraint::Length(3), // Header with tabs
-            Constraint::Min(0),    // Main content
-            Constraint::Length(3), // Footer with help and path
+            Constraint::Length(3),      // Header with tabs
+            Constraint::Length(banner), // Sync conflict banner
+            Constraint::Min(0),         // Main content
+            Constraint::Length(3),      // Footer with help and path
         ])
         .split(frame.area());
 
     render_header(frame, app, chunks[0]);
-    render_content(frame, app, chunks[1]);
-    render_footer(frame, app, chunks[2]);
+    if banner > 0 {
+        render_sync_banner(frame, app, chunks[1]);
+    }
+    render_content(frame, app, chunks[2]);
+    render_footer(frame, app, chunks[3]);
 
     // Render init wizard overlay if in init mode
     if app.is_init_mode() {
@@ -37,6 +44,11 @@ pub fn render(frame: &mut Frame, app: &App) {
         render_add_member_wizard(frame, app);
     }
 
+    // Render member action confirmation overlay
+    if app.is_member_action_mode() {
+        render_member_action_dialog(frame, app);
+    }
+
     // Render directory navigation wizard overlay if in navigate mode
     if app.is_navigate_dir_mode() {
         render_navigate_dir_wizard(frame, app);
@@ -51,6 +63,31 @@ pub fn render(frame: &mut Frame, app: &App) {
     if app.is_feedback_mode() {
         render_feedback_wizard(frame, app);
     }
+
+    // Render check-in, retrospective and apology wizard overlay
+    if app.is_log_mode() {
+        render_log_wizard(frame, app);
+    }
+
+    // Render OKR update wizard overlay
+    if app.is_okr_update_mode() {
+        render_okr_update_wizard(frame, app);
+    }
+
+    // Render due pulse prompts overlay
+    if app.is_pulse_mode() {
+        render_pulse_prompts(frame, app);
+    }
+
+    // Render login screen over everything else
+    if app.is_login_mode() {
+        render_login_screen(frame, app);
+    }
+
+    // Render pincode wizard overlay, which can also open from the login screen
+    if app.is_pincode_mode() {
+        render_pincode_wizard(frame, app);
+    }
 }
 
 /// Render the header with tabs
@@ -67,12 +104,13 @@ fn render_header(frame: &mut Frame, app: &App, area: Rect) {
         })
         .collect();
 
+    let title = match &app.current_user {
+        Some(email) => format!(" interactions.work · {} ", email),
+        None => " interactions.work ".to_string(),
+    };
+
     let tabs = Tabs::new(titles)
-        .block(
-            Block::default()
-                .borders(Borders::ALL)
-                .title(" interactions.work "),
-        )
+        .block(Block::default().borders(Borders::ALL).title(title))
         .highlight_style(Style::default().fg(Color::Yellow))
         .select(
             Tab::all()
@@ -91,18 +129,31 @@ fn render_content(frame: &mut Frame, app: &App, area: Rect) {
         Tab::Team => render_team(frame, app, area),
         Tab::Interactions => render_interactions(frame, app, area),
         Tab::Okrs => render_okrs(frame, app, area),
+        Tab::History => render_history(frame, app, area),
         Tab::Settings => render_settings(frame, app, area),
     }
 }
 
 /// Render the dashboard tab
 fn render_dashboard(frame: &mut Frame, app: &App, area: Rect) {
-    let chunks = Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
-        .split(area);
+    // Stack everything on narrow terminals, side by side otherwise
+    let narrow = area.width < NARROW_WIDTH;
+    let chunks = if narrow {
+        Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(app.quick_actions.len() as u16 + 2),
+                Constraint::Min(0),
+            ])
+            .split(area)
+    } else {
+        Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(35), Constraint::Percentage(65)])
+            .split(area)
+    };
 
-    // Quick actions on the left
+    // Quick actions
     let items: Vec<ListItem> = app
         .quick_actions
         .iter()
@@ -130,31 +181,186 @@ fn render_dashboard(frame: &mut Frame, app: &App, area: Rect) {
 
     frame.render_widget(actions_list, chunks[0]);
 
-    // Info panel on the right
-    let info_text = if app.is_initialized() {
-        format!(
-            "Team: {}\n\nWelcome to interactions.work!\n\n\
-             Use Tab to switch between sections.\n\
+    if !app.is_initialized() {
+        let info = Paragraph::new(
+            "No team initialized.\n\n\
+             Select 'Initialize Team' to set up a new team,\n\
+             or navigate to a directory with a .team/ folder.\n\n\
              Use ↑/↓ or j/k to navigate.\n\
              Press Enter to select.\n\
              Press q to quit.",
+        )
+        .block(Block::default().borders(Borders::ALL).title(" Welcome "))
+        .wrap(Wrap { trim: true });
+        frame.render_widget(info, chunks[1]);
+        return;
+    }
+
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(5), Constraint::Min(0)])
+        .split(chunks[1]);
+    render_activity_sparkline(frame, app, rows[0]);
+
+    if app.current_user.is_none() {
+        let info = Paragraph::new(format!(
+            "Team: {}\n\nLog in (press l) to see your kudos, objectives\n\
+             and feedback requests.",
             app.team_name()
+        ))
+        .block(Block::default().borders(Borders::ALL).title(" Welcome "))
+        .wrap(Wrap { trim: true });
+        frame.render_widget(info, rows[1]);
+        return;
+    }
+
+    let (kudos_area, okrs_area, requests_area) = if narrow {
+        let parts = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Ratio(1, 3),
+                Constraint::Ratio(1, 3),
+                Constraint::Ratio(1, 3),
+            ])
+            .split(rows[1]);
+        (parts[0], parts[1], parts[2])
+    } else {
+        let columns = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(rows[1]);
+        let left = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
+            .split(columns[0]);
+        (left[0], columns[1], left[1])
+    };
+
+    render_recent_kudos(frame, app, kudos_area);
+    render_okr_gauges(frame, app, okrs_area);
+    render_pending(frame, app, requests_area);
+}
+
+/// Below this width the dashboard stacks its panels vertically
+const NARROW_WIDTH: u16 = 80;
+
+/// Number of weeks of team activity shown on the dashboard
+const ACTIVITY_WEEKS: usize = 12;
+
+/// Render team interactions per week as a sparkline
+fn render_activity_sparkline(frame: &mut Frame, app: &App, area: Rect) {
+    let activity = app
+        .analytics
+        .activity(ACTIVITY_WEEKS, Utc::now(), app.viewer_tz);
+    let data: Vec<u64> = activity.iter().map(|a| a.count as u64).collect();
+    let this_week = data.last().copied().unwrap_or_default();
+
+    let mut title = format!(" Team activity, last {} weeks ", ACTIVITY_WEEKS);
+    if app.current_user.is_some() {
+        title.push_str(&format!(
+            "· you got {} kudos this week ",
+            app.kudos_received_this_week()
+        ));
+    }
+    let sparkline = Sparkline::default()
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(title)
+                .title_bottom(Line::from(format!(" {} this week ", this_week)).right_aligned()),
         )
+        .data(&data)
+        .style(Style::default().fg(Color::Magenta));
+    frame.render_widget(sparkline, area);
+}
+
+/// Render the most recent kudos the user received
+fn render_recent_kudos(frame: &mut Frame, app: &App, area: Rect) {
+    let visible = area.height.saturating_sub(2) as usize;
+    let items: Vec<ListItem> = if app.received_kudos.is_empty() {
+        vec![ListItem::new("No kudos yet").style(Style::default().fg(Color::DarkGray))]
     } else {
-        "No team initialized.\n\n\
-         Select 'Initialize Team' to set up a new team,\n\
-         or navigate to a directory with a .team/ folder.\n\n\
-         Use ↑/↓ or j/k to navigate.\n\
-         Press Enter to select.\n\
-         Press q to quit."
-            .to_string()
+        app.received_kudos
+            .iter()
+            .take(visible)
+            .map(|kudos| {
+                ListItem::new(Line::from(vec![
+                    Span::styled(
+                        time::format_in(kudos.timestamp, app.viewer_tz, "%m/%d "),
+                        Style::default().fg(Color::DarkGray),
+                    ),
+                    Span::styled(
+                        app.display_name(&kudos.from),
+                        Style::default().fg(Color::Magenta),
+                    ),
+                    Span::raw(format!(": {}", kudos.note.replace('\n', " "))),
+                ]))
+            })
+            .collect()
     };
 
-    let info = Paragraph::new(info_text)
-        .block(Block::default().borders(Borders::ALL).title(" Welcome "))
-        .wrap(Wrap { trim: true });
+    let list = List::new(items).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(" Recent kudos "),
+    );
+    frame.render_widget(list, area);
+}
 
-    frame.render_widget(info, chunks[1]);
+/// Render a progress gauge per objective
+fn render_okr_gauges(frame: &mut Frame, app: &App, area: Rect) {
+    let block = Block::default().borders(Borders::ALL).title(" OKRs ");
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    if app.objectives.is_empty() {
+        let empty = Paragraph::new("No objectives yet").style(Style::default().fg(Color::DarkGray));
+        frame.render_widget(empty, inner);
+        return;
+    }
+
+    let shown = app.objectives.len().min(inner.height as usize);
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(vec![Constraint::Length(1); shown])
+        .split(inner);
+    for (objective, row) in app.objectives.iter().zip(rows.iter()) {
+        let progress = objective.overall_progress().clamp(0.0, 1.0) as f64;
+        let gauge = LineGauge::default()
+            .label(objective.title.clone())
+            .ratio(progress)
+            .filled_style(Style::default().fg(Color::Green))
+            .unfilled_style(Style::default().fg(Color::DarkGray));
+        frame.render_widget(gauge, *row);
+    }
+}
+
+/// Render pulse prompts and feedback requests waiting for the user
+fn render_pending(frame: &mut Frame, app: &App, area: Rect) {
+    let now = Utc::now();
+    let mut items: Vec<ListItem> = app
+        .pulse_due
+        .iter()
+        .map(|due| ListItem::new(due.message(now)).style(Style::default().fg(Color::Yellow)))
+        .collect();
+    items.extend(app.pending_requests.iter().map(|request| {
+        let mut text = format!("Feedback for {}", app.display_name(&request.from));
+        if let Some(topic) = &request.topic {
+            text.push_str(&format!(": {}", topic));
+        }
+        ListItem::new(text)
+    }));
+    let count = items.len();
+    if items.is_empty() {
+        items.push(ListItem::new("Nothing pending").style(Style::default().fg(Color::DarkGray)));
+    }
+
+    let list = List::new(items).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(format!(" Pending ({}) ", count)),
+    );
+    frame.render_widget(list, area);
 }
 
 /// Render the team tab
@@ -225,17 +431,69 @@ fn render_team(frame: &mut Frame, app: &App, area: Rect) {
     frame.render_widget(members_list, chunks[0]);
 
     // Team info
-    let team_info = if let Some(team) = &app.team {
-        let manifesto = team.manifesto.as_deref().unwrap_or("No manifesto defined");
-        let vision = team.vision.as_deref().unwrap_or("No vision defined");
-        format!("Manifesto:\n{}\n\nVision:\n{}", manifesto, vision)
+    let team_info: Vec<Line> = if let Some(team) = &app.team {
+        let heading = Style::default()
+            .fg(Color::Cyan)
+            .add_modifier(Modifier::BOLD);
+        let mut lines = vec![Line::styled("Manifesto", heading)];
+        match &app.manifesto {
+            Some(manifesto) if !manifesto.is_empty() => {
+                if let Some(preamble) = &manifesto.preamble {
+                    lines.extend(preamble.lines().map(|l| Line::raw(l.to_string())));
+                }
+                for (i, principle) in manifesto.principles.iter().enumerate() {
+                    lines.push(Line::raw(""));
+                    lines.push(Line::from(vec![
+                        Span::styled(
+                            format!("{}. {}", i + 1, principle.title),
+                            Style::default().add_modifier(Modifier::BOLD),
+                        ),
+                        Span::styled(
+                            format!("  #{}", principle.id),
+                            Style::default().fg(Color::DarkGray),
+                        ),
+                        Span::styled(
+                            match app.principle_kudos.get(&principle.id) {
+                                Some(&n) if n > 0 => format!("  ★ {} kudos", n),
+                                _ => String::new(),
+                            },
+                            Style::default().fg(Color::Magenta),
+                        ),
+                    ]));
+                    if let Some(description) = &principle.description {
+                        lines.push(Line::raw(format!("   {}", description)));
+                    }
+                }
+            }
+            _ => lines.push(Line::raw("No manifesto defined")),
+        }
+
+        lines.push(Line::raw(""));
+        lines.push(Line::styled("Vision", heading));
+        lines.push(Line::raw(
+            team.vision
+                .clone()
+                .unwrap_or_else(|| "No vision defined".to_string()),
+        ));
+        if !team.archived.is_empty() {
+            lines.push(Line::raw(""));
+            lines.push(Line::styled("Former members", heading));
+            for email in &team.archived {
+                lines.push(Line::raw(format!("  {}", email)));
+            }
+        }
+        lines
     } else {
-        "No team loaded.\n\nCreate a team to define your manifesto and vision.".to_string()
+        vec![
+            Line::raw("No team loaded."),
+            Line::raw(""),
+            Line::raw("Create a team to define your manifesto and vision."),
+        ]
     };
 
     let info = Paragraph::new(team_info)
         .block(Block::default().borders(Borders::ALL).title(" Team Info "))
-        .wrap(Wrap { trim: true });
+        .wrap(Wrap { trim: false });
 
     frame.render_widget(info, chunks[1]);
 }
@@ -317,12 +575,14 @@ fn render_interactions(frame: &mut Frame, app: &App, area: Rect) {
 
                 let prefix = if is_selected { "> " } else { "  " };
                 let person = match app.interactions_view {
-                    InteractionsView::Sent => {
-                        interaction.with.first().map(|s| s.as_str()).unwrap_or("?")
-                    }
-                    InteractionsView::Received => interaction.from.as_str(),
+                    InteractionsView::Sent => match interaction.with.as_slice() {
+                        [] => "?".to_string(),
+                        [one] => one.clone(),
+                        [first, rest @ ..] => format!("{} +{}", first, rest.len()),
+                    },
+                    InteractionsView::Received => interaction.from.clone(),
                 };
-                let date = interaction.timestamp.format("%m/%d").to_string();
+                let date = time::format_in(interaction.timestamp, app.viewer_tz, "%m/%d");
                 let shared_marker = if interaction.shared { " *" } else { "" };
 
                 // Truncate note for list view
@@ -384,16 +644,14 @@ fn render_interactions(frame: &mut Frame, app: &App, area: Rect) {
         )
     } else if let Some(interaction) = interactions_list.get(app.interaction_index) {
         let direction = match app.interactions_view {
-            InteractionsView::Sent => format!(
-                "To: {}",
-                interaction.with.first().map(|s| s.as_str()).unwrap_or("?")
-            ),
+            InteractionsView::Sent => format!("To: {}", interaction.with.join(", ")),
             InteractionsView::Received => format!("From: {}", interaction.from),
         };
-        let date = interaction
-            .timestamp
-            .format("%B %d, %Y at %H:%M")
-            .to_string();
+        let date = time::format_in(
+            interaction.timestamp,
+            app.viewer_tz,
+            "%B %d, %Y at %H:%M %Z",
+        );
         let shared_text = if interaction.shared {
             "Shared with team"
         } else {
@@ -441,12 +699,124 @@ fn render_okrs(frame: &mut Frame, _app: &App, area: Rect) {
     frame.render_widget(paragraph, area);
 }
 
+/// Render the history tab: commits on the left, what the selected one
+/// changed on the right
+fn render_history(frame: &mut Frame, app: &App, area: Rect) {
+    let chunks = if area.width < NARROW_WIDTH {
+        Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(area)
+    } else {
+        Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(45), Constraint::Percentage(55)])
+            .split(area)
+    };
+
+    if app.history.is_empty() {
+        let paragraph = Paragraph::new(
+            "No changes to .team/ in the git history yet.\n\n\
+             Commit the team folder to git to see who changed the manifesto, \
+             roles and objectives, field by field.",
+        )
+        .block(Block::default().borders(Borders::ALL).title(" History "))
+        .wrap(Wrap { trim: true });
+        frame.render_widget(paragraph, area);
+        return;
+    }
+
+    let items: Vec<ListItem> = app
+        .history
+        .iter()
+        .enumerate()
+        .map(|(idx, entry)| {
+            let selected = idx == app.selected_index;
+            let style = if selected {
+                Style::default().fg(Color::Yellow).bold()
+            } else {
+                Style::default()
+            };
+            let prefix = if selected { "› " } else { "  " };
+            ListItem::new(Line::from(vec![
+                Span::styled(
+                    format!(
+                        "{}{} ",
+                        prefix,
+                        time::format_in(entry.commit.date, app.viewer_tz, "%Y-%m-%d")
+                    ),
+                    Style::default().fg(Color::DarkGray),
+                ),
+                Span::styled(format!("{}: ", entry.author_name()), style),
+                Span::styled(entry.commit.subject.clone(), style),
+            ]))
+        })
+        .collect();
+    let list = List::new(items).block(Block::default().borders(Borders::ALL).title(" History "));
+    frame.render_widget(list, chunks[0]);
+
+    let Some(entry) = app.history.get(app.selected_index) else {
+        return;
+    };
+    let heading = Style::default()
+        .fg(Color::Cyan)
+        .add_modifier(Modifier::BOLD);
+    let mut lines = vec![
+        Line::styled(entry.commit.subject.clone(), heading),
+        Line::styled(
+            format!(
+                "{} <{}>, {}, {}",
+                entry.author_name(),
+                entry.commit.author_email,
+                time::format_in(entry.commit.date, app.viewer_tz, "%Y-%m-%d %H:%M"),
+                &entry.commit.hash[..8]
+            ),
+            Style::default().fg(Color::DarkGray),
+        ),
+    ];
+    for file in &entry.files {
+        lines.push(Line::raw(""));
+        lines.push(Line::styled(
+            file.path.clone(),
+            Style::default().add_modifier(Modifier::BOLD),
+        ));
+        if file.changes.is_empty() {
+            lines.push(Line::styled(
+                "  (formatting only)",
+                Style::default().fg(Color::DarkGray),
+            ));
+        }
+        for change in &file.changes {
+            let color = match (&change.old, &change.new) {
+                (None, Some(_)) => Color::Green,
+                (Some(_), None) => Color::Red,
+                _ => Color::Yellow,
+            };
+            lines.push(Line::styled(
+                format!("  {}", change),
+                Style::default().fg(color),
+            ));
+        }
+    }
+    let details = Paragraph::new(lines)
+        .block(Block::default().borders(Borders::ALL).title(" Changes "))
+        .wrap(Wrap { trim: false });
+    frame.render_widget(details, chunks[1]);
+}
+
 /// Render the settings tab
 fn render_settings(frame: &mut Frame, app: &App, area: Rect) {
     let initialized_status = if app.is_initialized() { "Yes" } else { "No" };
+    let user_status = app
+        .current_user
+        .as_deref()
+        .unwrap_or("Not logged in (read-only)");
 
     let text = format!(
-        "Team initialized: {}\n\n\
+        "Team initialized: {}\n\
+         Logged in as: {}\n\
+         Timezone: {} (set in your profile.yaml)\n\
+         Press 'c' to change your pincode\n\n\
          Configuration:\n\
          • Storage: .team/ and .personal/ directories\n\
          • Format: YAML files\n\
@@ -455,7 +825,9 @@ fn render_settings(frame: &mut Frame, app: &App, area: Rect) {
          • Team data: .team/\n\
          • Personal data: .personal/ (gitignored)\n\
          • Published: Configured in .team/config.yaml",
-        initialized_status
+        initialized_status,
+        user_status,
+        app.viewer_tz.name()
     );
 
     let paragraph = Paragraph::new(text)
@@ -465,25 +837,69 @@ fn render_settings(frame: &mut Frame, app: &App, area: Rect) {
     frame.render_widget(paragraph, area);
 }
 
+/// Warn that a cloud sync client left conflict copies that need resolving
+fn render_sync_banner(frame: &mut Frame, app: &App, area: Rect) {
+    let text = format!(
+        " ⚠ {} sync conflict cop{} found; run 'interactions conflicts' to resolve",
+        app.sync_conflicts.len(),
+        if app.sync_conflicts.len() == 1 {
+            "y"
+        } else {
+            "ies"
+        }
+    );
+    let banner = Paragraph::new(text).style(
+        Style::default()
+            .fg(Color::Black)
+            .bg(Color::Yellow)
+            .add_modifier(Modifier::BOLD),
+    );
+    frame.render_widget(banner, area);
+}
+
 /// Render the footer with help text and working directory
 fn render_footer(frame: &mut Frame, app: &App, area: Rect) {
     let help_text = if app.is_init_mode()
         || app.is_add_member_mode()
         || app.is_kudos_mode()
         || app.is_feedback_mode()
+        || app.is_log_mode()
+        || app.is_okr_update_mode()
+        || app.is_pincode_mode()
     {
         "Enter: submit | Esc: cancel".to_string()
+    } else if app.is_pulse_mode() {
+        "↑↓: choose | Enter/1-9: open | s: snooze a day | Esc: dismiss".to_string()
     } else if app.is_navigate_dir_mode() {
         "↑↓: navigate | Enter: open | /: type path | Space: select folder | Esc: cancel".to_string()
     } else if let Some(msg) = &app.status_message {
         msg.clone()
+    } else if app.current_user.is_none() && app.team.is_some() {
+        "Read-only | l: log in | Tab: switch | ↑↓/jk: navigate | o: open folder | q: quit"
+            .to_string()
     } else if app.current_tab == Tab::Interactions {
         "Tab: switch | ↑↓: browse | ←→: sent/recv | 1/2: kudos/feedback | q: quit".to_string()
+    } else if app.is_member_action_mode() {
+        "y/Enter: confirm | n/Esc: cancel".to_string()
+    } else if app.current_tab == Tab::Team && app.is_current_user_leader() {
+        "Tab: switch | ↑↓: navigate | a: add | p: promote/demote | x: archive | d: remove | r: reset pincode | q: quit"
+            .to_string()
+    } else if app.current_tab == Tab::History {
+        "Tab: switch | ↑↓/jk: browse changes | o: open folder | q: quit".to_string()
+    } else if app.current_tab == Tab::Settings {
+        "Tab: switch | c: change pincode | o: open folder | l: lock | q: quit".to_string()
+    } else if app.current_tab == Tab::Dashboard && !app.pulse_due.is_empty() {
+        "Tab: switch | ↑↓/jk: navigate | Enter: select | u: pulse prompts | f: ask for feedback | l: lock | q: quit"
+            .to_string()
+    } else if app.current_tab == Tab::Dashboard && app.current_user.is_some() {
+        "Tab: switch | ↑↓/jk: navigate | Enter: select | f: ask for feedback | l: lock | q: quit"
+            .to_string()
     } else if app.current_tab == Tab::Team && app.team.is_some() {
         "Tab: switch | ↑↓/jk: navigate | Enter: select | a: add member | o: open folder | q: quit"
             .to_string()
     } else {
-        "Tab: switch | ↑↓/jk: navigate | Enter: select | o: open folder | q: quit".to_string()
+        "Tab: switch | ↑↓/jk: navigate | Enter: select | o: open folder | l: lock | q: quit"
+            .to_string()
     };
 
     // Create a two-part footer: help on left, path on right
@@ -719,6 +1135,258 @@ fn render_add_member_wizard(frame: &mut Frame, app: &App) {
     }
 }
 
+/// Render the login screen, hiding everything behind it
+fn render_login_screen(frame: &mut Frame, app: &App) {
+    let Some(state) = &app.login_state else {
+        return;
+    };
+
+    let area = frame.area();
+    frame.render_widget(Clear, area);
+
+    // Calculate centered popup area
+    let popup_width = 50.min(area.width.saturating_sub(4));
+    let popup_height = 16.min(area.height.saturating_sub(2));
+    let popup_x = (area.width.saturating_sub(popup_width)) / 2;
+    let popup_y = (area.height.saturating_sub(popup_height)) / 2;
+    let popup_area = Rect::new(popup_x, popup_y, popup_width, popup_height);
+
+    let title = if state.locked {
+        " Session Locked ".to_string()
+    } else {
+        format!(" Log in to {} ", app.team_name())
+    };
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(title)
+        .title_style(Style::default().fg(Color::Cyan).bold())
+        .border_style(Style::default().fg(Color::Cyan));
+
+    let inner_area = block.inner(popup_area);
+    frame.render_widget(block, popup_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(3),    // Member list or selected member
+            Constraint::Length(3), // Pincode input
+            Constraint::Length(1), // Error message
+            Constraint::Length(1), // Help text
+        ])
+        .split(inner_area);
+
+    match state.step {
+        LoginStep::SelectMember => {
+            let items: Vec<ListItem> = if state.members.is_empty() {
+                vec![ListItem::new("  No members found").style(Style::default().fg(Color::DarkGray))]
+            } else {
+                state
+                    .members
+                    .iter()
+                    .enumerate()
+                    .map(|(i, member)| {
+                        let is_selected = i == state.selected_index;
+                        let style = if is_selected {
+                            Style::default().fg(Color::Yellow).bold()
+                        } else {
+                            Style::default()
+                        };
+                        let prefix = if is_selected { "› " } else { "  " };
+                        ListItem::new(format!("{}{}", prefix, member.display_name())).style(style)
+                    })
+                    .collect()
+            };
+            let list = List::new(items).block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(" Who are you? "),
+            );
+            frame.render_widget(list, chunks[0]);
+        }
+        LoginStep::Pincode => {
+            let who = state
+                .selected_member()
+                .map(|m| format!("{}\n{}", m.display_name(), m.email))
+                .unwrap_or_default();
+            let info = Paragraph::new(who)
+                .alignment(Alignment::Center)
+                .wrap(Wrap { trim: true });
+            frame.render_widget(info, chunks[0]);
+
+            let input = Paragraph::new(format!("{}█", "*".repeat(state.input_buffer.len())))
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .title(" Pincode ")
+                        .border_style(Style::default().fg(Color::White)),
+                )
+                .style(Style::default().fg(Color::Yellow));
+            frame.render_widget(input, chunks[1]);
+        }
+    }
+
+    if let Some(secs) = state
+        .wait_secs()
+        .filter(|_| state.step == LoginStep::Pincode)
+    {
+        let wait = format!("Too many failed attempts, wait {}s", secs);
+        let wait_widget = Paragraph::new(wait).style(Style::default().fg(Color::Red));
+        frame.render_widget(wait_widget, chunks[2]);
+    } else if let Some(error) = &state.error_message {
+        let error_widget = Paragraph::new(error.as_str()).style(Style::default().fg(Color::Red));
+        frame.render_widget(error_widget, chunks[2]);
+    }
+
+    let help = match (state.step, state.locked) {
+        (LoginStep::SelectMember, _) => "↑↓: select | Enter: continue | Esc: read-only | q: quit",
+        (LoginStep::Pincode, false) => "Enter: log in | Tab: reset token | Esc: back",
+        (LoginStep::Pincode, true) => "Enter: unlock | Tab: reset token | Esc: switch user",
+    };
+    let help_widget = Paragraph::new(help).style(Style::default().fg(Color::DarkGray));
+    frame.render_widget(help_widget, chunks[3]);
+}
+
+/// Render the change/reset pincode wizard as a modal overlay
+fn render_pincode_wizard(frame: &mut Frame, app: &App) {
+    let Some(state) = &app.pincode_state else {
+        return;
+    };
+
+    // Calculate centered popup area
+    let area = frame.area();
+    let popup_width = 54.min(area.width.saturating_sub(4));
+    let popup_height = 10.min(area.height.saturating_sub(4));
+    let popup_x = (area.width.saturating_sub(popup_width)) / 2;
+    let popup_y = (area.height.saturating_sub(popup_height)) / 2;
+    let popup_area = Rect::new(popup_x, popup_y, popup_width, popup_height);
+
+    // Clear the area behind the popup
+    frame.render_widget(Clear, popup_area);
+
+    let title = match &state.mode {
+        PincodeMode::Change => " Change Pincode ".to_string(),
+        PincodeMode::Redeem { email } => format!(" Reset Pincode: {} ", email),
+    };
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(title)
+        .title_style(Style::default().fg(Color::Yellow).bold())
+        .border_style(Style::default().fg(Color::Yellow));
+
+    let inner_area = block.inner(popup_area);
+    frame.render_widget(block, popup_area);
+
+    // Layout inside the popup
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(2), // Progress indicator
+            Constraint::Length(2), // Prompt
+            Constraint::Length(3), // Input field
+            Constraint::Min(0),    // Error or info
+        ])
+        .split(inner_area);
+
+    // Progress indicator
+    let steps = state.steps();
+    let current_step_idx = steps.iter().position(|s| *s == state.step).unwrap_or(0);
+    let labels = match state.mode {
+        PincodeMode::Change => ["Current", "New", "Confirm"],
+        PincodeMode::Redeem { .. } => ["Token", "New", "Confirm"],
+    };
+    let progress: String = labels
+        .iter()
+        .enumerate()
+        .map(|(i, s)| {
+            if i == current_step_idx {
+                format!("[{}]", s)
+            } else if i < current_step_idx {
+                format!("✓{}", s)
+            } else {
+                format!(" {} ", s)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" → ");
+
+    let progress_widget = Paragraph::new(progress)
+        .style(Style::default().fg(Color::Cyan))
+        .alignment(Alignment::Center);
+    frame.render_widget(progress_widget, chunks[0]);
+
+    // Prompt
+    let prompt = Paragraph::new(state.step.prompt())
+        .style(Style::default().fg(Color::White))
+        .alignment(Alignment::Left);
+    frame.render_widget(prompt, chunks[1]);
+
+    // Input field, masked for pincodes
+    let input_block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::White));
+
+    let display_value = if state.step.is_secret() {
+        "*".repeat(state.input_buffer.len())
+    } else {
+        state.input_buffer.clone()
+    };
+    let input = Paragraph::new(format!("{}█", display_value))
+        .block(input_block)
+        .style(Style::default().fg(Color::Yellow));
+    frame.render_widget(input, chunks[2]);
+
+    // Error message or info
+    if let Some(error) = &state.error_message {
+        let error_widget = Paragraph::new(error.as_str())
+            .style(Style::default().fg(Color::Red))
+            .wrap(Wrap { trim: true });
+        frame.render_widget(error_widget, chunks[3]);
+    } else if matches!(state.mode, PincodeMode::Redeem { .. }) {
+        let info_widget = Paragraph::new("Ask a team leader for a one-time reset token")
+            .style(Style::default().fg(Color::DarkGray));
+        frame.render_widget(info_widget, chunks[3]);
+    }
+}
+
+/// Render the member action confirmation as a modal overlay
+fn render_member_action_dialog(frame: &mut Frame, app: &App) {
+    let Some(state) = &app.member_action_state else {
+        return;
+    };
+
+    // Calculate centered popup area
+    let area = frame.area();
+    let popup_width = 50.min(area.width.saturating_sub(4));
+    let popup_height = 9.min(area.height.saturating_sub(4));
+    let popup_x = (area.width.saturating_sub(popup_width)) / 2;
+    let popup_y = (area.height.saturating_sub(popup_height)) / 2;
+    let popup_area = Rect::new(popup_x, popup_y, popup_width, popup_height);
+
+    // Clear the area behind the popup
+    frame.render_widget(Clear, popup_area);
+
+    let color = match state.action {
+        MemberAction::Promote | MemberAction::Demote => Color::Yellow,
+        MemberAction::Archive | MemberAction::Remove => Color::Red,
+    };
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(format!(" {} ", state.action.title()))
+        .title_style(Style::default().fg(color).bold())
+        .border_style(Style::default().fg(color));
+
+    let text = format!(
+        "{}\n\n{}\n\nContinue? (y/n)",
+        state.email,
+        state.action.description()
+    );
+
+    let dialog = Paragraph::new(text).block(block).wrap(Wrap { trim: true });
+    frame.render_widget(dialog, popup_area);
+}
+
 /// Render the directory navigation wizard as a modal overlay
 fn render_navigate_dir_wizard(frame: &mut Frame, app: &App) {
     let Some(state) = &app.navigate_dir_state else {
@@ -852,7 +1520,7 @@ fn render_kudos_wizard(frame: &mut Frame, app: &App) {
     // Calculate centered popup area
     let area = frame.area();
     let popup_width = 60.min(area.width.saturating_sub(4));
-    let popup_height = 14.min(area.height.saturating_sub(4));
+    let popup_height = 18.min(area.height.saturating_sub(4));
     let popup_x = (area.width.saturating_sub(popup_width)) / 2;
     let popup_y = (area.height.saturating_sub(popup_height)) / 2;
     let popup_area = Rect::new(popup_x, popup_y, popup_width, popup_height);
@@ -882,11 +1550,12 @@ fn render_kudos_wizard(frame: &mut Frame, app: &App) {
         .split(inner_area);
 
     // Progress indicator
-    let steps = ["To", "Message", "Share?"];
+    let steps = ["To", "Message", "Value", "Share?"];
     let current_step_idx = match state.step {
         KudosStep::Recipient => 0,
         KudosStep::Note => 1,
-        KudosStep::Share => 2,
+        KudosStep::Principle => 2,
+        KudosStep::Share => 3,
     };
     let progress: String = steps
         .iter()
@@ -908,8 +1577,17 @@ fn render_kudos_wizard(frame: &mut Frame, app: &App) {
         .alignment(Alignment::Center);
     frame.render_widget(progress_widget, chunks[0]);
 
-    // Prompt
-    let prompt = Paragraph::new(state.step.prompt())
+    // Prompt, with the recipients chosen so far
+    let mut prompt_text = state.step.prompt().to_string();
+    if !state.recipients.is_empty() {
+        let names: Vec<&str> = state
+            .recipients
+            .iter()
+            .map(|email| state.picker.label(email))
+            .collect();
+        prompt_text.push_str(&format!("\nTo: {}", names.join(", ")));
+    }
+    let prompt = Paragraph::new(prompt_text)
         .style(Style::default().fg(Color::White))
         .alignment(Alignment::Left);
     frame.render_widget(prompt, chunks[1]);
@@ -924,25 +1602,80 @@ fn render_kudos_wizard(frame: &mut Frame, app: &App) {
         .style(Style::default().fg(Color::Yellow));
     frame.render_widget(input, chunks[2]);
 
+    // Suggestions below the message while picking a recipient
+    // and the manifesto principles while picking a value
+    let (message_area, list_area) =
+        if matches!(state.step, KudosStep::Recipient | KudosStep::Principle) {
+            let parts = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Length(2), Constraint::Min(0)])
+                .split(chunks[3]);
+            (parts[0], Some(parts[1]))
+        } else {
+            (chunks[3], None)
+        };
+    if let Some(area) = list_area {
+        if state.step == KudosStep::Recipient {
+            render_recipient_suggestions(frame, &state.picker, area);
+        } else if let Some(manifesto) = &app.manifesto {
+            let items: Vec<ListItem> = manifesto
+                .principles
+                .iter()
+                .enumerate()
+                .map(|(i, p)| ListItem::new(format!("{}. {}", i + 1, p.title)))
+                .collect();
+            frame.render_widget(List::new(items), area);
+        }
+    }
+
     // Error message or info
     if let Some(error) = &state.error_message {
         let error_widget = Paragraph::new(error.as_str())
             .style(Style::default().fg(Color::Red))
-            .alignment(Alignment::Left);
-        frame.render_widget(error_widget, chunks[3]);
+            .alignment(Alignment::Left)
+            .wrap(Wrap { trim: true });
+        frame.render_widget(error_widget, message_area);
     } else {
         let info = match state.step {
-            KudosStep::Recipient => "Enter the person's email or name",
+            KudosStep::Recipient if state.recipients.is_empty() => {
+                "Type a name or email | ↑↓: pick a suggestion"
+            }
+            KudosStep::Recipient => "Enter: add another, or on empty input continue | ⌫: remove",
             KudosStep::Note => "Express your appreciation",
+            KudosStep::Principle => "e.g. 1 or 1,3 - links the kudos to the manifesto",
             KudosStep::Share => "y = visible to team, n = private",
         };
         let info_widget = Paragraph::new(info)
             .style(Style::default().fg(Color::DarkGray))
             .alignment(Alignment::Left);
-        frame.render_widget(info_widget, chunks[3]);
+        frame.render_widget(info_widget, message_area);
     }
 }
 
+/// Render the recipient suggestions of a kudos or feedback wizard
+fn render_recipient_suggestions(frame: &mut Frame, picker: &RecipientPicker, area: Rect) {
+    let items: Vec<ListItem> = picker
+        .suggestions
+        .iter()
+        .enumerate()
+        .map(|(i, member)| {
+            let is_selected = i == picker.selected_index;
+            let style = if is_selected {
+                Style::default().fg(Color::Yellow).bold()
+            } else {
+                Style::default().fg(Color::Gray)
+            };
+            let prefix = if is_selected { "› " } else { "  " };
+            let label = match &member.name {
+                Some(name) => format!("{}{} <{}>", prefix, name, member.email),
+                None => format!("{}{}", prefix, member.email),
+            };
+            ListItem::new(label).style(style)
+        })
+        .collect();
+    frame.render_widget(List::new(items), area);
+}
+
 /// Render the feedback wizard as a modal overlay
 fn render_feedback_wizard(frame: &mut Frame, app: &App) {
     let Some(state) = &app.feedback_state else {
@@ -952,7 +1685,7 @@ fn render_feedback_wizard(frame: &mut Frame, app: &App) {
     // Calculate centered popup area
     let area = frame.area();
     let popup_width = 60.min(area.width.saturating_sub(4));
-    let popup_height = 14.min(area.height.saturating_sub(4));
+    let popup_height = 18.min(area.height.saturating_sub(4));
     let popup_x = (area.width.saturating_sub(popup_width)) / 2;
     let popup_y = (area.height.saturating_sub(popup_height)) / 2;
     let popup_area = Rect::new(popup_x, popup_y, popup_width, popup_height);
@@ -961,9 +1694,14 @@ fn render_feedback_wizard(frame: &mut Frame, app: &App) {
     frame.render_widget(Clear, popup_area);
 
     // Render the popup block
+    let title = if state.request {
+        " Ask for Feedback "
+    } else {
+        " Share Feedback "
+    };
     let block = Block::default()
         .borders(Borders::ALL)
-        .title(" Share Feedback ")
+        .title(title)
         .title_style(Style::default().fg(Color::Blue).bold())
         .border_style(Style::default().fg(Color::Blue));
 
@@ -982,7 +1720,11 @@ fn render_feedback_wizard(frame: &mut Frame, app: &App) {
         .split(inner_area);
 
     // Progress indicator
-    let steps = ["To", "Message", "Share?"];
+    let steps: &[&str] = if state.request {
+        &["From", "Topic"]
+    } else {
+        &["To", "Message", "Share?"]
+    };
     let current_step_idx = match state.step {
         FeedbackStep::Recipient => 0,
         FeedbackStep::Note => 1,
@@ -1008,8 +1750,18 @@ fn render_feedback_wizard(frame: &mut Frame, app: &App) {
         .alignment(Alignment::Center);
     frame.render_widget(progress_widget, chunks[0]);
 
-    // Prompt
-    let prompt = Paragraph::new(state.step.prompt())
+    // Prompt, with the recipients chosen so far
+    let mut prompt_text = state.step.prompt(state.request).to_string();
+    if !state.recipients.is_empty() {
+        let names: Vec<&str> = state
+            .recipients
+            .iter()
+            .map(|email| state.picker.label(email))
+            .collect();
+        let label = if state.request { "Asking" } else { "To" };
+        prompt_text.push_str(&format!("\n{}: {}", label, names.join(", ")));
+    }
+    let prompt = Paragraph::new(prompt_text)
         .style(Style::default().fg(Color::White))
         .alignment(Alignment::Left);
     frame.render_widget(prompt, chunks[1]);
@@ -1024,21 +1776,291 @@ fn render_feedback_wizard(frame: &mut Frame, app: &App) {
         .style(Style::default().fg(Color::Yellow));
     frame.render_widget(input, chunks[2]);
 
+    // Suggestions below the message while picking a recipient
+    let (message_area, suggestions_area) = if state.step == FeedbackStep::Recipient {
+        let parts = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(2), Constraint::Min(0)])
+            .split(chunks[3]);
+        (parts[0], Some(parts[1]))
+    } else {
+        (chunks[3], None)
+    };
+    if let Some(area) = suggestions_area {
+        render_recipient_suggestions(frame, &state.picker, area);
+    }
+
     // Error message or info
     if let Some(error) = &state.error_message {
         let error_widget = Paragraph::new(error.as_str())
             .style(Style::default().fg(Color::Red))
-            .alignment(Alignment::Left);
-        frame.render_widget(error_widget, chunks[3]);
+            .alignment(Alignment::Left)
+            .wrap(Wrap { trim: true });
+        frame.render_widget(error_widget, message_area);
     } else {
         let info = match state.step {
-            FeedbackStep::Recipient => "Enter the person's email or name",
+            FeedbackStep::Recipient if state.recipients.is_empty() => {
+                "Type a name or email | ↑↓: pick a suggestion"
+            }
+            FeedbackStep::Recipient => "Enter: add another, or on empty input continue | ⌫: remove",
+            FeedbackStep::Note if state.request => "Optional; Enter to send the request",
             FeedbackStep::Note => "Be constructive and specific",
             FeedbackStep::Share => "y = visible to team, n = private",
         };
         let info_widget = Paragraph::new(info)
             .style(Style::default().fg(Color::DarkGray))
             .alignment(Alignment::Left);
-        frame.render_widget(info_widget, chunks[3]);
+        frame.render_widget(info_widget, message_area);
+    }
+}
+
+/// Render the check-in, retrospective and apology wizard as a modal overlay
+fn render_log_wizard(frame: &mut Frame, app: &App) {
+    let Some(state) = &app.log_state else {
+        return;
+    };
+
+    // Calculate centered popup area
+    let area = frame.area();
+    let popup_width = 60.min(area.width.saturating_sub(4));
+    let popup_height = 18.min(area.height.saturating_sub(4));
+    let popup_x = (area.width.saturating_sub(popup_width)) / 2;
+    let popup_y = (area.height.saturating_sub(popup_height)) / 2;
+    let popup_area = Rect::new(popup_x, popup_y, popup_width, popup_height);
+
+    frame.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(format!(" Log {} ", state.kind.label()))
+        .title_style(Style::default().fg(Color::Green).bold())
+        .border_style(Style::default().fg(Color::Green));
+
+    let inner_area = block.inner(popup_area);
+    frame.render_widget(block, popup_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(2), // Progress indicator
+            Constraint::Length(2), // Prompt
+            Constraint::Length(3), // Input field
+            Constraint::Min(0),    // Error or info
+        ])
+        .split(inner_area);
+
+    // Progress indicator
+    let steps = ["With", "Note", "Share?"];
+    let current_step_idx = match state.step {
+        LogStep::With => 0,
+        LogStep::Note => 1,
+        LogStep::Share => 2,
+    };
+    let progress: String = steps
+        .iter()
+        .enumerate()
+        .map(|(i, s)| {
+            if i == current_step_idx {
+                format!("[{}]", s)
+            } else if i < current_step_idx {
+                format!("✓{}", s)
+            } else {
+                format!(" {} ", s)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" → ");
+    let progress_widget = Paragraph::new(progress)
+        .style(Style::default().fg(Color::Cyan))
+        .alignment(Alignment::Center);
+    frame.render_widget(progress_widget, chunks[0]);
+
+    // Prompt, with the participants chosen so far
+    let mut prompt_text = state.step.prompt(state.kind).to_string();
+    if !state.recipients.is_empty() {
+        let names: Vec<&str> = state
+            .recipients
+            .iter()
+            .map(|email| state.picker.label(email))
+            .collect();
+        prompt_text.push_str(&format!("\nWith: {}", names.join(", ")));
     }
+    let prompt = Paragraph::new(prompt_text)
+        .style(Style::default().fg(Color::White))
+        .alignment(Alignment::Left);
+    frame.render_widget(prompt, chunks[1]);
+
+    // Input field
+    let input_block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::White));
+    let input = Paragraph::new(format!("{}█", state.input_buffer))
+        .block(input_block)
+        .style(Style::default().fg(Color::Yellow));
+    frame.render_widget(input, chunks[2]);
+
+    // Suggestions below the message while picking participants
+    let (message_area, suggestions_area) = if state.step == LogStep::With {
+        let parts = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(2), Constraint::Min(0)])
+            .split(chunks[3]);
+        (parts[0], Some(parts[1]))
+    } else {
+        (chunks[3], None)
+    };
+    if let Some(area) = suggestions_area {
+        render_recipient_suggestions(frame, &state.picker, area);
+    }
+
+    if let Some(error) = &state.error_message {
+        let error_widget = Paragraph::new(error.as_str())
+            .style(Style::default().fg(Color::Red))
+            .alignment(Alignment::Left)
+            .wrap(Wrap { trim: true });
+        frame.render_widget(error_widget, message_area);
+    } else {
+        let info = match state.step {
+            LogStep::With if state.recipients.is_empty() => {
+                "Type a name or email | ↑↓: pick a suggestion"
+            }
+            LogStep::With => "Enter: add another, or on empty input continue | ⌫: remove",
+            LogStep::Note => "A sentence or two is enough",
+            LogStep::Share => "y = visible to team, n = private",
+        };
+        let info_widget = Paragraph::new(info)
+            .style(Style::default().fg(Color::DarkGray))
+            .alignment(Alignment::Left);
+        frame.render_widget(info_widget, message_area);
+    }
+}
+
+/// Render the OKR update wizard as a modal overlay
+fn render_okr_update_wizard(frame: &mut Frame, app: &App) {
+    let Some(state) = &app.okr_update_state else {
+        return;
+    };
+
+    let area = frame.area();
+    let popup_width = 60.min(area.width.saturating_sub(4));
+    let popup_height = 14.min(area.height.saturating_sub(4));
+    let popup_x = (area.width.saturating_sub(popup_width)) / 2;
+    let popup_y = (area.height.saturating_sub(popup_height)) / 2;
+    let popup_area = Rect::new(popup_x, popup_y, popup_width, popup_height);
+
+    frame.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(format!(" Update {} ", state.objective.title))
+        .title_style(Style::default().fg(Color::Magenta).bold())
+        .border_style(Style::default().fg(Color::Magenta));
+
+    let inner_area = block.inner(popup_area);
+    frame.render_widget(block, popup_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(2), // Progress indicator
+            Constraint::Length(3), // Prompt
+            Constraint::Length(3), // Input field
+            Constraint::Min(0),    // Error or info
+        ])
+        .split(inner_area);
+
+    let total = state.objective.key_results.len();
+    let position = if total == 0 {
+        "No key results".to_string()
+    } else {
+        format!("Key result {} of {}", (state.index + 1).min(total), total)
+    };
+    let position_widget = Paragraph::new(position)
+        .style(Style::default().fg(Color::Cyan))
+        .alignment(Alignment::Center);
+    frame.render_widget(position_widget, chunks[0]);
+
+    let prompt_text = match state.objective.key_results.get(state.index) {
+        Some(kr) => format!(
+            "{}\nProgress in % (now {:.0}%)",
+            kr.description,
+            kr.progress * 100.0
+        ),
+        None => "Nothing to measure yet. Press Enter to mark it reviewed.".to_string(),
+    };
+    let prompt = Paragraph::new(prompt_text)
+        .style(Style::default().fg(Color::White))
+        .wrap(Wrap { trim: true });
+    frame.render_widget(prompt, chunks[1]);
+
+    let input_block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::White));
+    let input = Paragraph::new(format!("{}█", state.input_buffer))
+        .block(input_block)
+        .style(Style::default().fg(Color::Yellow));
+    frame.render_widget(input, chunks[2]);
+
+    let (message, style) = match &state.error_message {
+        Some(error) => (error.as_str(), Style::default().fg(Color::Red)),
+        None => (
+            "Enter on empty input keeps the current progress",
+            Style::default().fg(Color::DarkGray),
+        ),
+    };
+    let message_widget = Paragraph::new(message)
+        .style(style)
+        .wrap(Wrap { trim: true });
+    frame.render_widget(message_widget, chunks[3]);
+}
+
+/// Render the pulse prompts due for the current user as a modal overlay
+fn render_pulse_prompts(frame: &mut Frame, app: &App) {
+    let Some(state) = &app.pulse_state else {
+        return;
+    };
+
+    let area = frame.area();
+    let popup_width = 64.min(area.width.saturating_sub(4));
+    let popup_height = (app.pulse_due.len() as u16 + 5).min(area.height.saturating_sub(4));
+    let popup_x = (area.width.saturating_sub(popup_width)) / 2;
+    let popup_y = (area.height.saturating_sub(popup_height)) / 2;
+    let popup_area = Rect::new(popup_x, popup_y, popup_width, popup_height);
+
+    frame.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(" Pulse ")
+        .title_style(Style::default().fg(Color::Yellow).bold())
+        .border_style(Style::default().fg(Color::Yellow));
+    let inner_area = block.inner(popup_area);
+    frame.render_widget(block, popup_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(2)])
+        .split(inner_area);
+
+    let now = Utc::now();
+    let items: Vec<ListItem> = app
+        .pulse_due
+        .iter()
+        .enumerate()
+        .map(|(i, due)| {
+            let style = if i == state.selected {
+                Style::default().fg(Color::Yellow).bold()
+            } else {
+                Style::default()
+            };
+            let prefix = if i == state.selected { "› " } else { "  " };
+            ListItem::new(format!("{}{}. {}", prefix, i + 1, due.message(now))).style(style)
+        })
+        .collect();
+    frame.render_widget(List::new(items), chunks[0]);
+
+    let info = Paragraph::new("Enter or a number opens the prompt, s snoozes it until tomorrow")
+        .style(Style::default().fg(Color::DarkGray))
+        .wrap(Wrap { trim: true });
+    frame.render_widget(info, chunks[1]);
 }